@@ -1,55 +1,112 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::endpoints::openapi::RouteDoc;
+
 use serde::Deserialize;
+use thiserror::Error;
 
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::{
+    collections::{HashMap, HashSet},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 pub(crate) const DEFAULT_BINDING_PORT: u16 = 14265;
 pub(crate) const DEFAULT_BINDING_IP_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
 
+/// A route of the REST API along with the HTTP method its filter accepts and the documentation declared next to it.
+pub(crate) struct Route {
+    pub(crate) name: &'static str,
+    pub(crate) path: &'static str,
+    pub(crate) method: &'static str,
+    pub(crate) doc: &'static RouteDoc,
+}
+
+macro_rules! routes {
+    ($($name:ident: $method:literal $path:literal => $($module:ident)::+,)*) => {
+        $(pub(crate) const $name: &str = $path;)*
+
+        /// The registry of all available routes.
+        pub(crate) const ROUTES: &[Route] = &[$(Route {
+            name: stringify!($name),
+            path: $name,
+            method: $method,
+            doc: &crate::endpoints::routes::$($module)::+::DOC,
+        },)*];
+    };
+}
+
 // all available routes
-pub(crate) const ROUTE_ADD_PEER: &str = "/api/v1/peers";
-pub(crate) const ROUTE_BALANCE_BECH32: &str = "/api/v1/addresses/:address";
-pub(crate) const ROUTE_BALANCE_ED25519: &str = "/api/v1/addresses/ed25519/:address";
-pub(crate) const ROUTE_HEALTH: &str = "/health";
-pub(crate) const ROUTE_INFO: &str = "/api/v1/info";
-pub(crate) const ROUTE_MESSAGE: &str = "/api/v1/messages/:messageId";
-pub(crate) const ROUTE_MESSAGE_CHILDREN: &str = "/api/v1/messages/:messageId/children";
-pub(crate) const ROUTE_MESSAGE_METADATA: &str = "/api/v1/messages/:messageId/metadata";
-pub(crate) const ROUTE_MESSAGE_RAW: &str = "/api/v1/messages/:messageId/raw";
-pub(crate) const ROUTE_MESSAGES_FIND: &str = "/api/v1/messages";
-pub(crate) const ROUTE_MILESTONE: &str = "/api/v1/milestones/:milestoneIndex";
-pub(crate) const ROUTE_MILESTONE_UTXO_CHANGES: &str = "/api/v1/milestones/:milestoneIndex/utxo-changes";
-pub(crate) const ROUTE_OUTPUT: &str = "/api/v1/outputs/:outputId";
-pub(crate) const ROUTE_OUTPUTS_BECH32: &str = "/api/v1/addresses/:address/outputs";
-pub(crate) const ROUTE_OUTPUTS_ED25519: &str = "/api/v1/addresses/ed25519/:address/outputs";
-pub(crate) const ROUTE_PEER: &str = "/api/v1/peers/:peerId";
-pub(crate) const ROUTE_PEERS: &str = "/api/v1/peers";
-pub(crate) const ROUTE_REMOVE_PEER: &str = "/api/v1/peers/:peerId";
-pub(crate) const ROUTE_SUBMIT_MESSAGE: &str = "/api/v1/messages";
-pub(crate) const ROUTE_SUBMIT_MESSAGE_RAW: &str = "/api/v1/messages";
-pub(crate) const ROUTE_TIPS: &str = "/api/v1/tips";
-pub(crate) const ROUTE_RECEIPTS: &str = "/api/v1/receipts";
-pub(crate) const ROUTE_RECEIPTS_AT: &str = "/api/v1/receipts/:milestoneIndex";
-pub(crate) const ROUTE_TREASURY: &str = "/api/v1/treasury";
-pub(crate) const ROUTE_TRANSACTION_INCLUDED_MESSAGE: &str = "/api/v1/transactions/:transactionId/included-message";
-pub(crate) const ROUTE_WHITE_FLAG: &str = "/api/plugins/debug/whiteflag";
+routes! {
+    ROUTE_ADD_PEER: "post" "/api/v1/peers" => api::v1::add_peer,
+    ROUTE_ADDRESS_HISTORY_ED25519: "get" "/api/v1/addresses/ed25519/:address/history"
+        => api::v1::address_history_ed25519,
+    ROUTE_BALANCE_BECH32: "get" "/api/v1/addresses/:address" => api::v1::balance_bech32,
+    ROUTE_BALANCE_ED25519: "get" "/api/v1/addresses/ed25519/:address" => api::v1::balance_ed25519,
+    ROUTE_CONFIG_RELOAD: "post" "/api/v1/config/reload" => api::v1::config_reload,
+    ROUTE_EVENTS: "get" "/api/v1/events" => api::v1::events,
+    ROUTE_HEALTH: "get" "/health" => health,
+    ROUTE_INFO: "get" "/api/v1/info" => api::v1::info,
+    ROUTE_MESSAGE: "get" "/api/v1/messages/:messageId" => api::v1::message,
+    ROUTE_MESSAGE_CHILDREN: "get" "/api/v1/messages/:messageId/children" => api::v1::message_children,
+    ROUTE_MESSAGE_FUTURE_CONE: "get" "/api/v1/messages/:messageId/future-cone" => api::v1::message_future_cone,
+    ROUTE_MESSAGE_METADATA: "get" "/api/v1/messages/:messageId/metadata" => api::v1::message_metadata,
+    ROUTE_MESSAGE_PAST_CONE: "get" "/api/v1/messages/:messageId/past-cone" => api::v1::message_past_cone,
+    ROUTE_MESSAGE_PROMOTE: "post" "/api/v1/messages/:messageId/promote" => api::v1::message_promote,
+    ROUTE_MESSAGE_RAW: "get" "/api/v1/messages/:messageId/raw" => api::v1::message_raw,
+    ROUTE_MESSAGE_REATTACH: "post" "/api/v1/messages/:messageId/reattach" => api::v1::message_reattach,
+    ROUTE_MESSAGES_BATCH: "post" "/api/v1/messages/batch" => api::v1::messages_batch,
+    ROUTE_MESSAGES_FIND: "get" "/api/v1/messages" => api::v1::messages_find,
+    ROUTE_MESSAGES_INDEXED: "get" "/api/v1/messages/indexed" => api::v1::messages_indexed,
+    ROUTE_MESSAGES_METADATA_BATCH: "post" "/api/v1/messages/metadata/batch" => api::v1::messages_metadata_batch,
+    ROUTE_MILESTONE: "get" "/api/v1/milestones/:milestoneIndex" => api::v1::milestone,
+    ROUTE_MILESTONE_UTXO_CHANGES: "get" "/api/v1/milestones/:milestoneIndex/utxo-changes"
+        => api::v1::milestone_utxo_changes,
+    ROUTE_OPENAPI: "get" "/api/v1/openapi.json" => api::v1::openapi,
+    ROUTE_OUTPUT: "get" "/api/v1/outputs/:outputId" => api::v1::output,
+    ROUTE_OUTPUT_PENDING_SPENDS: "get" "/api/v1/outputs/:outputId/pending-spends" => api::v1::output_pending_spends,
+    ROUTE_OUTPUTS_BATCH: "post" "/api/v1/outputs/batch" => api::v1::outputs_batch,
+    ROUTE_OUTPUTS_BECH32: "get" "/api/v1/addresses/:address/outputs" => api::v1::outputs_bech32,
+    ROUTE_OUTPUTS_ED25519: "get" "/api/v1/addresses/ed25519/:address/outputs" => api::v1::outputs_ed25519,
+    ROUTE_PEER: "get" "/api/v1/peers/:peerId" => api::v1::peer,
+    ROUTE_PEERS: "get" "/api/v1/peers" => api::v1::peers,
+    ROUTE_REMOVE_PEER: "delete" "/api/v1/peers/:peerId" => api::v1::remove_peer,
+    ROUTE_SUBMIT_MESSAGE: "post" "/api/v1/messages" => api::v1::submit_message,
+    ROUTE_SUBMIT_MESSAGE_RAW: "post" "/api/v1/messages" => api::v1::submit_message_raw,
+    ROUTE_TIPS: "get" "/api/v1/tips" => api::v1::tips,
+    ROUTE_RECEIPTS: "get" "/api/v1/receipts" => api::v1::receipts,
+    ROUTE_RECEIPTS_AT: "get" "/api/v1/receipts/:milestoneIndex" => api::v1::receipts_at,
+    ROUTE_TREASURY: "get" "/api/v1/treasury" => api::v1::treasury,
+    ROUTE_TRANSACTION_INCLUDED_MESSAGE: "get" "/api/v1/transactions/:transactionId/included-message"
+        => api::v1::transaction_included_message,
+    ROUTE_WHITE_FLAG: "post" "/api/plugins/debug/whiteflag" => api::plugins::debug::white_flag,
+}
 
 /// the routes that are available for public use
-pub(crate) const DEFAULT_PUBLIC_ROUTES: [&str; 21] = [
+pub(crate) const DEFAULT_PUBLIC_ROUTES: [&str; 30] = [
+    ROUTE_ADDRESS_HISTORY_ED25519,
     ROUTE_BALANCE_BECH32,
     ROUTE_BALANCE_ED25519,
+    ROUTE_EVENTS,
     ROUTE_HEALTH,
     ROUTE_INFO,
     ROUTE_MESSAGE,
     ROUTE_MESSAGE_CHILDREN,
+    ROUTE_MESSAGE_FUTURE_CONE,
     ROUTE_MESSAGE_METADATA,
+    ROUTE_MESSAGE_PAST_CONE,
     ROUTE_MESSAGE_RAW,
+    ROUTE_MESSAGES_BATCH,
     ROUTE_MESSAGES_FIND,
+    ROUTE_MESSAGES_METADATA_BATCH,
     ROUTE_MILESTONE,
     ROUTE_MILESTONE_UTXO_CHANGES,
+    ROUTE_OPENAPI,
     ROUTE_OUTPUT,
+    ROUTE_OUTPUT_PENDING_SPENDS,
+    ROUTE_OUTPUTS_BATCH,
     ROUTE_OUTPUTS_BECH32,
     ROUTE_OUTPUTS_ED25519,
     ROUTE_SUBMIT_MESSAGE,
@@ -66,16 +123,309 @@ pub(crate) const DEFAULT_ALLOWED_IPS: [IpAddr; 2] = [
 ];
 pub(crate) const DEFAULT_FEATURE_PROOF_OF_WORK: bool = true;
 pub(crate) const DEFAULT_WHITE_FLAG_SOLIDIFICATION_TIMEOUT: u64 = 2;
+pub(crate) const DEFAULT_MAX_EVENT_SUBSCRIPTIONS: usize = 100;
+pub(crate) const DEFAULT_MAX_EVENT_CONNECTIONS: usize = 100;
+pub(crate) const DEFAULT_TLS_RELOAD_INTERVAL: u64 = 60;
+pub(crate) const DEFAULT_MAX_BODY_SIZE: u64 = 1024 * 1024;
+pub(crate) const DEFAULT_MAX_CONE_DEPTH: u32 = 10;
+pub(crate) const DEFAULT_MAX_CONE_SIZE: usize = 1000;
+pub(crate) const DEFAULT_MAX_PAGE_SIZE: usize = 1000;
+pub(crate) const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+pub(crate) const DEFAULT_MAX_INDEXATION_SCAN: usize = 100_000;
+pub(crate) const DEFAULT_YMRSI_DELTA: u32 = 8;
+pub(crate) const DEFAULT_OMRSI_DELTA: u32 = 13;
+pub(crate) const DEFAULT_RATE_LIMIT_REQUESTS: u32 = 100;
+pub(crate) const DEFAULT_RATE_LIMIT_PERIOD: u64 = 60;
+pub(crate) const DEFAULT_CORS_ALLOWED_ORIGINS: [&str; 1] = ["*"];
+pub(crate) const DEFAULT_CORS_ALLOWED_METHODS: [&str; 4] = ["GET", "POST", "DELETE", "OPTIONS"];
+pub(crate) const DEFAULT_CORS_ALLOWED_HEADERS: [&str; 2] = ["Content-Type", "Authorization"];
+
+/// Errors occurring while building the REST API configuration.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The binding IP address could not be parsed.
+    #[error("invalid binding IP address {0}")]
+    InvalidBindingIpAddr(String),
+    /// The TLS certificate path is missing.
+    #[error("missing TLS certificate path")]
+    MissingTlsCertPath,
+    /// The TLS key path is missing.
+    #[error("missing TLS key path")]
+    MissingTlsKeyPath,
+}
+
+/// REST API TLS configuration builder.
+#[derive(Default, Deserialize)]
+pub struct RestApiTlsConfigBuilder {
+    cert_path: Option<String>,
+    key_path: Option<String>,
+    reload_interval: Option<u64>,
+}
+
+impl RestApiTlsConfigBuilder {
+    /// Creates a new TLS config builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the path of the PEM encoded certificate chain.
+    pub fn cert_path(mut self, cert_path: String) -> Self {
+        self.cert_path.replace(cert_path);
+        self
+    }
+
+    /// Sets the path of the PEM encoded private key.
+    pub fn key_path(mut self, key_path: String) -> Self {
+        self.key_path.replace(key_path);
+        self
+    }
+
+    /// Sets the interval, in seconds, at which the certificate and key files are checked for changes.
+    pub fn reload_interval(mut self, interval: u64) -> Self {
+        self.reload_interval.replace(interval);
+        self
+    }
+
+    /// Builds the TLS config.
+    pub fn finish(self) -> Result<RestApiTlsConfig, Error> {
+        Ok(RestApiTlsConfig {
+            cert_path: PathBuf::from(self.cert_path.ok_or(Error::MissingTlsCertPath)?),
+            key_path: PathBuf::from(self.key_path.ok_or(Error::MissingTlsKeyPath)?),
+            reload_interval: Duration::from_secs(self.reload_interval.unwrap_or(DEFAULT_TLS_RELOAD_INTERVAL)),
+        })
+    }
+}
+
+/// REST API TLS configuration.
+#[derive(Clone)]
+pub struct RestApiTlsConfig {
+    pub(crate) cert_path: PathBuf,
+    pub(crate) key_path: PathBuf,
+    pub(crate) reload_interval: Duration,
+}
+
+impl RestApiTlsConfig {
+    /// Returns a builder for this config.
+    pub fn build() -> RestApiTlsConfigBuilder {
+        RestApiTlsConfigBuilder::new()
+    }
+
+    /// Returns the path of the PEM encoded certificate chain.
+    pub fn cert_path(&self) -> &Path {
+        &self.cert_path
+    }
+
+    /// Returns the path of the PEM encoded private key.
+    pub fn key_path(&self) -> &Path {
+        &self.key_path
+    }
+
+    /// Returns the interval at which the certificate and key files are checked for changes.
+    pub fn reload_interval(&self) -> Duration {
+        self.reload_interval
+    }
+}
+
+/// REST API CORS configuration builder.
+#[derive(Default, Deserialize)]
+pub struct RestApiCorsConfigBuilder {
+    allowed_origins: Option<Vec<String>>,
+    allowed_methods: Option<Vec<String>>,
+    allowed_headers: Option<Vec<String>>,
+    max_age: Option<u64>,
+}
+
+impl RestApiCorsConfigBuilder {
+    /// Creates a new CORS config builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the origins allowed to make cross-origin requests, `*` allowing any origin.
+    pub fn allowed_origins(mut self, origins: Vec<String>) -> Self {
+        self.allowed_origins.replace(origins);
+        self
+    }
+
+    /// Sets the methods allowed in cross-origin requests.
+    pub fn allowed_methods(mut self, methods: Vec<String>) -> Self {
+        self.allowed_methods.replace(methods);
+        self
+    }
+
+    /// Sets the headers allowed in cross-origin requests.
+    pub fn allowed_headers(mut self, headers: Vec<String>) -> Self {
+        self.allowed_headers.replace(headers);
+        self
+    }
+
+    /// Sets the duration, in seconds, for which browsers may cache the result of a preflight request.
+    pub fn max_age(mut self, max_age: u64) -> Self {
+        self.max_age.replace(max_age);
+        self
+    }
+
+    /// Builds the CORS config.
+    pub fn finish(self) -> RestApiCorsConfig {
+        RestApiCorsConfig {
+            allowed_origins: self
+                .allowed_origins
+                .unwrap_or_else(|| DEFAULT_CORS_ALLOWED_ORIGINS.iter().map(|s| s.to_string()).collect()),
+            allowed_methods: self
+                .allowed_methods
+                .unwrap_or_else(|| DEFAULT_CORS_ALLOWED_METHODS.iter().map(|s| s.to_string()).collect()),
+            allowed_headers: self
+                .allowed_headers
+                .unwrap_or_else(|| DEFAULT_CORS_ALLOWED_HEADERS.iter().map(|s| s.to_string()).collect()),
+            max_age: self.max_age.map(Duration::from_secs),
+        }
+    }
+}
+
+/// REST API CORS configuration.
+#[derive(Clone)]
+pub struct RestApiCorsConfig {
+    pub(crate) allowed_origins: Vec<String>,
+    pub(crate) allowed_methods: Vec<String>,
+    pub(crate) allowed_headers: Vec<String>,
+    pub(crate) max_age: Option<Duration>,
+}
+
+impl RestApiCorsConfig {
+    /// Returns a builder for this config.
+    pub fn build() -> RestApiCorsConfigBuilder {
+        RestApiCorsConfigBuilder::new()
+    }
+
+    /// Returns the origins allowed to make cross-origin requests.
+    pub fn allowed_origins(&self) -> &Vec<String> {
+        &self.allowed_origins
+    }
+
+    /// Returns the methods allowed in cross-origin requests.
+    pub fn allowed_methods(&self) -> &Vec<String> {
+        &self.allowed_methods
+    }
+
+    /// Returns the headers allowed in cross-origin requests.
+    pub fn allowed_headers(&self) -> &Vec<String> {
+        &self.allowed_headers
+    }
+
+    /// Returns the duration for which browsers may cache the result of a preflight request.
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+}
+
+/// REST API rate limit configuration builder.
+#[derive(Default, Deserialize)]
+pub struct RestApiRateLimitConfigBuilder {
+    requests: Option<u32>,
+    period: Option<u64>,
+    routes: Option<HashMap<String, u32>>,
+    api_tokens: Option<Vec<String>>,
+}
+
+impl RestApiRateLimitConfigBuilder {
+    /// Creates a new rate limit config builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of requests a client can make to a route per period.
+    pub fn requests(mut self, requests: u32) -> Self {
+        self.requests.replace(requests);
+        self
+    }
+
+    /// Sets the period, in seconds, over which requests are counted.
+    pub fn period(mut self, period: u64) -> Self {
+        self.period.replace(period);
+        self
+    }
+
+    /// Sets the number of requests per period of specific routes, `0` exempting a route from rate limiting.
+    pub fn routes(mut self, routes: HashMap<String, u32>) -> Self {
+        self.routes.replace(routes);
+        self
+    }
+
+    /// Sets the API tokens whose requests are counted per token instead of per IP address.
+    pub fn api_tokens(mut self, api_tokens: Vec<String>) -> Self {
+        self.api_tokens.replace(api_tokens);
+        self
+    }
+
+    /// Builds the rate limit config.
+    pub fn finish(self) -> RestApiRateLimitConfig {
+        RestApiRateLimitConfig {
+            requests: self.requests.unwrap_or(DEFAULT_RATE_LIMIT_REQUESTS),
+            period: Duration::from_secs(self.period.unwrap_or(DEFAULT_RATE_LIMIT_PERIOD)),
+            routes: self.routes.unwrap_or_default(),
+            api_tokens: self.api_tokens.unwrap_or_default().into_iter().collect(),
+        }
+    }
+}
+
+/// REST API rate limit configuration.
+#[derive(Clone)]
+pub struct RestApiRateLimitConfig {
+    pub(crate) requests: u32,
+    pub(crate) period: Duration,
+    pub(crate) routes: HashMap<String, u32>,
+    pub(crate) api_tokens: HashSet<String>,
+}
+
+impl RestApiRateLimitConfig {
+    /// Returns a builder for this config.
+    pub fn build() -> RestApiRateLimitConfigBuilder {
+        RestApiRateLimitConfigBuilder::new()
+    }
+
+    /// Returns the number of requests a client can make to a route per period.
+    pub fn requests(&self) -> u32 {
+        self.requests
+    }
+
+    /// Returns the period over which requests are counted.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns the number of requests a client can make to the given route per period.
+    pub fn route_requests(&self, route: &str) -> u32 {
+        self.routes.get(route).copied().unwrap_or(self.requests)
+    }
+
+    /// Returns the API tokens whose requests are counted per token instead of per IP address.
+    pub fn api_tokens(&self) -> &HashSet<String> {
+        &self.api_tokens
+    }
+}
 
 /// REST API configuration builder.
 #[derive(Default, Deserialize)]
 pub struct RestApiConfigBuilder {
     binding_port: Option<u16>,
-    binding_ip_addr: Option<IpAddr>,
+    binding_ip_addr: Option<String>,
     public_routes: Option<Vec<String>>,
     allowed_ips: Option<Vec<IpAddr>>,
     feature_proof_of_work: Option<bool>,
     white_flag_solidification_timeout: Option<u64>,
+    max_event_subscriptions: Option<usize>,
+    max_event_connections: Option<usize>,
+    tls: Option<RestApiTlsConfigBuilder>,
+    cors: Option<RestApiCorsConfigBuilder>,
+    rate_limit: Option<RestApiRateLimitConfigBuilder>,
+    max_body_size: Option<u64>,
+    max_cone_depth: Option<u32>,
+    max_cone_size: Option<usize>,
+    max_page_size: Option<usize>,
+    max_batch_size: Option<usize>,
+    max_indexation_scan: Option<usize>,
+    ymrsi_delta: Option<u32>,
+    omrsi_delta: Option<u32>,
 }
 
 impl RestApiConfigBuilder {
@@ -92,12 +442,7 @@ impl RestApiConfigBuilder {
 
     /// Sets the binding IP address for the REST API.
     pub fn binding_ip_addr(mut self, addr: &str) -> Self {
-        match addr.parse() {
-            Ok(addr) => {
-                self.binding_ip_addr.replace(addr);
-            }
-            Err(e) => panic!("Error parsing IP address: {:?}", e),
-        }
+        self.binding_ip_addr.replace(addr.to_owned());
         self
     }
 
@@ -125,12 +470,93 @@ impl RestApiConfigBuilder {
         self
     }
 
+    /// Sets the maximum number of topics a single event stream connection can subscribe to.
+    pub fn max_event_subscriptions(mut self, max: usize) -> Self {
+        self.max_event_subscriptions.replace(max);
+        self
+    }
+
+    /// Sets the maximum number of simultaneous event stream connections.
+    pub fn max_event_connections(mut self, max: usize) -> Self {
+        self.max_event_connections.replace(max);
+        self
+    }
+
+    /// Serves the REST API over TLS.
+    pub fn tls(mut self, tls: RestApiTlsConfigBuilder) -> Self {
+        self.tls.replace(tls);
+        self
+    }
+
+    /// Enables cross-origin requests.
+    pub fn cors(mut self, cors: RestApiCorsConfigBuilder) -> Self {
+        self.cors.replace(cors);
+        self
+    }
+
+    /// Enables per-client rate limiting.
+    pub fn rate_limit(mut self, rate_limit: RestApiRateLimitConfigBuilder) -> Self {
+        self.rate_limit.replace(rate_limit);
+        self
+    }
+
+    /// Sets the maximum size, in bytes, of request bodies.
+    pub fn max_body_size(mut self, max_body_size: u64) -> Self {
+        self.max_body_size.replace(max_body_size);
+        self
+    }
+
+    /// Sets the maximum number of levels of a future cone query.
+    pub fn max_cone_depth(mut self, max_cone_depth: u32) -> Self {
+        self.max_cone_depth.replace(max_cone_depth);
+        self
+    }
+
+    /// Sets the maximum number of messages returned by a cone query.
+    pub fn max_cone_size(mut self, max_cone_size: usize) -> Self {
+        self.max_cone_size.replace(max_cone_size);
+        self
+    }
+
+    /// Sets the maximum number of entries in a page of a paginated query.
+    pub fn max_page_size(mut self, max_page_size: usize) -> Self {
+        self.max_page_size.replace(max_page_size);
+        self
+    }
+
+    /// Sets the maximum number of ids of a batch query.
+    pub fn max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size.replace(max_batch_size);
+        self
+    }
+
+    /// Sets the maximum number of indexation entries visited by an indexed query.
+    pub fn max_indexation_scan(mut self, max_indexation_scan: usize) -> Self {
+        self.max_indexation_scan.replace(max_indexation_scan);
+        self
+    }
+
+    /// Sets the maximum delta between the solid milestone index and the YMRSI of a message before it should be
+    /// promoted. It should match the one of the tip selection.
+    pub fn ymrsi_delta(mut self, ymrsi_delta: u32) -> Self {
+        self.ymrsi_delta.replace(ymrsi_delta);
+        self
+    }
+
+    /// Sets the maximum delta between the solid milestone index and the OMRSI of a message before it should be
+    /// promoted. It should match the one of the tip selection.
+    pub fn omrsi_delta(mut self, omrsi_delta: u32) -> Self {
+        self.omrsi_delta.replace(omrsi_delta);
+        self
+    }
+
     /// Builds the REST API config.
-    pub fn finish(self) -> RestApiConfig {
-        let binding_socket_addr = match self.binding_ip_addr.unwrap_or(DEFAULT_BINDING_IP_ADDR) {
-            IpAddr::V4(ip) => SocketAddr::new(IpAddr::V4(ip), self.binding_port.unwrap_or(DEFAULT_BINDING_PORT)),
-            IpAddr::V6(ip) => SocketAddr::new(IpAddr::V6(ip), self.binding_port.unwrap_or(DEFAULT_BINDING_PORT)),
+    pub fn finish(self) -> Result<RestApiConfig, Error> {
+        let binding_ip_addr = match self.binding_ip_addr {
+            Some(addr) => addr.parse().map_err(|_| Error::InvalidBindingIpAddr(addr))?,
+            None => DEFAULT_BINDING_IP_ADDR,
         };
+        let binding_socket_addr = SocketAddr::new(binding_ip_addr, self.binding_port.unwrap_or(DEFAULT_BINDING_PORT));
         let public_routes = self
             .public_routes
             .unwrap_or_else(|| DEFAULT_PUBLIC_ROUTES.iter().map(|s| s.to_string()).collect());
@@ -139,14 +565,29 @@ impl RestApiConfigBuilder {
         let white_flag_solidification_timeout = self
             .white_flag_solidification_timeout
             .unwrap_or(DEFAULT_WHITE_FLAG_SOLIDIFICATION_TIMEOUT);
+        let max_event_subscriptions = self.max_event_subscriptions.unwrap_or(DEFAULT_MAX_EVENT_SUBSCRIPTIONS);
+        let max_event_connections = self.max_event_connections.unwrap_or(DEFAULT_MAX_EVENT_CONNECTIONS);
 
-        RestApiConfig {
+        Ok(RestApiConfig {
             binding_socket_addr,
             public_routes,
             allowed_ips,
             feature_proof_of_work,
             white_flag_solidification_timeout,
-        }
+            max_event_subscriptions,
+            max_event_connections,
+            tls: self.tls.map(RestApiTlsConfigBuilder::finish).transpose()?,
+            cors: self.cors.map(RestApiCorsConfigBuilder::finish),
+            rate_limit: self.rate_limit.map(RestApiRateLimitConfigBuilder::finish),
+            max_body_size: self.max_body_size.unwrap_or(DEFAULT_MAX_BODY_SIZE),
+            max_cone_depth: self.max_cone_depth.unwrap_or(DEFAULT_MAX_CONE_DEPTH),
+            max_cone_size: self.max_cone_size.unwrap_or(DEFAULT_MAX_CONE_SIZE),
+            max_page_size: self.max_page_size.unwrap_or(DEFAULT_MAX_PAGE_SIZE),
+            max_batch_size: self.max_batch_size.unwrap_or(DEFAULT_MAX_BATCH_SIZE),
+            max_indexation_scan: self.max_indexation_scan.unwrap_or(DEFAULT_MAX_INDEXATION_SCAN),
+            ymrsi_delta: self.ymrsi_delta.unwrap_or(DEFAULT_YMRSI_DELTA),
+            omrsi_delta: self.omrsi_delta.unwrap_or(DEFAULT_OMRSI_DELTA),
+        })
     }
 }
 
@@ -158,6 +599,19 @@ pub struct RestApiConfig {
     pub(crate) allowed_ips: Vec<IpAddr>,
     pub(crate) feature_proof_of_work: bool,
     pub(crate) white_flag_solidification_timeout: u64,
+    pub(crate) max_event_subscriptions: usize,
+    pub(crate) max_event_connections: usize,
+    pub(crate) tls: Option<RestApiTlsConfig>,
+    pub(crate) cors: Option<RestApiCorsConfig>,
+    pub(crate) rate_limit: Option<RestApiRateLimitConfig>,
+    pub(crate) max_body_size: u64,
+    pub(crate) max_cone_depth: u32,
+    pub(crate) max_cone_size: usize,
+    pub(crate) max_page_size: usize,
+    pub(crate) max_batch_size: usize,
+    pub(crate) max_indexation_scan: usize,
+    pub(crate) ymrsi_delta: u32,
+    pub(crate) omrsi_delta: u32,
 }
 
 impl RestApiConfig {
@@ -190,4 +644,71 @@ impl RestApiConfig {
     pub fn white_flag_solidification_timeout(&self) -> u64 {
         self.white_flag_solidification_timeout
     }
+
+    /// Returns the maximum number of topics a single event stream connection can subscribe to.
+    pub fn max_event_subscriptions(&self) -> usize {
+        self.max_event_subscriptions
+    }
+
+    /// Returns the maximum number of simultaneous event stream connections.
+    pub fn max_event_connections(&self) -> usize {
+        self.max_event_connections
+    }
+
+    /// Returns the TLS configuration, if the REST API is served over TLS.
+    pub fn tls(&self) -> Option<&RestApiTlsConfig> {
+        self.tls.as_ref()
+    }
+
+    /// Returns the CORS configuration, if cross-origin requests are enabled.
+    pub fn cors(&self) -> Option<&RestApiCorsConfig> {
+        self.cors.as_ref()
+    }
+
+    /// Returns the rate limit configuration, if rate limiting is enabled.
+    pub fn rate_limit(&self) -> Option<&RestApiRateLimitConfig> {
+        self.rate_limit.as_ref()
+    }
+
+    /// Returns the maximum size of request bodies.
+    pub fn max_body_size(&self) -> u64 {
+        self.max_body_size
+    }
+
+    /// Returns the maximum number of levels of a future cone query.
+    pub fn max_cone_depth(&self) -> u32 {
+        self.max_cone_depth
+    }
+
+    /// Returns the maximum number of messages returned by a cone query.
+    pub fn max_cone_size(&self) -> usize {
+        self.max_cone_size
+    }
+
+    /// Returns the maximum number of entries in a page of a paginated query.
+    pub fn max_page_size(&self) -> usize {
+        self.max_page_size
+    }
+
+    /// Returns the maximum number of ids of a batch query.
+    pub fn max_batch_size(&self) -> usize {
+        self.max_batch_size
+    }
+
+    /// Returns the maximum number of indexation entries visited by an indexed query.
+    pub fn max_indexation_scan(&self) -> usize {
+        self.max_indexation_scan
+    }
+
+    /// Returns the maximum delta between the solid milestone index and the YMRSI of a message before it should be
+    /// promoted.
+    pub fn ymrsi_delta(&self) -> u32 {
+        self.ymrsi_delta
+    }
+
+    /// Returns the maximum delta between the solid milestone index and the OMRSI of a message before it should be
+    /// promoted.
+    pub fn omrsi_delta(&self) -> u32 {
+        self.omrsi_delta
+    }
 }
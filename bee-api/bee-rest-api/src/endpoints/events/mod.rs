@@ -0,0 +1,539 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod topic;
+
+pub(crate) use topic::EventTopic;
+
+use crate::{
+    endpoints::{
+        config::RestApiConfig, routes::api::v1::message_metadata::create_message_metadata, storage::StorageBackend,
+        ApiWorker,
+    },
+    types::{
+        dtos::MessageDto,
+        responses::{MilestoneResponse, OutputResponse},
+    },
+};
+
+use bee_ledger::{
+    types::CreatedOutput,
+    workers::{event::MilestoneConfirmed, storage::fetch_milestone_outputs},
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::MilestoneIndex,
+    output::{Output, OutputId},
+    payload::{transaction::Essence, Payload},
+    MessageId,
+};
+use bee_protocol::workers::{
+    event::{MessageProcessed, MessageSolidified},
+    PendingSpends,
+};
+use bee_runtime::{node::Node, shutdown_stream::ShutdownStream};
+use bee_tangle::{event::LatestMilestoneChanged, MsTangle};
+
+use futures::{future::Future, stream::StreamExt};
+use log::{debug, error, warn};
+use serde::Serialize;
+use thiserror::Error;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+};
+
+// Number of events buffered for a subscriber before it is considered too slow and disconnected.
+const SUBSCRIBER_BUFFER_SIZE: usize = 1000;
+
+/// An event sent to the subscribers of a topic.
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct Event {
+    pub(crate) topic: String,
+    pub(crate) data: serde_json::Value,
+}
+
+#[derive(Debug, Error)]
+pub(crate) enum SubscriptionError {
+    #[error("subscription limit of {0} topics reached")]
+    LimitReached(usize),
+    #[error("connection limit of {0} event streams reached")]
+    ConnectionLimitReached(usize),
+}
+
+struct Subscriber {
+    tx: mpsc::Sender<Event>,
+    topics: HashSet<EventTopic>,
+}
+
+type Subscribers = HashMap<usize, Subscriber>;
+
+/// The event stream connections and the topics they subscribed to.
+#[derive(Clone)]
+pub(crate) struct EventSubscribers {
+    max_connections: usize,
+    next_id: Arc<AtomicUsize>,
+    subscribers: Arc<Mutex<Subscribers>>,
+}
+
+impl EventSubscribers {
+    /// Creates an empty set of subscribers accepting at most `max_connections` event streams.
+    pub(crate) fn new(max_connections: usize) -> Self {
+        Self {
+            max_connections,
+            next_id: Arc::new(AtomicUsize::new(0)),
+            subscribers: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn subscribers(&self) -> MutexGuard<Subscribers> {
+        // Subscribers are always left consistent, a panicking holder can't corrupt them.
+        self.subscribers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Adds a subscriber without any topic and returns its handle and the receiving end of its events.
+    ///
+    /// The receiving end is closed if the subscriber doesn't keep up with its events.
+    pub(crate) fn add(&self) -> Result<(SubscriberHandle, mpsc::Receiver<Event>), SubscriptionError> {
+        let mut subscribers = self.subscribers();
+
+        if subscribers.len() >= self.max_connections {
+            return Err(SubscriptionError::ConnectionLimitReached(self.max_connections));
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = mpsc::channel(SUBSCRIBER_BUFFER_SIZE);
+
+        subscribers.insert(
+            id,
+            Subscriber {
+                tx,
+                topics: HashSet::new(),
+            },
+        );
+
+        Ok((
+            SubscriberHandle {
+                id,
+                subscribers: self.clone(),
+            },
+            rx,
+        ))
+    }
+
+    /// Drops all subscribers, which terminates their streams.
+    pub(crate) fn clear(&self) {
+        self.subscribers().clear();
+    }
+
+    // Collects the values extracted from all subscribed topics, allowing handlers to skip the work of building events
+    // nobody listens to.
+    fn subscribed<T, F>(&self, f: F) -> HashSet<T>
+    where
+        T: Eq + Hash,
+        F: Fn(&EventTopic) -> Option<T>,
+    {
+        self.subscribers()
+            .values()
+            .flat_map(|subscriber| subscriber.topics.iter().filter_map(&f))
+            .collect()
+    }
+
+    fn publish<T: Serialize>(&self, topic: EventTopic, data: &T) {
+        let mut subscribers = self.subscribers();
+
+        if !subscribers
+            .values()
+            .any(|subscriber| subscriber.topics.contains(&topic))
+        {
+            return;
+        }
+
+        let event = match serde_json::to_value(data) {
+            Ok(data) => Event {
+                topic: topic.to_string(),
+                data,
+            },
+            Err(e) => {
+                error!("Serializing {} event failed: {}.", topic, e);
+                return;
+            }
+        };
+
+        // Dropping a subscriber closes its receiving end, which terminates the stream of a client that lags behind.
+        subscribers.retain(|id, subscriber| {
+            if !subscriber.topics.contains(&topic) {
+                return true;
+            }
+
+            match subscriber.tx.try_send(event.clone()) {
+                Ok(()) => true,
+                Err(TrySendError::Full(_)) => {
+                    warn!("Disconnecting event subscriber {} lagging behind.", id);
+                    false
+                }
+                // The connection is closing and the subscriber is about to be removed anyway.
+                Err(TrySendError::Closed(_)) => false,
+            }
+        });
+    }
+}
+
+/// Handle of a subscriber, removing it from the subscribers when dropped.
+pub(crate) struct SubscriberHandle {
+    id: usize,
+    subscribers: EventSubscribers,
+}
+
+impl SubscriberHandle {
+    pub(crate) fn subscribe(&self, topic: EventTopic, limit: usize) -> Result<(), SubscriptionError> {
+        if let Some(subscriber) = self.subscribers.subscribers().get_mut(&self.id) {
+            if !subscriber.topics.contains(&topic) && subscriber.topics.len() >= limit {
+                return Err(SubscriptionError::LimitReached(limit));
+            }
+            subscriber.topics.insert(topic);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn unsubscribe(&self, topic: &EventTopic) {
+        if let Some(subscriber) = self.subscribers.subscribers().get_mut(&self.id) {
+            subscriber.topics.remove(topic);
+        }
+    }
+}
+
+impl Drop for SubscriberHandle {
+    fn drop(&mut self) {
+        self.subscribers.subscribers().remove(&self.id);
+    }
+}
+
+fn event_handler<N, E, F, Fut>(node: &mut N, f: F)
+where
+    N: Node,
+    N::Backend: StorageBackend,
+    E: Any + Clone + Send + Sync,
+    F: 'static + Fn(E) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send,
+{
+    let bus = node.bus();
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    node.spawn::<ApiWorker, _, _>(|shutdown| async move {
+        debug!("Event handler running.");
+
+        let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+
+        while let Some(event) = receiver.next().await {
+            f(event).await;
+        }
+
+        debug!("Event handler stopped.");
+    });
+
+    bus.add_listener::<ApiWorker, E, _>(move |event: &E| {
+        // Handlers are stopped as soon as the shutdown signal is received while listeners are removed with the worker,
+        // failing sends in between are therefore expected.
+        let _ = tx.send(event.clone());
+    });
+}
+
+async fn publish_message_metadata<B: StorageBackend>(
+    tangle: &MsTangle<B>,
+    pending_spends: &PendingSpends,
+    rest_api_config: &RestApiConfig,
+    subscribers: &EventSubscribers,
+    message_ids: impl Iterator<Item = MessageId>,
+) {
+    let subscribed = subscribers.subscribed(|topic| match topic {
+        EventTopic::MessageMetadata(message_id) => Some(*message_id),
+        _ => None,
+    });
+
+    if subscribed.is_empty() {
+        return;
+    }
+
+    for message_id in message_ids.filter(|message_id| subscribed.contains(message_id)) {
+        if let Some(metadata) = create_message_metadata(message_id, tangle, pending_spends, rest_api_config).await {
+            subscribers.publish(EventTopic::MessageMetadata(message_id), &metadata);
+        }
+    }
+}
+
+async fn publish_indexation<B: StorageBackend>(
+    tangle: &MsTangle<B>,
+    subscribers: &EventSubscribers,
+    message_id: MessageId,
+) {
+    let subscribed = subscribers.subscribed(|topic| match topic {
+        EventTopic::Indexation(index) => Some(index.clone()),
+        _ => None,
+    });
+
+    if subscribed.is_empty() {
+        return;
+    }
+
+    if let Some(message) = tangle.get(&message_id).await.map(|m| (*m).clone()) {
+        let indexation = match message.payload() {
+            Some(Payload::Indexation(indexation)) => indexation,
+            Some(Payload::Transaction(transaction)) => match transaction.essence() {
+                Essence::Regular(essence) => match essence.payload() {
+                    Some(Payload::Indexation(indexation)) => indexation,
+                    _ => return,
+                },
+                _ => return,
+            },
+            _ => return,
+        };
+        let index = hex::encode(indexation.index());
+
+        if subscribed.contains(&index) {
+            subscribers.publish(EventTopic::Indexation(index), &MessageDto::from(&message));
+        }
+    }
+}
+
+fn output_address(output: &Output) -> Option<Ed25519Address> {
+    match output {
+        Output::SignatureLockedSingle(output) => match output.address() {
+            Address::Ed25519(address) => Some(*address),
+            _ => None,
+        },
+        Output::SignatureLockedDustAllowance(output) => match output.address() {
+            Address::Ed25519(address) => Some(*address),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+async fn publish_address_outputs<B: StorageBackend>(
+    storage: &B,
+    subscribers: &EventSubscribers,
+    index: MilestoneIndex,
+) {
+    let subscribed = subscribers.subscribed(|topic| match topic {
+        EventTopic::AddressOutputs(address) => Some(*address),
+        _ => None,
+    });
+
+    if subscribed.is_empty() {
+        return;
+    }
+
+    let outputs = match fetch_milestone_outputs(storage, index).await {
+        Ok(outputs) => outputs,
+        Err(e) => {
+            error!("Fetching outputs of milestone {} failed: {}.", *index, e);
+            return;
+        }
+    };
+
+    for (output_id, output, is_spent) in outputs {
+        publish_address_output(subscribers, &subscribed, output_id, &output, is_spent);
+    }
+}
+
+fn publish_address_output(
+    subscribers: &EventSubscribers,
+    subscribed: &HashSet<Ed25519Address>,
+    output_id: OutputId,
+    output: &CreatedOutput,
+    is_spent: bool,
+) {
+    if let Some(address) = output_address(output.inner()).filter(|address| subscribed.contains(address)) {
+        subscribers.publish(
+            EventTopic::AddressOutputs(address),
+            &OutputResponse {
+                message_id: output.message_id().to_string(),
+                transaction_id: output_id.transaction_id().to_string(),
+                output_index: output_id.index(),
+                is_spent,
+                output: output.inner().into(),
+            },
+        );
+    }
+}
+
+/// Registers the handlers forwarding bus events to the subscribers of the event stream.
+pub(crate) fn init<N: Node>(node: &mut N, subscribers: &EventSubscribers, rest_api_config: &RestApiConfig)
+where
+    N::Backend: StorageBackend,
+{
+    let tangle = node.resource::<MsTangle<N::Backend>>();
+    let storage = node.storage();
+    let pending_spends = node.resource::<PendingSpends>();
+
+    {
+        let subscribers = subscribers.clone();
+        event_handler(node, move |event: LatestMilestoneChanged| {
+            let subscribers = subscribers.clone();
+            async move {
+                let milestone = MilestoneResponse {
+                    milestone_index: *event.index,
+                    message_id: event.milestone.message_id().to_string(),
+                    timestamp: event.milestone.timestamp(),
+                };
+                subscribers.publish(EventTopic::LatestMilestone, &milestone);
+            }
+        });
+    }
+    {
+        let subscribers = subscribers.clone();
+        let tangle = tangle.clone();
+        let pending_spends = pending_spends.clone();
+        let rest_api_config = rest_api_config.clone();
+        event_handler(node, move |event: MilestoneConfirmed| {
+            let subscribers = subscribers.clone();
+            let tangle = tangle.clone();
+            let storage = storage.clone();
+            let pending_spends = pending_spends.clone();
+            let rest_api_config = rest_api_config.clone();
+            async move {
+                let milestone = MilestoneResponse {
+                    milestone_index: *event.index,
+                    message_id: event.id.to_string(),
+                    timestamp: event.timestamp,
+                };
+                subscribers.publish(EventTopic::ConfirmedMilestone, &milestone);
+
+                let referenced = event
+                    .included_messages
+                    .iter()
+                    .chain(event.excluded_no_transaction_messages.iter())
+                    .chain(
+                        event
+                            .excluded_conflicting_messages
+                            .iter()
+                            .map(|(message_id, _)| message_id),
+                    )
+                    .copied();
+                publish_message_metadata(&tangle, &pending_spends, &rest_api_config, &subscribers, referenced).await;
+                publish_address_outputs(&*storage, &subscribers, event.index).await;
+            }
+        });
+    }
+    {
+        let subscribers = subscribers.clone();
+        let tangle = tangle.clone();
+        let rest_api_config = rest_api_config.clone();
+        event_handler(node, move |event: MessageSolidified| {
+            let subscribers = subscribers.clone();
+            let tangle = tangle.clone();
+            let pending_spends = pending_spends.clone();
+            let rest_api_config = rest_api_config.clone();
+            async move {
+                publish_message_metadata(
+                    &tangle,
+                    &pending_spends,
+                    &rest_api_config,
+                    &subscribers,
+                    std::iter::once(event.message_id),
+                )
+                .await;
+            }
+        });
+    }
+    {
+        let subscribers = subscribers.clone();
+        event_handler(node, move |event: MessageProcessed| {
+            let subscribers = subscribers.clone();
+            let tangle = tangle.clone();
+            async move {
+                publish_indexation(&tangle, &subscribers, event.message_id).await;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_are_published_to_subscribers_of_the_topic() {
+        let subscribers = EventSubscribers::new(10);
+        let (latest, mut latest_rx) = subscribers.add().unwrap();
+        let (confirmed, mut confirmed_rx) = subscribers.add().unwrap();
+
+        latest.subscribe(EventTopic::LatestMilestone, 10).unwrap();
+        confirmed.subscribe(EventTopic::ConfirmedMilestone, 10).unwrap();
+        subscribers.publish(EventTopic::LatestMilestone, &42);
+
+        let event = latest_rx.try_recv().unwrap();
+        assert_eq!(event.topic, "milestones/latest");
+        assert_eq!(event.data, serde_json::json!(42));
+        assert!(confirmed_rx.try_recv().is_err());
+
+        latest.unsubscribe(&EventTopic::LatestMilestone);
+        subscribers.publish(EventTopic::LatestMilestone, &43);
+        assert!(latest_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn subscriptions_are_limited() {
+        let subscribers = EventSubscribers::new(10);
+        let (subscriber, _rx) = subscribers.add().unwrap();
+
+        subscriber.subscribe(EventTopic::LatestMilestone, 1).unwrap();
+        // Subscribing twice to the same topic doesn't count against the limit.
+        subscriber.subscribe(EventTopic::LatestMilestone, 1).unwrap();
+        assert!(matches!(
+            subscriber.subscribe(EventTopic::ConfirmedMilestone, 1),
+            Err(SubscriptionError::LimitReached(1))
+        ));
+    }
+
+    #[test]
+    fn connections_are_limited() {
+        let subscribers = EventSubscribers::new(2);
+        let first = subscribers.add().unwrap();
+        let _second = subscribers.add().unwrap();
+
+        assert!(matches!(
+            subscribers.add(),
+            Err(SubscriptionError::ConnectionLimitReached(2))
+        ));
+
+        // Dropping a handle frees its connection slot.
+        drop(first);
+        assert!(subscribers.add().is_ok());
+    }
+
+    #[test]
+    fn lagging_subscribers_are_disconnected() {
+        let subscribers = EventSubscribers::new(10);
+        let (lagging, mut lagging_rx) = subscribers.add().unwrap();
+        let (reading, mut reading_rx) = subscribers.add().unwrap();
+
+        lagging.subscribe(EventTopic::LatestMilestone, 10).unwrap();
+        reading.subscribe(EventTopic::LatestMilestone, 10).unwrap();
+
+        for index in 0..=SUBSCRIBER_BUFFER_SIZE {
+            subscribers.publish(EventTopic::LatestMilestone, &index);
+            assert!(reading_rx.try_recv().is_ok());
+        }
+
+        // The buffered events are still delivered before the stream ends.
+        for _ in 0..SUBSCRIBER_BUFFER_SIZE {
+            assert!(lagging_rx.try_recv().is_ok());
+        }
+        assert!(matches!(
+            lagging_rx.try_recv(),
+            Err(mpsc::error::TryRecvError::Disconnected)
+        ));
+        assert_eq!(subscribers.subscribers().len(), 1);
+    }
+}
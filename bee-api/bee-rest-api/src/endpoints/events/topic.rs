@@ -0,0 +1,70 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_message::{
+    address::{Address, Ed25519Address},
+    MessageId,
+};
+
+use std::{fmt, str::FromStr};
+
+/// Topics a client of the event stream can subscribe to.
+///
+/// * `milestones/latest`
+/// * `milestones/confirmed`
+/// * `messages/{messageId}/metadata`
+/// * `messages/indexation/{index}`, with a hex encoded index
+/// * `addresses/{address}/outputs`, with a bech32 address
+/// * `addresses/ed25519/{address}/outputs`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum EventTopic {
+    LatestMilestone,
+    ConfirmedMilestone,
+    MessageMetadata(MessageId),
+    Indexation(String),
+    AddressOutputs(Ed25519Address),
+}
+
+impl FromStr for EventTopic {
+    type Err = String;
+
+    fn from_str(topic: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid topic {}", topic);
+        let segments = topic.split('/').collect::<Vec<_>>();
+
+        match segments.as_slice() {
+            ["milestones", "latest"] => Ok(EventTopic::LatestMilestone),
+            ["milestones", "confirmed"] => Ok(EventTopic::ConfirmedMilestone),
+            ["messages", "indexation", index] => match hex::decode(index) {
+                // Normalised to lowercase so that all spellings of an index match the published events.
+                Ok(index) if !index.is_empty() => Ok(EventTopic::Indexation(hex::encode(index))),
+                _ => Err(invalid()),
+            },
+            ["messages", message_id, "metadata"] => message_id
+                .parse::<MessageId>()
+                .map(EventTopic::MessageMetadata)
+                .map_err(|_| invalid()),
+            ["addresses", "ed25519", address, "outputs"] => address
+                .parse::<Ed25519Address>()
+                .map(EventTopic::AddressOutputs)
+                .map_err(|_| invalid()),
+            ["addresses", address, "outputs"] => match Address::try_from_bech32(address) {
+                Ok(Address::Ed25519(address)) => Ok(EventTopic::AddressOutputs(address)),
+                _ => Err(invalid()),
+            },
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for EventTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventTopic::LatestMilestone => write!(f, "milestones/latest"),
+            EventTopic::ConfirmedMilestone => write!(f, "milestones/confirmed"),
+            EventTopic::MessageMetadata(message_id) => write!(f, "messages/{}/metadata", message_id),
+            EventTopic::Indexation(index) => write!(f, "messages/indexation/{}", index),
+            EventTopic::AddressOutputs(address) => write!(f, "addresses/ed25519/{}/outputs", address),
+        }
+    }
+}
@@ -1,17 +1,97 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, Bech32Hrp, NetworkId};
+use crate::endpoints::{
+    config::RestApiConfig,
+    events::EventSubscribers,
+    rejection::CustomRejection,
+    routes::{
+        api::v1::{config_reload::ConfigReloader, info::UpdateStatus},
+        health::WorkerStatuses,
+    },
+    server::RemoteAddr,
+    storage::StorageBackend,
+    Bech32Hrp, NetworkId,
+};
 
+use bee_ledger::workers::config::LedgerConfig;
 use bee_network::NetworkCommandSender;
-use bee_protocol::workers::{
-    config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+use bee_protocol::{
+    types::metrics::ThroughputMetrics,
+    workers::{
+        config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, PendingSpends,
+        RequestedMessages,
+    },
 };
 use bee_runtime::{event::Bus, node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::MsTangle;
 
+use futures::stream::{Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
 use tokio::sync::mpsc;
-use warp::Filter;
+use warp::{
+    hyper::body::{Buf, Bytes},
+    reject, Filter, Rejection,
+};
+
+use std::net::SocketAddr;
+
+async fn read_body(
+    body: impl Stream<Item = Result<impl Buf, warp::Error>>,
+    length: Option<u64>,
+    max_size: u64,
+) -> Result<Bytes, Rejection> {
+    if length.map_or(false, |length| length > max_size) {
+        return Err(reject::custom(CustomRejection::PayloadTooLarge));
+    }
+
+    let mut bytes = Vec::with_capacity(length.unwrap_or(0) as usize);
+    futures::pin_mut!(body);
+
+    while let Some(mut chunk) = body
+        .try_next()
+        .await
+        .map_err(|e| reject::custom(CustomRejection::BadRequest(format!("invalid body: {}", e))))?
+    {
+        // A chunked body doesn't announce its length and has to be checked while it is read.
+        if (bytes.len() + chunk.remaining()) as u64 > max_size {
+            return Err(reject::custom(CustomRejection::PayloadTooLarge));
+        }
+        while chunk.has_remaining() {
+            let len = chunk.chunk().len();
+            bytes.extend_from_slice(chunk.chunk());
+            chunk.advance(len);
+        }
+    }
+
+    Ok(Bytes::from(bytes))
+}
+
+/// Extracts a body of at most `max_size` bytes, whether its length is announced or it is sent in chunks.
+pub(crate) fn body_bytes(max_size: u64) -> impl Filter<Extract = (Bytes,), Error = Rejection> + Clone {
+    warp::header::optional::<u64>("content-length")
+        .and(warp::body::stream())
+        .and_then(move |length, body| read_body(body, length, max_size))
+}
+
+/// Extracts a JSON body of at most `max_size` bytes, whether its length is announced or it is sent in chunks.
+pub(crate) fn json_body<T: DeserializeOwned + Send>(
+    max_size: u64,
+) -> impl Filter<Extract = (T,), Error = Rejection> + Clone {
+    body_bytes(max_size).and_then(|bytes: Bytes| async move {
+        serde_json::from_slice(&bytes)
+            .map_err(|e| reject::custom(CustomRejection::BadRequest(format!("invalid JSON body: {}", e))))
+    })
+}
+
+/// Extracts the address of the client, whether the request was served by warp or by the TLS server.
+pub(crate) fn remote_addr() -> impl Filter<Extract = (Option<SocketAddr>,), Error = std::convert::Infallible> + Clone {
+    warp::addr::remote().and(warp::ext::optional::<RemoteAddr>()).map(
+        |addr: Option<SocketAddr>, remote_addr: Option<RemoteAddr>| {
+            addr.or(remote_addr.map(|remote_addr| remote_addr.0))
+        },
+    )
+}
 
 pub(crate) fn with_network_id(
     network_id: NetworkId,
@@ -37,12 +117,24 @@ pub(crate) fn with_protocol_config(
     warp::any().map(move || config.clone())
 }
 
+pub(crate) fn with_ledger_config(
+    config: LedgerConfig,
+) -> impl Filter<Extract = (LedgerConfig,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || config.clone())
+}
+
 pub(crate) fn with_tangle<B: StorageBackend>(
     tangle: ResourceHandle<MsTangle<B>>,
 ) -> impl Filter<Extract = (ResourceHandle<MsTangle<B>>,), Error = std::convert::Infallible> + Clone {
     warp::any().map(move || tangle.clone())
 }
 
+pub(crate) fn with_pending_spends(
+    pending_spends: ResourceHandle<PendingSpends>,
+) -> impl Filter<Extract = (ResourceHandle<PendingSpends>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || pending_spends.clone())
+}
+
 pub(crate) fn with_storage<B: StorageBackend>(
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = (ResourceHandle<B>,), Error = std::convert::Infallible> + Clone {
@@ -62,6 +154,30 @@ pub(crate) fn with_peer_manager(
     warp::any().map(move || peer_manager.clone())
 }
 
+pub(crate) fn with_config_reloader(
+    config_reloader: ResourceHandle<ConfigReloader>,
+) -> impl Filter<Extract = (ResourceHandle<ConfigReloader>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || config_reloader.clone())
+}
+
+pub(crate) fn with_update_status(
+    update_status: ResourceHandle<UpdateStatus>,
+) -> impl Filter<Extract = (ResourceHandle<UpdateStatus>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || update_status.clone())
+}
+
+pub(crate) fn with_worker_statuses(
+    worker_statuses: ResourceHandle<WorkerStatuses>,
+) -> impl Filter<Extract = (ResourceHandle<WorkerStatuses>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || worker_statuses.clone())
+}
+
+pub(crate) fn with_throughput_metrics(
+    throughput_metrics: ResourceHandle<ThroughputMetrics>,
+) -> impl Filter<Extract = (ResourceHandle<ThroughputMetrics>,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || throughput_metrics.clone())
+}
+
 pub(crate) fn with_network_command_sender(
     command_sender: ResourceHandle<NetworkCommandSender>,
 ) -> impl Filter<Extract = (ResourceHandle<NetworkCommandSender>,), Error = std::convert::Infallible> + Clone {
@@ -91,3 +207,56 @@ pub(crate) fn with_requested_messages(
 ) -> impl Filter<Extract = (ResourceHandle<RequestedMessages>,), Error = std::convert::Infallible> + Clone {
     warp::any().map(move || requested_messages.clone())
 }
+
+pub(crate) fn with_event_subscribers(
+    event_subscribers: EventSubscribers,
+) -> impl Filter<Extract = (EventSubscribers,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || event_subscribers.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::stream;
+
+    fn chunks(chunks: &[&'static [u8]]) -> impl Stream<Item = Result<Bytes, warp::Error>> {
+        stream::iter(
+            chunks
+                .iter()
+                .map(|chunk| Ok(Bytes::from_static(chunk)))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn is_too_large(result: Result<Bytes, Rejection>) -> bool {
+        matches!(result.unwrap_err().find(), Some(CustomRejection::PayloadTooLarge))
+    }
+
+    #[tokio::test]
+    async fn chunked_bodies_are_accepted_up_to_the_limit() {
+        let body = read_body(chunks(&[b"abc", b"def"]), None, 6).await.unwrap();
+
+        assert_eq!(&body[..], b"abcdef");
+        assert!(is_too_large(read_body(chunks(&[b"abc", b"defg"]), None, 6).await));
+    }
+
+    #[tokio::test]
+    async fn announced_length_is_checked_before_reading() {
+        assert!(is_too_large(read_body(chunks(&[]), Some(7), 6).await));
+        assert_eq!(&read_body(chunks(&[b"abc"]), Some(3), 6).await.unwrap()[..], b"abc");
+    }
+
+    #[tokio::test]
+    async fn json_bodies_are_parsed() {
+        let filter = json_body::<serde_json::Value>(64);
+        let value = warp::test::request()
+            .body(r#"{"parentMessageIds":[]}"#)
+            .filter(&filter)
+            .await
+            .unwrap();
+
+        assert_eq!(value["parentMessageIds"], serde_json::json!([]));
+        assert!(warp::test::request().body("{").filter(&filter).await.is_err());
+    }
+}
@@ -1,35 +1,52 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod events;
 mod filters;
+mod openapi;
+mod rate_limit;
+mod server;
 
 pub mod config;
 pub mod path_params;
 pub mod permission;
+pub mod query_params;
 pub mod rejection;
 pub mod routes;
 pub mod storage;
 
 use config::RestApiConfig;
+use events::EventSubscribers;
+use permission::AccessList;
+use rate_limit::RateLimiter;
 use rejection::CustomRejection;
+use routes::{
+    api::v1::{config_reload::ConfigReloader, info::UpdateStatus},
+    health::WorkerStatuses,
+};
 use storage::StorageBackend;
 
 use crate::types::body::{DefaultErrorResponse, ErrorBody};
 
+use bee_ledger::workers::config::LedgerConfig;
 use bee_network::NetworkCommandSender;
-use bee_protocol::workers::{
-    config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorker, PeerManager, PeerManagerResWorker,
-    RequestedMessages,
+use bee_protocol::{
+    types::metrics::{NodeMetrics, ThroughputMetrics},
+    workers::{
+        config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorker, MetricsWorker, PeerManager,
+        PeerManagerResWorker, PendingSpends, RequestedMessages, TransactionPayloadWorker,
+    },
 };
 use bee_runtime::{
     node::{Node, NodeBuilder},
+    resource::ResourceHandle,
     worker::{Error as WorkerError, Worker},
 };
 use bee_tangle::{MsTangle, TangleWorker};
 
 use async_trait::async_trait;
 use log::{error, info};
-use warp::{http::StatusCode, Filter, Rejection, Reply};
+use warp::{http::StatusCode, Rejection, Reply};
 
 use std::{any::TypeId, convert::Infallible};
 
@@ -41,6 +58,7 @@ pub(crate) const CONFIRMED_THRESHOLD: u32 = 5;
 pub async fn init<N: Node>(
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
+    ledger_config: LedgerConfig,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
     node_builder: N::Builder,
@@ -48,7 +66,7 @@ pub async fn init<N: Node>(
 where
     N::Backend: StorageBackend,
 {
-    node_builder.with_worker_cfg::<ApiWorker>((rest_api_config, protocol_config, network_id, bech32_hrp))
+    node_builder.with_worker_cfg::<ApiWorker>((rest_api_config, protocol_config, ledger_config, network_id, bech32_hrp))
 }
 
 pub struct ApiWorker;
@@ -57,7 +75,7 @@ impl<N: Node> Worker<N> for ApiWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (RestApiConfig, ProtocolConfig, NetworkId, Bech32Hrp);
+    type Config = (RestApiConfig, ProtocolConfig, LedgerConfig, NetworkId, Bech32Hrp);
     type Error = WorkerError;
 
     fn dependencies() -> &'static [TypeId] {
@@ -65,6 +83,8 @@ where
             TypeId::of::<TangleWorker>(),
             TypeId::of::<MessageSubmitterWorker>(),
             TypeId::of::<PeerManagerResWorker>(),
+            TypeId::of::<MetricsWorker>(),
+            TypeId::of::<TransactionPayloadWorker>(),
         ]
         .leak()
     }
@@ -72,8 +92,9 @@ where
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
         let rest_api_config = config.0;
         let protocol_config = config.1;
-        let network_id = config.2;
-        let bech32_hrp = config.3;
+        let ledger_config = config.2;
+        let network_id = config.3;
+        let bech32_hrp = config.4;
 
         let tangle = node.resource::<MsTangle<N::Backend>>();
         let storage = node.storage();
@@ -83,14 +104,30 @@ where
         let peer_manager = node.resource::<PeerManager>();
         let network_controller = node.resource::<NetworkCommandSender>();
         let node_info = node.info();
+        let throughput_metrics = node.resource::<ThroughputMetrics>();
         let bus = node.bus();
+        let event_subscribers = EventSubscribers::new(rest_api_config.max_event_connections());
+        let node_metrics = node.resource::<NodeMetrics>();
+        let worker_statuses = node.resource::<WorkerStatuses>();
+        let config_reloader = node.resource::<ConfigReloader>();
+        let update_status = node.resource::<UpdateStatus>();
+        let pending_spends = node.resource::<PendingSpends>();
+        let rate_limiter = RateLimiter::new(rest_api_config.rate_limit().cloned(), node_metrics.clone());
+        let access_list = AccessList::new(
+            rest_api_config.public_routes.clone(),
+            rest_api_config.allowed_ips.clone(),
+        );
+
+        node.register_resource(access_list.clone());
+
+        events::init(node, &event_subscribers, &rest_api_config);
 
         node.spawn::<Self, _, _>(|shutdown| async move {
             info!("Running.");
 
             let routes = routes::filter_all(
-                rest_api_config.public_routes.clone(),
-                rest_api_config.allowed_ips.clone(),
+                access_list,
+                rate_limiter,
                 tangle,
                 storage,
                 message_submitter,
@@ -98,21 +135,23 @@ where
                 bech32_hrp,
                 rest_api_config.clone(),
                 protocol_config,
+                ledger_config,
                 peer_manager,
                 network_controller,
                 node_info,
+                throughput_metrics,
                 bus,
                 message_requester,
                 requested_messages,
-            )
-            .recover(handle_rejection);
+                event_subscribers.clone(),
+                worker_statuses,
+                config_reloader,
+                update_status,
+                pending_spends,
+            );
+            let routes = server::recover(routes, rest_api_config.cors(), node_metrics);
 
-            let (_, server) =
-                warp::serve(routes).bind_with_graceful_shutdown(rest_api_config.binding_socket_addr(), async {
-                    shutdown.await.ok();
-                });
-
-            server.await;
+            server::serve(routes, rest_api_config, shutdown, event_subscribers).await;
 
             info!("Stopped.");
         });
@@ -121,7 +160,7 @@ where
     }
 }
 
-async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible> {
+async fn handle_rejection(err: Rejection, node_metrics: ResourceHandle<NodeMetrics>) -> Result<impl Reply, Infallible> {
     let (http_code, err_code, reason) = match err.find() {
         // handle custom rejections
         Some(CustomRejection::Forbidden) => (StatusCode::FORBIDDEN, "403".to_string(), "access forbidden".to_string()),
@@ -130,10 +169,29 @@ async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible> {
         Some(CustomRejection::ServiceUnavailable(reason)) => {
             (StatusCode::SERVICE_UNAVAILABLE, "503".to_string(), reason.to_owned())
         }
+        Some(CustomRejection::TooManyRequests) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            "429".to_string(),
+            "too many requests".to_string(),
+        ),
+        Some(CustomRejection::PayloadTooLarge) => {
+            node_metrics.rest_api_bodies_too_large_inc();
+            (
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "413".to_string(),
+                "request body too large".to_string(),
+            )
+        }
         // handle default rejections
         _ => {
             if err.is_not_found() {
                 (StatusCode::NOT_FOUND, "404".to_string(), "data not found".to_string())
+            } else if err.find::<warp::cors::CorsForbidden>().is_some() {
+                (
+                    StatusCode::FORBIDDEN,
+                    "403".to_string(),
+                    "origin not allowed".to_string(),
+                )
             } else if err.find::<warp::reject::MethodNotAllowed>().is_some() {
                 (StatusCode::FORBIDDEN, "403".to_string(), "access forbidden".to_string())
             } else {
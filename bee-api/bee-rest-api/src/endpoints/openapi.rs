@@ -0,0 +1,285 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! OpenAPI 3 specification of the REST API, generated from the routes and the schemas of their bodies.
+
+use crate::{
+    endpoints::config::{Route, ROUTES},
+    types::body::{DefaultErrorResponse, ErrorBody},
+};
+
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::Schema,
+    JsonSchema,
+};
+use serde_json::{json, Map, Value};
+
+const OPENAPI_VERSION: &str = "3.0.3";
+
+type SchemaFn = fn(&mut SchemaGenerator) -> Schema;
+
+/// Returns the schema of a body, to be declared as `Content::Json(schema::<T>)`.
+pub(crate) fn schema<T: JsonSchema>(generator: &mut SchemaGenerator) -> Schema {
+    generator.subschema_for::<T>()
+}
+
+/// Content of a request or response body.
+pub(crate) enum Content {
+    Empty,
+    Json(SchemaFn),
+    Binary,
+    EventStream,
+}
+
+impl Content {
+    fn to_value(&self, generator: &mut SchemaGenerator) -> Option<Value> {
+        let (media_type, schema) = match self {
+            Content::Empty => return None,
+            Content::Json(schema) => ("application/json", json!(schema(generator))),
+            Content::Binary => (
+                "application/octet-stream",
+                json!({ "type": "string", "format": "binary" }),
+            ),
+            Content::EventStream => ("text/event-stream", json!({ "type": "string" })),
+        };
+
+        Some(json!({ media_type: { "schema": schema } }))
+    }
+}
+
+/// A query parameter of a route, declared next to its filter and read by its handler by name.
+pub(crate) struct QueryParam {
+    pub(crate) name: &'static str,
+    pub(crate) required: bool,
+}
+
+impl QueryParam {
+    pub(crate) const fn optional(name: &'static str) -> Self {
+        Self { name, required: false }
+    }
+
+    pub(crate) const fn required(name: &'static str) -> Self {
+        Self { name, required: true }
+    }
+}
+
+/// The documentation of a route, declared next to its filter as a `DOC` constant.
+pub(crate) struct RouteDoc {
+    pub(crate) summary: &'static str,
+    pub(crate) query: &'static [QueryParam],
+    pub(crate) request: Content,
+    pub(crate) status: u16,
+    pub(crate) response: Content,
+}
+
+impl Route {
+    /// Returns the identifier of the operation, e.g. `messageMetadata` for `ROUTE_MESSAGE_METADATA`.
+    fn id(&self) -> String {
+        let mut id = String::new();
+
+        for (i, word) in self.name.trim_start_matches("ROUTE_").split('_').enumerate() {
+            let word = word.to_lowercase();
+            if i == 0 {
+                id.push_str(&word);
+            } else {
+                id.push_str(&word[..1].to_uppercase());
+                id.push_str(&word[1..]);
+            }
+        }
+
+        id
+    }
+
+    /// Returns the route in the OpenAPI path template syntax, e.g. `/api/v1/messages/{messageId}`.
+    fn template(&self) -> String {
+        self.path
+            .split('/')
+            .map(|segment| match segment.strip_prefix(':') {
+                Some(parameter) => format!("{{{}}}", parameter),
+                None => segment.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn parameters(&self) -> Vec<Value> {
+        let path = self
+            .path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix(':'))
+            .map(|name| {
+                let kind = if name == "milestoneIndex" { "integer" } else { "string" };
+                json!({ "name": name, "in": "path", "required": true, "schema": { "type": kind } })
+            });
+        let query = self.doc.query.iter().map(|param| {
+            json!({ "name": param.name, "in": "query", "required": param.required, "schema": { "type": "string" } })
+        });
+
+        path.chain(query).collect()
+    }
+
+    fn operation(&self, generator: &mut SchemaGenerator) -> Value {
+        let doc = self.doc;
+
+        let mut response = json!({ "description": doc.summary });
+        if let Some(content) = doc.response.to_value(generator) {
+            response["content"] = content;
+        }
+
+        let mut operation = json!({
+            "operationId": self.id(),
+            "summary": doc.summary,
+            "parameters": self.parameters(),
+            "responses": {
+                doc.status.to_string(): response,
+                "default": {
+                    "description": "Error.",
+                    "content": {
+                        "application/json": { "schema": schema::<ErrorBody<DefaultErrorResponse>>(generator) }
+                    }
+                }
+            }
+        });
+        if let Some(content) = doc.request.to_value(generator) {
+            operation["requestBody"] = json!({ "required": true, "content": content });
+        }
+
+        operation
+    }
+}
+
+/// Generates the OpenAPI specification of the REST API.
+pub(crate) fn spec() -> Value {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+    let mut paths = Map::new();
+
+    for route in ROUTES {
+        let value = route.operation(&mut generator);
+        let path = paths.entry(route.template()).or_insert_with(|| json!({}));
+
+        match path.get_mut(route.method) {
+            // Routes sharing a path and a method only differ by the content type of their request, e.g. the JSON and
+            // the raw message submissions.
+            Some(existing) => {
+                if let (Some(Value::Object(content)), Some(Value::Object(other))) = (
+                    existing.pointer_mut("/requestBody/content"),
+                    value.pointer("/requestBody/content"),
+                ) {
+                    content.extend(other.clone());
+                }
+            }
+            None => path[route.method] = value,
+        }
+    }
+
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": "Bee REST API",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths,
+        "components": {
+            "schemas": generator.definitions(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn every_operation_is_in_the_spec() {
+        let spec = spec();
+
+        for route in ROUTES {
+            let path = route.template();
+            assert!(
+                spec["paths"][&path][route.method].is_object(),
+                "{} {} is missing from the spec",
+                route.method,
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn every_json_body_has_a_schema() {
+        let mut generator = SchemaSettings::openapi3().into_generator();
+
+        for route in ROUTES {
+            for content in [&route.doc.request, &route.doc.response].iter() {
+                if let Content::Json(schema) = content {
+                    assert_ne!(
+                        json!(schema(&mut generator)),
+                        json!(true),
+                        "{} has an empty schema",
+                        route.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn spec_references_defined_schemas() {
+        let spec = spec();
+        let schemas = spec["components"]["schemas"].as_object().unwrap();
+        let spec = spec.to_string();
+
+        for reference in spec.split("\"$ref\":\"#/components/schemas/").skip(1) {
+            let name = reference.split('"').next().unwrap();
+            assert!(schemas.contains_key(name), "{} is not defined", name);
+        }
+    }
+
+    #[test]
+    fn routes_are_unique() {
+        let mut seen = HashSet::new();
+
+        for route in ROUTES {
+            assert!(seen.insert(route.name), "{} is registered twice", route.name);
+        }
+    }
+
+    #[test]
+    fn query_parameters_are_unique() {
+        for route in ROUTES {
+            let mut seen = HashSet::new();
+
+            for param in route.doc.query {
+                assert!(seen.insert(param.name), "{} declares {} twice", route.name, param.name);
+            }
+        }
+    }
+
+    #[test]
+    fn query_parameters_are_in_the_spec() {
+        let spec = spec();
+        let parameters = spec["paths"]["/api/v1/messages"]["get"]["parameters"]
+            .as_array()
+            .unwrap();
+
+        assert!(parameters.contains(&json!({
+            "name": "index",
+            "in": "query",
+            "required": true,
+            "schema": { "type": "string" }
+        })));
+    }
+
+    #[test]
+    fn shared_operations_accept_every_content_type() {
+        let spec = spec();
+        let content = spec["paths"]["/api/v1/messages"]["post"]["requestBody"]["content"]
+            .as_object()
+            .unwrap();
+
+        assert!(content.contains_key("application/json"));
+        assert!(content.contains_key("application/octet-stream"));
+    }
+}
@@ -1,25 +1,58 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::endpoints::rejection::CustomRejection;
+use crate::endpoints::{filters::remote_addr, rejection::CustomRejection};
 
 use warp::{reject, Filter, Rejection};
 
-use std::net::{IpAddr, SocketAddr};
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, RwLock},
+};
+
+/// The routes available for public use and the IP addresses allowed to access all the routes.
+/// Shared by all the routes so that it can be updated while the API is running.
+#[derive(Clone, Default)]
+pub struct AccessList(Arc<RwLock<(Vec<String>, Vec<IpAddr>)>>);
+
+impl AccessList {
+    /// Creates a new access list.
+    pub fn new(public_routes: Vec<String>, allowed_ips: Vec<IpAddr>) -> Self {
+        Self(Arc::new(RwLock::new((public_routes, allowed_ips))))
+    }
+
+    /// Replaces the public routes and the allowed IP addresses.
+    pub fn update(&self, public_routes: Vec<String>, allowed_ips: Vec<IpAddr>) {
+        *self.0.write().expect("Access list lock poisoned") = (public_routes, allowed_ips);
+    }
+
+    /// Returns the routes available for public use.
+    pub fn public_routes(&self) -> Vec<String> {
+        self.0.read().expect("Access list lock poisoned").0.clone()
+    }
+
+    /// Returns the IP addresses allowed to access all the routes.
+    pub fn allowed_ips(&self) -> Vec<IpAddr> {
+        self.0.read().expect("Access list lock poisoned").1.clone()
+    }
+
+    fn allows(&self, route: &str, ip: &IpAddr) -> bool {
+        let (public_routes, allowed_ips) = &*self.0.read().expect("Access list lock poisoned");
+
+        allowed_ips.contains(ip) || public_routes.iter().any(|public_route| public_route == route)
+    }
+}
 
 pub fn has_permission(
     route: &'static str,
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
 ) -> impl Filter<Extract = (), Error = Rejection> + Clone {
-    warp::addr::remote()
+    remote_addr()
         .and_then(move |addr: Option<SocketAddr>| {
-            let route = route.to_owned();
-            let public_routes = public_routes.clone();
-            let allowed_ips = allowed_ips.clone();
+            let access_list = access_list.clone();
             async move {
                 if let Some(v) = addr {
-                    if allowed_ips.contains(&v.ip()) || public_routes.contains(&route) {
+                    if access_list.allows(route, &v.ip()) {
                         return Ok(());
                     }
                 }
@@ -0,0 +1,58 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::endpoints::rejection::CustomRejection;
+
+use warp::{reject, Rejection};
+
+use std::{collections::HashMap, str::FromStr};
+
+/// Parses an optional query parameter, rejecting the request if it is present but invalid.
+pub(super) fn query_param<T: FromStr>(query: &HashMap<String, String>, name: &str) -> Result<Option<T>, Rejection> {
+    query
+        .get(name)
+        .map(|value| {
+            value
+                .parse::<T>()
+                .map_err(|_| reject::custom(CustomRejection::BadRequest(format!("invalid {}", name))))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn present_parameter_is_parsed() {
+        assert_eq!(
+            query_param::<usize>(&query(&[("depth", "3")]), "depth").unwrap(),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn missing_parameter_is_none() {
+        assert_eq!(
+            query_param::<usize>(&query(&[("depth", "3")]), "maxResults").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn invalid_parameter_is_rejected() {
+        let rejection = query_param::<usize>(&query(&[("depth", "-1")]), "depth").unwrap_err();
+
+        assert!(matches!(
+            rejection.find::<CustomRejection>(),
+            Some(CustomRejection::BadRequest(message)) if message == "invalid depth"
+        ));
+    }
+}
@@ -0,0 +1,238 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::endpoints::{config::RestApiRateLimitConfig, filters::remote_addr, rejection::CustomRejection};
+
+use bee_protocol::types::metrics::NodeMetrics;
+use bee_runtime::resource::ResourceHandle;
+
+use warp::{reject, Filter, Rejection};
+
+use std::{
+    collections::HashMap,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Instant,
+};
+
+// Maximum number of buckets kept in memory, bounding the state an attacker can create by cycling through addresses.
+const MAX_BUCKETS: usize = 100_000;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum ClientKey {
+    Ip(IpAddr),
+    Token(String),
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+struct Buckets {
+    buckets: HashMap<(ClientKey, &'static str), Bucket>,
+    purged: Instant,
+}
+
+impl Buckets {
+    // Forgets the least recently updated tenth of the buckets, their clients start over with a full budget.
+    fn evict(&mut self) {
+        let mut updated = self.buckets.values().map(|bucket| bucket.updated).collect::<Vec<_>>();
+        let oldest = updated.len() / 10;
+        let (_, threshold, _) = updated.select_nth_unstable(oldest);
+        let threshold = *threshold;
+
+        self.buckets.retain(|_, bucket| bucket.updated > threshold);
+    }
+}
+
+/// Token bucket rate limiter keeping a budget per client and route.
+#[derive(Clone)]
+pub(crate) struct RateLimiter {
+    config: Option<Arc<RestApiRateLimitConfig>>,
+    buckets: Arc<Mutex<Buckets>>,
+    metrics: ResourceHandle<NodeMetrics>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: Option<RestApiRateLimitConfig>, metrics: ResourceHandle<NodeMetrics>) -> Self {
+        Self {
+            config: config.map(Arc::new),
+            buckets: Arc::new(Mutex::new(Buckets {
+                buckets: HashMap::new(),
+                purged: Instant::now(),
+            })),
+            metrics,
+        }
+    }
+
+    fn buckets(&self) -> MutexGuard<Buckets> {
+        // Buckets are always left consistent, a panicking holder can't corrupt them.
+        self.buckets.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn client_key(
+        config: &RestApiRateLimitConfig,
+        addr: Option<SocketAddr>,
+        authorization: Option<String>,
+    ) -> Option<ClientKey> {
+        // Only known tokens are honoured, otherwise clients could bypass the limits by sending random ones.
+        match authorization.as_deref().and_then(|value| value.strip_prefix("Bearer ")) {
+            Some(token) if config.api_tokens().contains(token) => Some(ClientKey::Token(token.to_owned())),
+            _ => addr.map(|addr| ClientKey::Ip(addr.ip())),
+        }
+    }
+
+    fn try_acquire(&self, route: &'static str, addr: Option<SocketAddr>, authorization: Option<String>) -> bool {
+        self.metrics.rest_api_requests_inc();
+
+        let config = match &self.config {
+            Some(config) => config,
+            None => return true,
+        };
+        let requests = config.route_requests(route);
+        let key = match Self::client_key(config, addr, authorization) {
+            Some(key) if requests != 0 => key,
+            _ => return true,
+        };
+        let capacity = requests as f64;
+        let refill_rate = capacity / config.period().as_secs_f64().max(1.0);
+        let now = Instant::now();
+        let mut buckets = self.buckets();
+
+        // Buckets that have been idle for a whole period are full again and can be forgotten.
+        if now.duration_since(buckets.purged) >= config.period() {
+            buckets
+                .buckets
+                .retain(|_, bucket| now.duration_since(bucket.updated) < config.period());
+            buckets.purged = now;
+        }
+
+        if buckets.buckets.len() >= MAX_BUCKETS && !buckets.buckets.contains_key(&(key.clone(), route)) {
+            buckets.evict();
+        }
+
+        let bucket = buckets.buckets.entry((key, route)).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+
+        bucket.tokens = (bucket.tokens + now.duration_since(bucket.updated).as_secs_f64() * refill_rate).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            self.metrics.rest_api_requests_rate_limited_inc();
+            false
+        }
+    }
+}
+
+pub(crate) fn rate_limit(
+    route: &'static str,
+    rate_limiter: RateLimiter,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    remote_addr()
+        .and(warp::header::optional::<String>("authorization"))
+        .and_then(move |addr: Option<SocketAddr>, authorization: Option<String>| {
+            let rate_limiter = rate_limiter.clone();
+            async move {
+                if rate_limiter.try_acquire(route, addr, authorization) {
+                    Ok(())
+                } else {
+                    Err(reject::custom(CustomRejection::TooManyRequests))
+                }
+            }
+        })
+        .untuple_one()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::endpoints::config::RestApiRateLimitConfigBuilder;
+
+    const ROUTE: &str = "/api/v1/messages";
+
+    fn rate_limiter(config: RestApiRateLimitConfigBuilder) -> RateLimiter {
+        RateLimiter::new(Some(config.finish()), ResourceHandle::new(NodeMetrics::default()))
+    }
+
+    fn addr(ip: &str) -> Option<SocketAddr> {
+        Some(SocketAddr::new(ip.parse().unwrap(), 14265))
+    }
+
+    #[test]
+    fn budget_is_kept_per_client_and_route() {
+        let limiter = rate_limiter(RestApiRateLimitConfigBuilder::new().requests(2).period(60));
+
+        assert!(limiter.try_acquire(ROUTE, addr("10.0.0.1"), None));
+        assert!(limiter.try_acquire(ROUTE, addr("10.0.0.1"), None));
+        assert!(!limiter.try_acquire(ROUTE, addr("10.0.0.1"), None));
+        assert!(limiter.try_acquire(ROUTE, addr("10.0.0.2"), None));
+        assert!(limiter.try_acquire("/api/v1/info", addr("10.0.0.1"), None));
+
+        assert_eq!(limiter.metrics.rest_api_requests(), 5);
+        assert_eq!(limiter.metrics.rest_api_requests_rate_limited(), 1);
+    }
+
+    #[test]
+    fn routes_can_be_exempted() {
+        let limiter = rate_limiter(
+            RestApiRateLimitConfigBuilder::new()
+                .requests(1)
+                .routes(vec![(ROUTE.to_string(), 0)].into_iter().collect()),
+        );
+
+        for _ in 0..10 {
+            assert!(limiter.try_acquire(ROUTE, addr("10.0.0.1"), None));
+        }
+    }
+
+    #[test]
+    fn only_known_tokens_have_their_own_budget() {
+        let limiter = rate_limiter(
+            RestApiRateLimitConfigBuilder::new()
+                .requests(1)
+                .api_tokens(vec!["token".to_string()]),
+        );
+
+        assert!(limiter.try_acquire(ROUTE, addr("10.0.0.1"), Some("Bearer token".to_string())));
+        assert!(limiter.try_acquire(ROUTE, addr("10.0.0.1"), Some("Bearer unknown".to_string())));
+        assert!(!limiter.try_acquire(ROUTE, addr("10.0.0.1"), Some("Bearer other".to_string())));
+        assert!(!limiter.try_acquire(ROUTE, addr("10.0.0.2"), Some("Bearer token".to_string())));
+    }
+
+    #[test]
+    fn least_recently_updated_buckets_are_evicted() {
+        let now = Instant::now();
+        let mut buckets = Buckets {
+            buckets: HashMap::new(),
+            purged: now,
+        };
+
+        for i in 0..100u32 {
+            buckets.buckets.insert(
+                (ClientKey::Token(i.to_string()), ROUTE),
+                Bucket {
+                    tokens: 0.0,
+                    updated: now + std::time::Duration::from_millis(i as u64),
+                },
+            );
+        }
+
+        buckets.evict();
+
+        assert!(buckets.buckets.len() < 100);
+        assert!(buckets.buckets.len() >= 80);
+        assert!(!buckets
+            .buckets
+            .contains_key(&(ClientKey::Token("0".to_string()), ROUTE)));
+        assert!(buckets
+            .buckets
+            .contains_key(&(ClientKey::Token("99".to_string()), ROUTE)));
+    }
+}
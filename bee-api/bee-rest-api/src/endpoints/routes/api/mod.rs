@@ -4,11 +4,21 @@
 pub mod plugins;
 pub mod v1;
 
-use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, Bech32Hrp, NetworkId};
+use crate::endpoints::{
+    config::RestApiConfig, events::EventSubscribers, permission::AccessList, rate_limit::RateLimiter,
+    storage::StorageBackend, Bech32Hrp, NetworkId,
+};
+
+use v1::{config_reload::ConfigReloader, info::UpdateStatus};
 
+use bee_ledger::workers::config::LedgerConfig;
 use bee_network::NetworkCommandSender;
-use bee_protocol::workers::{
-    config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+use bee_protocol::{
+    types::metrics::ThroughputMetrics,
+    workers::{
+        config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, PendingSpends,
+        RequestedMessages,
+    },
 };
 use bee_runtime::{event::Bus, node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::MsTangle;
@@ -16,15 +26,13 @@ use bee_tangle::MsTangle;
 use tokio::sync::mpsc;
 use warp::{self, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
     warp::path("api")
 }
 
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
     storage: ResourceHandle<B>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
@@ -32,16 +40,22 @@ pub(crate) fn filter<B: StorageBackend>(
     bech32_hrp: Bech32Hrp,
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
+    ledger_config: LedgerConfig,
     peer_manager: ResourceHandle<PeerManager>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
     node_info: ResourceHandle<NodeInfo>,
+    throughput_metrics: ResourceHandle<ThroughputMetrics>,
     bus: ResourceHandle<Bus<'static>>,
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
+    event_subscribers: EventSubscribers,
+    config_reloader: ResourceHandle<ConfigReloader>,
+    update_status: ResourceHandle<UpdateStatus>,
+    pending_spends: ResourceHandle<PendingSpends>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     v1::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
         storage.clone(),
         message_submitter,
@@ -49,13 +63,19 @@ pub(crate) fn filter<B: StorageBackend>(
         bech32_hrp,
         rest_api_config.clone(),
         protocol_config,
+        ledger_config,
         peer_manager,
         network_command_sender,
         node_info,
+        throughput_metrics,
+        event_subscribers,
+        config_reloader,
+        update_status,
+        pending_spends,
     )
     .or(plugins::filter(
-        public_routes,
-        allowed_ips,
+        access_list,
+        rate_limiter,
         storage,
         tangle,
         bus,
@@ -1,9 +1,11 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-mod white_flag;
+pub(crate) mod white_flag;
 
-use crate::endpoints::{config::RestApiConfig, storage::StorageBackend};
+use crate::endpoints::{
+    config::RestApiConfig, permission::AccessList, rate_limit::RateLimiter, storage::StorageBackend,
+};
 
 use bee_protocol::workers::{MessageRequesterWorker, RequestedMessages};
 use bee_runtime::{event::Bus, resource::ResourceHandle};
@@ -11,15 +13,13 @@ use bee_tangle::MsTangle;
 
 use warp::{self, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
     super::path().and(warp::path("debug"))
 }
 
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
     tangle: ResourceHandle<MsTangle<B>>,
     bus: ResourceHandle<Bus<'static>>,
@@ -28,8 +28,8 @@ pub(crate) fn filter<B: StorageBackend>(
     rest_api_config: RestApiConfig,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     white_flag::filter(
-        public_routes,
-        allowed_ips,
+        access_list,
+        rate_limiter,
         storage,
         tangle,
         bus,
@@ -5,13 +5,16 @@ use crate::{
     endpoints::{
         config::{RestApiConfig, ROUTE_WHITE_FLAG},
         filters::{
-            with_bus, with_message_requester, with_requested_messages, with_rest_api_config, with_storage, with_tangle,
+            json_body, with_bus, with_message_requester, with_requested_messages, with_rest_api_config, with_storage,
+            with_tangle,
         },
-        permission::has_permission,
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
         rejection::CustomRejection,
         storage::StorageBackend,
     },
-    types::{body::SuccessBody, responses::WhiteFlagResponse},
+    types::{body::SuccessBody, requests::WhiteFlagRequest, responses::WhiteFlagResponse},
 };
 
 use bee_ledger::workers::consensus::{self, WhiteFlagMetadata};
@@ -29,7 +32,6 @@ use std::{
     any::TypeId,
     collections::HashSet,
     iter::FromIterator,
-    net::IpAddr,
     sync::{Arc, Mutex},
     time::Duration,
 };
@@ -38,9 +40,17 @@ fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
     super::path().and(warp::path("whiteflag")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Computes the merkle tree hash of a white flag traversal.",
+    query: &[],
+    request: Content::Json(schema::<WhiteFlagRequest>),
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<WhiteFlagResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
     tangle: ResourceHandle<MsTangle<B>>,
     bus: ResourceHandle<Bus<'static>>,
@@ -50,8 +60,9 @@ pub(crate) fn filter<B: StorageBackend>(
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::post())
-        .and(has_permission(ROUTE_WHITE_FLAG, public_routes, allowed_ips))
-        .and(warp::body::json())
+        .and(has_permission(ROUTE_WHITE_FLAG, access_list))
+        .and(rate_limit(ROUTE_WHITE_FLAG, rate_limiter))
+        .and(json_body(rest_api_config.max_body_size()))
         .and(with_storage(storage))
         .and(with_tangle(tangle))
         .and(with_bus(bus))
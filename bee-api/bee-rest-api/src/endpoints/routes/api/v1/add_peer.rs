@@ -3,14 +3,17 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_ADD_PEER,
-        filters::{with_network_command_sender, with_peer_manager},
-        permission::has_permission,
+        config::{RestApiConfig, ROUTE_ADD_PEER},
+        filters::{json_body, with_network_command_sender, with_peer_manager},
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
         rejection::CustomRejection,
     },
     types::{
         body::SuccessBody,
         dtos::{PeerDto, RelationDto},
+        requests::AddPeerRequest,
         responses::AddPeerResponse,
     },
 };
@@ -22,22 +25,30 @@ use bee_runtime::resource::ResourceHandle;
 use serde_json::Value as JsonValue;
 use warp::{http::StatusCode, reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
     super::path().and(warp::path("peers")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Adds a peer to the node.",
+    query: &[],
+    request: Content::Json(schema::<AddPeerRequest>),
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<AddPeerResponse>>),
+};
+
 pub(crate) fn filter(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     peer_manager: ResourceHandle<PeerManager>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
+    rest_api_config: RestApiConfig,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::post())
-        .and(has_permission(ROUTE_ADD_PEER, public_routes, allowed_ips))
-        .and(warp::body::json())
+        .and(has_permission(ROUTE_ADD_PEER, access_list))
+        .and(rate_limit(ROUTE_ADD_PEER, rate_limiter))
+        .and(json_body(rest_api_config.max_body_size()))
         .and(with_peer_manager(peer_manager))
         .and(with_network_command_sender(network_command_sender))
         .and_then(add_peer)
@@ -0,0 +1,111 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_ADDRESS_HISTORY_ED25519},
+        filters::{with_ledger_config, with_rest_api_config, with_storage},
+        openapi::{schema, Content, QueryParam, RouteDoc},
+        path_params::ed25519_address,
+        permission::{has_permission, AccessList},
+        query_params::query_param,
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
+    },
+    types::{body::SuccessBody, responses::AddressHistoryResponse},
+};
+
+use bee_ledger::{
+    types::{AddressHistoryEntry, AddressHistoryPage},
+    workers::config::LedgerConfig,
+};
+use bee_message::{address::Ed25519Address, milestone::MilestoneIndex};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::access::Fetch;
+
+use warp::{reject, Filter, Rejection, Reply};
+
+use std::collections::HashMap;
+
+fn path() -> impl Filter<Extract = (Ed25519Address,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("addresses"))
+        .and(warp::path("ed25519"))
+        .and(ed25519_address())
+        .and(warp::path("history"))
+        .and(warp::path::end())
+}
+
+const PAGE: QueryParam = QueryParam::optional("page");
+const PAGE_SIZE: QueryParam = QueryParam::optional("pageSize");
+const FROM: QueryParam = QueryParam::optional("from");
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns a page of the balance changes of an ed25519 address, ordered by milestone index.",
+    query: &[PAGE, PAGE_SIZE, FROM],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<AddressHistoryResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+    ledger_config: LedgerConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_ADDRESS_HISTORY_ED25519, access_list))
+        .and(rate_limit(ROUTE_ADDRESS_HISTORY_ED25519, rate_limiter))
+        .and(warp::query::<HashMap<String, String>>())
+        .and(with_storage(storage))
+        .and(with_rest_api_config(rest_api_config))
+        .and(with_ledger_config(ledger_config))
+        .and_then(address_history_ed25519)
+}
+
+pub(crate) async fn address_history_ed25519<B: StorageBackend>(
+    addr: Ed25519Address,
+    query: HashMap<String, String>,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+    ledger_config: LedgerConfig,
+) -> Result<impl Reply, Rejection> {
+    if !ledger_config.address_history() {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            "the address history is disabled on this node".to_string(),
+        )));
+    }
+
+    let page = query_param(&query, PAGE.name)?.unwrap_or(0);
+    let page_size = query_param(&query, PAGE_SIZE.name)?
+        .unwrap_or_else(|| rest_api_config.max_page_size())
+        .min(rest_api_config.max_page_size());
+    let from = query_param(&query, FROM.name)?.unwrap_or(0);
+
+    let history = match Fetch::<AddressHistoryPage, Vec<AddressHistoryEntry>>::fetch(
+        &*storage,
+        &AddressHistoryPage::new(addr, MilestoneIndex(from), page.saturating_mul(page_size), page_size),
+    )
+    .await
+    .map_err(|_| {
+        reject::custom(CustomRejection::ServiceUnavailable(
+            "can not fetch from storage".to_string(),
+        ))
+    })? {
+        Some(history) => history,
+        None => vec![],
+    };
+
+    Ok(warp::reply::json(&SuccessBody::new(AddressHistoryResponse {
+        address_type: 1,
+        address: addr.to_string(),
+        page,
+        page_size,
+        count: history.len(),
+        history: history.iter().map(Into::into).collect(),
+    })))
+}
@@ -1,9 +1,19 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::endpoints::{
-    config::ROUTE_BALANCE_BECH32, filters::with_storage, path_params::bech32_address, permission::has_permission,
-    rejection::CustomRejection, routes::api::v1::balance_ed25519::balance_ed25519, storage::StorageBackend,
+use crate::{
+    endpoints::{
+        config::ROUTE_BALANCE_BECH32,
+        filters::with_storage,
+        openapi::{schema, Content, RouteDoc},
+        path_params::bech32_address,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        routes::api::v1::balance_ed25519::balance_ed25519,
+        storage::StorageBackend,
+    },
+    types::{body::SuccessBody, responses::BalanceAddressResponse},
 };
 
 use bee_message::address::Address;
@@ -11,8 +21,6 @@ use bee_runtime::resource::ResourceHandle;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (Address,), Error = warp::Rejection> + Clone {
     super::path()
         .and(warp::path("addresses"))
@@ -20,14 +28,23 @@ fn path() -> impl Filter<Extract = (Address,), Error = warp::Rejection> + Clone
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the balance of a bech32 address.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<BalanceAddressResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_BALANCE_BECH32, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_BALANCE_BECH32, access_list))
+        .and(rate_limit(ROUTE_BALANCE_BECH32, rate_limiter))
         .and(with_storage(storage))
         .and_then(balance_bech32)
 }
@@ -3,8 +3,14 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_BALANCE_ED25519, filters::with_storage, path_params::ed25519_address, permission::has_permission,
-        rejection::CustomRejection, storage::StorageBackend,
+        config::ROUTE_BALANCE_ED25519,
+        filters::with_storage,
+        openapi::{schema, Content, RouteDoc},
+        path_params::ed25519_address,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::BalanceAddressResponse},
 };
@@ -16,8 +22,6 @@ use bee_storage::access::Fetch;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (Ed25519Address,), Error = warp::Rejection> + Clone {
     super::path()
         .and(warp::path("addresses"))
@@ -26,14 +30,23 @@ fn path() -> impl Filter<Extract = (Ed25519Address,), Error = warp::Rejection> +
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the balance of an ed25519 address.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<BalanceAddressResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_BALANCE_ED25519, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_BALANCE_ED25519, access_list))
+        .and(rate_limit(ROUTE_BALANCE_ED25519, rate_limiter))
         .and(with_storage(storage))
         .and_then(balance_ed25519)
 }
@@ -0,0 +1,82 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::ROUTE_CONFIG_RELOAD,
+        filters::with_config_reloader,
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+    },
+    types::{body::SuccessBody, responses::ConfigReloadResponse},
+};
+
+use bee_runtime::resource::ResourceHandle;
+
+use tokio::sync::{mpsc, oneshot};
+use warp::{reject, Filter, Rejection, Reply};
+
+/// A request to reload the node configuration, answered with the applied changes or the reason of the failure.
+pub type ConfigReloadRequest = oneshot::Sender<Result<ConfigReloadResponse, String>>;
+
+/// Sends configuration reload requests to the node, which registers it and serves the requests.
+pub struct ConfigReloader(mpsc::UnboundedSender<ConfigReloadRequest>);
+
+impl ConfigReloader {
+    pub fn new(sender: mpsc::UnboundedSender<ConfigReloadRequest>) -> Self {
+        Self(sender)
+    }
+
+    /// Requests a reload of the configuration and waits for its outcome.
+    pub async fn reload(&self) -> Result<Result<ConfigReloadResponse, String>, CustomRejection> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.0
+            .send(sender)
+            .map_err(|_| CustomRejection::ServiceUnavailable("configuration reload is not available".to_string()))?;
+
+        receiver
+            .await
+            .map_err(|_| CustomRejection::ServiceUnavailable("configuration reload was interrupted".to_string()))
+    }
+}
+
+fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    super::path()
+        .and(warp::path("config"))
+        .and(warp::path("reload"))
+        .and(warp::path::end())
+}
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Reloads the configuration, applying the changes that don't require a restart.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<ConfigReloadResponse>>),
+};
+
+pub(crate) fn filter(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    config_reloader: ResourceHandle<ConfigReloader>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(ROUTE_CONFIG_RELOAD, access_list))
+        .and(rate_limit(ROUTE_CONFIG_RELOAD, rate_limiter))
+        .and(with_config_reloader(config_reloader))
+        .and_then(config_reload)
+}
+
+pub(crate) async fn config_reload(config_reloader: ResourceHandle<ConfigReloader>) -> Result<impl Reply, Rejection> {
+    match config_reloader.reload().await.map_err(reject::custom)? {
+        Ok(response) => Ok(warp::reply::json(&SuccessBody::new(response))),
+        Err(e) => Err(reject::custom(CustomRejection::BadRequest(format!(
+            "failed to reload the configuration: {}",
+            e
+        )))),
+    }
+}
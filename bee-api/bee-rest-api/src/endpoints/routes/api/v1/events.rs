@@ -0,0 +1,235 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_EVENTS},
+        events::{EventSubscribers, EventTopic, SubscriberHandle},
+        filters::{with_event_subscribers, with_rest_api_config},
+        openapi::{Content, QueryParam, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+    },
+    types::body::{DefaultErrorResponse, ErrorBody},
+};
+
+use futures::{
+    future::{ready, FutureExt},
+    sink::SinkExt,
+    stream::{self, StreamExt},
+};
+use log::{debug, warn};
+use serde::Deserialize;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio_stream::wrappers::ReceiverStream;
+use warp::{
+    reject,
+    sse::{self, Event as SseEvent},
+    ws::{Message, WebSocket, Ws},
+    Filter, Rejection, Reply,
+};
+
+use std::collections::{HashMap, HashSet};
+
+// Number of replies buffered for a WebSocket client before it is considered to flood commands without reading them and
+// disconnected.
+const REPLY_BUFFER_SIZE: usize = 16;
+
+/// Commands sent by clients over the WebSocket, e.g. `{"type": "subscribe", "topic": "milestones/latest"}`.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum EventCommand {
+    Subscribe { topic: String },
+    Unsubscribe { topic: String },
+}
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path().and(warp::path("events")).and(warp::path::end())
+}
+
+const TOPICS: QueryParam = QueryParam::optional("topics");
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Streams the events of the given topics, or of the topics subscribed to over a WebSocket.",
+    query: &[TOPICS],
+    request: Content::Empty,
+    status: 200,
+    response: Content::EventStream,
+};
+
+pub(crate) fn filter(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    rest_api_config: RestApiConfig,
+    event_subscribers: EventSubscribers,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_EVENTS, access_list))
+        .and(rate_limit(ROUTE_EVENTS, rate_limiter))
+        .and(
+            warp::ws()
+                .and(with_rest_api_config(rest_api_config.clone()))
+                .and(with_event_subscribers(event_subscribers.clone()))
+                .map(events_ws)
+                .or(warp::query()
+                    .and_then(|query: HashMap<String, String>| async move {
+                        match query.get(TOPICS.name) {
+                            Some(topics) => topics
+                                .split(',')
+                                .map(str::parse::<EventTopic>)
+                                .collect::<Result<HashSet<_>, _>>()
+                                .map_err(|e| reject::custom(CustomRejection::BadRequest(e))),
+                            None => Err(reject::custom(CustomRejection::BadRequest(
+                                "invalid query parameter".to_string(),
+                            ))),
+                        }
+                    })
+                    .and(with_rest_api_config(rest_api_config))
+                    .and(with_event_subscribers(event_subscribers))
+                    .and_then(events_sse)),
+        )
+}
+
+pub(crate) fn events_ws(ws: Ws, rest_api_config: RestApiConfig, event_subscribers: EventSubscribers) -> impl Reply {
+    ws.on_upgrade(move |socket| ws_connected(socket, rest_api_config.max_event_subscriptions(), event_subscribers))
+}
+
+pub(crate) async fn events_sse(
+    topics: HashSet<EventTopic>,
+    rest_api_config: RestApiConfig,
+    event_subscribers: EventSubscribers,
+) -> Result<impl Reply, Rejection> {
+    let limit = rest_api_config.max_event_subscriptions();
+    let (subscriber, rx) = event_subscribers
+        .add()
+        .map_err(|e| reject::custom(CustomRejection::ServiceUnavailable(e.to_string())))?;
+
+    for topic in topics {
+        subscriber
+            .subscribe(topic, limit)
+            .map_err(|e| reject::custom(CustomRejection::BadRequest(e.to_string())))?;
+    }
+
+    // The subscriber is moved into the stream so that it is removed when the client disconnects.
+    let events = ReceiverStream::new(rx).map(move |event| {
+        let _ = &subscriber;
+        SseEvent::default().event(event.topic).json_data(event.data)
+    });
+
+    Ok(sse::reply(sse::keep_alive().stream(events)))
+}
+
+fn error_message(code: &str, message: String) -> Option<Message> {
+    serde_json::to_string(&ErrorBody::new(DefaultErrorResponse {
+        code: code.to_string(),
+        message,
+    }))
+    .ok()
+    .map(Message::text)
+}
+
+async fn ws_connected(mut socket: WebSocket, limit: usize, event_subscribers: EventSubscribers) {
+    let (subscriber, rx) = match event_subscribers.add() {
+        Ok(added) => added,
+        Err(e) => {
+            if let Some(error) = error_message("503", e.to_string()) {
+                let _ = socket.send(error).await;
+            }
+            let _ = socket.close().await;
+            return;
+        }
+    };
+    let (ws_tx, mut ws_rx) = socket.split();
+    let (reply_tx, reply_rx) = mpsc::channel(REPLY_BUFFER_SIZE);
+
+    // The stream of events ends when the subscribers are cleared at shutdown or when the client lags behind. The marker
+    // chained to it then closes the connection even though replies could still be sent.
+    let events = ReceiverStream::new(rx)
+        .filter_map(|event| {
+            ready(
+                serde_json::to_string(&event)
+                    .ok()
+                    .map(|event| Some(Message::text(event))),
+            )
+        })
+        .chain(stream::once(ready(None)));
+    let replies = ReceiverStream::new(reply_rx).map(Some);
+    let outgoing = stream::select(events, replies)
+        .take_while(|message| ready(message.is_some()))
+        .filter_map(|message| ready(message.map(Ok)));
+
+    let task = tokio::spawn(outgoing.forward(ws_tx).map(|result| {
+        if let Err(e) = result {
+            debug!("Event stream send error: {}.", e);
+        }
+    }));
+
+    while let Some(result) = ws_rx.next().await {
+        let message = match result {
+            Ok(message) => message,
+            Err(e) => {
+                debug!("Event stream error: {}.", e);
+                break;
+            }
+        };
+
+        if let Ok(text) = message.to_str() {
+            if let Err(reason) = ws_command(text, limit, &subscriber) {
+                if let Some(error) = error_message("400", reason) {
+                    if !queue_reply(&reply_tx, error) {
+                        // The client doesn't read its replies, so the pending sends are dropped along with the
+                        // connection.
+                        warn!("Disconnecting event subscriber flooding commands.");
+                        task.abort();
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    drop(subscriber);
+    drop(reply_tx);
+    let _ = task.await;
+}
+
+/// Queues a reply to a WebSocket client, returning whether the client keeps up with its replies.
+fn queue_reply(reply_tx: &mpsc::Sender<Message>, reply: Message) -> bool {
+    match reply_tx.try_send(reply) {
+        Ok(()) => true,
+        Err(TrySendError::Full(_)) => false,
+        // The connection is closing anyway.
+        Err(TrySendError::Closed(_)) => true,
+    }
+}
+
+fn ws_command(text: &str, limit: usize, subscriber: &SubscriberHandle) -> Result<(), String> {
+    match serde_json::from_str(text).map_err(|_| "invalid command".to_string())? {
+        EventCommand::Subscribe { topic } => subscriber.subscribe(topic.parse()?, limit).map_err(|e| e.to_string()),
+        EventCommand::Unsubscribe { topic } => {
+            subscriber.unsubscribe(&topic.parse()?);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flooding_client_is_detected() {
+        let (reply_tx, mut reply_rx) = mpsc::channel(REPLY_BUFFER_SIZE);
+
+        for _ in 0..REPLY_BUFFER_SIZE {
+            assert!(queue_reply(&reply_tx, Message::text("error")));
+        }
+        assert!(!queue_reply(&reply_tx, Message::text("error")));
+
+        // Reading a reply makes room for the next one.
+        assert!(reply_rx.try_recv().is_ok());
+        assert!(queue_reply(&reply_tx, Message::text("error")));
+    }
+}
@@ -6,67 +6,100 @@ use crate::{
         config::{RestApiConfig, ROUTE_INFO},
         filters::{
             with_bech32_hrp, with_network_id, with_node_info, with_peer_manager, with_protocol_config,
-            with_rest_api_config, with_tangle,
+            with_rest_api_config, with_storage, with_tangle, with_throughput_metrics, with_update_status,
         },
-        permission::has_permission,
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
         routes::health,
         storage::StorageBackend,
         Bech32Hrp, NetworkId,
     },
-    types::{body::SuccessBody, responses::InfoResponse},
+    types::{body::SuccessBody, dtos::UpdateDto, responses::InfoResponse},
 };
 
-use bee_protocol::workers::{config::ProtocolConfig, PeerManager};
+use bee_ledger::workers::storage;
+use bee_protocol::{
+    types::metrics::ThroughputMetrics,
+    workers::{config::ProtocolConfig, PeerManager},
+};
 use bee_runtime::{node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::MsTangle;
 
-use warp::{Filter, Rejection, Reply};
+use warp::{reject, Filter, Rejection, Reply};
 
-use std::{convert::Infallible, net::IpAddr};
+use std::sync::RwLock;
 
 fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
     super::path().and(warp::path("info")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns general information about the node.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<InfoResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
+    storage: ResourceHandle<B>,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
     node_info: ResourceHandle<NodeInfo>,
     peer_manager: ResourceHandle<PeerManager>,
+    throughput_metrics: ResourceHandle<ThroughputMetrics>,
+    update_status: ResourceHandle<UpdateStatus>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_INFO, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_INFO, access_list))
+        .and(rate_limit(ROUTE_INFO, rate_limiter))
         .and(with_tangle(tangle))
+        .and(with_storage(storage))
         .and(with_network_id(network_id))
         .and(with_bech32_hrp(bech32_hrp))
         .and(with_rest_api_config(rest_api_config))
         .and(with_protocol_config(protocol_config))
         .and(with_node_info(node_info))
         .and(with_peer_manager(peer_manager))
+        .and(with_throughput_metrics(throughput_metrics))
+        .and(with_update_status(update_status))
         .and_then(info)
 }
 
 pub(crate) async fn info<B: StorageBackend>(
     tangle: ResourceHandle<MsTangle<B>>,
+    storage: ResourceHandle<B>,
     network_id: NetworkId,
     bech32_hrp: Bech32Hrp,
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
     node_info: ResourceHandle<NodeInfo>,
     peer_manager: ResourceHandle<PeerManager>,
-) -> Result<impl Reply, Infallible> {
+    throughput_metrics: ResourceHandle<ThroughputMetrics>,
+    update_status: ResourceHandle<UpdateStatus>,
+) -> Result<impl Reply, Rejection> {
     let latest_milestone_index = tangle.get_latest_milestone_index();
     let latest_milestone_timestamp = tangle
         .get_milestone(latest_milestone_index)
         .await
         .map(|m| m.timestamp())
         .unwrap_or_default();
+    let confirmed_milestone_index = tangle.get_confirmed_milestone_index();
+    let ledger_state_hash = storage::fetch_ledger_state_hash(&*storage, confirmed_milestone_index)
+        .await
+        .map_err(|_| {
+            reject::custom(CustomRejection::ServiceUnavailable(
+                "can not fetch from storage".to_string(),
+            ))
+        })?;
 
     Ok(warp::reply::json(&SuccessBody::new(InfoResponse {
         name: node_info.name.clone(),
@@ -75,13 +108,14 @@ pub(crate) async fn info<B: StorageBackend>(
         network_id: network_id.0,
         bech32_hrp,
         min_pow_score: protocol_config.minimum_pow_score(),
-        messages_per_second: 0f64,            // TODO
-        referenced_messages_per_second: 0f64, // TODO
-        referenced_rate: 0f64,                // TODO
+        messages_per_second: throughput_metrics.messages_per_second(),
+        referenced_messages_per_second: throughput_metrics.referenced_messages_per_second(),
+        referenced_rate: throughput_metrics.referenced_rate(),
         latest_milestone_timestamp,
         latest_milestone_index: *latest_milestone_index,
-        confirmed_milestone_index: *tangle.get_confirmed_milestone_index(),
+        confirmed_milestone_index: *confirmed_milestone_index,
         pruning_index: *tangle.get_pruning_index(),
+        ledger_state_hash: ledger_state_hash.map(|hash| hash.to_string()),
         features: {
             let mut features = Vec::new();
             if rest_api_config.feature_proof_of_work() {
@@ -89,5 +123,22 @@ pub(crate) async fn info<B: StorageBackend>(
             }
             features
         },
+        update: update_status.get(),
     })))
 }
+
+/// Outcome of the last check of the release feed, updated by the node and reported by the info endpoint.
+#[derive(Default)]
+pub struct UpdateStatus(RwLock<Option<UpdateDto>>);
+
+impl UpdateStatus {
+    /// Sets the outcome of the last check.
+    pub fn set(&self, update: UpdateDto) {
+        self.0.write().expect("Update status lock poisoned").replace(update);
+    }
+
+    /// Returns the outcome of the last check, if any.
+    pub fn get(&self) -> Option<UpdateDto> {
+        self.0.read().expect("Update status lock poisoned").clone()
+    }
+}
@@ -3,8 +3,14 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_MESSAGE, filters::with_tangle, path_params::message_id, permission::has_permission,
-        rejection::CustomRejection, storage::StorageBackend,
+        config::ROUTE_MESSAGE,
+        filters::with_tangle,
+        openapi::{schema, Content, RouteDoc},
+        path_params::message_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
     },
     types::{body::SuccessBody, dtos::MessageDto, responses::MessageResponse},
 };
@@ -15,8 +21,6 @@ use bee_tangle::MsTangle;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (MessageId,), Error = Rejection> + Clone {
     super::path()
         .and(warp::path("messages"))
@@ -24,14 +28,23 @@ fn path() -> impl Filter<Extract = (MessageId,), Error = Rejection> + Clone {
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns a message.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessageResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MESSAGE, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_MESSAGE, access_list))
+        .and(rate_limit(ROUTE_MESSAGE, rate_limiter))
         .and(with_tangle(tangle))
         .and_then(message)
 }
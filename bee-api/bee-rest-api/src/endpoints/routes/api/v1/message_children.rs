@@ -3,7 +3,12 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_MESSAGE_CHILDREN, filters::with_tangle, path_params::message_id, permission::has_permission,
+        config::ROUTE_MESSAGE_CHILDREN,
+        filters::with_tangle,
+        openapi::{schema, Content, RouteDoc},
+        path_params::message_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
         storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::MessageChildrenResponse},
@@ -15,7 +20,7 @@ use bee_tangle::MsTangle;
 
 use warp::{Filter, Rejection, Reply};
 
-use std::{iter::FromIterator, net::IpAddr};
+use std::iter::FromIterator;
 
 fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clone {
     super::path()
@@ -25,14 +30,23 @@ fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clon
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the children of a message.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessageChildrenResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MESSAGE_CHILDREN, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_MESSAGE_CHILDREN, access_list))
+        .and(rate_limit(ROUTE_MESSAGE_CHILDREN, rate_limiter))
         .and(with_tangle(tangle))
         .and_then(message_children)
 }
@@ -0,0 +1,240 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_MESSAGE_FUTURE_CONE},
+        filters::{with_pending_spends, with_rest_api_config, with_tangle},
+        openapi::{schema, Content, QueryParam, RouteDoc},
+        path_params::message_id,
+        permission::{has_permission, AccessList},
+        query_params::query_param,
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        routes::api::v1::message_metadata::create_message_metadata,
+        storage::StorageBackend,
+        CONFIRMED_THRESHOLD,
+    },
+    types::{body::SuccessBody, responses::MessageConeResponse},
+};
+
+use bee_message::MessageId;
+use bee_protocol::workers::PendingSpends;
+use bee_runtime::resource::ResourceHandle;
+use bee_tangle::MsTangle;
+
+use warp::{reject, Filter, Rejection, Reply};
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clone {
+    super::path()
+        .and(warp::path("messages"))
+        .and(message_id())
+        .and(warp::path("future-cone"))
+        .and(warp::path::end())
+}
+
+const DEPTH: QueryParam = QueryParam::optional("depth");
+const MAX_RESULTS: QueryParam = QueryParam::optional("maxResults");
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the metadata of the future cone of a message, up to a number of levels.",
+    query: &[DEPTH, MAX_RESULTS],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessageConeResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    rest_api_config: RestApiConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_MESSAGE_FUTURE_CONE, access_list))
+        .and(rate_limit(ROUTE_MESSAGE_FUTURE_CONE, rate_limiter))
+        .and(warp::query::<HashMap<String, String>>())
+        .and(with_tangle(tangle))
+        .and(with_pending_spends(pending_spends))
+        .and(with_rest_api_config(rest_api_config))
+        .and_then(message_future_cone)
+}
+
+/// A breadth-first walk of a future cone, level by level, so that every message is reached through its shortest path
+/// from the root whatever the order of the children.
+struct FutureCone {
+    max_depth: usize,
+    max_results: usize,
+    queue: VecDeque<(MessageId, usize)>,
+    seen: HashSet<MessageId>,
+    messages: Vec<MessageId>,
+    truncated: bool,
+}
+
+impl FutureCone {
+    fn new(root: MessageId, max_depth: usize, max_results: usize) -> Self {
+        let mut cone = Self {
+            max_depth,
+            max_results,
+            queue: VecDeque::new(),
+            seen: HashSet::new(),
+            messages: Vec::new(),
+            truncated: false,
+        };
+        cone.admit(root, 0);
+
+        cone
+    }
+
+    fn admit(&mut self, id: MessageId, depth: usize) {
+        if self.seen.contains(&id) {
+            return;
+        }
+        if self.messages.len() >= self.max_results {
+            self.truncated = true;
+            return;
+        }
+
+        self.seen.insert(id);
+        self.messages.push(id);
+        self.queue.push_back((id, depth));
+    }
+
+    /// Pops the next message whose children belong to the cone, along with its depth.
+    fn pop(&mut self) -> Option<(MessageId, usize)> {
+        while let Some((id, depth)) = self.queue.pop_front() {
+            if self.truncated {
+                break;
+            }
+            if depth < self.max_depth {
+                return Some((id, depth));
+            }
+        }
+
+        None
+    }
+
+    /// Admits the children of a popped message in the next level of the cone.
+    fn push_children(&mut self, depth: usize, children: impl IntoIterator<Item = MessageId>) {
+        for child in children {
+            self.admit(child, depth + 1);
+        }
+    }
+}
+
+pub(crate) async fn message_future_cone<B: StorageBackend>(
+    message_id: MessageId,
+    query: HashMap<String, String>,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    rest_api_config: RestApiConfig,
+) -> Result<impl Reply, Rejection> {
+    if !tangle.is_confirmed_threshold(CONFIRMED_THRESHOLD) {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            "the node is not synchronized".to_string(),
+        )));
+    }
+
+    let depth = query_param(&query, DEPTH.name)?
+        .unwrap_or_else(|| rest_api_config.max_cone_depth())
+        .min(rest_api_config.max_cone_depth());
+    let max_results = query_param(&query, MAX_RESULTS.name)?
+        .unwrap_or_else(|| rest_api_config.max_cone_size())
+        .min(rest_api_config.max_cone_size());
+
+    if !tangle.contains(&message_id).await {
+        return Err(reject::custom(CustomRejection::NotFound(
+            "can not find message".to_string(),
+        )));
+    }
+
+    let mut cone = FutureCone::new(message_id, depth, max_results);
+
+    while let Some((id, depth)) = cone.pop() {
+        cone.push_children(depth, tangle.get_children(&id).await.unwrap_or_default());
+    }
+
+    let truncated = cone.truncated;
+
+    let mut messages = Vec::with_capacity(cone.messages.len());
+    for id in cone.messages {
+        if let Some(metadata) = create_message_metadata(id, &tangle, &pending_spends, &rest_api_config).await {
+            messages.push(metadata);
+        }
+    }
+
+    Ok(warp::reply::json(&SuccessBody::new(MessageConeResponse {
+        message_id: message_id.to_string(),
+        max_results,
+        count: messages.len(),
+        truncated,
+        messages,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(byte: u8) -> MessageId {
+        MessageId::new([byte; 32])
+    }
+
+    fn walk(children: &[(u8, &[u8])], max_depth: usize, max_results: usize) -> FutureCone {
+        let children = children
+            .iter()
+            .map(|(parent, children)| (id(*parent), children.iter().map(|child| id(*child)).collect()))
+            .collect::<HashMap<_, Vec<_>>>();
+        let mut cone = FutureCone::new(id(0), max_depth, max_results);
+
+        while let Some((message, depth)) = cone.pop() {
+            cone.push_children(depth, children.get(&message).cloned().unwrap_or_default());
+        }
+
+        cone
+    }
+
+    #[test]
+    fn root_is_admitted() {
+        let cone = walk(&[(0, &[1])], 0, 10);
+
+        assert_eq!(cone.messages, vec![id(0)]);
+        assert!(!cone.truncated);
+    }
+
+    #[test]
+    fn depth_is_bounded() {
+        let cone = walk(&[(0, &[1]), (1, &[2]), (2, &[3])], 2, 10);
+
+        assert_eq!(cone.messages, vec![id(0), id(1), id(2)]);
+        assert!(!cone.truncated);
+    }
+
+    #[test]
+    fn diamond_is_walked_by_shortest_path() {
+        // root -> A -> B -> C and root -> C, with the longest path listed first.
+        let cone = walk(&[(0, &[1, 3]), (1, &[2]), (2, &[3]), (3, &[4])], 2, 10);
+
+        assert_eq!(cone.messages, vec![id(0), id(1), id(3), id(2), id(4)]);
+        assert!(!cone.truncated);
+    }
+
+    #[test]
+    fn shared_children_are_admitted_once() {
+        let cone = walk(&[(0, &[1, 2]), (1, &[3]), (2, &[3])], 10, 10);
+
+        assert_eq!(cone.messages, vec![id(0), id(1), id(2), id(3)]);
+    }
+
+    #[test]
+    fn results_are_bounded() {
+        let cone = walk(&[(0, &[1, 2]), (1, &[3])], 10, 2);
+
+        assert_eq!(cone.messages, vec![id(0), id(1)]);
+        assert!(cone.truncated);
+    }
+}
@@ -3,21 +3,27 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_MESSAGE_METADATA, filters::with_tangle, path_params::message_id, permission::has_permission,
-        rejection::CustomRejection, storage::StorageBackend, CONFIRMED_THRESHOLD,
+        config::{RestApiConfig, ROUTE_MESSAGE_METADATA},
+        filters::{with_pending_spends, with_rest_api_config, with_tangle},
+        openapi::{schema, Content, RouteDoc},
+        path_params::message_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
+        CONFIRMED_THRESHOLD,
     },
     types::{body::SuccessBody, dtos::LedgerInclusionStateDto, responses::MessageMetadataResponse},
 };
 
 use bee_ledger_types::types::ConflictReason;
-use bee_message::{payload::Payload, MessageId};
+use bee_message::{milestone::MilestoneIndex, payload::Payload, Message, MessageId};
+use bee_protocol::workers::PendingSpends;
 use bee_runtime::resource::ResourceHandle;
-use bee_tangle::MsTangle;
+use bee_tangle::{config::TangleConfig, metadata::MessageMetadata, MsTangle};
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clone {
     super::path()
         .and(warp::path("messages"))
@@ -26,21 +32,36 @@ fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clon
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the metadata of a message.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessageMetadataResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    rest_api_config: RestApiConfig,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MESSAGE_METADATA, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_MESSAGE_METADATA, access_list))
+        .and(rate_limit(ROUTE_MESSAGE_METADATA, rate_limiter))
         .and(with_tangle(tangle))
+        .and(with_pending_spends(pending_spends))
+        .and(with_rest_api_config(rest_api_config))
         .and_then(message_metadata)
 }
 
 pub(crate) async fn message_metadata<B: StorageBackend>(
     message_id: MessageId,
     tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    rest_api_config: RestApiConfig,
 ) -> Result<impl Reply, Rejection> {
     if !tangle.is_confirmed_threshold(CONFIRMED_THRESHOLD) {
         return Err(reject::custom(CustomRejection::ServiceUnavailable(
@@ -48,120 +69,247 @@ pub(crate) async fn message_metadata<B: StorageBackend>(
         )));
     }
 
-    match tangle.get(&message_id).await.map(|m| (*m).clone()) {
-        Some(message) => {
-            // existing message <=> existing metadata, therefore unwrap() is safe
-            let metadata = tangle.get_metadata(&message_id).await.unwrap();
-
-            // TODO: access constants from URTS
-            let ymrsi_delta = 8;
-            let omrsi_delta = 13;
-            let below_max_depth = 15;
-
-            let (
-                is_solid,
-                referenced_by_milestone_index,
-                milestone_index,
-                ledger_inclusion_state,
-                conflict_reason,
-                should_promote,
-                should_reattach,
-            ) = {
-                let is_solid;
-                let referenced_by_milestone_index;
-                let milestone_index;
-                let ledger_inclusion_state;
-                let conflict_reason;
-                let should_promote;
-                let should_reattach;
-
-                if let Some(milestone) = metadata.milestone_index() {
-                    // message is referenced by a milestone
-                    is_solid = true;
-                    referenced_by_milestone_index = Some(*milestone);
-
-                    if metadata.flags().is_milestone() {
-                        milestone_index = Some(*milestone);
-                    } else {
-                        milestone_index = None;
-                    }
-
-                    ledger_inclusion_state = Some(if let Some(Payload::Transaction(_)) = message.payload() {
-                        if metadata.conflict() != ConflictReason::None {
-                            conflict_reason = Some(metadata.conflict());
-                            LedgerInclusionStateDto::Conflicting
-                        } else {
-                            conflict_reason = None;
-                            // maybe not checked by the ledger yet, but still
-                            // returning "included". should
-                            // `metadata.flags().is_conflicting` return an Option
-                            // instead?
-                            LedgerInclusionStateDto::Included
-                        }
-                    } else {
-                        conflict_reason = None;
-                        LedgerInclusionStateDto::NoTransaction
-                    });
-                    should_reattach = None;
-                    should_promote = None;
-                } else if metadata.flags().is_solid() {
-                    // message is not referenced by a milestone but solid
-                    is_solid = true;
-                    referenced_by_milestone_index = None;
-                    milestone_index = None;
-                    ledger_inclusion_state = None;
-                    conflict_reason = None;
+    match create_message_metadata(message_id, &tangle, &pending_spends, &rest_api_config).await {
+        Some(metadata) => Ok(warp::reply::json(&SuccessBody::new(metadata))),
+        None => Err(reject::custom(CustomRejection::NotFound(
+            "can not find message".to_string(),
+        ))),
+    }
+}
+
+pub async fn create_message_metadata<B: StorageBackend>(
+    message_id: MessageId,
+    tangle: &MsTangle<B>,
+    pending_spends: &PendingSpends,
+    rest_api_config: &RestApiConfig,
+) -> Option<MessageMetadataResponse> {
+    let message = tangle.get(&message_id).await.map(|m| (*m).clone())?;
+    // existing message <=> existing metadata, therefore unwrap() is safe
+    let metadata = tangle.get_metadata(&message_id).await.unwrap();
+
+    Some(message_metadata_response(
+        message_id,
+        &message,
+        &metadata,
+        tangle.get_solid_milestone_index(),
+        TipSelectionDeltas::new(tangle.config(), rest_api_config),
+        pending_spends,
+    ))
+}
+
+/// The maximum deltas between the solid milestone index and the YMRSI/OMRSI of a message before the URTS tip selection
+/// considers it lazy or below max depth.
+#[derive(Clone, Copy)]
+struct TipSelectionDeltas {
+    below_max_depth: u32,
+    ymrsi_delta: u32,
+    omrsi_delta: u32,
+}
+
+impl TipSelectionDeltas {
+    fn new(tangle_config: &TangleConfig, rest_api_config: &RestApiConfig) -> Self {
+        Self {
+            below_max_depth: tangle_config.below_max_depth(),
+            ymrsi_delta: rest_api_config.ymrsi_delta(),
+            omrsi_delta: rest_api_config.omrsi_delta(),
+        }
+    }
+
+    /// Returns whether a solid message with the given YMRSI and OMRSI should be promoted and whether it should be
+    /// reattached.
+    fn score(
+        &self,
+        solid_milestone_index: MilestoneIndex,
+        ymrsi: MilestoneIndex,
+        omrsi: MilestoneIndex,
+    ) -> (bool, bool) {
+        let smi = *solid_milestone_index;
+
+        if smi.saturating_sub(*omrsi) > self.below_max_depth {
+            (false, true)
+        } else if smi.saturating_sub(*ymrsi) > self.ymrsi_delta || smi.saturating_sub(*omrsi) > self.omrsi_delta {
+            (true, false)
+        } else {
+            (false, false)
+        }
+    }
+}
+
+fn message_metadata_response(
+    message_id: MessageId,
+    message: &Message,
+    metadata: &MessageMetadata,
+    solid_milestone_index: MilestoneIndex,
+    deltas: TipSelectionDeltas,
+    pending_spends: &PendingSpends,
+) -> MessageMetadataResponse {
+    let (
+        is_solid,
+        referenced_by_milestone_index,
+        milestone_index,
+        ledger_inclusion_state,
+        conflict_reason,
+        should_promote,
+        should_reattach,
+    ) = {
+        let is_solid;
+        let referenced_by_milestone_index;
+        let milestone_index;
+        let ledger_inclusion_state;
+        let conflict_reason;
+        let should_promote;
+        let should_reattach;
 
-                    let lmi = *tangle.get_solid_milestone_index();
-                    // unwrap() of OMRSI/YMRSI is safe since message is solid
-                    if (lmi - *metadata.omrsi().unwrap().index()) > below_max_depth {
-                        should_promote = Some(false);
-                        should_reattach = Some(true);
-                    } else if (lmi - *metadata.ymrsi().unwrap().index()) > ymrsi_delta
-                        || (lmi - omrsi_delta) > omrsi_delta
-                    {
-                        should_promote = Some(true);
-                        should_reattach = Some(false);
-                    } else {
-                        should_promote = Some(false);
-                        should_reattach = Some(false);
-                    };
+        if let Some(milestone) = metadata.milestone_index() {
+            // message is referenced by a milestone
+            is_solid = true;
+            referenced_by_milestone_index = Some(*milestone);
+
+            if metadata.flags().is_milestone() {
+                milestone_index = Some(*milestone);
+            } else {
+                milestone_index = None;
+            }
+
+            ledger_inclusion_state = Some(if let Some(Payload::Transaction(_)) = message.payload() {
+                if metadata.conflict() != ConflictReason::None {
+                    conflict_reason = Some(metadata.conflict());
+                    LedgerInclusionStateDto::Conflicting
                 } else {
-                    // the message is not referenced by a milestone and not solid
-                    is_solid = false;
-                    referenced_by_milestone_index = None;
-                    milestone_index = None;
-                    ledger_inclusion_state = None;
                     conflict_reason = None;
-                    should_reattach = Some(true);
-                    should_promote = Some(false);
+                    // maybe not checked by the ledger yet, but still
+                    // returning "included". should
+                    // `metadata.flags().is_conflicting` return an Option
+                    // instead?
+                    LedgerInclusionStateDto::Included
                 }
+            } else {
+                conflict_reason = None;
+                LedgerInclusionStateDto::NoTransaction
+            });
+            should_reattach = None;
+            should_promote = None;
+        } else if metadata.flags().is_solid() {
+            // message is not referenced by a milestone but solid
+            is_solid = true;
+            referenced_by_milestone_index = None;
+            milestone_index = None;
+            ledger_inclusion_state = None;
+            // A likely conflict found by the transaction pre-validation, until the white flag rules on it.
+            conflict_reason = Some(metadata.conflict()).filter(|conflict| *conflict != ConflictReason::None);
 
-                (
-                    is_solid,
-                    referenced_by_milestone_index,
-                    milestone_index,
-                    ledger_inclusion_state,
-                    conflict_reason,
-                    should_reattach,
-                    should_promote,
-                )
-            };
-
-            Ok(warp::reply::json(&SuccessBody::new(MessageMetadataResponse {
-                message_id: message_id.to_string(),
-                parent_message_ids: message.parents().iter().map(|id| id.to_string()).collect(),
-                is_solid,
-                referenced_by_milestone_index,
-                milestone_index,
-                ledger_inclusion_state,
-                conflict_reason: conflict_reason.map(|c| c as u8),
-                should_promote,
-                should_reattach,
-            })))
+            // unwrap() of OMRSI/YMRSI is safe since message is solid
+            let (promote, reattach) = deltas.score(
+                solid_milestone_index,
+                metadata.ymrsi().unwrap().index(),
+                metadata.omrsi().unwrap().index(),
+            );
+            should_promote = Some(promote);
+            should_reattach = Some(reattach);
+        } else {
+            // the message is not referenced by a milestone and not solid
+            is_solid = false;
+            referenced_by_milestone_index = None;
+            milestone_index = None;
+            ledger_inclusion_state = None;
+            conflict_reason = Some(metadata.conflict()).filter(|conflict| *conflict != ConflictReason::None);
+            should_reattach = Some(true);
+            should_promote = Some(false);
         }
-        None => Err(reject::custom(CustomRejection::NotFound(
-            "can not find message".to_string(),
-        ))),
+
+        (
+            is_solid,
+            referenced_by_milestone_index,
+            milestone_index,
+            ledger_inclusion_state,
+            conflict_reason,
+            should_promote,
+            should_reattach,
+        )
+    };
+
+    MessageMetadataResponse {
+        message_id: message_id.to_string(),
+        parent_message_ids: message.parents().iter().map(|id| id.to_string()).collect(),
+        is_solid,
+        referenced_by_milestone_index,
+        milestone_index,
+        ledger_inclusion_state,
+        conflict_reason: conflict_reason.map(|c| c as u8),
+        should_promote,
+        should_reattach,
+        // Only transactions not referenced by a milestone yet are pending.
+        pending_conflict: pending_spends.is_conflicting(&message_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DELTAS: TipSelectionDeltas = TipSelectionDeltas {
+        below_max_depth: 15,
+        ymrsi_delta: 8,
+        omrsi_delta: 13,
+    };
+
+    #[test]
+    fn default_deltas_match_the_tip_selection() {
+        let config = RestApiConfig::build().finish().unwrap();
+
+        assert_eq!(config.ymrsi_delta(), DELTAS.ymrsi_delta);
+        assert_eq!(config.omrsi_delta(), DELTAS.omrsi_delta);
+    }
+
+    #[test]
+    fn recent_message_is_left_alone() {
+        assert_eq!(
+            DELTAS.score(MilestoneIndex(100), MilestoneIndex(95), MilestoneIndex(90)),
+            (false, false)
+        );
+    }
+
+    #[test]
+    fn lazy_ymrsi_is_promoted() {
+        assert_eq!(
+            DELTAS.score(MilestoneIndex(100), MilestoneIndex(91), MilestoneIndex(90)),
+            (true, false)
+        );
+    }
+
+    #[test]
+    fn lazy_omrsi_is_promoted() {
+        assert_eq!(
+            DELTAS.score(MilestoneIndex(100), MilestoneIndex(95), MilestoneIndex(86)),
+            (true, false)
+        );
+    }
+
+    #[test]
+    fn below_max_depth_is_reattached() {
+        assert_eq!(
+            DELTAS.score(MilestoneIndex(100), MilestoneIndex(95), MilestoneIndex(84)),
+            (false, true)
+        );
+    }
+
+    #[test]
+    fn reaching_a_delta_is_not_lazy() {
+        assert_eq!(
+            DELTAS.score(MilestoneIndex(100), MilestoneIndex(92), MilestoneIndex(87)),
+            (false, false)
+        );
+        assert_eq!(
+            DELTAS.score(MilestoneIndex(100), MilestoneIndex(92), MilestoneIndex(85)),
+            (true, false)
+        );
+    }
+
+    #[test]
+    fn indexes_ahead_of_the_solid_milestone_are_recent() {
+        assert_eq!(
+            DELTAS.score(MilestoneIndex(100), MilestoneIndex(101), MilestoneIndex(101)),
+            (false, false)
+        );
     }
 }
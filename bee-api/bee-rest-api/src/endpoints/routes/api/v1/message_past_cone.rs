@@ -0,0 +1,132 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_MESSAGE_PAST_CONE},
+        filters::{with_pending_spends, with_rest_api_config, with_tangle},
+        openapi::{schema, Content, QueryParam, RouteDoc},
+        path_params::message_id,
+        permission::{has_permission, AccessList},
+        query_params::query_param,
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        routes::api::v1::message_metadata::create_message_metadata,
+        storage::StorageBackend,
+        CONFIRMED_THRESHOLD,
+    },
+    types::{body::SuccessBody, responses::MessageConeResponse},
+};
+
+use bee_message::MessageId;
+use bee_protocol::workers::PendingSpends;
+use bee_runtime::resource::ResourceHandle;
+use bee_tangle::{traversal, MsTangle};
+
+use warp::{reject, Filter, Rejection, Reply};
+
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clone {
+    super::path()
+        .and(warp::path("messages"))
+        .and(message_id())
+        .and(warp::path("past-cone"))
+        .and(warp::path::end())
+}
+
+const MAX_RESULTS: QueryParam = QueryParam::optional("maxResults");
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the metadata of the past cone of a message, up to its confirming milestone.",
+    query: &[MAX_RESULTS],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessageConeResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    rest_api_config: RestApiConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_MESSAGE_PAST_CONE, access_list))
+        .and(rate_limit(ROUTE_MESSAGE_PAST_CONE, rate_limiter))
+        .and(warp::query::<HashMap<String, String>>())
+        .and(with_tangle(tangle))
+        .and(with_pending_spends(pending_spends))
+        .and(with_rest_api_config(rest_api_config))
+        .and_then(message_past_cone)
+}
+
+pub(crate) async fn message_past_cone<B: StorageBackend>(
+    message_id: MessageId,
+    query: HashMap<String, String>,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    rest_api_config: RestApiConfig,
+) -> Result<impl Reply, Rejection> {
+    if !tangle.is_confirmed_threshold(CONFIRMED_THRESHOLD) {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            "the node is not synchronized".to_string(),
+        )));
+    }
+
+    let max_results = query_param(&query, MAX_RESULTS.name)?
+        .unwrap_or_else(|| rest_api_config.max_cone_size())
+        .min(rest_api_config.max_cone_size());
+
+    let confirming_index = tangle
+        .get_metadata(&message_id)
+        .await
+        .ok_or_else(|| reject::custom(CustomRejection::NotFound("can not find message".to_string())))?
+        .milestone_index();
+
+    let count = AtomicUsize::new(0);
+    let truncated = AtomicBool::new(false);
+    let mut cone = Vec::new();
+
+    // The cone ends at the messages that were referenced by an older milestone than the one confirming the message,
+    // or at the referenced messages if the message is not confirmed yet.
+    traversal::visit_parents_depth_first(
+        &**tangle,
+        message_id,
+        |_, _, metadata| {
+            let matches = if metadata.milestone_index() != confirming_index {
+                false
+            } else if count.fetch_add(1, Ordering::Relaxed) >= max_results {
+                truncated.store(true, Ordering::Relaxed);
+                false
+            } else {
+                true
+            };
+            async move { matches }
+        },
+        |id, _, _| cone.push(*id),
+        |_, _, _| {},
+        |_| {},
+    )
+    .await;
+
+    let mut messages = Vec::with_capacity(cone.len());
+    for id in cone {
+        if let Some(metadata) = create_message_metadata(id, &tangle, &pending_spends, &rest_api_config).await {
+            messages.push(metadata);
+        }
+    }
+
+    Ok(warp::reply::json(&SuccessBody::new(MessageConeResponse {
+        message_id: message_id.to_string(),
+        max_results,
+        count: messages.len(),
+        truncated: truncated.load(Ordering::Relaxed),
+        messages,
+    })))
+}
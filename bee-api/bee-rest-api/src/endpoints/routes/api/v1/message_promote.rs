@@ -0,0 +1,118 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_MESSAGE_PROMOTE},
+        filters::{
+            with_message_submitter, with_network_id, with_pending_spends, with_protocol_config, with_rest_api_config,
+            with_tangle,
+        },
+        openapi::{schema, Content, RouteDoc},
+        path_params::message_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        routes::api::v1::{
+            message_metadata::create_message_metadata,
+            submit_message::{build_message_with_pow, forward_to_message_submitter},
+        },
+        storage::StorageBackend,
+        NetworkId, CONFIRMED_THRESHOLD,
+    },
+    types::{body::SuccessBody, responses::SubmitMessageResponse},
+};
+
+use bee_message::{parents::MESSAGE_PARENTS_RANGE, MessageId};
+use bee_protocol::workers::{config::ProtocolConfig, MessageSubmitterWorkerEvent, PendingSpends};
+use bee_runtime::resource::ResourceHandle;
+use bee_tangle::MsTangle;
+
+use tokio::sync::mpsc;
+use warp::{http::StatusCode, reject, Filter, Rejection, Reply};
+
+fn path() -> impl Filter<Extract = (MessageId,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("messages"))
+        .and(message_id())
+        .and(warp::path("promote"))
+        .and(warp::path::end())
+}
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Promotes a message by issuing an empty message referencing it and fresh tips.",
+    query: &[],
+    request: Content::Empty,
+    status: 201,
+    response: Content::Json(schema::<SuccessBody<SubmitMessageResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
+    network_id: NetworkId,
+    rest_api_config: RestApiConfig,
+    protocol_config: ProtocolConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(ROUTE_MESSAGE_PROMOTE, access_list))
+        .and(rate_limit(ROUTE_MESSAGE_PROMOTE, rate_limiter))
+        .and(with_tangle(tangle))
+        .and(with_pending_spends(pending_spends))
+        .and(with_message_submitter(message_submitter))
+        .and(with_network_id(network_id))
+        .and(with_rest_api_config(rest_api_config))
+        .and(with_protocol_config(protocol_config))
+        .and_then(message_promote)
+}
+
+/// Issues an empty message referencing the message to promote and fresh tips.
+pub(crate) async fn message_promote<B: StorageBackend>(
+    message_id: MessageId,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
+    network_id: NetworkId,
+    rest_api_config: RestApiConfig,
+    protocol_config: ProtocolConfig,
+) -> Result<impl Reply, Rejection> {
+    if !tangle.is_confirmed_threshold(CONFIRMED_THRESHOLD) {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            "the node is not synchronized".to_string(),
+        )));
+    }
+
+    let metadata = create_message_metadata(message_id, &tangle, &pending_spends, &rest_api_config)
+        .await
+        .ok_or_else(|| reject::custom(CustomRejection::NotFound("can not find message".to_string())))?;
+
+    if metadata.should_promote != Some(true) {
+        return Err(reject::custom(CustomRejection::BadRequest(
+            "message does not need to be promoted".to_string(),
+        )));
+    }
+
+    let mut parents = tangle.get_messages_to_approve().await.ok_or_else(|| {
+        reject::custom(CustomRejection::ServiceUnavailable(
+            "can not promote message: no tips available".to_string(),
+        ))
+    })?;
+    parents.retain(|parent| parent != &message_id);
+    parents.truncate(*MESSAGE_PARENTS_RANGE.end() - 1);
+    parents.push(message_id);
+    parents.sort_unstable();
+
+    let message = build_message_with_pow(network_id.1, parents, None, &rest_api_config, &protocol_config)?;
+    let message_id = forward_to_message_submitter(message, tangle, message_submitter).await?;
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&SuccessBody::new(SubmitMessageResponse {
+            message_id: message_id.to_string(),
+        })),
+        StatusCode::CREATED,
+    ))
+}
@@ -2,8 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::endpoints::{
-    config::ROUTE_MESSAGE_RAW, filters::with_tangle, path_params::message_id, permission::has_permission,
-    rejection::CustomRejection, storage::StorageBackend,
+    config::ROUTE_MESSAGE_RAW,
+    filters::with_tangle,
+    openapi::{Content, RouteDoc},
+    path_params::message_id,
+    permission::{has_permission, AccessList},
+    rate_limit::{rate_limit, RateLimiter},
+    rejection::CustomRejection,
+    storage::StorageBackend,
 };
 
 use bee_common::packable::Packable;
@@ -13,8 +19,6 @@ use bee_tangle::MsTangle;
 
 use warp::{http::Response, reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clone {
     super::path()
         .and(warp::path("messages"))
@@ -23,14 +27,23 @@ fn path() -> impl Filter<Extract = (MessageId,), Error = warp::Rejection> + Clon
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the raw bytes of a message.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Binary,
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MESSAGE_RAW, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_MESSAGE_RAW, access_list))
+        .and(rate_limit(ROUTE_MESSAGE_RAW, rate_limiter))
         .and(with_tangle(tangle))
         .and_then(message_raw)
 }
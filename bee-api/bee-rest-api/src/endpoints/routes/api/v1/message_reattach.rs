@@ -0,0 +1,123 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_MESSAGE_REATTACH},
+        filters::{
+            with_message_submitter, with_network_id, with_pending_spends, with_protocol_config, with_rest_api_config,
+            with_tangle,
+        },
+        openapi::{schema, Content, RouteDoc},
+        path_params::message_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        routes::api::v1::{
+            message_metadata::create_message_metadata,
+            submit_message::{build_message_with_pow, forward_to_message_submitter},
+        },
+        storage::StorageBackend,
+        NetworkId, CONFIRMED_THRESHOLD,
+    },
+    types::{body::SuccessBody, responses::SubmitMessageResponse},
+};
+
+use bee_message::MessageId;
+use bee_protocol::workers::{config::ProtocolConfig, MessageSubmitterWorkerEvent, PendingSpends};
+use bee_runtime::resource::ResourceHandle;
+use bee_tangle::MsTangle;
+
+use tokio::sync::mpsc;
+use warp::{http::StatusCode, reject, Filter, Rejection, Reply};
+
+fn path() -> impl Filter<Extract = (MessageId,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("messages"))
+        .and(message_id())
+        .and(warp::path("reattach"))
+        .and(warp::path::end())
+}
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Reattaches a message by reissuing its payload on fresh tips.",
+    query: &[],
+    request: Content::Empty,
+    status: 201,
+    response: Content::Json(schema::<SuccessBody<SubmitMessageResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
+    network_id: NetworkId,
+    rest_api_config: RestApiConfig,
+    protocol_config: ProtocolConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(ROUTE_MESSAGE_REATTACH, access_list))
+        .and(rate_limit(ROUTE_MESSAGE_REATTACH, rate_limiter))
+        .and(with_tangle(tangle))
+        .and(with_pending_spends(pending_spends))
+        .and(with_message_submitter(message_submitter))
+        .and(with_network_id(network_id))
+        .and(with_rest_api_config(rest_api_config))
+        .and(with_protocol_config(protocol_config))
+        .and_then(message_reattach)
+}
+
+/// Reissues the payload of the message to reattach on fresh tips.
+pub(crate) async fn message_reattach<B: StorageBackend>(
+    message_id: MessageId,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
+    network_id: NetworkId,
+    rest_api_config: RestApiConfig,
+    protocol_config: ProtocolConfig,
+) -> Result<impl Reply, Rejection> {
+    if !tangle.is_confirmed_threshold(CONFIRMED_THRESHOLD) {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            "the node is not synchronized".to_string(),
+        )));
+    }
+
+    let metadata = create_message_metadata(message_id, &tangle, &pending_spends, &rest_api_config)
+        .await
+        .ok_or_else(|| reject::custom(CustomRejection::NotFound("can not find message".to_string())))?;
+
+    if metadata.should_reattach != Some(true) {
+        return Err(reject::custom(CustomRejection::BadRequest(
+            "message does not need to be reattached".to_string(),
+        )));
+    }
+
+    // The message was found right above, it can only be missing if it has been pruned in between.
+    let payload = tangle
+        .get(&message_id)
+        .await
+        .ok_or_else(|| reject::custom(CustomRejection::NotFound("can not find message".to_string())))?
+        .payload()
+        .clone();
+
+    let mut parents = tangle.get_messages_to_approve().await.ok_or_else(|| {
+        reject::custom(CustomRejection::ServiceUnavailable(
+            "can not reattach message: no tips available".to_string(),
+        ))
+    })?;
+    parents.sort_unstable();
+
+    let message = build_message_with_pow(network_id.1, parents, payload, &rest_api_config, &protocol_config)?;
+    let message_id = forward_to_message_submitter(message, tangle, message_submitter).await?;
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&SuccessBody::new(SubmitMessageResponse {
+            message_id: message_id.to_string(),
+        })),
+        StatusCode::CREATED,
+    ))
+}
@@ -0,0 +1,143 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_MESSAGES_BATCH},
+        filters::{json_body, with_rest_api_config, with_storage},
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
+    },
+    types::{
+        body::{DefaultErrorResponse, SuccessBody},
+        dtos::MessageDto,
+        requests::MessagesBatchRequest,
+        responses::{BatchItem, MessagesBatchResponse},
+    },
+};
+
+use bee_message::{Message, MessageId};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::access::Fetch;
+
+use serde_json::Value as JsonValue;
+use warp::{reject, Filter, Rejection, Reply};
+
+use std::str::FromStr;
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("messages"))
+        .and(warp::path("batch"))
+        .and(warp::path::end())
+}
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns several messages at once.",
+    query: &[],
+    request: Content::Json(schema::<MessagesBatchRequest>),
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessagesBatchResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(ROUTE_MESSAGES_BATCH, access_list))
+        .and(rate_limit(ROUTE_MESSAGES_BATCH, rate_limiter))
+        .and(json_body(rest_api_config.max_body_size()))
+        .and(with_storage(storage))
+        .and(with_rest_api_config(rest_api_config))
+        .and_then(messages_batch)
+}
+
+/// Parses the list of ids held by the `field` of a batch request body.
+///
+/// A malformed list, or one holding more than `max_batch_size` ids, rejects the whole request. Each id is parsed
+/// separately so that an invalid one only fails its own item.
+pub(crate) fn batch_ids<T: FromStr>(
+    value: &JsonValue,
+    field: &str,
+    max_batch_size: usize,
+) -> Result<Vec<(String, Option<T>)>, Rejection> {
+    let ids = value[field].as_array().ok_or_else(|| {
+        reject::custom(CustomRejection::BadRequest(format!(
+            "invalid {}: expected an array",
+            field
+        )))
+    })?;
+
+    if ids.len() > max_batch_size {
+        return Err(reject::custom(CustomRejection::BadRequest(format!(
+            "invalid {}: expected at most {} ids",
+            field, max_batch_size
+        ))));
+    }
+
+    ids.iter()
+        .map(|id| {
+            let id = id.as_str().ok_or_else(|| {
+                reject::custom(CustomRejection::BadRequest(format!(
+                    "invalid {}: expected strings",
+                    field
+                )))
+            })?;
+
+            Ok((id.to_string(), id.parse::<T>().ok()))
+        })
+        .collect()
+}
+
+/// Creates the item of a batch response for an id that could not be fetched.
+pub(crate) fn batch_error<T>(id: String, code: &str, message: &str) -> BatchItem<T> {
+    BatchItem {
+        id,
+        data: None,
+        error: Some(DefaultErrorResponse {
+            code: code.to_string(),
+            message: message.to_string(),
+        }),
+    }
+}
+
+pub(crate) async fn messages_batch<B: StorageBackend>(
+    value: JsonValue,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+) -> Result<impl Reply, Rejection> {
+    let ids = batch_ids::<MessageId>(&value, "messageIds", rest_api_config.max_batch_size())?;
+    let message_ids = ids.iter().filter_map(|(_, id)| *id).collect::<Vec<_>>();
+
+    let mut messages = Fetch::<Vec<MessageId>, Vec<Option<Message>>>::fetch(&*storage, &message_ids)
+        .await
+        .map_err(|_| {
+            reject::custom(CustomRejection::ServiceUnavailable(
+                "can not fetch from storage".to_string(),
+            ))
+        })?
+        .unwrap_or_default()
+        .into_iter();
+
+    Ok(warp::reply::json(&SuccessBody::new(MessagesBatchResponse {
+        messages: ids
+            .into_iter()
+            .map(|(id, message_id)| match message_id.map(|_| messages.next().flatten()) {
+                Some(Some(message)) => BatchItem {
+                    id,
+                    data: Some(MessageDto::from(&message)),
+                    error: None,
+                },
+                Some(None) => batch_error(id, "404", "can not find message"),
+                None => batch_error(id, "400", "invalid message id"),
+            })
+            .collect(),
+    })))
+}
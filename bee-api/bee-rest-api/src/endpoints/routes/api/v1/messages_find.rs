@@ -3,7 +3,12 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_MESSAGES_FIND, filters::with_storage, permission::has_permission, rejection::CustomRejection,
+        config::ROUTE_MESSAGES_FIND,
+        filters::with_storage,
+        openapi::{schema, Content, QueryParam, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
         storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::MessagesFindResponse},
@@ -18,22 +23,33 @@ use bee_storage::access::Fetch;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::{collections::HashMap, net::IpAddr};
+use std::collections::HashMap;
 
 fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
     super::path().and(warp::path("messages")).and(warp::path::end())
 }
 
+const INDEX: QueryParam = QueryParam::required("index");
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the messages with the given indexation key.",
+    query: &[INDEX],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessagesFindResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MESSAGES_FIND, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_MESSAGES_FIND, access_list))
+        .and(rate_limit(ROUTE_MESSAGES_FIND, rate_limiter))
         .and(warp::query().and_then(|query: HashMap<String, String>| async move {
-            match query.get("index") {
+            match query.get(INDEX.name) {
                 Some(i) => Ok(i.to_string()),
                 None => Err(reject::custom(CustomRejection::BadRequest(
                     "invalid query parameter".to_string(),
@@ -0,0 +1,278 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_MESSAGES_INDEXED},
+        filters::{with_ledger_config, with_rest_api_config, with_storage},
+        openapi::{schema, Content, QueryParam, RouteDoc},
+        permission::{has_permission, AccessList},
+        query_params::query_param,
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
+    },
+    types::{body::SuccessBody, responses::MessagesIndexedResponse},
+};
+
+use bee_ledger::{
+    types::{IndexationPage, IndexationPageEntries},
+    workers::config::LedgerConfig,
+};
+use bee_message::{milestone::MilestoneIndex, payload::indexation::INDEXATION_PADDED_INDEX_LENGTH};
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::access::Fetch;
+
+use warp::{reject, Filter, Rejection, Reply};
+
+use std::collections::HashMap;
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("messages"))
+        .and(warp::path("indexed"))
+        .and(warp::path::end())
+}
+
+const INDEX: QueryParam = QueryParam::optional("index");
+const PREFIX: QueryParam = QueryParam::optional("prefix");
+const FROM: QueryParam = QueryParam::optional("from");
+const TO: QueryParam = QueryParam::optional("to");
+const FROM_MILESTONE: QueryParam = QueryParam::optional("fromMilestone");
+const TO_MILESTONE: QueryParam = QueryParam::optional("toMilestone");
+const PAGE: QueryParam = QueryParam::optional("page");
+const PAGE_SIZE: QueryParam = QueryParam::optional("pageSize");
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns a page of the referenced messages matching an indexation key or prefix, by milestone order.",
+    query: &[INDEX, PREFIX, FROM, TO, FROM_MILESTONE, TO_MILESTONE, PAGE, PAGE_SIZE],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessagesIndexedResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+    ledger_config: LedgerConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_MESSAGES_INDEXED, access_list))
+        .and(rate_limit(ROUTE_MESSAGES_INDEXED, rate_limiter))
+        .and(warp::query::<HashMap<String, String>>())
+        .and(with_storage(storage))
+        .and(with_rest_api_config(rest_api_config))
+        .and(with_ledger_config(ledger_config))
+        .and_then(messages_indexed)
+}
+
+/// A page of indexation entries requested through the query parameters.
+#[derive(Debug)]
+struct IndexedQuery {
+    index: String,
+    page: usize,
+    page_size: usize,
+    indexation_page: IndexationPage,
+}
+
+/// A page may not start past the `max_scan` entries visited by a lookup, which would be truncated anyway.
+fn indexed_query(
+    query: &HashMap<String, String>,
+    max_page_size: usize,
+    max_scan: usize,
+) -> Result<IndexedQuery, Rejection> {
+    let (index, prefix) = match (query.get(INDEX.name), query.get(PREFIX.name)) {
+        (Some(index), None) => (index, false),
+        (None, Some(prefix)) => (prefix, true),
+        _ => {
+            return Err(reject::custom(CustomRejection::BadRequest(
+                "exactly one of index and prefix must be given".to_string(),
+            )));
+        }
+    };
+    let index_bytes = hex::decode(index)
+        .ok()
+        .filter(|bytes| (1..=INDEXATION_PADDED_INDEX_LENGTH).contains(&bytes.len()))
+        .ok_or_else(|| reject::custom(CustomRejection::BadRequest("invalid index".to_string())))?
+        .into_boxed_slice();
+    let from = query_param(query, FROM.name)?.unwrap_or(0);
+    let to = query_param(query, TO.name)?.unwrap_or(u64::MAX);
+    let from_milestone = query_param(query, FROM_MILESTONE.name)?.unwrap_or(0);
+    let to_milestone = query_param(query, TO_MILESTONE.name)?.unwrap_or(u32::MAX);
+    let page = query_param(query, PAGE.name)?.unwrap_or(0);
+    let page_size = query_param(query, PAGE_SIZE.name)?
+        .unwrap_or(max_page_size)
+        .min(max_page_size);
+
+    let skip = page
+        .checked_mul(page_size)
+        .filter(|skip| *skip < max_scan)
+        .ok_or_else(|| reject::custom(CustomRejection::BadRequest("invalid page".to_string())))?;
+    let indexation_page = if prefix {
+        IndexationPage::prefix(index_bytes, skip, page_size)
+    } else {
+        IndexationPage::index(index_bytes, skip, page_size)
+    }
+    .with_milestones(MilestoneIndex(from_milestone), MilestoneIndex(to_milestone))
+    .with_timestamps(from, to)
+    .with_max_visited(max_scan);
+
+    Ok(IndexedQuery {
+        index: index.clone(),
+        page,
+        page_size,
+        indexation_page,
+    })
+}
+
+pub(crate) async fn messages_indexed<B: StorageBackend>(
+    query: HashMap<String, String>,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+    ledger_config: LedgerConfig,
+) -> Result<impl Reply, Rejection> {
+    if !ledger_config.indexation() {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            "the indexation is disabled on this node".to_string(),
+        )));
+    }
+
+    let query = indexed_query(
+        &query,
+        rest_api_config.max_page_size(),
+        rest_api_config.max_indexation_scan(),
+    )?;
+
+    let entries = Fetch::<IndexationPage, IndexationPageEntries>::fetch(&*storage, &query.indexation_page)
+        .await
+        .map_err(|_| {
+            reject::custom(CustomRejection::ServiceUnavailable(
+                "can not fetch from storage".to_string(),
+            ))
+        })?
+        .unwrap_or_default();
+
+    Ok(warp::reply::json(&SuccessBody::new(MessagesIndexedResponse {
+        index: query.index,
+        prefix: query.indexation_page.is_prefix(),
+        page: query.page,
+        page_size: query.page_size,
+        count: entries.entries().len(),
+        truncated: entries.is_truncated(),
+        messages: entries.entries().iter().map(Into::into).collect(),
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn is_bad_request(result: Result<IndexedQuery, Rejection>, expected: &str) -> bool {
+        matches!(
+            result.unwrap_err().find::<CustomRejection>(),
+            Some(CustomRejection::BadRequest(message)) if message == expected
+        )
+    }
+
+    #[test]
+    fn index_query_is_paged_in_storage() {
+        let query = indexed_query(
+            &query(&[
+                ("index", "626565"),
+                ("from", "100"),
+                ("to", "200"),
+                ("fromMilestone", "10"),
+                ("page", "2"),
+                ("pageSize", "10"),
+            ]),
+            100,
+            1000,
+        )
+        .unwrap();
+
+        assert_eq!(query.index, "626565");
+        assert_eq!((query.page, query.page_size), (2, 10));
+        assert_eq!(
+            query.indexation_page,
+            IndexationPage::index(b"bee".to_vec().into_boxed_slice(), 20, 10)
+                .with_milestones(MilestoneIndex(10), MilestoneIndex(u32::MAX))
+                .with_timestamps(100, 200)
+                .with_max_visited(1000)
+        );
+    }
+
+    #[test]
+    fn prefix_query_defaults_to_every_milestone() {
+        let query = indexed_query(&query(&[("prefix", "62")]), 100, 1000).unwrap();
+
+        assert_eq!((query.page, query.page_size), (0, 100));
+        assert_eq!(
+            query.indexation_page,
+            IndexationPage::prefix(b"b".to_vec().into_boxed_slice(), 0, 100).with_max_visited(1000)
+        );
+    }
+
+    #[test]
+    fn page_size_is_capped() {
+        let query = indexed_query(&query(&[("index", "62"), ("pageSize", "1000")]), 100, 1000).unwrap();
+
+        assert_eq!(query.page_size, 100);
+        assert_eq!(query.indexation_page.limit(), 100);
+    }
+
+    #[test]
+    fn exactly_one_of_index_and_prefix() {
+        let message = "exactly one of index and prefix must be given";
+
+        assert!(is_bad_request(indexed_query(&query(&[]), 100, 1000), message));
+        assert!(is_bad_request(
+            indexed_query(&query(&[("index", "62"), ("prefix", "62")]), 100, 1000),
+            message
+        ));
+    }
+
+    #[test]
+    fn invalid_indexes_are_rejected() {
+        let too_long = "62".repeat(INDEXATION_PADDED_INDEX_LENGTH + 1);
+
+        assert!(is_bad_request(
+            indexed_query(&query(&[("index", "6")]), 100, 1000),
+            "invalid index"
+        ));
+        assert!(is_bad_request(
+            indexed_query(&query(&[("index", "")]), 100, 1000),
+            "invalid index"
+        ));
+        assert!(is_bad_request(
+            indexed_query(&query(&[("prefix", "")]), 100, 1000),
+            "invalid index"
+        ));
+        assert!(is_bad_request(
+            indexed_query(&query(&[("prefix", too_long.as_str())]), 100, 1000),
+            "invalid index"
+        ));
+        assert!(is_bad_request(
+            indexed_query(&query(&[("index", "62"), ("toMilestone", "-1")]), 100, 1000),
+            "invalid toMilestone"
+        ));
+    }
+
+    #[test]
+    fn pages_past_the_scan_are_rejected() {
+        let query_page = |page: &str| indexed_query(&query(&[("index", "62"), ("page", page)]), 100, 1000);
+
+        assert_eq!(query_page("9").unwrap().indexation_page.skip(), 900);
+        assert!(is_bad_request(query_page("10"), "invalid page"));
+        assert!(is_bad_request(query_page(&usize::MAX.to_string()), "invalid page"));
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_MESSAGES_METADATA_BATCH},
+        filters::{json_body, with_pending_spends, with_rest_api_config, with_tangle},
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        routes::api::v1::{
+            message_metadata::create_message_metadata,
+            messages_batch::{batch_error, batch_ids},
+        },
+        storage::StorageBackend,
+        CONFIRMED_THRESHOLD,
+    },
+    types::{
+        body::SuccessBody,
+        requests::MessagesBatchRequest,
+        responses::{BatchItem, MessageMetadataResponse, MessagesMetadataBatchResponse},
+    },
+};
+
+use bee_message::MessageId;
+use bee_protocol::workers::PendingSpends;
+use bee_runtime::resource::ResourceHandle;
+use bee_tangle::MsTangle;
+
+use serde_json::Value as JsonValue;
+use warp::{reject, Filter, Rejection, Reply};
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("messages"))
+        .and(warp::path("metadata"))
+        .and(warp::path("batch"))
+        .and(warp::path::end())
+}
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the metadata of several messages at once.",
+    query: &[],
+    request: Content::Json(schema::<MessagesBatchRequest>),
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessagesMetadataBatchResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    rest_api_config: RestApiConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(ROUTE_MESSAGES_METADATA_BATCH, access_list))
+        .and(rate_limit(ROUTE_MESSAGES_METADATA_BATCH, rate_limiter))
+        .and(json_body(rest_api_config.max_body_size()))
+        .and(with_tangle(tangle))
+        .and(with_pending_spends(pending_spends))
+        .and(with_rest_api_config(rest_api_config))
+        .and_then(messages_metadata_batch)
+}
+
+/// Reads the metadata of each message from the tangle, like the single message metadata route does, so that both
+/// routes agree on the state of a message.
+pub(crate) async fn messages_metadata_batch<B: StorageBackend>(
+    value: JsonValue,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    rest_api_config: RestApiConfig,
+) -> Result<impl Reply, Rejection> {
+    if !tangle.is_confirmed_threshold(CONFIRMED_THRESHOLD) {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            "the node is not synchronized".to_string(),
+        )));
+    }
+
+    let ids = batch_ids::<MessageId>(&value, "messageIds", rest_api_config.max_batch_size())?;
+    let mut metadata = Vec::with_capacity(ids.len());
+
+    for (id, message_id) in ids {
+        let response = match message_id {
+            Some(message_id) => {
+                Some(create_message_metadata(message_id, &tangle, &pending_spends, &rest_api_config).await)
+            }
+            None => None,
+        };
+
+        metadata.push(metadata_batch_item(id, response));
+    }
+
+    Ok(warp::reply::json(&SuccessBody::new(MessagesMetadataBatchResponse {
+        metadata,
+    })))
+}
+
+/// Creates the item of an id from the metadata found for it, if the id is valid.
+fn metadata_batch_item(
+    id: String,
+    response: Option<Option<MessageMetadataResponse>>,
+) -> BatchItem<MessageMetadataResponse> {
+    match response {
+        Some(Some(metadata)) => BatchItem {
+            id,
+            data: Some(metadata),
+            error: None,
+        },
+        Some(None) => batch_error(id, "404", "can not find message"),
+        None => batch_error(id, "400", "invalid message id"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn metadata(message_id: &str) -> MessageMetadataResponse {
+        MessageMetadataResponse {
+            message_id: message_id.to_string(),
+            parent_message_ids: Vec::new(),
+            is_solid: true,
+            referenced_by_milestone_index: None,
+            milestone_index: None,
+            ledger_inclusion_state: None,
+            conflict_reason: None,
+            should_promote: Some(false),
+            should_reattach: Some(false),
+            pending_conflict: None,
+        }
+    }
+
+    #[test]
+    fn ids_are_parsed_separately() {
+        let message_id = "0".repeat(64);
+        let ids = batch_ids::<MessageId>(&json!({ "messageIds": [message_id, "invalid"] }), "messageIds", 2).unwrap();
+
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0].0, message_id);
+        assert!(ids[0].1.is_some());
+        assert_eq!(ids[1].0, "invalid");
+        assert!(ids[1].1.is_none());
+    }
+
+    #[test]
+    fn too_many_ids_are_rejected() {
+        let message_id = "0".repeat(64);
+
+        assert!(batch_ids::<MessageId>(&json!({ "messageIds": [message_id, message_id] }), "messageIds", 1).is_err());
+    }
+
+    #[test]
+    fn missing_ids_are_rejected() {
+        assert!(batch_ids::<MessageId>(&json!({}), "messageIds", 1).is_err());
+        assert!(batch_ids::<MessageId>(&json!({ "messageIds": [1] }), "messageIds", 1).is_err());
+    }
+
+    #[test]
+    fn found_metadata_is_returned() {
+        let item = metadata_batch_item("a".to_string(), Some(Some(metadata("a"))));
+
+        assert_eq!(item.id, "a");
+        assert_eq!(item.data.unwrap().message_id, "a");
+        assert!(item.error.is_none());
+    }
+
+    #[test]
+    fn unknown_message_is_not_found() {
+        let item = metadata_batch_item("a".to_string(), Some(None));
+
+        assert!(item.data.is_none());
+        assert_eq!(item.error.unwrap().code, "404");
+    }
+
+    #[test]
+    fn invalid_id_is_a_bad_request() {
+        let item = metadata_batch_item("invalid".to_string(), None);
+
+        assert!(item.data.is_none());
+        assert_eq!(item.error.unwrap().code, "400");
+    }
+
+    #[test]
+    fn item_serializes_either_data_or_error() {
+        let found = serde_json::to_value(metadata_batch_item("a".to_string(), Some(Some(metadata("a"))))).unwrap();
+        let missing = serde_json::to_value(metadata_batch_item("b".to_string(), Some(None))).unwrap();
+
+        assert!(found.get("data").is_some() && found.get("error").is_none());
+        assert!(missing.get("data").is_none() && missing.get("error").is_some());
+    }
+}
@@ -3,8 +3,14 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_MILESTONE, filters::with_tangle, path_params::milestone_index, permission::has_permission,
-        rejection::CustomRejection, storage::StorageBackend,
+        config::ROUTE_MILESTONE,
+        filters::with_tangle,
+        openapi::{schema, Content, RouteDoc},
+        path_params::milestone_index,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::MilestoneResponse},
 };
@@ -15,8 +21,6 @@ use bee_tangle::MsTangle;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (MilestoneIndex,), Error = Rejection> + Clone {
     super::path()
         .and(warp::path("milestones"))
@@ -24,14 +28,23 @@ fn path() -> impl Filter<Extract = (MilestoneIndex,), Error = Rejection> + Clone
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns a milestone.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MilestoneResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MILESTONE, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_MILESTONE, access_list))
+        .and(rate_limit(ROUTE_MILESTONE, rate_limiter))
         .and(with_tangle(tangle))
         .and_then(milestone)
 }
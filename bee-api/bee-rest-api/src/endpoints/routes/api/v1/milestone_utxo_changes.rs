@@ -3,21 +3,25 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_MILESTONE_UTXO_CHANGES, filters::with_storage, path_params::milestone_index,
-        permission::has_permission, rejection::CustomRejection, storage::StorageBackend,
+        config::ROUTE_MILESTONE_UTXO_CHANGES,
+        filters::with_storage,
+        openapi::{schema, Content, RouteDoc},
+        path_params::milestone_index,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::UtxoChangesResponse},
 };
 
-use bee_ledger::types::OutputDiff;
+use bee_ledger::{types::OutputDiff, workers::storage};
 use bee_message::milestone::MilestoneIndex;
 use bee_runtime::resource::ResourceHandle;
 use bee_storage::access::Fetch;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (MilestoneIndex,), Error = Rejection> + Clone {
     super::path()
         .and(warp::path("milestones"))
@@ -26,14 +30,23 @@ fn path() -> impl Filter<Extract = (MilestoneIndex,), Error = Rejection> + Clone
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the UTXO changes of a milestone.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<UtxoChangesResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_MILESTONE_UTXO_CHANGES, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_MILESTONE_UTXO_CHANGES, access_list))
+        .and(rate_limit(ROUTE_MILESTONE_UTXO_CHANGES, rate_limiter))
         .and(with_storage(storage))
         .and_then(milestone_utxo_changes)
 }
@@ -56,9 +69,16 @@ pub(crate) async fn milestone_utxo_changes<B: StorageBackend>(
             )));
         }
     };
+    let ledger_state_hash = storage::fetch_ledger_state_hash(&*storage, index).await.map_err(|_| {
+        reject::custom(CustomRejection::ServiceUnavailable(
+            "can not fetch from storage".to_string(),
+        ))
+    })?;
+
     Ok(warp::reply::json(&SuccessBody::new(UtxoChangesResponse {
         index: *index,
         created_outputs: fetched.created_outputs().iter().map(|id| id.to_string()).collect(),
         consumed_outputs: fetched.consumed_outputs().iter().map(|id| id.to_string()).collect(),
+        ledger_state_hash: ledger_state_hash.map(|hash| hash.to_string()),
     })))
 }
@@ -2,17 +2,30 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod add_peer;
+pub mod address_history_ed25519;
 pub mod balance_bech32;
 pub mod balance_ed25519;
+pub mod config_reload;
+pub mod events;
 pub mod info;
 pub mod message;
 pub mod message_children;
+pub mod message_future_cone;
 pub mod message_metadata;
+pub mod message_past_cone;
+pub mod message_promote;
 pub mod message_raw;
+pub mod message_reattach;
+pub mod messages_batch;
 pub mod messages_find;
+pub mod messages_indexed;
+pub mod messages_metadata_batch;
 pub mod milestone;
 pub mod milestone_utxo_changes;
+pub mod openapi;
 pub mod output;
+pub mod output_pending_spends;
+pub mod outputs_batch;
 pub mod outputs_bech32;
 pub mod outputs_ed25519;
 pub mod peer;
@@ -26,17 +39,25 @@ pub mod tips;
 pub mod transaction_included_message;
 pub mod treasury;
 
-use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, Bech32Hrp, NetworkId};
+use crate::endpoints::{
+    config::RestApiConfig, events::EventSubscribers, permission::AccessList, rate_limit::RateLimiter,
+    storage::StorageBackend, Bech32Hrp, NetworkId,
+};
 
+use config_reload::ConfigReloader;
+use info::UpdateStatus;
+
+use bee_ledger::workers::config::LedgerConfig;
 use bee_network::NetworkCommandSender;
-use bee_protocol::workers::{config::ProtocolConfig, MessageSubmitterWorkerEvent, PeerManager};
+use bee_protocol::{
+    types::metrics::ThroughputMetrics,
+    workers::{config::ProtocolConfig, MessageSubmitterWorkerEvent, PeerManager, PendingSpends},
+};
 use bee_runtime::{node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::MsTangle;
 
 use warp::{self, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 use tokio::sync::mpsc;
 
 pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
@@ -44,8 +65,8 @@ pub(crate) fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clo
 }
 
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
     storage: ResourceHandle<B>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
@@ -53,132 +74,229 @@ pub(crate) fn filter<B: StorageBackend>(
     bech32_hrp: Bech32Hrp,
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
+    ledger_config: LedgerConfig,
     peer_manager: ResourceHandle<PeerManager>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
     node_info: ResourceHandle<NodeInfo>,
+    throughput_metrics: ResourceHandle<ThroughputMetrics>,
+    event_subscribers: EventSubscribers,
+    config_reloader: ResourceHandle<ConfigReloader>,
+    update_status: ResourceHandle<UpdateStatus>,
+    pending_spends: ResourceHandle<PendingSpends>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     add_peer::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         peer_manager.clone(),
         network_command_sender.clone(),
+        rest_api_config.clone(),
     )
+    .or(address_history_ed25519::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        storage.clone(),
+        rest_api_config.clone(),
+        ledger_config.clone(),
+    ))
     .or(balance_bech32::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
     .or(balance_ed25519::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
+    .or(config_reload::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        config_reloader,
+    ))
+    .or(events::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        rest_api_config.clone(),
+        event_subscribers,
+    ))
     .or(info::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
+        storage.clone(),
         network_id.clone(),
         bech32_hrp,
         rest_api_config.clone(),
         protocol_config.clone(),
         node_info,
         peer_manager.clone(),
+        throughput_metrics,
+        update_status,
     ))
     .or(message::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
     ))
     .or(message_children::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
     ))
+    .or(message_future_cone::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        tangle.clone(),
+        pending_spends.clone(),
+        rest_api_config.clone(),
+    ))
     .or(message_metadata::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
+        tangle.clone(),
+        pending_spends.clone(),
+        rest_api_config.clone(),
+    ))
+    .or(message_past_cone::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
+        pending_spends.clone(),
+        rest_api_config.clone(),
+    ))
+    .or(message_promote::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        tangle.clone(),
+        pending_spends.clone(),
+        message_submitter.clone(),
+        network_id.clone(),
+        rest_api_config.clone(),
+        protocol_config.clone(),
     ))
     .or(message_raw::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
     ))
+    .or(message_reattach::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        tangle.clone(),
+        pending_spends.clone(),
+        message_submitter.clone(),
+        network_id.clone(),
+        rest_api_config.clone(),
+        protocol_config.clone(),
+    ))
+    .or(messages_batch::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        storage.clone(),
+        rest_api_config.clone(),
+    ))
     .or(messages_find::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
+        storage.clone(),
+    ))
+    .or(messages_indexed::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
+        rest_api_config.clone(),
+        ledger_config.clone(),
+    ))
+    .or(messages_metadata_batch::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        tangle.clone(),
+        pending_spends.clone(),
+        rest_api_config.clone(),
     ))
     .or(milestone::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
     ))
     .or(milestone_utxo_changes::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
+    .or(openapi::filter(access_list.clone(), rate_limiter.clone()))
     .or(output::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
+    .or(output_pending_spends::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        pending_spends,
+    ))
+    .or(outputs_batch::filter(
+        access_list.clone(),
+        rate_limiter.clone(),
+        storage.clone(),
+        rest_api_config.clone(),
+    ))
     .or(outputs_bech32::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
     .or(outputs_ed25519::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
     .or(peer::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         peer_manager.clone(),
     ))
-    .or(peers::filter(public_routes.clone(), allowed_ips.clone(), peer_manager))
+    .or(peers::filter(access_list.clone(), rate_limiter.clone(), peer_manager))
     .or(receipts::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
     .or(receipts_at::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
     .or(remove_peer::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         network_command_sender,
     ))
     .or(submit_message::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
         message_submitter.clone(),
         network_id,
-        rest_api_config,
+        rest_api_config.clone(),
         protocol_config,
     ))
     .or(submit_message_raw::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
         message_submitter,
+        rest_api_config,
     ))
-    .or(tips::filter(public_routes.clone(), allowed_ips.clone(), tangle.clone()))
+    .or(tips::filter(access_list.clone(), rate_limiter.clone(), tangle.clone()))
     .or(treasury::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         storage.clone(),
     ))
     .or(transaction_included_message::filter(
-        public_routes,
-        allowed_ips,
+        access_list,
+        rate_limiter,
         storage,
         tangle,
     ))
@@ -0,0 +1,42 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::endpoints::{
+    config::ROUTE_OPENAPI,
+    openapi::{self, schema, Content, RouteDoc},
+    permission::{has_permission, AccessList},
+    rate_limit::{rate_limit, RateLimiter},
+};
+
+use serde_json::{Map, Value};
+
+use warp::{Filter, Rejection, Reply};
+
+use std::convert::Infallible;
+
+fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
+    super::path().and(warp::path("openapi.json")).and(warp::path::end())
+}
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the OpenAPI specification of the REST API.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<Map<String, Value>>),
+};
+
+pub(crate) fn filter(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_OPENAPI, access_list))
+        .and(rate_limit(ROUTE_OPENAPI, rate_limiter))
+        .and_then(openapi)
+}
+
+pub(crate) async fn openapi() -> Result<impl Reply, Infallible> {
+    Ok(warp::reply::json(&openapi::spec()))
+}
@@ -3,8 +3,14 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_OUTPUT, filters::with_storage, path_params::output_id, permission::has_permission,
-        rejection::CustomRejection, storage::StorageBackend,
+        config::ROUTE_OUTPUT,
+        filters::with_storage,
+        openapi::{schema, Content, RouteDoc},
+        path_params::output_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::OutputResponse},
 };
@@ -16,8 +22,6 @@ use bee_storage::access::Fetch;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (OutputId,), Error = Rejection> + Clone {
     super::path()
         .and(warp::path("outputs"))
@@ -25,14 +29,23 @@ fn path() -> impl Filter<Extract = (OutputId,), Error = Rejection> + Clone {
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns an output.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<OutputResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_OUTPUT, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_OUTPUT, access_list))
+        .and(rate_limit(ROUTE_OUTPUT, rate_limiter))
         .and(with_storage(storage))
         .and_then(output)
 }
@@ -0,0 +1,67 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::ROUTE_OUTPUT_PENDING_SPENDS,
+        filters::with_pending_spends,
+        openapi::{schema, Content, RouteDoc},
+        path_params::output_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+    },
+    types::{body::SuccessBody, responses::OutputPendingSpendsResponse},
+};
+
+use bee_message::output::OutputId;
+use bee_protocol::workers::PendingSpends;
+use bee_runtime::resource::ResourceHandle;
+
+use warp::{Filter, Rejection, Reply};
+
+use std::convert::Infallible;
+
+fn path() -> impl Filter<Extract = (OutputId,), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("outputs"))
+        .and(output_id())
+        .and(warp::path("pending-spends"))
+        .and(warp::path::end())
+}
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the messages spending an output that aren't referenced by a milestone yet.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<OutputPendingSpendsResponse>>),
+};
+
+pub(crate) fn filter(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    pending_spends: ResourceHandle<PendingSpends>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::get())
+        .and(has_permission(ROUTE_OUTPUT_PENDING_SPENDS, access_list))
+        .and(rate_limit(ROUTE_OUTPUT_PENDING_SPENDS, rate_limiter))
+        .and(with_pending_spends(pending_spends))
+        .and_then(output_pending_spends)
+}
+
+/// Returns the messages spending an output that aren't referenced by a milestone yet, more than one being a double
+/// spend of which at most one can be included.
+pub(crate) async fn output_pending_spends(
+    output_id: OutputId,
+    pending_spends: ResourceHandle<PendingSpends>,
+) -> Result<impl Reply, Infallible> {
+    Ok(warp::reply::json(&SuccessBody::new(OutputPendingSpendsResponse {
+        output_id: output_id.to_string(),
+        message_ids: pending_spends
+            .get(&output_id)
+            .iter()
+            .map(|message_id| message_id.to_string())
+            .collect(),
+    })))
+}
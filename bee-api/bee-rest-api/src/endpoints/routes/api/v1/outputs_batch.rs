@@ -0,0 +1,110 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    endpoints::{
+        config::{RestApiConfig, ROUTE_OUTPUTS_BATCH},
+        filters::{json_body, with_rest_api_config, with_storage},
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        routes::api::v1::messages_batch::{batch_error, batch_ids},
+        storage::StorageBackend,
+    },
+    types::{
+        body::SuccessBody,
+        requests::OutputsBatchRequest,
+        responses::{BatchItem, OutputResponse, OutputsBatchResponse},
+    },
+};
+
+use bee_ledger::types::{ConsumedOutput, CreatedOutput};
+use bee_message::output::OutputId;
+use bee_runtime::resource::ResourceHandle;
+use bee_storage::access::Fetch;
+
+use serde_json::Value as JsonValue;
+use warp::{reject, Filter, Rejection, Reply};
+
+fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    super::path()
+        .and(warp::path("outputs"))
+        .and(warp::path("batch"))
+        .and(warp::path::end())
+}
+
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns several outputs at once.",
+    query: &[],
+    request: Content::Json(schema::<OutputsBatchRequest>),
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<OutputsBatchResponse>>),
+};
+
+pub(crate) fn filter<B: StorageBackend>(
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    self::path()
+        .and(warp::post())
+        .and(has_permission(ROUTE_OUTPUTS_BATCH, access_list))
+        .and(rate_limit(ROUTE_OUTPUTS_BATCH, rate_limiter))
+        .and(json_body(rest_api_config.max_body_size()))
+        .and(with_storage(storage))
+        .and(with_rest_api_config(rest_api_config))
+        .and_then(outputs_batch)
+}
+
+pub(crate) async fn outputs_batch<B: StorageBackend>(
+    value: JsonValue,
+    storage: ResourceHandle<B>,
+    rest_api_config: RestApiConfig,
+) -> Result<impl Reply, Rejection> {
+    let ids = batch_ids::<OutputId>(&value, "outputIds", rest_api_config.max_batch_size())?;
+    let output_ids = ids.iter().filter_map(|(_, id)| *id).collect::<Vec<_>>();
+
+    let outputs = Fetch::<Vec<OutputId>, Vec<Option<CreatedOutput>>>::fetch(&*storage, &output_ids)
+        .await
+        .map_err(|_| {
+            reject::custom(CustomRejection::ServiceUnavailable(
+                "can not fetch from storage".to_string(),
+            ))
+        })?
+        .unwrap_or_default();
+    let consumed = Fetch::<Vec<OutputId>, Vec<Option<ConsumedOutput>>>::fetch(&*storage, &output_ids)
+        .await
+        .map_err(|_| {
+            reject::custom(CustomRejection::ServiceUnavailable(
+                "can not fetch from storage".to_string(),
+            ))
+        })?
+        .unwrap_or_default();
+
+    let mut fetched = outputs.into_iter().zip(consumed.into_iter());
+
+    Ok(warp::reply::json(&SuccessBody::new(OutputsBatchResponse {
+        outputs: ids
+            .into_iter()
+            .map(
+                |(id, output_id)| match output_id.map(|output_id| (output_id, fetched.next())) {
+                    Some((output_id, Some((Some(output), consumed)))) => BatchItem {
+                        id,
+                        data: Some(OutputResponse {
+                            message_id: output.message_id().to_string(),
+                            transaction_id: output_id.transaction_id().to_string(),
+                            output_index: output_id.index(),
+                            is_spent: consumed.is_some(),
+                            output: output.inner().into(),
+                        }),
+                        error: None,
+                    },
+                    Some(_) => batch_error(id, "404", "can not find output"),
+                    None => batch_error(id, "400", "invalid output id"),
+                },
+            )
+            .collect(),
+    })))
+}
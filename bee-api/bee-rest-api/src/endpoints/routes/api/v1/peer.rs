@@ -3,7 +3,12 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_PEER, filters::with_peer_manager, path_params::peer_id, permission::has_permission,
+        config::ROUTE_PEER,
+        filters::with_peer_manager,
+        openapi::{schema, Content, RouteDoc},
+        path_params::peer_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
         rejection::CustomRejection,
     },
     types::{body::SuccessBody, dtos::PeerDto, responses::PeerResponse},
@@ -15,8 +20,6 @@ use bee_runtime::resource::ResourceHandle;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (PeerId,), Error = Rejection> + Clone {
     super::path()
         .and(warp::path("peers"))
@@ -24,14 +27,23 @@ fn path() -> impl Filter<Extract = (PeerId,), Error = Rejection> + Clone {
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns a peer of the node.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<PeerResponse>>),
+};
+
 pub(crate) fn filter(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     peer_manager: ResourceHandle<PeerManager>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_PEER, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_PEER, access_list))
+        .and(rate_limit(ROUTE_PEER, rate_limiter))
         .and(with_peer_manager(peer_manager))
         .and_then(peer)
 }
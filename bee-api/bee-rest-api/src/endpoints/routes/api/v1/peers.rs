@@ -2,7 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    endpoints::{config::ROUTE_PEERS, filters::with_peer_manager, permission::has_permission},
+    endpoints::{
+        config::ROUTE_PEERS,
+        filters::with_peer_manager,
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+    },
     types::{body::SuccessBody, dtos::PeerDto, responses::PeersResponse},
 };
 
@@ -11,20 +17,29 @@ use bee_runtime::resource::ResourceHandle;
 
 use warp::{Filter, Rejection, Reply};
 
-use std::{convert::Infallible, net::IpAddr};
+use std::convert::Infallible;
 
 fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
     super::path().and(warp::path("peers")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the peers of the node.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<PeersResponse>>),
+};
+
 pub(crate) fn filter(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     peer_manager: ResourceHandle<PeerManager>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_PEERS, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_PEERS, access_list))
+        .and(rate_limit(ROUTE_PEERS, rate_limiter))
         .and(with_peer_manager(peer_manager))
         .and_then(peers)
 }
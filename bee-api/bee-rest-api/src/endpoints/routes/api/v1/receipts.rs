@@ -3,7 +3,12 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_RECEIPTS, filters::with_storage, permission::has_permission, rejection::CustomRejection,
+        config::ROUTE_RECEIPTS,
+        filters::with_storage,
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
         storage::StorageBackend,
     },
     types::{body::SuccessBody, dtos::ReceiptDto, responses::ReceiptsResponse},
@@ -17,20 +22,29 @@ use bee_storage::access::AsStream;
 use futures::stream::StreamExt;
 use warp::{Filter, Rejection, Reply};
 
-use std::{convert::TryFrom, net::IpAddr};
+use std::convert::TryFrom;
 
 fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
     super::path().and(warp::path("receipts")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns all stored receipts.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<ReceiptsResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_RECEIPTS, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_RECEIPTS, access_list))
+        .and(rate_limit(ROUTE_RECEIPTS, rate_limiter))
         .and(with_storage(storage))
         .and_then(receipts)
 }
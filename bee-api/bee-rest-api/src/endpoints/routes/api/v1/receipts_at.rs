@@ -3,8 +3,14 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_RECEIPTS_AT, filters::with_storage, path_params::milestone_index, permission::has_permission,
-        rejection::CustomRejection, storage::StorageBackend,
+        config::ROUTE_RECEIPTS_AT,
+        filters::with_storage,
+        openapi::{schema, Content, RouteDoc},
+        path_params::milestone_index,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
     },
     types::{body::SuccessBody, dtos::ReceiptDto, responses::ReceiptsResponse},
 };
@@ -16,7 +22,7 @@ use bee_storage::access::Fetch;
 
 use warp::{Filter, Rejection, Reply};
 
-use std::{convert::TryFrom, net::IpAddr};
+use std::convert::TryFrom;
 
 fn path() -> impl Filter<Extract = (MilestoneIndex,), Error = Rejection> + Clone {
     super::path()
@@ -25,14 +31,23 @@ fn path() -> impl Filter<Extract = (MilestoneIndex,), Error = Rejection> + Clone
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the receipts of a milestone.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<ReceiptsResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_RECEIPTS_AT, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_RECEIPTS_AT, access_list))
+        .and(rate_limit(ROUTE_RECEIPTS_AT, rate_limiter))
         .and(with_storage(storage))
         .and_then(receipts_at)
 }
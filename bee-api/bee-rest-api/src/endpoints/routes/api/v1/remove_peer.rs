@@ -2,7 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::endpoints::{
-    config::ROUTE_REMOVE_PEER, filters::with_network_command_sender, path_params::peer_id, permission::has_permission,
+    config::ROUTE_REMOVE_PEER,
+    filters::with_network_command_sender,
+    openapi::{Content, RouteDoc},
+    path_params::peer_id,
+    permission::{has_permission, AccessList},
+    rate_limit::{rate_limit, RateLimiter},
     rejection::CustomRejection,
 };
 
@@ -11,8 +16,6 @@ use bee_runtime::resource::ResourceHandle;
 
 use warp::{http::StatusCode, reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (PeerId,), Error = warp::Rejection> + Clone {
     super::path()
         .and(warp::path("peers"))
@@ -20,14 +23,23 @@ fn path() -> impl Filter<Extract = (PeerId,), Error = warp::Rejection> + Clone {
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Removes a peer from the node.",
+    query: &[],
+    request: Content::Empty,
+    status: 204,
+    response: Content::Empty,
+};
+
 pub(crate) fn filter(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::delete())
-        .and(has_permission(ROUTE_REMOVE_PEER, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_REMOVE_PEER, access_list))
+        .and(rate_limit(ROUTE_REMOVE_PEER, rate_limiter))
         .and(with_network_command_sender(network_command_sender))
         .and_then(remove_peer)
 }
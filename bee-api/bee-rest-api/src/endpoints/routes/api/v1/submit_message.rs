@@ -4,13 +4,17 @@
 use crate::{
     endpoints::{
         config::{RestApiConfig, ROUTE_SUBMIT_MESSAGE},
-        filters::{with_message_submitter, with_network_id, with_protocol_config, with_rest_api_config, with_tangle},
-        permission::has_permission,
+        filters::{
+            json_body, with_message_submitter, with_network_id, with_protocol_config, with_rest_api_config, with_tangle,
+        },
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
         rejection::CustomRejection,
         storage::StorageBackend,
         NetworkId,
     },
-    types::{body::SuccessBody, dtos::PayloadDto, responses::SubmitMessageResponse},
+    types::{body::SuccessBody, dtos::PayloadDto, requests::SubmitMessageRequest, responses::SubmitMessageResponse},
 };
 
 use bee_message::{parents::Parents, payload::Payload, Message, MessageBuilder, MessageId};
@@ -25,15 +29,23 @@ use serde_json::Value as JsonValue;
 use tokio::sync::mpsc;
 use warp::{http::StatusCode, reject, Filter, Rejection, Reply};
 
-use std::{convert::TryFrom, net::IpAddr};
+use std::convert::TryFrom;
 
 fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
     super::path().and(warp::path("messages")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Submits a message.",
+    query: &[],
+    request: Content::Json(schema::<SubmitMessageRequest>),
+    status: 201,
+    response: Content::Json(schema::<SuccessBody<SubmitMessageResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
     network_id: NetworkId,
@@ -42,8 +54,9 @@ pub(crate) fn filter<B: StorageBackend>(
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::post())
-        .and(has_permission(ROUTE_SUBMIT_MESSAGE, public_routes, allowed_ips))
-        .and(warp::body::json())
+        .and(has_permission(ROUTE_SUBMIT_MESSAGE, access_list))
+        .and(rate_limit(ROUTE_SUBMIT_MESSAGE, rate_limiter))
+        .and(json_body(rest_api_config.max_body_size()))
         .and(with_tangle(tangle))
         .and(with_message_submitter(message_submitter))
         .and(with_network_id(network_id))
@@ -142,7 +155,11 @@ pub(crate) async fn submit_message<B: StorageBackend>(
                     "invalid nonce: expected an u64-string".to_string(),
                 ))
             })?;
-        if parsed == 0 { None } else { Some(parsed) }
+        if parsed == 0 {
+            None
+        } else {
+            Some(parsed)
+        }
     };
 
     let message = if let Some(nonce) = nonce {
@@ -159,26 +176,7 @@ pub(crate) async fn submit_message<B: StorageBackend>(
             .finish()
             .map_err(|e| reject::custom(CustomRejection::BadRequest(e.to_string())))?
     } else {
-        if !rest_api_config.feature_proof_of_work() {
-            return Err(reject::custom(CustomRejection::ServiceUnavailable(
-                "can not auto-fill nonce: feature `PoW` not enabled".to_string(),
-            )));
-        }
-        let mut builder = MessageBuilder::new()
-            .with_network_id(network_id)
-            .with_parents(
-                Parents::new(parents).map_err(|e| reject::custom(CustomRejection::BadRequest(e.to_string())))?,
-            )
-            .with_nonce_provider(
-                MinerBuilder::new().with_num_workers(num_cpus::get()).finish(),
-                protocol_config.minimum_pow_score(),
-            );
-        if let Some(payload) = payload {
-            builder = builder.with_payload(payload)
-        }
-        builder
-            .finish()
-            .map_err(|e| reject::custom(CustomRejection::BadRequest(e.to_string())))?
+        build_message_with_pow(network_id, parents, payload, &rest_api_config, &protocol_config)?
     };
 
     let message_id = forward_to_message_submitter(message, tangle, message_submitter).await?;
@@ -191,6 +189,34 @@ pub(crate) async fn submit_message<B: StorageBackend>(
     ))
 }
 
+/// Builds a message whose nonce is computed by the node.
+pub(crate) fn build_message_with_pow(
+    network_id: u64,
+    parents: Vec<MessageId>,
+    payload: Option<Payload>,
+    rest_api_config: &RestApiConfig,
+    protocol_config: &ProtocolConfig,
+) -> Result<Message, Rejection> {
+    if !rest_api_config.feature_proof_of_work() {
+        return Err(reject::custom(CustomRejection::ServiceUnavailable(
+            "can not auto-fill nonce: feature `PoW` not enabled".to_string(),
+        )));
+    }
+    let mut builder = MessageBuilder::new()
+        .with_network_id(network_id)
+        .with_parents(Parents::new(parents).map_err(|e| reject::custom(CustomRejection::BadRequest(e.to_string())))?)
+        .with_nonce_provider(
+            MinerBuilder::new().with_num_workers(num_cpus::get()).finish(),
+            protocol_config.minimum_pow_score(),
+        );
+    if let Some(payload) = payload {
+        builder = builder.with_payload(payload)
+    }
+    builder
+        .finish()
+        .map_err(|e| reject::custom(CustomRejection::BadRequest(e.to_string())))
+}
+
 pub(crate) async fn forward_to_message_submitter<B: StorageBackend>(
     message: Message,
     tangle: ResourceHandle<MsTangle<B>>,
@@ -3,9 +3,11 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_SUBMIT_MESSAGE_RAW,
-        filters::{with_message_submitter, with_tangle},
-        permission::has_permission,
+        config::{RestApiConfig, ROUTE_SUBMIT_MESSAGE_RAW},
+        filters::{body_bytes, with_message_submitter, with_tangle},
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
         rejection::CustomRejection,
         routes::api::v1::submit_message::forward_to_message_submitter,
         storage::StorageBackend,
@@ -22,22 +24,30 @@ use bee_tangle::MsTangle;
 use tokio::sync::mpsc;
 use warp::{http::StatusCode, reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
     super::path().and(warp::path("messages")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Submits a message as raw bytes.",
+    query: &[],
+    request: Content::Binary,
+    status: 201,
+    response: Content::Json(schema::<SuccessBody<SubmitMessageResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
+    rest_api_config: RestApiConfig,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::post())
-        .and(has_permission(ROUTE_SUBMIT_MESSAGE_RAW, public_routes, allowed_ips))
-        .and(warp::body::bytes())
+        .and(has_permission(ROUTE_SUBMIT_MESSAGE_RAW, access_list))
+        .and(rate_limit(ROUTE_SUBMIT_MESSAGE_RAW, rate_limiter))
+        .and(body_bytes(rest_api_config.max_body_size()))
         .and(with_tangle(tangle))
         .and(with_message_submitter(message_submitter))
         .and_then(submit_message_raw)
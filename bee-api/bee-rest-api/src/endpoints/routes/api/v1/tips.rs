@@ -3,8 +3,14 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_TIPS, filters::with_tangle, permission::has_permission, rejection::CustomRejection,
-        storage::StorageBackend, CONFIRMED_THRESHOLD,
+        config::ROUTE_TIPS,
+        filters::with_tangle,
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        storage::StorageBackend,
+        CONFIRMED_THRESHOLD,
     },
     types::{body::SuccessBody, responses::TipsResponse},
 };
@@ -14,20 +20,27 @@ use bee_tangle::MsTangle;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
     super::path().and(warp::path("tips")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns tips to attach a message to.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<TipsResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_TIPS, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_TIPS, access_list))
+        .and(rate_limit(ROUTE_TIPS, rate_limiter))
         .and(with_tangle(tangle))
         .and_then(tips)
 }
@@ -1,14 +1,19 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::endpoints::{
-    config::ROUTE_TRANSACTION_INCLUDED_MESSAGE,
-    filters::{with_storage, with_tangle},
-    path_params::transaction_id,
-    permission::has_permission,
-    rejection::CustomRejection,
-    routes::api::v1::message,
-    storage::StorageBackend,
+use crate::{
+    endpoints::{
+        config::ROUTE_TRANSACTION_INCLUDED_MESSAGE,
+        filters::{with_storage, with_tangle},
+        openapi::{schema, Content, RouteDoc},
+        path_params::transaction_id,
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
+        routes::api::v1::message,
+        storage::StorageBackend,
+    },
+    types::{body::SuccessBody, responses::MessageResponse},
 };
 
 use bee_ledger::types::CreatedOutput;
@@ -19,8 +24,6 @@ use bee_tangle::MsTangle;
 
 use warp::{reject, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (TransactionId,), Error = Rejection> + Clone {
     super::path()
         .and(warp::path("transactions"))
@@ -29,19 +32,24 @@ fn path() -> impl Filter<Extract = (TransactionId,), Error = Rejection> + Clone
         .and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the message that included a transaction in the ledger.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<MessageResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
     tangle: ResourceHandle<MsTangle<B>>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(
-            ROUTE_TRANSACTION_INCLUDED_MESSAGE,
-            public_routes,
-            allowed_ips,
-        ))
+        .and(has_permission(ROUTE_TRANSACTION_INCLUDED_MESSAGE, access_list))
+        .and(rate_limit(ROUTE_TRANSACTION_INCLUDED_MESSAGE, rate_limiter))
         .and(with_storage(storage))
         .and(with_tangle(tangle))
         .and_then(transaction_included_message)
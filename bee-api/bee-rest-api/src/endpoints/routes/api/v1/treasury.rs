@@ -3,7 +3,12 @@
 
 use crate::{
     endpoints::{
-        config::ROUTE_TREASURY, filters::with_storage, permission::has_permission, rejection::CustomRejection,
+        config::ROUTE_TREASURY,
+        filters::with_storage,
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        rejection::CustomRejection,
         storage::StorageBackend,
     },
     types::{body::SuccessBody, responses::TreasuryResponse},
@@ -14,20 +19,27 @@ use bee_runtime::resource::ResourceHandle;
 
 use warp::{Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 fn path() -> impl Filter<Extract = (), Error = Rejection> + Clone {
     super::path().and(warp::path("treasury")).and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the treasury.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<TreasuryResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     storage: ResourceHandle<B>,
 ) -> impl Filter<Extract = impl warp::Reply, Error = warp::Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_TREASURY, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_TREASURY, access_list))
+        .and(rate_limit(ROUTE_TREASURY, rate_limiter))
         .and(with_storage(storage))
         .and_then(treasury)
 }
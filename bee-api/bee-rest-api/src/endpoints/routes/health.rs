@@ -1,11 +1,20 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::endpoints::{
-    config::ROUTE_HEALTH,
-    filters::{with_peer_manager, with_tangle},
-    permission::has_permission,
-    storage::StorageBackend,
+use crate::{
+    endpoints::{
+        config::ROUTE_HEALTH,
+        filters::{with_peer_manager, with_tangle, with_worker_statuses},
+        openapi::{schema, Content, RouteDoc},
+        permission::{has_permission, AccessList},
+        rate_limit::{rate_limit, RateLimiter},
+        storage::StorageBackend,
+    },
+    types::{
+        body::SuccessBody,
+        dtos::{WorkerDto, WorkerStatusDto},
+        responses::HealthResponse,
+    },
 };
 
 use bee_protocol::workers::PeerManager;
@@ -15,8 +24,9 @@ use bee_tangle::MsTangle;
 use warp::{http::StatusCode, Filter, Rejection, Reply};
 
 use std::{
+    collections::HashMap,
     convert::Infallible,
-    net::IpAddr,
+    sync::RwLock,
     time::{SystemTime, UNIX_EPOCH},
 };
 
@@ -27,28 +37,87 @@ fn path() -> impl Filter<Extract = (), Error = warp::Rejection> + Clone {
     warp::path("health").and(warp::path::end())
 }
 
+pub(crate) const DOC: RouteDoc = RouteDoc {
+    summary: "Returns the health of the node and its workers.",
+    query: &[],
+    request: Content::Empty,
+    status: 200,
+    response: Content::Json(schema::<SuccessBody<HealthResponse>>),
+};
+
 pub(crate) fn filter<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
     peer_manager: ResourceHandle<PeerManager>,
+    worker_statuses: ResourceHandle<WorkerStatuses>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     self::path()
         .and(warp::get())
-        .and(has_permission(ROUTE_HEALTH, public_routes, allowed_ips))
+        .and(has_permission(ROUTE_HEALTH, access_list))
+        .and(rate_limit(ROUTE_HEALTH, rate_limiter))
         .and(with_tangle(tangle))
         .and(with_peer_manager(peer_manager))
+        .and(with_worker_statuses(worker_statuses))
         .and_then(health)
 }
 
 pub(crate) async fn health<B: StorageBackend>(
     tangle: ResourceHandle<MsTangle<B>>,
     peer_manager: ResourceHandle<PeerManager>,
+    worker_statuses: ResourceHandle<WorkerStatuses>,
 ) -> Result<impl Reply, Infallible> {
-    if is_healthy(&tangle, &peer_manager).await {
-        Ok(StatusCode::OK)
-    } else {
-        Ok(StatusCode::SERVICE_UNAVAILABLE)
+    let workers = worker_statuses.workers();
+    let is_healthy = is_healthy(&tangle, &peer_manager).await
+        && workers.iter().all(|worker| worker.status == WorkerStatusDto::Running);
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&SuccessBody::new(HealthResponse { is_healthy, workers })),
+        if is_healthy {
+            StatusCode::OK
+        } else {
+            StatusCode::SERVICE_UNAVAILABLE
+        },
+    ))
+}
+
+/// Supervision status of the node workers, updated by the node and reported by the health endpoint.
+#[derive(Default)]
+pub struct WorkerStatuses(RwLock<HashMap<&'static str, WorkerStatusDto>>);
+
+impl WorkerStatuses {
+    /// Sets the status of a worker.
+    pub fn set(&self, worker: &'static str, status: WorkerStatusDto) {
+        self.0
+            .write()
+            .expect("Worker statuses lock poisoned")
+            .insert(worker, status);
+    }
+
+    /// Returns the status of a worker, if known.
+    pub fn get(&self, worker: &str) -> Option<WorkerStatusDto> {
+        self.0
+            .read()
+            .expect("Worker statuses lock poisoned")
+            .get(worker)
+            .copied()
+    }
+
+    /// Returns the statuses of all known workers, sorted by name.
+    pub fn workers(&self) -> Vec<WorkerDto> {
+        let mut workers = self
+            .0
+            .read()
+            .expect("Worker statuses lock poisoned")
+            .iter()
+            .map(|(name, status)| WorkerDto {
+                name: name.to_string(),
+                status: *status,
+            })
+            .collect::<Vec<_>>();
+
+        workers.sort_by(|a, b| a.name.cmp(&b.name));
+        workers
     }
 }
 
@@ -4,11 +4,27 @@
 pub mod api;
 pub mod health;
 
-use crate::endpoints::{config::RestApiConfig, storage::StorageBackend, Bech32Hrp, NetworkId};
+use crate::endpoints::{
+    config::RestApiConfig,
+    events::EventSubscribers,
+    permission::AccessList,
+    rate_limit::RateLimiter,
+    routes::{
+        api::v1::{config_reload::ConfigReloader, info::UpdateStatus},
+        health::WorkerStatuses,
+    },
+    storage::StorageBackend,
+    Bech32Hrp, NetworkId,
+};
 
+use bee_ledger::workers::config::LedgerConfig;
 use bee_network::NetworkCommandSender;
-use bee_protocol::workers::{
-    config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, RequestedMessages,
+use bee_protocol::{
+    types::metrics::ThroughputMetrics,
+    workers::{
+        config::ProtocolConfig, MessageRequesterWorker, MessageSubmitterWorkerEvent, PeerManager, PendingSpends,
+        RequestedMessages,
+    },
 };
 use bee_runtime::{event::Bus, node::NodeInfo, resource::ResourceHandle};
 use bee_tangle::MsTangle;
@@ -16,11 +32,9 @@ use bee_tangle::MsTangle;
 use tokio::sync::mpsc;
 use warp::{self, Filter, Rejection, Reply};
 
-use std::net::IpAddr;
-
 pub(crate) fn filter_all<B: StorageBackend>(
-    public_routes: Vec<String>,
-    allowed_ips: Vec<IpAddr>,
+    access_list: AccessList,
+    rate_limiter: RateLimiter,
     tangle: ResourceHandle<MsTangle<B>>,
     storage: ResourceHandle<B>,
     message_submitter: mpsc::UnboundedSender<MessageSubmitterWorkerEvent>,
@@ -28,16 +42,23 @@ pub(crate) fn filter_all<B: StorageBackend>(
     bech32_hrp: Bech32Hrp,
     rest_api_config: RestApiConfig,
     protocol_config: ProtocolConfig,
+    ledger_config: LedgerConfig,
     peer_manager: ResourceHandle<PeerManager>,
     network_command_sender: ResourceHandle<NetworkCommandSender>,
     node_info: ResourceHandle<NodeInfo>,
+    throughput_metrics: ResourceHandle<ThroughputMetrics>,
     bus: ResourceHandle<Bus<'static>>,
     message_requester: MessageRequesterWorker,
     requested_messages: ResourceHandle<RequestedMessages>,
+    event_subscribers: EventSubscribers,
+    worker_statuses: ResourceHandle<WorkerStatuses>,
+    config_reloader: ResourceHandle<ConfigReloader>,
+    update_status: ResourceHandle<UpdateStatus>,
+    pending_spends: ResourceHandle<PendingSpends>,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     api::filter(
-        public_routes.clone(),
-        allowed_ips.clone(),
+        access_list.clone(),
+        rate_limiter.clone(),
         tangle.clone(),
         storage,
         message_submitter,
@@ -45,12 +66,24 @@ pub(crate) fn filter_all<B: StorageBackend>(
         bech32_hrp,
         rest_api_config,
         protocol_config,
+        ledger_config,
         peer_manager.clone(),
         network_command_sender,
         node_info,
+        throughput_metrics,
         bus,
         message_requester,
         requested_messages,
+        event_subscribers,
+        config_reloader,
+        update_status,
+        pending_spends,
     )
-    .or(health::filter(public_routes, allowed_ips, tangle, peer_manager))
+    .or(health::filter(
+        access_list,
+        rate_limiter,
+        tangle,
+        peer_manager,
+        worker_statuses,
+    ))
 }
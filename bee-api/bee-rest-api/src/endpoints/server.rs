@@ -0,0 +1,364 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::endpoints::{
+    config::{RestApiConfig, RestApiCorsConfig, RestApiTlsConfig},
+    events::EventSubscribers,
+    handle_rejection,
+};
+
+use bee_protocol::types::metrics::NodeMetrics;
+use bee_runtime::resource::ResourceHandle;
+
+use futures::{
+    future::{self, ready, Future, FutureExt},
+    stream::StreamExt,
+};
+use hyper::{
+    server::accept,
+    service::{make_service_fn, service_fn, Service},
+    Server,
+};
+use log::{debug, error, info, warn};
+use rustls::{
+    internal::pemfile::{certs, pkcs8_private_keys, rsa_private_keys},
+    sign::{self, CertifiedKey},
+    ClientHello, NoClientAuth, ResolvesServerCert, ServerConfig,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    time::timeout,
+};
+use tokio_rustls::{server::TlsStream, TlsAcceptor};
+use tokio_stream::wrappers::TcpListenerStream;
+use warp::{cors::Builder as CorsBuilder, filters::BoxedFilter, Filter, Rejection, Reply};
+
+use std::{
+    convert::Infallible,
+    fs::File,
+    io::BufReader,
+    net::SocketAddr,
+    path::Path,
+    sync::{Arc, PoisonError, RwLock},
+    time::{Duration, SystemTime},
+};
+
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_PENDING_TLS_HANDSHAKES: usize = 64;
+
+pub(crate) type Routes = BoxedFilter<(Box<dyn Reply>,)>;
+
+/// Address of the peer of a connection accepted by the TLS server, which warp can't provide by itself.
+#[derive(Clone, Copy)]
+pub(crate) struct RemoteAddr(pub(crate) SocketAddr);
+
+/// Builds the CORS policy applied to all routes.
+pub(crate) fn cors(config: &RestApiCorsConfig) -> CorsBuilder {
+    let mut cors = warp::cors()
+        .allow_methods(config.allowed_methods().iter().map(String::as_str))
+        .allow_headers(config.allowed_headers().iter().map(String::as_str));
+
+    if config.allowed_origins().iter().any(|origin| origin == "*") {
+        cors = cors.allow_any_origin();
+    } else {
+        cors = cors.allow_origins(config.allowed_origins().iter().map(String::as_str));
+    }
+
+    if let Some(max_age) = config.max_age() {
+        cors = cors.max_age(max_age);
+    }
+
+    cors
+}
+
+/// Recovers the rejections of the routes into error responses and applies the CORS policy, if any.
+///
+/// Errors are recovered inside the CORS policy so that they carry its headers, while the requests rejected by the
+/// policy itself are recovered outside of it.
+pub(crate) fn recover<F, R>(
+    routes: F,
+    cors_config: Option<&RestApiCorsConfig>,
+    node_metrics: ResourceHandle<NodeMetrics>,
+) -> Routes
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone + Send + Sync + 'static,
+    R: Reply + 'static,
+{
+    let routes = {
+        let node_metrics = node_metrics.clone();
+        routes.recover(move |err| handle_rejection(err, node_metrics.clone()))
+    };
+
+    match cors_config {
+        Some(cors_config) => routes
+            .with(cors(cors_config))
+            .recover(move |err| handle_rejection(err, node_metrics.clone()))
+            .map(|reply| Box::new(reply) as Box<dyn Reply>)
+            .boxed(),
+        None => routes.map(|reply| Box::new(reply) as Box<dyn Reply>).boxed(),
+    }
+}
+
+/// Resolves the certificate presented to clients, which can be replaced while connections are being served.
+struct ReloadableCertificate(RwLock<CertifiedKey>);
+
+impl ReloadableCertificate {
+    fn replace(&self, certified_key: CertifiedKey) {
+        *self.0.write().unwrap_or_else(PoisonError::into_inner) = certified_key;
+    }
+}
+
+impl ResolvesServerCert for ReloadableCertificate {
+    fn resolve(&self, _: ClientHello) -> Option<CertifiedKey> {
+        Some(self.0.read().unwrap_or_else(PoisonError::into_inner).clone())
+    }
+}
+
+fn load_certified_key(tls: &RestApiTlsConfig) -> Result<CertifiedKey, String> {
+    let cert_file = File::open(tls.cert_path()).map_err(|e| format!("can not open certificate file: {}", e))?;
+    let cert_chain = certs(&mut BufReader::new(cert_file)).map_err(|_| "invalid certificate file".to_string())?;
+
+    if cert_chain.is_empty() {
+        return Err("no certificate found".to_string());
+    }
+
+    let key_file = std::fs::read(tls.key_path()).map_err(|e| format!("can not read key file: {}", e))?;
+    let key = pkcs8_private_keys(&mut key_file.as_slice())
+        .ok()
+        .and_then(|keys| keys.into_iter().next())
+        .or_else(|| {
+            rsa_private_keys(&mut key_file.as_slice())
+                .ok()
+                .and_then(|keys| keys.into_iter().next())
+        })
+        .ok_or_else(|| "no private key found".to_string())?;
+    let key = sign::any_supported_type(&key).map_err(|_| "unsupported private key".to_string())?;
+
+    Ok(CertifiedKey::new(cert_chain, Arc::new(key)))
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+fn tls_files_modified(tls: &RestApiTlsConfig) -> (Option<SystemTime>, Option<SystemTime>) {
+    (modified(tls.cert_path()), modified(tls.key_path()))
+}
+
+// Reloads the certificate whenever its files change, keeping the current one if the new files can't be loaded.
+async fn reload_certificate(tls: RestApiTlsConfig, certificate: Arc<ReloadableCertificate>) {
+    let mut modified = tls_files_modified(&tls);
+
+    loop {
+        tokio::time::sleep(tls.reload_interval()).await;
+
+        let current = tls_files_modified(&tls);
+
+        if current == modified {
+            continue;
+        }
+        modified = current;
+
+        match load_certified_key(&tls) {
+            Ok(certified_key) => {
+                certificate.replace(certified_key);
+                info!("TLS certificate and key reloaded.");
+            }
+            Err(e) => warn!(
+                "Reloading TLS certificate and key failed, keeping the current ones: {}.",
+                e
+            ),
+        }
+    }
+}
+
+async fn stop(signal: impl Future<Output = ()>, event_subscribers: EventSubscribers) {
+    signal.await;
+    // Event streams are long-lived connections that would otherwise prevent the graceful shutdown.
+    event_subscribers.clear();
+}
+
+async fn serve_tls(
+    routes: Routes,
+    config: &RestApiConfig,
+    tls: &RestApiTlsConfig,
+    signal: impl Future<Output = ()> + Send + 'static,
+) -> Result<(), String> {
+    let certificate = Arc::new(ReloadableCertificate(RwLock::new(load_certified_key(tls)?)));
+    let mut server_config = ServerConfig::new(NoClientAuth::new());
+    server_config.cert_resolver = certificate.clone();
+    server_config.set_protocols(&[b"h2".to_vec(), b"http/1.1".to_vec()]);
+    let acceptor = TlsAcceptor::from(Arc::new(server_config));
+
+    let listener = TcpListener::bind(config.binding_socket_addr())
+        .await
+        .map_err(|e| format!("can not bind to {}: {}", config.binding_socket_addr(), e))?;
+
+    // Failing connections are dropped here since any error of the incoming stream would stop the server.
+    let incoming = TcpListenerStream::new(listener)
+        .filter_map(|stream| ready(stream.map_err(|e| debug!("Accepting connection failed: {}.", e)).ok()))
+        .map(move |stream| timeout(TLS_HANDSHAKE_TIMEOUT, acceptor.accept(stream)))
+        .buffer_unordered(MAX_PENDING_TLS_HANDSHAKES)
+        .filter_map(|handshake| {
+            ready(match handshake {
+                Ok(Ok(stream)) => Some(Ok::<_, Infallible>(stream)),
+                Ok(Err(e)) => {
+                    debug!("TLS handshake failed: {}.", e);
+                    None
+                }
+                Err(_) => {
+                    debug!("TLS handshake timed out.");
+                    None
+                }
+            })
+        });
+
+    let service = warp::service(routes);
+    let make_service = make_service_fn(move |stream: &TlsStream<TcpStream>| {
+        let remote_addr = stream.get_ref().0.peer_addr().ok().map(RemoteAddr);
+        let service = service.clone();
+
+        future::ok::<_, Infallible>(service_fn(move |mut request| {
+            if let Some(remote_addr) = remote_addr {
+                request.extensions_mut().insert(remote_addr);
+            }
+            service.clone().call(request)
+        }))
+    });
+
+    info!("Available at https://{}.", config.binding_socket_addr());
+
+    let reload = tokio::spawn(reload_certificate(tls.clone(), certificate));
+    let result = Server::builder(accept::from_stream(incoming))
+        .serve(make_service)
+        .with_graceful_shutdown(signal)
+        .await;
+    reload.abort();
+
+    result.map_err(|e| e.to_string())
+}
+
+/// Serves the routes until the shutdown signal is received.
+///
+/// When TLS is enabled, the certificate and key are reloaded in place whenever their files change, without affecting
+/// established connections.
+pub(crate) async fn serve(
+    routes: Routes,
+    config: RestApiConfig,
+    shutdown: impl Future + Send + 'static,
+    event_subscribers: EventSubscribers,
+) {
+    let signal = stop(shutdown.map(drop), event_subscribers);
+
+    match config.tls() {
+        None => match warp::serve(routes).try_bind_with_graceful_shutdown(config.binding_socket_addr(), signal) {
+            Ok((addr, server)) => {
+                info!("Available at http://{}.", addr);
+                server.await;
+            }
+            Err(e) => error!("Serving the REST API failed: {}.", e),
+        },
+        Some(tls) => {
+            if let Err(e) = serve_tls(routes, &config, tls, signal).await {
+                error!("Serving the REST API over TLS failed: {}.", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::endpoints::{
+        config::{RestApiCorsConfigBuilder, RestApiTlsConfigBuilder},
+        filters::remote_addr,
+        rejection::CustomRejection,
+    };
+
+    use warp::{http::StatusCode, reject};
+
+    fn tls_config(cert: &str, key: &str) -> RestApiTlsConfig {
+        let fixture = |name| format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+
+        RestApiTlsConfigBuilder::new()
+            .cert_path(fixture(cert))
+            .key_path(fixture(key))
+            .finish()
+            .unwrap()
+    }
+
+    fn failing_routes() -> Routes {
+        let routes = warp::path("fail")
+            .and_then(|| async { Err::<String, _>(reject::custom(CustomRejection::BadRequest("bad".to_string()))) });
+        let cors = RestApiCorsConfigBuilder::new()
+            .allowed_origins(vec!["https://example.org".to_string()])
+            .finish();
+
+        recover(routes, Some(&cors), ResourceHandle::new(NodeMetrics::default()))
+    }
+
+    #[test]
+    fn certificate_and_key_are_loaded() {
+        let certified_key = load_certified_key(&tls_config("cert.pem", "key.pem")).unwrap();
+
+        assert_eq!(certified_key.cert.len(), 1);
+    }
+
+    #[test]
+    fn invalid_certificate_or_key_is_rejected() {
+        assert!(load_certified_key(&tls_config("missing.pem", "key.pem")).is_err());
+        assert!(load_certified_key(&tls_config("key.pem", "key.pem")).is_err());
+        assert!(load_certified_key(&tls_config("cert.pem", "cert.pem")).is_err());
+    }
+
+    #[tokio::test]
+    async fn remote_addr_is_provided_by_warp_or_the_tls_server() {
+        let addr = "10.0.0.1:14265".parse::<SocketAddr>().unwrap();
+
+        assert_eq!(
+            warp::test::request()
+                .remote_addr(addr)
+                .filter(&remote_addr())
+                .await
+                .unwrap(),
+            Some(addr)
+        );
+        assert_eq!(
+            warp::test::request()
+                .extension(RemoteAddr(addr))
+                .filter(&remote_addr())
+                .await
+                .unwrap(),
+            Some(addr)
+        );
+        assert_eq!(warp::test::request().filter(&remote_addr()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn errors_carry_cors_headers() {
+        let response = warp::test::request()
+            .path("/fail")
+            .header("origin", "https://example.org")
+            .reply(&failing_routes())
+            .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers().get("access-control-allow-origin").unwrap(),
+            "https://example.org"
+        );
+    }
+
+    #[tokio::test]
+    async fn forbidden_origins_are_recovered() {
+        let response = warp::test::request()
+            .path("/fail")
+            .header("origin", "https://example.com")
+            .reply(&failing_routes())
+            .await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(response.headers().get("access-control-allow-origin").is_none());
+    }
+}
@@ -1,9 +1,13 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use bee_ledger::types::{ConsumedOutput, OutputDiff, Receipt};
+use bee_ledger::types::{
+    AddressHistoryEntry, AddressHistoryPage, ConsumedOutput, CreatedOutput, IndexationPage, IndexationPageEntries,
+    OutputDiff, Receipt,
+};
 use bee_message::{
-    address::Ed25519Address, milestone::MilestoneIndex, output::OutputId, payload::indexation::PaddedIndex, MessageId,
+    address::Ed25519Address, milestone::MilestoneIndex, output::OutputId, payload::indexation::PaddedIndex, Message,
+    MessageId,
 };
 use bee_storage::{
     access::{AsStream, Fetch},
@@ -14,9 +18,14 @@ pub trait StorageBackend:
     backend::StorageBackend
     + Fetch<PaddedIndex, Vec<MessageId>>
     + Fetch<Ed25519Address, Vec<OutputId>>
+    + Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>>
+    + Fetch<IndexationPage, IndexationPageEntries>
     + Fetch<MilestoneIndex, OutputDiff>
     + Fetch<MilestoneIndex, Vec<Receipt>>
     + Fetch<OutputId, ConsumedOutput>
+    + Fetch<Vec<MessageId>, Vec<Option<Message>>>
+    + Fetch<Vec<OutputId>, Vec<Option<CreatedOutput>>>
+    + Fetch<Vec<OutputId>, Vec<Option<ConsumedOutput>>>
     + for<'a> AsStream<'a, (MilestoneIndex, Receipt), ()>
     + bee_protocol::workers::storage::StorageBackend
     + bee_ledger::workers::storage::StorageBackend
@@ -27,9 +36,14 @@ impl<T> StorageBackend for T where
     T: backend::StorageBackend
         + Fetch<PaddedIndex, Vec<MessageId>>
         + Fetch<Ed25519Address, Vec<OutputId>>
+        + Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>>
+        + Fetch<IndexationPage, IndexationPageEntries>
         + Fetch<MilestoneIndex, OutputDiff>
         + Fetch<MilestoneIndex, Vec<Receipt>>
         + Fetch<OutputId, ConsumedOutput>
+        + Fetch<Vec<MessageId>, Vec<Option<Message>>>
+        + Fetch<Vec<OutputId>, Vec<Option<CreatedOutput>>>
+        + Fetch<Vec<OutputId>, Vec<Option<ConsumedOutput>>>
         + for<'a> AsStream<'a, (MilestoneIndex, Receipt), ()>
         + bee_protocol::workers::storage::StorageBackend
         + bee_ledger::workers::storage::StorageBackend
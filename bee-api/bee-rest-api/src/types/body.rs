@@ -1,13 +1,14 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use serde::Serialize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
 /// Represents the data that can be included into `SuccessBody` and `ErrorBody`.
 pub trait BodyInner {}
 
 /// Describes the response body of a successful HTTP request.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, JsonSchema)]
 pub struct SuccessBody<T: BodyInner> {
     pub data: T,
 }
@@ -19,7 +20,7 @@ impl<T: BodyInner> SuccessBody<T> {
 }
 
 /// Describes the response body of a unsuccessful HTTP request.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, JsonSchema)]
 pub struct ErrorBody<T: BodyInner> {
     pub error: T,
 }
@@ -31,7 +32,7 @@ impl<T: BodyInner> ErrorBody<T> {
 }
 
 /// Describes the default error format.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct DefaultErrorResponse {
     pub code: String,
     pub message: String,
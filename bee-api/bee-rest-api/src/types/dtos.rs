@@ -3,7 +3,7 @@
 
 use crate::types::error::Error;
 
-use bee_ledger::types::Receipt;
+use bee_ledger::types::{AddressHistoryEntry, IndexationEntry, Receipt};
 use bee_message::{
     address::{Address, Ed25519Address, ED25519_ADDRESS_LENGTH},
     input::{Input, TreasuryInput, UtxoInput},
@@ -27,13 +27,14 @@ use bee_message::{
 };
 use bee_protocol::types::peer::Peer;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
 
 use std::convert::{TryFrom, TryInto};
 
 /// The message object that nodes gossip around in the network.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MessageDto {
     #[serde(rename = "networkId")]
     pub network_id: String,
@@ -132,7 +133,7 @@ impl TryFrom<&PayloadDto> for Payload {
 }
 
 /// The payload type to define a value transaction.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TransactionPayloadDto {
     #[serde(rename = "type")]
     pub kind: u32,
@@ -194,7 +195,7 @@ impl TryFrom<&EssenceDto> for Essence {
 }
 
 /// Describes the essence data making up a transaction by defining its inputs and outputs and an optional payload.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct RegularEssenceDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -291,7 +292,7 @@ impl TryFrom<&InputDto> for Input {
 }
 
 /// Describes an input which references an unspent transaction output to consume.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UtxoInputDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -302,7 +303,7 @@ pub struct UtxoInputDto {
 }
 
 /// Describes an input which references an unspent treasury output to consume.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TreasuryInputDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -311,7 +312,8 @@ pub struct TreasuryInputDto {
 }
 
 /// Describes all the different output types.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, JsonSchema)]
+#[schemars(untagged)]
 pub enum OutputDto {
     SignatureLockedSingle(SignatureLockedSingleOutputDto),
     SignatureLockedDustAllowance(SignatureLockedDustAllowanceOutputDto),
@@ -409,7 +411,7 @@ impl Serialize for OutputDto {
 }
 
 /// Describes a deposit to a single address which is unlocked via a signature.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct SignatureLockedSingleOutputDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -419,7 +421,7 @@ pub struct SignatureLockedSingleOutputDto {
 
 /// Output type for deposits that enables an address to receive dust outputs. It can be consumed as an input like a
 /// regular SigLockedSingleOutput.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct SignatureLockedDustAllowanceOutputDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -454,7 +456,7 @@ impl TryFrom<&AddressDto> for Address {
 }
 
 /// Describes an Ed25519 address.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Ed25519AddressDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -482,7 +484,7 @@ impl TryFrom<&Ed25519AddressDto> for Ed25519Address {
 }
 
 /// Describes a treasury output.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TreasuryOutputDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -545,7 +547,7 @@ impl TryFrom<&UnlockBlockDto> for UnlockBlock {
 }
 
 /// Defines an unlock block containing signature(s) unlocking input(s).
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct SignatureUnlockDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -560,7 +562,7 @@ pub enum SignatureDto {
 }
 
 /// Defines an Ed25519 signature.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Ed25519SignatureDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -571,7 +573,7 @@ pub struct Ed25519SignatureDto {
 
 /// References a previous unlock block in order to substitute the duplication of the same unlock block data for inputs
 /// which unlock through the same data.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ReferenceUnlockDto {
     #[serde(rename = "type")]
     pub kind: u8,
@@ -580,7 +582,7 @@ pub struct ReferenceUnlockDto {
 }
 
 /// The payload type to define a milestone.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MilestonePayloadDto {
     #[serde(rename = "type")]
     pub kind: u32,
@@ -680,7 +682,7 @@ impl TryFrom<&MilestonePayloadDto> for MilestonePayload {
 }
 
 /// The payload type to define a indexation payload.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct IndexationPayloadDto {
     #[serde(rename = "type")]
     pub kind: u32,
@@ -710,7 +712,7 @@ impl TryFrom<&IndexationPayloadDto> for IndexationPayload {
 }
 
 /// The payload type to define a receipt.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ReceiptPayloadDto {
     #[serde(rename = "type")]
     pub kind: u32,
@@ -755,7 +757,7 @@ impl TryFrom<&ReceiptPayloadDto> for ReceiptPayload {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MigratedFundsEntryDto {
     #[serde(rename = "tailTransactionHash")]
     pub tail_transaction_hash: String,
@@ -788,7 +790,7 @@ impl TryFrom<&MigratedFundsEntryDto> for MigratedFundsEntry {
 }
 
 /// The payload type to define a treasury transaction.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TreasuryTransactionPayloadDto {
     #[serde(rename = "type")]
     pub kind: u32,
@@ -818,7 +820,7 @@ impl TryFrom<&TreasuryTransactionPayloadDto> for TreasuryTransactionPayload {
 }
 
 /// Describes a peer.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct PeerDto {
     pub id: String,
     #[serde(rename = "multiAddresses")]
@@ -874,14 +876,14 @@ impl From<&Peer> for PeerDto {
 }
 
 /// Returns all information about the gossip stream with the peer.
-#[derive(Clone, Debug, Serialize, Deserialize, Default)]
+#[derive(Clone, Debug, Serialize, Deserialize, Default, JsonSchema)]
 pub struct GossipDto {
     pub heartbeat: HeartbeatDto,
     pub metrics: MetricsDto,
 }
 
 /// Describes the relation with the peer.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum RelationDto {
     #[serde(rename = "known")]
     Known,
@@ -892,7 +894,7 @@ pub enum RelationDto {
 }
 
 /// Describes the heartbeat of a node.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
 pub struct HeartbeatDto {
     #[serde(rename = "solidMilestoneIndex")]
     pub solid_milestone_index: u32,
@@ -907,7 +909,7 @@ pub struct HeartbeatDto {
 }
 
 /// Describes metrics of a gossip stream.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
 pub struct MetricsDto {
     #[serde(rename = "newMessages")]
     pub new_messages: u64,
@@ -934,7 +936,7 @@ pub struct MetricsDto {
 }
 
 /// Describes a receipt.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ReceiptDto {
     pub receipt: ReceiptPayloadDto,
     #[serde(rename = "milestoneIndex")]
@@ -950,8 +952,51 @@ impl From<Receipt> for ReceiptDto {
     }
 }
 
+/// Describes the balance change of an address caused by a transaction.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AddressHistoryEntryDto {
+    #[serde(rename = "milestoneIndex")]
+    pub milestone_index: u32,
+    #[serde(rename = "transactionId")]
+    pub transaction_id: String,
+    pub delta: i64,
+}
+
+impl From<&AddressHistoryEntry> for AddressHistoryEntryDto {
+    fn from(value: &AddressHistoryEntry) -> Self {
+        AddressHistoryEntryDto {
+            milestone_index: *value.index(),
+            transaction_id: value.transaction_id().to_string(),
+            delta: value.delta(),
+        }
+    }
+}
+
+/// Describes a message with an indexation payload referenced by a milestone.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct IndexationEntryDto {
+    pub index: String,
+    #[serde(rename = "milestoneIndex")]
+    pub milestone_index: u32,
+    #[serde(rename = "milestoneTimestamp")]
+    pub milestone_timestamp: u64,
+    #[serde(rename = "messageId")]
+    pub message_id: String,
+}
+
+impl From<&IndexationEntry> for IndexationEntryDto {
+    fn from(value: &IndexationEntry) -> Self {
+        IndexationEntryDto {
+            index: hex::encode(value.index()),
+            milestone_index: *value.milestone_index(),
+            milestone_timestamp: value.timestamp(),
+            message_id: value.message_id().to_string(),
+        }
+    }
+}
+
 /// Describes the ledger inclusion state of a transaction.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum LedgerInclusionStateDto {
     #[serde(rename = "conflicting")]
     Conflicting,
@@ -960,3 +1005,34 @@ pub enum LedgerInclusionStateDto {
     #[serde(rename = "noTransaction")]
     NoTransaction,
 }
+
+/// Describes the supervision status of a node worker.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum WorkerStatusDto {
+    #[serde(rename = "running")]
+    Running,
+    #[serde(rename = "restarting")]
+    Restarting,
+    #[serde(rename = "degraded")]
+    Degraded,
+    #[serde(rename = "stopped")]
+    Stopped,
+}
+
+/// Describes the outcome of the last check of the release feed.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateDto {
+    #[serde(rename = "latestVersion")]
+    pub latest_version: String,
+    #[serde(rename = "updateAvailable")]
+    pub update_available: bool,
+    #[serde(rename = "securityAdvisory")]
+    pub security_advisory: bool,
+}
+
+/// Describes the supervision status of a named node worker.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WorkerDto {
+    pub name: String,
+    pub status: WorkerStatusDto,
+}
@@ -4,4 +4,5 @@
 pub mod body;
 pub mod dtos;
 pub mod error;
+pub mod requests;
 pub mod responses;
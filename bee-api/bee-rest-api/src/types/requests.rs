@@ -0,0 +1,57 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::dtos::PayloadDto;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Request of POST /api/v1/messages.
+/// Missing fields are filled in by the node.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SubmitMessageRequest {
+    #[serde(rename = "networkId", skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(rename = "parentMessageIds", skip_serializing_if = "Option::is_none")]
+    pub parent_message_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<PayloadDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+}
+
+/// Request of POST /api/v1/peers.
+/// Adds a peer to the node.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AddPeerRequest {
+    #[serde(rename = "multiAddress")]
+    pub multi_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+}
+
+/// Request of POST /api/v1/outputs/batch.
+/// Fetches several outputs at once.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OutputsBatchRequest {
+    #[serde(rename = "outputIds")]
+    pub output_ids: Vec<String>,
+}
+
+/// Request of:
+/// * POST /api/v1/messages/batch, fetches several messages at once.
+/// * POST /api/v1/messages/metadata/batch, fetches the metadata of several messages at once.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MessagesBatchRequest {
+    #[serde(rename = "messageIds")]
+    pub message_ids: Vec<String>,
+}
+
+/// Request of POST /api/plugins/debug/whiteflag.
+/// Computes the white flag traversal of the given parents.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WhiteFlagRequest {
+    pub index: u32,
+    #[serde(rename = "parentMessageIds")]
+    pub parent_message_ids: Vec<String>,
+}
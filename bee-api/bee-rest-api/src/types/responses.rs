@@ -2,15 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::types::{
-    body::BodyInner,
-    dtos::{LedgerInclusionStateDto, MessageDto, OutputDto, PeerDto, ReceiptDto},
+    body::{BodyInner, DefaultErrorResponse},
+    dtos::{
+        AddressHistoryEntryDto, IndexationEntryDto, LedgerInclusionStateDto, MessageDto, OutputDto, PeerDto,
+        ReceiptDto, UpdateDto, WorkerDto,
+    },
 };
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Response of GET /api/v1/info.
 /// Returns general information about the node.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct InfoResponse {
     pub name: String,
     pub version: String,
@@ -36,14 +40,40 @@ pub struct InfoResponse {
     pub confirmed_milestone_index: u32,
     #[serde(rename = "pruningIndex")]
     pub pruning_index: u32,
+    #[serde(rename = "ledgerStateHash", skip_serializing_if = "Option::is_none")]
+    pub ledger_state_hash: Option<String>,
     pub features: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update: Option<UpdateDto>,
 }
 
 impl BodyInner for InfoResponse {}
 
+/// Response of GET /health.
+/// Returns whether the node is healthy and the supervision status of its workers.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct HealthResponse {
+    #[serde(rename = "isHealthy")]
+    pub is_healthy: bool,
+    pub workers: Vec<WorkerDto>,
+}
+
+impl BodyInner for HealthResponse {}
+
+/// Response of POST /api/v1/config/reload.
+/// Returns the configuration paths whose changes were applied and those that require a restart.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ConfigReloadResponse {
+    pub applied: Vec<String>,
+    #[serde(rename = "restartRequired")]
+    pub restart_required: Vec<String>,
+}
+
+impl BodyInner for ConfigReloadResponse {}
+
 /// Response of GET /api/v1/tips.
 /// Returns non-lazy tips.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TipsResponse {
     #[serde(rename = "tipMessageIds")]
     pub tip_message_ids: Vec<String>,
@@ -53,7 +83,7 @@ impl BodyInner for TipsResponse {}
 
 /// Response of POST /api/v1/messages.
 /// Returns the message identifier of the submitted message.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, JsonSchema)]
 pub struct SubmitMessageResponse {
     #[serde(rename = "messageId")]
     pub message_id: String,
@@ -63,7 +93,7 @@ impl BodyInner for SubmitMessageResponse {}
 
 /// Response of GET /api/v1/messages?index={INDEX}.
 /// Returns all messages ids that match a given indexation key.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MessagesFindResponse {
     pub index: String,
     #[serde(rename = "maxResults")]
@@ -75,16 +105,32 @@ pub struct MessagesFindResponse {
 
 impl BodyInner for MessagesFindResponse {}
 
+/// Response of GET /api/v1/messages/indexed?index={INDEX}|prefix={PREFIX}.
+/// Returns a page of the referenced messages with a matching indexation key, ordered by referencing milestone.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MessagesIndexedResponse {
+    pub index: String,
+    pub prefix: bool,
+    pub page: usize,
+    #[serde(rename = "pageSize")]
+    pub page_size: usize,
+    pub count: usize,
+    pub truncated: bool,
+    pub messages: Vec<IndexationEntryDto>,
+}
+
+impl BodyInner for MessagesIndexedResponse {}
+
 /// Response of GET /api/v1/messages/{message_id}.
 /// Returns a specific message.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MessageResponse(pub MessageDto);
 
 impl BodyInner for MessageResponse {}
 
 /// Response of GET /api/v1/messages/{message_id}/metadata.
 /// Returns the metadata of a message.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MessageMetadataResponse {
     #[serde(rename = "messageId")]
     pub message_id: String,
@@ -104,13 +150,15 @@ pub struct MessageMetadataResponse {
     pub should_promote: Option<bool>,
     #[serde(rename = "shouldReattach", skip_serializing_if = "Option::is_none")]
     pub should_reattach: Option<bool>,
+    #[serde(rename = "pendingConflict", skip_serializing_if = "Option::is_none")]
+    pub pending_conflict: Option<bool>,
 }
 
 impl BodyInner for MessageMetadataResponse {}
 
 /// Response of GET /api/v1/messages/{message_id}/children.
 /// Returns all children of a specific message.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MessageChildrenResponse {
     #[serde(rename = "messageId")]
     pub message_id: String,
@@ -123,9 +171,25 @@ pub struct MessageChildrenResponse {
 
 impl BodyInner for MessageChildrenResponse {}
 
+/// Response of:
+/// * GET /api/v1/messages/{message_id}/past-cone, returns the past cone of a message up to its confirming milestone.
+/// * GET /api/v1/messages/{message_id}/future-cone, returns the future cone of a message up to a number of levels.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MessageConeResponse {
+    #[serde(rename = "messageId")]
+    pub message_id: String,
+    #[serde(rename = "maxResults")]
+    pub max_results: usize,
+    pub count: usize,
+    pub truncated: bool,
+    pub messages: Vec<MessageMetadataResponse>,
+}
+
+impl BodyInner for MessageConeResponse {}
+
 /// Response of GET /api/v1/outputs/{output_id}.
 /// Returns all information about a specific output.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct OutputResponse {
     #[serde(rename = "messageId")]
     pub message_id: String,
@@ -140,9 +204,59 @@ pub struct OutputResponse {
 
 impl BodyInner for OutputResponse {}
 
+/// Response of GET /api/v1/outputs/{output_id}/pending-spends.
+/// Returns the messages spending an output that aren't referenced by a milestone yet.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OutputPendingSpendsResponse {
+    #[serde(rename = "outputId")]
+    pub output_id: String,
+    #[serde(rename = "messageIds")]
+    pub message_ids: Vec<String>,
+}
+
+impl BodyInner for OutputPendingSpendsResponse {}
+
+/// Result of a single id of a batch query.
+/// Holds either the requested item or the error that prevented fetching it.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BatchItem<T> {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<DefaultErrorResponse>,
+}
+
+/// Response of POST /api/v1/outputs/batch.
+/// Returns the requested outputs, in the order of the request.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OutputsBatchResponse {
+    pub outputs: Vec<BatchItem<OutputResponse>>,
+}
+
+impl BodyInner for OutputsBatchResponse {}
+
+/// Response of POST /api/v1/messages/batch.
+/// Returns the requested messages, in the order of the request.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MessagesBatchResponse {
+    pub messages: Vec<BatchItem<MessageDto>>,
+}
+
+impl BodyInner for MessagesBatchResponse {}
+
+/// Response of POST /api/v1/messages/metadata/batch.
+/// Returns the metadata of the requested messages, in the order of the request.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MessagesMetadataBatchResponse {
+    pub metadata: Vec<BatchItem<MessageMetadataResponse>>,
+}
+
+impl BodyInner for MessagesMetadataBatchResponse {}
+
 /// Response of GET /api/v1/addresses/{address}.
 /// Returns information about an address.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct BalanceAddressResponse {
     #[serde(rename = "addressType")]
     pub address_type: u8,
@@ -156,7 +270,7 @@ impl BodyInner for BalanceAddressResponse {}
 
 /// Response of GET /api/v1/addresses/{address}/outputs.
 /// Returns the outputs of an address.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct OutputsAddressResponse {
     #[serde(rename = "addressType")]
     pub address_type: u8,
@@ -170,17 +284,33 @@ pub struct OutputsAddressResponse {
 
 impl BodyInner for OutputsAddressResponse {}
 
+/// Response of GET /api/v1/addresses/ed25519/{address}/history.
+/// Returns a page of the balance changes of an address, ordered by milestone index.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AddressHistoryResponse {
+    #[serde(rename = "addressType")]
+    pub address_type: u8,
+    pub address: String,
+    pub page: usize,
+    #[serde(rename = "pageSize")]
+    pub page_size: usize,
+    pub count: usize,
+    pub history: Vec<AddressHistoryEntryDto>,
+}
+
+impl BodyInner for AddressHistoryResponse {}
+
 /// Response of:
 /// * GET /api/v1/receipts/{milestone_index}, returns all stored receipts for the given milestone index.
 /// * GET /api/v1/receipts, returns all stored receipts, independent of a milestone index.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ReceiptsResponse(pub Vec<ReceiptDto>);
 
 impl BodyInner for ReceiptsResponse {}
 
 /// Response of GET /api/v1/treasury.
 /// Returns all information about the treasury.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TreasuryResponse {
     #[serde(rename = "milestoneId")]
     pub milestone_id: String,
@@ -191,7 +321,7 @@ impl BodyInner for TreasuryResponse {}
 
 /// Response of GET /api/v1/milestone/{milestone_index}.
 /// Returns information about a milestone.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct MilestoneResponse {
     #[serde(rename = "index")]
     pub milestone_index: u32,
@@ -204,41 +334,43 @@ impl BodyInner for MilestoneResponse {}
 
 /// Response of GET /api/v1/milestone/{milestone_index}/utxo-changes.
 /// Returns all UTXO changes that happened at a specific milestone.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UtxoChangesResponse {
     pub index: u32,
     #[serde(rename = "createdOutputs")]
     pub created_outputs: Vec<String>,
     #[serde(rename = "consumedOutputs")]
     pub consumed_outputs: Vec<String>,
+    #[serde(rename = "ledgerStateHash", skip_serializing_if = "Option::is_none")]
+    pub ledger_state_hash: Option<String>,
 }
 
 impl BodyInner for UtxoChangesResponse {}
 
 /// Response of GET /api/v1/peers.
 /// Returns information about all peers of the node.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct PeersResponse(pub Vec<PeerDto>);
 
 impl BodyInner for PeersResponse {}
 
 /// Response of POST /api/v1/peers.
 /// Returns information about the added peer.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct AddPeerResponse(pub PeerDto);
 
 impl BodyInner for AddPeerResponse {}
 
 /// Response of GET /api/v1/peer/{peer_id}.
 /// Returns information about a specific peer of the node.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct PeerResponse(pub PeerDto);
 
 impl BodyInner for PeerResponse {}
 
 /// Response of GET /api/plugins/debug/whiteflag.
 /// Returns the computed merkle tree hash for the given white flag traversal.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct WhiteFlagResponse {
     #[serde(rename = "merkleTreeHash")]
     pub merkle_tree_hash: String,
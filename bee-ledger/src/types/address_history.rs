@@ -0,0 +1,161 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::error::Error;
+
+use bee_common::packable::{Packable, Read, Write};
+use bee_message::{address::Ed25519Address, milestone::MilestoneIndex, payload::transaction::TransactionId};
+
+/// Represents the balance change of an address caused by a transaction confirmed by a milestone.
+///
+/// The milestone index is packed in big endian so that the entries of an address are stored in milestone order.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AddressHistoryEntry {
+    index: MilestoneIndex,
+    transaction_id: TransactionId,
+    delta: i64,
+}
+
+impl AddressHistoryEntry {
+    /// Creates a new `AddressHistoryEntry`.
+    pub fn new(index: MilestoneIndex, transaction_id: TransactionId, delta: i64) -> Self {
+        Self {
+            index,
+            transaction_id,
+            delta,
+        }
+    }
+
+    /// Returns the milestone index of the `AddressHistoryEntry`.
+    pub fn index(&self) -> MilestoneIndex {
+        self.index
+    }
+
+    /// Returns the transaction id of the `AddressHistoryEntry`.
+    pub fn transaction_id(&self) -> &TransactionId {
+        &self.transaction_id
+    }
+
+    /// Returns the balance change of the `AddressHistoryEntry`.
+    pub fn delta(&self) -> i64 {
+        self.delta
+    }
+}
+
+impl Packable for AddressHistoryEntry {
+    type Error = Error;
+
+    fn packed_len(&self) -> usize {
+        self.index.packed_len() + self.transaction_id.packed_len() + core::mem::size_of::<i64>()
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        writer.write_all(&self.index.to_be_bytes())?;
+        self.transaction_id.pack(writer)?;
+        writer.write_all(&self.delta.to_le_bytes())?;
+
+        Ok(())
+    }
+
+    fn unpack_inner<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Self, Self::Error> {
+        let mut index = [0u8; 4];
+        reader.read_exact(&mut index)?;
+        let transaction_id = TransactionId::unpack_inner::<R, CHECK>(reader)?;
+        let mut delta = [0u8; 8];
+        reader.read_exact(&mut delta)?;
+
+        Ok(Self {
+            index: MilestoneIndex(u32::from_be_bytes(index)),
+            transaction_id,
+            delta: i64::from_le_bytes(delta),
+        })
+    }
+}
+
+/// Selects a page of the history of an address, i.e. at most `limit` entries after skipping `skip` entries from the
+/// ones of milestones starting at `from`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AddressHistoryPage {
+    address: Ed25519Address,
+    from: MilestoneIndex,
+    skip: usize,
+    limit: usize,
+}
+
+impl AddressHistoryPage {
+    /// Creates a new `AddressHistoryPage`.
+    pub fn new(address: Ed25519Address, from: MilestoneIndex, skip: usize, limit: usize) -> Self {
+        Self {
+            address,
+            from,
+            skip,
+            limit,
+        }
+    }
+
+    /// Returns the address of the `AddressHistoryPage`.
+    pub fn address(&self) -> &Ed25519Address {
+        &self.address
+    }
+
+    /// Returns the milestone index the `AddressHistoryPage` starts from.
+    pub fn from(&self) -> MilestoneIndex {
+        self.from
+    }
+
+    /// Returns the number of entries skipped by the `AddressHistoryPage`.
+    pub fn skip(&self) -> usize {
+        self.skip
+    }
+
+    /// Returns the maximum number of entries of the `AddressHistoryPage`.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the storage key the entries of the `AddressHistoryPage` are looked up from.
+    pub fn seek_key(&self) -> Vec<u8> {
+        let mut key = self.address.as_ref().to_vec();
+        key.extend_from_slice(&self.from.to_be_bytes());
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pack_unpack() {
+        let entry = AddressHistoryEntry::new(MilestoneIndex(42), TransactionId::new([0x2a; 32]), -1000);
+        let packed = entry.pack_new();
+
+        assert_eq!(packed.len(), entry.packed_len());
+        assert_eq!(AddressHistoryEntry::unpack(&mut packed.as_slice()).unwrap(), entry);
+    }
+
+    #[test]
+    fn packed_in_milestone_order() {
+        let lower = AddressHistoryEntry::new(MilestoneIndex(255), TransactionId::new([0xff; 32]), 1);
+        let higher = AddressHistoryEntry::new(MilestoneIndex(256), TransactionId::new([0x00; 32]), 1);
+
+        assert!(lower.pack_new() < higher.pack_new());
+    }
+
+    #[test]
+    fn seek_key_precedes_the_entries_from_its_milestone() {
+        let address = Ed25519Address::new([0x2a; 32]);
+        let page = AddressHistoryPage::new(address, MilestoneIndex(256), 0, 10);
+        let key = |index| {
+            let mut key = address.as_ref().to_vec();
+            key.extend_from_slice(
+                &AddressHistoryEntry::new(MilestoneIndex(index), TransactionId::new([0x00; 32]), 1).pack_new(),
+            );
+            key
+        };
+
+        assert!(key(255) < page.seek_key());
+        assert!(page.seek_key() <= key(256));
+        assert!(page.seek_key().starts_with(address.as_ref()));
+    }
+}
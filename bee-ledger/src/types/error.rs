@@ -75,4 +75,10 @@ pub enum Error {
     /// Milestone length mismatch.
     #[error("Milestone length mismatch: expected {0}, got {1}")]
     MilestoneLengthMismatch(usize, usize),
+    /// Invalid optional index.
+    #[error("Invalid optional index: {0}")]
+    InvalidOptionalIndex(u8),
+    /// Invalid indexation entry index length.
+    #[error("Invalid indexation entry index length: {0}")]
+    InvalidIndexationEntryLength(usize),
 }
@@ -0,0 +1,406 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::error::Error;
+
+use bee_common::packable::{Packable, Read, Write};
+use bee_message::{milestone::MilestoneIndex, payload::indexation::INDEXATION_PADDED_INDEX_LENGTH, MessageId};
+
+/// Length of the key grouping the entries of a raw index, i.e. the padded index followed by its length.
+pub const INDEXATION_INDEX_KEY_LENGTH: usize = INDEXATION_PADDED_INDEX_LENGTH + 1;
+
+/// Represents a message with an indexation payload referenced by a milestone.
+///
+/// The raw index is packed zero padded and followed by its length, so that the entries of an index are grouped
+/// together, then the milestone index in big endian so that they are stored in milestone order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexationEntry {
+    index: Box<[u8]>,
+    milestone_index: MilestoneIndex,
+    timestamp: u64,
+    message_id: MessageId,
+}
+
+impl IndexationEntry {
+    /// Creates a new `IndexationEntry`.
+    pub fn new(index: Box<[u8]>, milestone_index: MilestoneIndex, timestamp: u64, message_id: MessageId) -> Self {
+        Self {
+            index,
+            milestone_index,
+            timestamp,
+            message_id,
+        }
+    }
+
+    /// Returns the raw index of the `IndexationEntry`.
+    pub fn index(&self) -> &[u8] {
+        &self.index
+    }
+
+    /// Returns the index of the milestone referencing the message of the `IndexationEntry`.
+    pub fn milestone_index(&self) -> MilestoneIndex {
+        self.milestone_index
+    }
+
+    /// Returns the timestamp of the milestone referencing the message of the `IndexationEntry`.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Returns the message id of the `IndexationEntry`.
+    pub fn message_id(&self) -> &MessageId {
+        &self.message_id
+    }
+}
+
+impl Packable for IndexationEntry {
+    type Error = Error;
+
+    fn packed_len(&self) -> usize {
+        INDEXATION_INDEX_KEY_LENGTH
+            + self.milestone_index.packed_len()
+            + self.timestamp.packed_len()
+            + self.message_id.packed_len()
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        writer.write_all(&index_key(&self.index))?;
+        writer.write_all(&self.milestone_index.to_be_bytes())?;
+        writer.write_all(&self.timestamp.to_be_bytes())?;
+        self.message_id.pack(writer)?;
+
+        Ok(())
+    }
+
+    fn unpack_inner<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Self, Self::Error> {
+        let mut padded_index = [0u8; INDEXATION_PADDED_INDEX_LENGTH];
+        reader.read_exact(&mut padded_index)?;
+        let index_len = u8::unpack_inner::<R, CHECK>(reader)? as usize;
+
+        if CHECK && !(1..=INDEXATION_PADDED_INDEX_LENGTH).contains(&index_len) {
+            return Err(Error::InvalidIndexationEntryLength(index_len));
+        }
+
+        let mut milestone_index = [0u8; 4];
+        reader.read_exact(&mut milestone_index)?;
+        let mut timestamp = [0u8; 8];
+        reader.read_exact(&mut timestamp)?;
+        let message_id = MessageId::unpack_inner::<R, CHECK>(reader)?;
+
+        Ok(Self {
+            index: padded_index[..index_len.min(INDEXATION_PADDED_INDEX_LENGTH)].into(),
+            milestone_index: MilestoneIndex(u32::from_be_bytes(milestone_index)),
+            timestamp: u64::from_be_bytes(timestamp),
+            message_id,
+        })
+    }
+}
+
+fn index_key(index: &[u8]) -> Vec<u8> {
+    let mut key = vec![0u8; INDEXATION_INDEX_KEY_LENGTH];
+    key[..index.len()].copy_from_slice(index);
+    key[INDEXATION_PADDED_INDEX_LENGTH] = index.len() as u8;
+    key
+}
+
+/// Selects a page of the indexation entries matching a raw index, or a prefix of it, i.e. at most `limit` entries after
+/// skipping `skip` entries from the ones within the milestone and timestamp bounds, in milestone order.
+///
+/// The lookup gives up after visiting `max_visited` stored entries, e.g. the ones of other indexes sharing the
+/// milestones of a prefix, and reports the page as truncated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexationPage {
+    index: Box<[u8]>,
+    prefix: bool,
+    from_milestone: MilestoneIndex,
+    to_milestone: MilestoneIndex,
+    from_timestamp: u64,
+    to_timestamp: u64,
+    skip: usize,
+    limit: usize,
+    max_visited: usize,
+}
+
+impl IndexationPage {
+    /// Creates a new `IndexationPage` of the entries of exactly `index`.
+    pub fn index(index: Box<[u8]>, skip: usize, limit: usize) -> Self {
+        Self::new(index, false, skip, limit)
+    }
+
+    /// Creates a new `IndexationPage` of the entries of the indexes starting with `prefix`.
+    pub fn prefix(prefix: Box<[u8]>, skip: usize, limit: usize) -> Self {
+        Self::new(prefix, true, skip, limit)
+    }
+
+    fn new(index: Box<[u8]>, prefix: bool, skip: usize, limit: usize) -> Self {
+        Self {
+            index,
+            prefix,
+            from_milestone: MilestoneIndex(0),
+            to_milestone: MilestoneIndex(u32::MAX),
+            from_timestamp: 0,
+            to_timestamp: u64::MAX,
+            skip,
+            limit,
+            max_visited: usize::MAX,
+        }
+    }
+
+    /// Restricts the `IndexationPage` to the entries of the milestones in `[from, to]`.
+    pub fn with_milestones(mut self, from: MilestoneIndex, to: MilestoneIndex) -> Self {
+        self.from_milestone = from;
+        self.to_milestone = to;
+        self
+    }
+
+    /// Restricts the `IndexationPage` to the entries of the milestones with a timestamp in `[from, to]`.
+    pub fn with_timestamps(mut self, from: u64, to: u64) -> Self {
+        self.from_timestamp = from;
+        self.to_timestamp = to;
+        self
+    }
+
+    /// Bounds the number of stored entries visited by the lookup of the `IndexationPage`.
+    pub fn with_max_visited(mut self, max_visited: usize) -> Self {
+        self.max_visited = max_visited;
+        self
+    }
+
+    /// Returns the raw index, or prefix, of the `IndexationPage`.
+    pub fn raw_index(&self) -> &[u8] {
+        &self.index
+    }
+
+    /// Returns whether the `IndexationPage` matches the indexes starting with its raw index.
+    pub fn is_prefix(&self) -> bool {
+        self.prefix
+    }
+
+    /// Returns the number of entries skipped by the `IndexationPage`.
+    pub fn skip(&self) -> usize {
+        self.skip
+    }
+
+    /// Returns the maximum number of entries of the `IndexationPage`.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the maximum number of stored entries visited by the lookup of the `IndexationPage`.
+    pub fn max_visited(&self) -> usize {
+        self.max_visited
+    }
+
+    /// Returns the storage key the entries of the `IndexationPage` are looked up from.
+    ///
+    /// Entries of an exact index are looked up by index then milestone, entries of a prefix by milestone only.
+    pub fn seek_key(&self) -> Vec<u8> {
+        let mut key = if self.prefix { Vec::new() } else { self.index_key() };
+        key.extend_from_slice(&self.from_milestone.to_be_bytes());
+        key
+    }
+
+    /// Returns the key grouping the entries of the exact index of the `IndexationPage`.
+    pub fn index_key(&self) -> Vec<u8> {
+        index_key(&self.index)
+    }
+
+    /// Returns whether `entry` precedes the bounds of the `IndexationPage`.
+    ///
+    /// Milestone timestamps increase with milestone indexes, so the entries are also stored in timestamp order.
+    pub fn is_before(&self, entry: &IndexationEntry) -> bool {
+        entry.milestone_index() < self.from_milestone || entry.timestamp() < self.from_timestamp
+    }
+
+    /// Returns whether `entry` follows the bounds of the `IndexationPage`.
+    pub fn is_after(&self, entry: &IndexationEntry) -> bool {
+        entry.milestone_index() > self.to_milestone || entry.timestamp() > self.to_timestamp
+    }
+
+    /// Returns whether the raw index of `entry` matches the one of the `IndexationPage`.
+    pub fn matches(&self, entry: &IndexationEntry) -> bool {
+        if self.prefix {
+            entry.index().starts_with(&self.index)
+        } else {
+            *entry.index() == *self.index
+        }
+    }
+
+    /// Selects the entries of the `IndexationPage` from stored entries in storage order, starting at its seek key.
+    pub fn select(&self, stored: impl Iterator<Item = IndexationEntry>) -> IndexationPageEntries {
+        let mut entries = Vec::new();
+        let mut skip = self.skip;
+        let mut visited = 0;
+
+        for entry in stored.take_while(|entry| !self.is_after(entry)) {
+            if entries.len() >= self.limit {
+                break;
+            }
+            if visited >= self.max_visited {
+                return IndexationPageEntries::new(entries, true);
+            }
+            visited += 1;
+
+            if self.is_before(&entry) || !self.matches(&entry) {
+                continue;
+            }
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+
+            entries.push(entry);
+        }
+
+        IndexationPageEntries::new(entries, false)
+    }
+}
+
+/// The entries selected by an `IndexationPage`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IndexationPageEntries {
+    entries: Vec<IndexationEntry>,
+    truncated: bool,
+}
+
+impl IndexationPageEntries {
+    /// Creates a new `IndexationPageEntries`.
+    pub fn new(entries: Vec<IndexationEntry>, truncated: bool) -> Self {
+        Self { entries, truncated }
+    }
+
+    /// Returns the entries of the `IndexationPageEntries`.
+    pub fn entries(&self) -> &[IndexationEntry] {
+        &self.entries
+    }
+
+    /// Returns whether the lookup gave up before reaching the end of the page.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Consumes the `IndexationPageEntries` and returns its entries.
+    pub fn into_entries(self) -> Vec<IndexationEntry> {
+        self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(index: &[u8], milestone_index: u32, timestamp: u64) -> IndexationEntry {
+        IndexationEntry::new(
+            index.into(),
+            MilestoneIndex(milestone_index),
+            timestamp,
+            MessageId::new([0xff; 32]),
+        )
+    }
+
+    #[test]
+    fn pack_unpack() {
+        let entry = IndexationEntry::new(
+            b"bee".to_vec().into_boxed_slice(),
+            MilestoneIndex(42),
+            1_620_000_000,
+            MessageId::new([0x2a; 32]),
+        );
+        let packed = entry.pack_new();
+
+        assert_eq!(packed.len(), entry.packed_len());
+        assert_eq!(IndexationEntry::unpack(&mut packed.as_slice()).unwrap(), entry);
+    }
+
+    #[test]
+    fn packed_grouped_by_index_in_milestone_order() {
+        assert!(entry(b"bee", 255, 0).pack_new() < entry(b"bee", 256, 0).pack_new());
+        // A shorter index isn't interleaved with the longer ones it prefixes.
+        assert!(entry(b"be", 256, 0).pack_new() < entry(b"be\0", 0, 0).pack_new());
+        assert!(entry(b"be\0", 256, 0).pack_new() < entry(b"bee", 0, 0).pack_new());
+        assert!(entry(b"bee", 0, 0).pack_new().starts_with(b"be"));
+    }
+
+    #[test]
+    fn unpack_invalid_length() {
+        let mut packed = entry(b"bee", 0, 0).pack_new();
+        packed[INDEXATION_PADDED_INDEX_LENGTH] = 0;
+
+        assert!(matches!(
+            IndexationEntry::unpack(&mut packed.as_slice()),
+            Err(Error::InvalidIndexationEntryLength(0))
+        ));
+    }
+
+    #[test]
+    fn seek_key_precedes_the_entries_from_its_milestone() {
+        let page = IndexationPage::index(b"bee".to_vec().into_boxed_slice(), 0, 10)
+            .with_milestones(MilestoneIndex(256), MilestoneIndex(512));
+
+        assert!(entry(b"bee", 255, 0).pack_new() < page.seek_key());
+        assert!(page.seek_key() <= entry(b"bee", 256, 0).pack_new());
+        assert!(entry(b"bee", 256, 0).pack_new().starts_with(&page.index_key()));
+        assert!(!entry(b"bee\0", 256, 0).pack_new().starts_with(&page.index_key()));
+    }
+
+    #[test]
+    fn bounds_and_matches() {
+        let page = IndexationPage::prefix(b"be".to_vec().into_boxed_slice(), 0, 10)
+            .with_milestones(MilestoneIndex(10), MilestoneIndex(20))
+            .with_timestamps(100, 200);
+
+        assert!(page.is_before(&entry(b"bee", 9, 150)));
+        assert!(page.is_before(&entry(b"bee", 15, 99)));
+        assert!(!page.is_before(&entry(b"bee", 10, 100)));
+        assert!(page.is_after(&entry(b"bee", 21, 150)));
+        assert!(page.is_after(&entry(b"bee", 15, 201)));
+        assert!(!page.is_after(&entry(b"bee", 20, 200)));
+        assert!(page.matches(&entry(b"bee", 15, 150)));
+        assert!(!page.matches(&entry(b"b", 15, 150)));
+        assert!(!IndexationPage::index(b"be".to_vec().into_boxed_slice(), 0, 10).matches(&entry(b"bee", 15, 150)));
+        assert_eq!(page.seek_key(), 10u32.to_be_bytes());
+    }
+
+    #[test]
+    fn select_skips_and_limits_the_matching_entries() {
+        let page = IndexationPage::prefix(b"be".to_vec().into_boxed_slice(), 1, 2)
+            .with_milestones(MilestoneIndex(10), MilestoneIndex(20));
+        let stored = vec![
+            entry(b"bee", 9, 0),
+            entry(b"bee", 10, 0),
+            entry(b"ab", 11, 0),
+            entry(b"bef", 12, 0),
+            entry(b"bee", 13, 0),
+            entry(b"bee", 14, 0),
+        ];
+
+        assert_eq!(
+            page.select(stored.into_iter()),
+            IndexationPageEntries::new(vec![entry(b"bef", 12, 0), entry(b"bee", 13, 0)], false)
+        );
+    }
+
+    #[test]
+    fn select_stops_after_the_bounds() {
+        let page = IndexationPage::index(b"bee".to_vec().into_boxed_slice(), 0, 10)
+            .with_milestones(MilestoneIndex(0), MilestoneIndex(10))
+            .with_max_visited(2);
+        let stored = vec![entry(b"bee", 9, 0), entry(b"bee", 10, 0), entry(b"bee", 11, 0)];
+
+        assert_eq!(
+            page.select(stored.into_iter()),
+            IndexationPageEntries::new(vec![entry(b"bee", 9, 0), entry(b"bee", 10, 0)], false)
+        );
+    }
+
+    #[test]
+    fn select_is_truncated_after_max_visited() {
+        let page = IndexationPage::prefix(b"be".to_vec().into_boxed_slice(), 0, 10).with_max_visited(2);
+        let stored = vec![entry(b"ab", 1, 0), entry(b"bee", 2, 0), entry(b"bee", 3, 0)];
+
+        assert_eq!(
+            page.select(stored.into_iter()),
+            IndexationPageEntries::new(vec![entry(b"bee", 2, 0)], true)
+        );
+    }
+}
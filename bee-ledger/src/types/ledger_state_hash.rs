@@ -0,0 +1,146 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_common::packable::{Packable, Read, Write};
+
+use core::fmt;
+
+/// The length of a `LedgerStateHash`.
+pub const LEDGER_STATE_HASH_LENGTH: usize = 32;
+
+/// A commutative hash over the unspent outputs and the unspent treasury output of the ledger.
+///
+/// The hash is the sum modulo 2^256 of the hashes of all the elements of the ledger state, read as big endian integers,
+/// which makes it independent of the order in which elements are added and allows incremental updates by subtracting
+/// removed elements. Unlike a XOR, an element added twice doesn't cancel out.
+///
+/// It is meant to compare ledger states between nodes and is not collision resistant: ledger states with the same hash
+/// can be crafted.
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct LedgerStateHash([u8; LEDGER_STATE_HASH_LENGTH]);
+
+impl LedgerStateHash {
+    /// Creates a new `LedgerStateHash`.
+    pub fn new(bytes: [u8; LEDGER_STATE_HASH_LENGTH]) -> Self {
+        bytes.into()
+    }
+
+    /// Adds an element hash to the `LedgerStateHash`.
+    pub fn insert(&mut self, element: &[u8; LEDGER_STATE_HASH_LENGTH]) {
+        let mut carry = 0u16;
+
+        for (byte, element_byte) in self.0.iter_mut().zip(element.iter()).rev() {
+            let sum = *byte as u16 + *element_byte as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+    }
+
+    /// Removes an element hash from the `LedgerStateHash`.
+    pub fn remove(&mut self, element: &[u8; LEDGER_STATE_HASH_LENGTH]) {
+        let mut borrow = 0i16;
+
+        for (byte, element_byte) in self.0.iter_mut().zip(element.iter()).rev() {
+            let difference = *byte as i16 - *element_byte as i16 - borrow;
+            *byte = difference as u8;
+            borrow = (difference < 0) as i16;
+        }
+    }
+}
+
+impl From<[u8; LEDGER_STATE_HASH_LENGTH]> for LedgerStateHash {
+    fn from(bytes: [u8; LEDGER_STATE_HASH_LENGTH]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl AsRef<[u8]> for LedgerStateHash {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Display for LedgerStateHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0.iter() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for LedgerStateHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LedgerStateHash({})", self)
+    }
+}
+
+impl Packable for LedgerStateHash {
+    type Error = std::io::Error;
+
+    fn packed_len(&self) -> usize {
+        LEDGER_STATE_HASH_LENGTH
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        writer.write_all(&self.0)
+    }
+
+    fn unpack_inner<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Self, Self::Error> {
+        let mut bytes = [0u8; LEDGER_STATE_HASH_LENGTH];
+        reader.read_exact(&mut bytes)?;
+
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_is_commutative_and_reversible() {
+        let a = [0xffu8; LEDGER_STATE_HASH_LENGTH];
+        let b = [0x2au8; LEDGER_STATE_HASH_LENGTH];
+
+        let mut ab = LedgerStateHash::default();
+        ab.insert(&a);
+        ab.insert(&b);
+
+        let mut ba = LedgerStateHash::default();
+        ba.insert(&b);
+        ba.insert(&a);
+
+        assert_eq!(ab, ba);
+
+        ab.remove(&b);
+        ab.remove(&a);
+
+        assert_eq!(ab, LedgerStateHash::default());
+    }
+
+    #[test]
+    fn insert_carries() {
+        let mut one = [0u8; LEDGER_STATE_HASH_LENGTH];
+        one[LEDGER_STATE_HASH_LENGTH - 1] = 1;
+        let mut hash = LedgerStateHash::new([0xff; LEDGER_STATE_HASH_LENGTH]);
+
+        hash.insert(&one);
+        assert_eq!(hash, LedgerStateHash::default());
+
+        hash.remove(&one);
+        assert_eq!(hash, LedgerStateHash::new([0xff; LEDGER_STATE_HASH_LENGTH]));
+    }
+
+    #[test]
+    fn duplicate_elements_do_not_cancel_out() {
+        let a = [0x2au8; LEDGER_STATE_HASH_LENGTH];
+        let mut hash = LedgerStateHash::default();
+
+        hash.insert(&a);
+        hash.insert(&a);
+
+        assert_ne!(hash, LedgerStateHash::default());
+    }
+}
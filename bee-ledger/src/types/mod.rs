@@ -5,28 +5,40 @@
 
 pub mod snapshot;
 
+mod address_history;
 mod balance;
 mod balance_diff;
 mod conflict;
 mod consumed_output;
 mod created_output;
 mod error;
+mod indexation;
+mod key_manager;
+mod key_range;
 mod ledger_index;
+mod ledger_state_hash;
 mod migration;
+mod optional_index;
 mod output_diff;
 mod receipt;
 mod treasury_diff;
 mod treasury_output;
 mod unspent;
 
+pub use address_history::{AddressHistoryEntry, AddressHistoryPage};
 pub use balance::Balance;
 pub use balance_diff::{BalanceDiff, BalanceDiffs};
 pub use conflict::ConflictReason;
 pub use consumed_output::ConsumedOutput;
 pub use created_output::CreatedOutput;
 pub use error::Error;
+pub use indexation::{IndexationEntry, IndexationPage, IndexationPageEntries, INDEXATION_INDEX_KEY_LENGTH};
+pub use key_manager::KeyManager;
+pub use key_range::MilestoneKeyRange;
 pub use ledger_index::LedgerIndex;
+pub use ledger_state_hash::{LedgerStateHash, LEDGER_STATE_HASH_LENGTH};
 pub use migration::Migration;
+pub use optional_index::OptionalIndex;
 pub use output_diff::OutputDiff;
 pub use receipt::Receipt;
 pub use treasury_diff::TreasuryDiff;
@@ -0,0 +1,52 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::Error;
+
+use bee_common::packable::Packable;
+
+use std::{
+    convert::{TryFrom, TryInto},
+    io::{Read, Write},
+};
+
+/// Represents the optional indexes of the ledger, each of them keeping track of the milestone index it was pruned up to.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OptionalIndex {
+    /// The history of the balance changes of the addresses.
+    AddressHistory = 0,
+    /// The messages with an indexation payload referenced by milestones, by raw index.
+    Indexation = 1,
+}
+
+impl TryFrom<u8> for OptionalIndex {
+    type Error = Error;
+
+    fn try_from(kind: u8) -> Result<Self, Self::Error> {
+        Ok(match kind {
+            0 => Self::AddressHistory,
+            1 => Self::Indexation,
+            x => return Err(Error::InvalidOptionalIndex(x)),
+        })
+    }
+}
+
+impl Packable for OptionalIndex {
+    type Error = Error;
+
+    fn packed_len(&self) -> usize {
+        (*self as u8).packed_len()
+    }
+
+    fn pack<W: Write>(&self, writer: &mut W) -> Result<(), Self::Error> {
+        Ok((*self as u8).pack(writer)?)
+    }
+
+    fn unpack_inner<R: Read + ?Sized, const CHECK: bool>(reader: &mut R) -> Result<Self, Self::Error>
+    where
+        Self: Sized,
+    {
+        u8::unpack_inner::<R, CHECK>(reader)?.try_into()
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    types::{AddressHistoryEntry, ConsumedOutput, CreatedOutput},
+    workers::error::Error,
+};
+
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::MilestoneIndex,
+    output::{Output, OutputId},
+    payload::transaction::TransactionId,
+};
+
+use std::collections::HashMap;
+
+fn output_address_amount(output: &CreatedOutput) -> Result<(Ed25519Address, u64), Error> {
+    let (address, amount) = match output.inner() {
+        Output::SignatureLockedSingle(output) => (output.address(), output.amount()),
+        Output::SignatureLockedDustAllowance(output) => (output.address(), output.amount()),
+        output => return Err(Error::UnsupportedOutputKind(output.kind())),
+    };
+
+    match address {
+        Address::Ed25519(address) => Ok((*address, amount)),
+        address => Err(Error::UnsupportedAddressKind(address.kind())),
+    }
+}
+
+/// Computes the address history entries of a milestone from the outputs it created and consumed.
+///
+/// Outputs of the same transaction and address are merged into a single entry holding the net balance change.
+pub(crate) fn address_history_entries<'a>(
+    index: MilestoneIndex,
+    created_outputs: impl Iterator<Item = (&'a OutputId, &'a CreatedOutput)>,
+    consumed_outputs: impl Iterator<Item = (&'a CreatedOutput, &'a ConsumedOutput)>,
+) -> Result<Vec<(Ed25519Address, AddressHistoryEntry)>, Error> {
+    let mut deltas = HashMap::<(Ed25519Address, TransactionId), i64>::new();
+
+    for (output_id, created_output) in created_outputs {
+        let (address, amount) = output_address_amount(created_output)?;
+        *deltas.entry((address, *output_id.transaction_id())).or_default() += amount as i64;
+    }
+
+    for (created_output, consumed_output) in consumed_outputs {
+        let (address, amount) = output_address_amount(created_output)?;
+        *deltas.entry((address, *consumed_output.target())).or_default() -= amount as i64;
+    }
+
+    Ok(deltas
+        .into_iter()
+        .map(|((address, transaction_id), delta)| (address, AddressHistoryEntry::new(index, transaction_id, delta)))
+        .collect())
+}
@@ -0,0 +1,56 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+
+const DEFAULT_ADDRESS_HISTORY: bool = false;
+const DEFAULT_INDEXATION: bool = false;
+
+#[derive(Default, Deserialize)]
+pub struct LedgerConfigBuilder {
+    address_history: Option<bool>,
+    indexation: Option<bool>,
+}
+
+impl LedgerConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn address_history(mut self, address_history: bool) -> Self {
+        self.address_history.replace(address_history);
+        self
+    }
+
+    pub fn indexation(mut self, indexation: bool) -> Self {
+        self.indexation.replace(indexation);
+        self
+    }
+
+    pub fn finish(self) -> LedgerConfig {
+        LedgerConfig {
+            address_history: self.address_history.unwrap_or(DEFAULT_ADDRESS_HISTORY),
+            indexation: self.indexation.unwrap_or(DEFAULT_INDEXATION),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LedgerConfig {
+    address_history: bool,
+    indexation: bool,
+}
+
+impl LedgerConfig {
+    pub fn build() -> LedgerConfigBuilder {
+        LedgerConfigBuilder::new()
+    }
+
+    pub fn address_history(&self) -> bool {
+        self.address_history
+    }
+
+    pub fn indexation(&self) -> bool {
+        self.indexation
+    }
+}
@@ -8,5 +8,6 @@ pub(crate) mod white_flag;
 pub(crate) mod worker;
 
 pub use metadata::WhiteFlagMetadata;
-pub use white_flag::white_flag;
+pub use state::validate_confirmation_state;
+pub use white_flag::{validate_transaction, white_flag};
 pub use worker::{ConsensusWorker, ConsensusWorkerEvent};
@@ -12,6 +12,7 @@ use crate::{
 use bee_message::{
     address::Address,
     constants::IOTA_SUPPLY,
+    milestone::MilestoneIndex,
     output::{self, dust_outputs_max},
 };
 use bee_storage::access::AsStream;
@@ -77,9 +78,40 @@ async fn validate_ledger_balance_state<B: StorageBackend>(storage: &B, treasury:
     Ok(())
 }
 
+/// Checks that the last milestone applied to the ledger was entirely stored, metadata of the milestone included.
+pub async fn validate_confirmation_state<B: StorageBackend>(storage: &B) -> Result<(), Error> {
+    let ledger_index = match storage::fetch_ledger_index(storage).await? {
+        Some(ledger_index) => MilestoneIndex(*ledger_index),
+        None => return Ok(()),
+    };
+    let snapshot_index = match storage::fetch_snapshot_info(storage).await? {
+        Some(snapshot_info) => snapshot_info.snapshot_index(),
+        None => return Ok(()),
+    };
+
+    // Milestones up to the snapshot index come from the snapshot and are not confirmed by the node.
+    if ledger_index <= snapshot_index {
+        return Ok(());
+    }
+
+    if storage::fetch_output_diff(storage, ledger_index).await?.is_none() {
+        return Err(Error::InconsistentConfirmation(ledger_index));
+    }
+
+    let milestone = storage::fetch_milestone(storage, ledger_index)
+        .await?
+        .ok_or(Error::InconsistentConfirmation(ledger_index))?;
+
+    match storage::fetch_message_metadata(storage, milestone.message_id()).await? {
+        Some(metadata) if metadata.flags().is_referenced() => Ok(()),
+        _ => Err(Error::InconsistentConfirmation(ledger_index)),
+    }
+}
+
 pub(crate) async fn validate_ledger_state<B: StorageBackend>(storage: &B) -> Result<(), Error> {
     let treasury = storage::fetch_unspent_treasury_output(storage).await?.inner().amount();
 
+    validate_confirmation_state(storage).await?;
     validate_ledger_unspent_state(storage, treasury).await?;
     validate_ledger_balance_state(storage, treasury).await
 }
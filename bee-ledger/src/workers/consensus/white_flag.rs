@@ -35,6 +35,71 @@ fn verify_signature(address: &Address, unlock_blocks: &UnlockBlocks, index: usiz
     }
 }
 
+/// Checks a transaction against the current ledger state, ahead of the confirmation of its message.
+///
+/// Only the inputs, their signatures and the sum of the amounts are checked, independently of the other unconfirmed
+/// transactions, so the outcome is a likely conflict that the white flag confirms or not.
+pub async fn validate_transaction<B: StorageBackend>(
+    storage: &B,
+    transaction: &TransactionPayload,
+) -> Result<ConflictReason, Error> {
+    let essence = match transaction.essence() {
+        Essence::Regular(essence) => essence,
+        essence => return Err(Error::UnsupportedTransactionEssenceKind(essence.kind())),
+    };
+    let essence_hash = Essence::from(essence.clone()).hash();
+    let mut consumed_amount: u64 = 0;
+    let mut created_amount: u64 = 0;
+
+    for (index, input) in essence.inputs().iter().enumerate() {
+        let output_id = match input {
+            Input::Utxo(input) => input.output_id(),
+            input => return Err(Error::UnsupportedInputKind(input.kind())),
+        };
+
+        let output = match storage::fetch_output(storage, output_id).await? {
+            Some(output) => output,
+            None => return Ok(ConflictReason::InputUtxoNotFound),
+        };
+
+        if !storage::is_output_unspent(storage, output_id).await? {
+            return Ok(ConflictReason::InputUtxoAlreadySpent);
+        }
+
+        let (address, amount) = match output.inner() {
+            Output::SignatureLockedSingle(output) => (output.address(), output.amount()),
+            Output::SignatureLockedDustAllowance(output) => (output.address(), output.amount()),
+            output => return Err(Error::UnsupportedOutputKind(output.kind())),
+        };
+
+        if !verify_signature(address, transaction.unlock_blocks(), index, &essence_hash) {
+            return Ok(ConflictReason::InvalidSignature);
+        }
+
+        consumed_amount = consumed_amount
+            .checked_add(amount)
+            .ok_or_else(|| Error::ConsumedAmountOverflow(consumed_amount as u128 + amount as u128))?;
+    }
+
+    for output in essence.outputs() {
+        let amount = match output {
+            Output::SignatureLockedSingle(output) => output.amount(),
+            Output::SignatureLockedDustAllowance(output) => output.amount(),
+            output => return Err(Error::UnsupportedOutputKind(output.kind())),
+        };
+
+        created_amount = created_amount
+            .checked_add(amount)
+            .ok_or_else(|| Error::CreatedAmountOverflow(created_amount as u128 + amount as u128))?;
+    }
+
+    if created_amount != consumed_amount {
+        return Ok(ConflictReason::InputOutputSumMismatch);
+    }
+
+    Ok(ConflictReason::None)
+}
+
 async fn apply_regular_essence<B: StorageBackend>(
     storage: &B,
     message_id: &MessageId,
@@ -2,15 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    types::{CreatedOutput, LedgerIndex, Migration, Receipt, TreasuryOutput},
+    types::{CreatedOutput, LedgerIndex, Migration, OptionalIndex, Receipt, TreasuryOutput},
     workers::{
+        config::LedgerConfig,
         consensus::{metadata::WhiteFlagMetadata, state::validate_ledger_state, white_flag},
         error::Error,
         event::{MilestoneConfirmed, OutputConsumed, OutputCreated},
+        indexation::indexation_entry,
         pruning::{
-            condition::{should_prune, should_snapshot},
+            condition::{should_prune, should_prune_address_history, should_prune_indexation, should_snapshot},
             config::PruningConfig,
-            constants::{PRUNING_THRESHOLD, SOLID_ENTRY_POINT_THRESHOLD_FUTURE, SOLID_ENTRY_POINT_THRESHOLD_PAST},
+            constants::SOLID_ENTRY_POINT_THRESHOLD_FUTURE,
         },
         snapshot::{config::SnapshotConfig, worker::SnapshotWorker},
         storage::{self, StorageBackend},
@@ -29,8 +31,8 @@ use bee_tangle::{MsTangle, TangleWorker};
 
 use async_trait::async_trait;
 
-use futures::stream::StreamExt;
-use log::{error, info, warn};
+use futures::{future::FutureExt, stream::StreamExt};
+use log::{debug, error, info, warn};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
@@ -73,6 +75,7 @@ async fn confirm<N: Node>(
     message_id: MessageId,
     ledger_index: &mut LedgerIndex,
     receipt_migrated_at: &mut MilestoneIndex,
+    ledger_config: &LedgerConfig,
 ) -> Result<(), Error>
 where
     N::Backend: StorageBackend,
@@ -149,6 +152,53 @@ where
         None
     };
 
+    let mut referenced_messages = Vec::with_capacity(metadata.referenced_messages);
+
+    for (message_id, conflict) in metadata
+        .excluded_no_transaction_messages
+        .iter()
+        .chain(metadata.included_messages.iter())
+        .map(|message_id| (message_id, ConflictReason::None))
+        .chain(
+            metadata
+                .excluded_conflicting_messages
+                .iter()
+                .map(|(message_id, conflict)| (message_id, *conflict)),
+        )
+    {
+        let mut message_metadata = tangle
+            .get_metadata(message_id)
+            .await
+            .ok_or(Error::MissingMessageMetadata(*message_id))?;
+
+        message_metadata.set_conflict(conflict);
+        message_metadata.reference(milestone.essence().timestamp());
+
+        referenced_messages.push((*message_id, message_metadata));
+    }
+
+    let mut indexation_entries = Vec::new();
+
+    if ledger_config.indexation() {
+        // The indexation payloads of conflicting transactions are not indexed.
+        for message_id in metadata
+            .excluded_no_transaction_messages
+            .iter()
+            .chain(metadata.included_messages.iter())
+        {
+            let message = tangle.get(message_id).await.ok_or(Error::MissingMessage(*message_id))?;
+
+            if let Some(entry) = indexation_entry(
+                message_id,
+                &message,
+                milestone.essence().index(),
+                milestone.essence().timestamp(),
+            ) {
+                indexation_entries.push(entry);
+            }
+        }
+    }
+
     storage::apply_milestone(
         &*storage,
         metadata.index,
@@ -156,34 +206,21 @@ where
         &metadata.consumed_outputs,
         &metadata.balance_diffs,
         &migration,
+        &referenced_messages,
+        ledger_config.address_history(),
+        &indexation_entries,
     )
     .await?;
 
+    // The confirmed milestone index is only cached by the tangle once committed with the ledger index.
     *ledger_index = LedgerIndex(milestone.essence().index());
     tangle.update_confirmed_milestone_index(milestone.essence().index());
 
-    for message_id in metadata.excluded_no_transaction_messages.iter() {
-        tangle
-            .update_metadata(message_id, |message_metadata| {
-                message_metadata.set_conflict(ConflictReason::None);
-                message_metadata.reference(milestone.essence().timestamp());
-            })
-            .await;
-    }
-
-    for (message_id, conflict) in metadata.excluded_conflicting_messages.iter() {
-        tangle
-            .update_metadata(message_id, |message_metadata| {
-                message_metadata.set_conflict(*conflict);
-                message_metadata.reference(milestone.essence().timestamp());
-            })
-            .await;
-    }
-
-    for message_id in metadata.included_messages.iter() {
+    // The metadata is already stored, this only brings the tangle cache up to date.
+    for (message_id, referenced_metadata) in referenced_messages.iter() {
         tangle
             .update_metadata(message_id, |message_metadata| {
-                message_metadata.set_conflict(ConflictReason::None);
+                message_metadata.set_conflict(referenced_metadata.conflict());
                 message_metadata.reference(milestone.essence().timestamp());
             })
             .await;
@@ -232,7 +269,7 @@ impl<N: Node> Worker<N> for ConsensusWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (SnapshotConfig, PruningConfig);
+    type Config = (LedgerConfig, SnapshotConfig, PruningConfig);
     type Error = Error;
 
     fn dependencies() -> &'static [TypeId] {
@@ -240,7 +277,7 @@ where
     }
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
-        let (snapshot_config, pruning_config) = config;
+        let (ledger_config, snapshot_config, pruning_config) = config;
 
         let (tx, rx) = mpsc::unbounded_channel();
 
@@ -261,22 +298,23 @@ where
         } else {
             snapshot_config.depth()
         };
-        let delay_min = snapshot_config.depth() + SOLID_ENTRY_POINT_THRESHOLD_PAST + PRUNING_THRESHOLD + 1;
-        let delay = if pruning_config.delay() < delay_min {
-            warn!(
-                "Configuration value for \"delay\" is too low ({}), value changed to {}.",
-                pruning_config.delay(),
-                delay_min
-            );
-            delay_min
-        } else {
-            pruning_config.delay()
-        };
+        // The node config is rejected if pruning is enabled with a delay lower than the minimum one.
+        let delay = pruning_config.delay();
 
         // Unwrap is fine because we just inserted the ledger index.
         // TODO unwrap
         let mut ledger_index = storage::fetch_ledger_index(&*storage).await.unwrap().unwrap();
         let mut receipt_migrated_at = MilestoneIndex(0);
+        // TODO unwrap
+        let mut address_history_pruning_index = storage::fetch_pruning_index(&*storage, OptionalIndex::AddressHistory)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| tangle.get_pruning_index());
+        // TODO unwrap
+        let mut indexation_pruning_index = storage::fetch_pruning_index(&*storage, OptionalIndex::Indexation)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| tangle.get_pruning_index());
 
         node.spawn::<Self, _, _>(|shutdown| async move {
             info!("Running.");
@@ -291,6 +329,7 @@ where
                     message_id,
                     &mut ledger_index,
                     &mut receipt_migrated_at,
+                    &ledger_config,
                 )
                 .await
                 {
@@ -313,8 +352,64 @@ where
                     //     error!("Failed to prune database: {:?}.", e);
                     // }
                 }
+
+                if should_prune_address_history(
+                    MilestoneIndex(*ledger_index),
+                    address_history_pruning_index,
+                    delay,
+                    &ledger_config,
+                    &pruning_config,
+                ) {
+                    let target_index = MilestoneIndex(*ledger_index - delay);
+
+                    match storage::prune_address_history(&*storage, address_history_pruning_index, target_index).await {
+                        Ok(()) => address_history_pruning_index = target_index,
+                        Err(e) => error!("Failed to prune address history: {}.", e),
+                    }
+                }
+
+                if should_prune_indexation(
+                    MilestoneIndex(*ledger_index),
+                    indexation_pruning_index,
+                    delay,
+                    &ledger_config,
+                    &pruning_config,
+                ) {
+                    let target_index = MilestoneIndex(*ledger_index - delay);
+
+                    match storage::prune_indexation(&*storage, indexation_pruning_index, target_index).await {
+                        Ok(()) => indexation_pruning_index = target_index,
+                        Err(e) => error!("Failed to prune indexation entries: {}.", e),
+                    }
+                }
             }
 
+            // Before the worker completely stops, the receiver needs to be drained for the pending milestones to be
+            // confirmed and applied to the ledger before the storage shuts down.
+
+            let (_, mut receiver) = receiver.split();
+            let mut count: usize = 0;
+
+            while let Some(Some(ConsensusWorkerEvent(message_id))) = receiver.next().now_or_never() {
+                if let Err(e) = confirm::<N>(
+                    &tangle,
+                    &storage,
+                    &bus,
+                    message_id,
+                    &mut ledger_index,
+                    &mut receipt_migrated_at,
+                    &ledger_config,
+                )
+                .await
+                {
+                    error!("Confirmation error on {}: {}.", message_id, e);
+                    break;
+                }
+                count += 1;
+            }
+
+            debug!("Drained {} milestones.", count);
+
             info!("Stopped.");
         });
 
@@ -6,7 +6,7 @@ use crate::{
     workers::snapshot::error::Error as SnapshotError,
 };
 
-use bee_message::{address::Address, Error as MessageError, MessageId};
+use bee_message::{address::Address, milestone::MilestoneIndex, output::OutputId, Error as MessageError, MessageId};
 
 /// Errors occurring during consensus.
 #[derive(Debug, thiserror::Error)]
@@ -31,6 +31,10 @@ pub enum Error {
     UnsupportedPayloadKind(u32),
     #[error("Message was not found")]
     MilestoneMessageNotFound,
+    #[error("Metadata of referenced message {0} was not found")]
+    MissingMessageMetadata(MessageId),
+    #[error("Milestone {0} is applied to the ledger but its confirmation was not fully stored")]
+    InconsistentConfirmation(MilestoneIndex),
     #[error("Message payload was not a milestone")]
     NoMilestonePayload,
     #[error("Tried to confirm {0} on top of {1}")]
@@ -57,6 +61,10 @@ pub enum Error {
     DecreasingReceiptMigratedAtIndex,
     #[error("Missing unspent output: {0}")]
     MissingUnspentOutput(Unspent),
+    #[error("Missing created output: {0}")]
+    MissingCreatedOutput(OutputId),
+    #[error("Missing consumed output: {0}")]
+    MissingConsumedOutput(OutputId),
     #[error("")]
     Storage(Box<dyn std::error::Error + Send>),
 }
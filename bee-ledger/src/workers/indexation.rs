@@ -0,0 +1,38 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::IndexationEntry;
+
+use bee_message::{
+    milestone::MilestoneIndex,
+    payload::{transaction::Essence, Payload},
+    Message, MessageId,
+};
+
+/// Computes the indexation entry of a message referenced by a milestone, if it holds an indexation payload, either
+/// directly or within a transaction.
+pub(crate) fn indexation_entry(
+    message_id: &MessageId,
+    message: &Message,
+    index: MilestoneIndex,
+    timestamp: u64,
+) -> Option<IndexationEntry> {
+    let indexation = match message.payload() {
+        Some(Payload::Indexation(indexation)) => indexation,
+        Some(Payload::Transaction(transaction)) => match transaction.essence() {
+            Essence::Regular(essence) => match essence.payload() {
+                Some(Payload::Indexation(indexation)) => indexation,
+                _ => return None,
+            },
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(IndexationEntry::new(
+        indexation.index().into(),
+        index,
+        timestamp,
+        *message_id,
+    ))
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod config;
 pub mod consensus;
 pub mod error;
 pub mod event;
@@ -8,10 +9,17 @@ pub mod pruning;
 pub mod snapshot;
 pub mod storage;
 
+mod address_history;
+mod indexation;
+mod state_hash;
+
 pub use storage::StorageBackend;
 
+use crate::types::KeyManager;
+
 use bee_runtime::node::{Node, NodeBuilder};
 
+use config::LedgerConfig;
 use consensus::ConsensusWorker;
 use pruning::config::PruningConfig;
 use snapshot::{config::SnapshotConfig, worker::SnapshotWorker};
@@ -19,14 +27,16 @@ use snapshot::{config::SnapshotConfig, worker::SnapshotWorker};
 pub fn init<N>(
     node_builder: N::Builder,
     network_id: u64,
+    ledger_config: LedgerConfig,
     snapshot_config: SnapshotConfig,
     pruning_config: PruningConfig,
+    key_manager: KeyManager,
 ) -> N::Builder
 where
     N: Node,
     N::Backend: StorageBackend,
 {
     node_builder
-        .with_worker_cfg::<SnapshotWorker>((network_id, snapshot_config.clone()))
-        .with_worker_cfg::<ConsensusWorker>((snapshot_config, pruning_config))
+        .with_worker_cfg::<SnapshotWorker>((network_id, snapshot_config.clone(), key_manager))
+        .with_worker_cfg::<ConsensusWorker>((ledger_config, snapshot_config, pruning_config))
 }
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::workers::{
+    config::LedgerConfig,
     pruning::{
         config::PruningConfig,
         constants::{PRUNING_THRESHOLD, SOLID_ENTRY_POINT_THRESHOLD_PAST},
@@ -75,3 +76,42 @@ pub(crate) fn should_prune<B: StorageBackend>(
 
     true
 }
+
+pub(crate) fn should_prune_address_history(
+    index: MilestoneIndex,
+    pruned_index: MilestoneIndex,
+    delay: u32,
+    ledger_config: &LedgerConfig,
+    config: &PruningConfig,
+) -> bool {
+    if !config.enabled() || !config.prune_address_history() || !ledger_config.address_history() {
+        return false;
+    }
+
+    if *index <= delay {
+        return false;
+    }
+
+    // We prune in "PRUNING_THRESHOLD" steps to batch the deletions.
+    *index - delay >= *pruned_index + PRUNING_THRESHOLD
+}
+
+pub(crate) fn should_prune_indexation(
+    index: MilestoneIndex,
+    pruned_index: MilestoneIndex,
+    delay: u32,
+    ledger_config: &LedgerConfig,
+    config: &PruningConfig,
+) -> bool {
+    // The entries refer to messages that are pruned along with the tangle, so they are not kept separately.
+    if !config.enabled() || !ledger_config.indexation() {
+        return false;
+    }
+
+    if *index <= delay {
+        return false;
+    }
+
+    // We prune in "PRUNING_THRESHOLD" steps to batch the deletions.
+    *index - delay >= *pruned_index + PRUNING_THRESHOLD
+}
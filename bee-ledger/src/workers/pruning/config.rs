@@ -6,12 +6,14 @@ use serde::Deserialize;
 const DEFAULT_ENABLED: bool = true;
 const DEFAULT_DELAY: u32 = 60480;
 const DEFAULT_PRUNE_RECEIPTS: bool = false;
+const DEFAULT_PRUNE_ADDRESS_HISTORY: bool = false;
 
 #[derive(Default, Deserialize)]
 pub struct PruningConfigBuilder {
     enabled: Option<bool>,
     delay: Option<u32>,
     prune_receipts: Option<bool>,
+    prune_address_history: Option<bool>,
 }
 
 impl PruningConfigBuilder {
@@ -34,11 +36,17 @@ impl PruningConfigBuilder {
         self
     }
 
+    pub fn prune_address_history(mut self, prune_address_history: bool) -> Self {
+        self.prune_address_history.replace(prune_address_history);
+        self
+    }
+
     pub fn finish(self) -> PruningConfig {
         PruningConfig {
             enabled: self.enabled.unwrap_or(DEFAULT_ENABLED),
             delay: self.delay.unwrap_or(DEFAULT_DELAY),
             prune_receipts: self.prune_receipts.unwrap_or(DEFAULT_PRUNE_RECEIPTS),
+            prune_address_history: self.prune_address_history.unwrap_or(DEFAULT_PRUNE_ADDRESS_HISTORY),
         }
     }
 }
@@ -48,6 +56,7 @@ pub struct PruningConfig {
     enabled: bool,
     delay: u32,
     prune_receipts: bool,
+    prune_address_history: bool,
 }
 
 impl PruningConfig {
@@ -66,4 +75,8 @@ impl PruningConfig {
     pub fn prune_receipts(&self) -> bool {
         self.prune_receipts
     }
+
+    pub fn prune_address_history(&self) -> bool {
+        self.prune_address_history
+    }
 }
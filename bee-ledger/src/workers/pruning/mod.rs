@@ -6,6 +6,11 @@ pub mod config;
 pub(crate) mod condition;
 pub(crate) mod constants;
 
+/// Returns the minimum pruning delay, in milestones, allowed for a snapshot depth.
+pub fn minimum_pruning_delay(snapshot_depth: u32) -> u32 {
+    snapshot_depth + constants::SOLID_ENTRY_POINT_THRESHOLD_PAST + constants::PRUNING_THRESHOLD + 1
+}
+
 // use bee_protocol::{
 //     tangle::{helper, MsTangle},
 //     MilestoneIndex,
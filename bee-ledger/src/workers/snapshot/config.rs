@@ -6,17 +6,93 @@ use serde::Deserialize;
 use std::path::{Path, PathBuf};
 
 const DEFAULT_FULL_PATH: &str = "./snapshots/mainnet/latest-full_snapshot.bin";
-const DEFAULT_DOWNLOAD_URLS: Vec<String> = Vec::new();
+const DEFAULT_DOWNLOAD_URLS: Vec<DownloadUrl> = Vec::new();
 const DEFAULT_DEPTH: u32 = 50;
 const DEFAULT_INTERVAL_SYNCED: u32 = 50;
 const DEFAULT_INTERVAL_UNSYNCED: u32 = 1000;
 
+/// A snapshot download source, with optional expected SHA-256 digests of the files it serves.
+#[derive(Clone, Deserialize)]
+#[serde(from = "DownloadUrlBuilder")]
+pub struct DownloadUrl {
+    url: String,
+    full_sha256: Option<String>,
+    delta_sha256: Option<String>,
+}
+
+impl DownloadUrl {
+    /// Creates a new `DownloadUrl` without expected digests.
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            full_sha256: None,
+            delta_sha256: None,
+        }
+    }
+
+    /// Sets the expected SHA-256 digest, hex encoded, of the full snapshot file served by the `DownloadUrl`.
+    pub fn with_full_sha256(mut self, full_sha256: String) -> Self {
+        self.full_sha256.replace(full_sha256.to_lowercase());
+        self
+    }
+
+    /// Sets the expected SHA-256 digest, hex encoded, of the delta snapshot file served by the `DownloadUrl`.
+    pub fn with_delta_sha256(mut self, delta_sha256: String) -> Self {
+        self.delta_sha256.replace(delta_sha256.to_lowercase());
+        self
+    }
+
+    /// Returns the URL of the `DownloadUrl`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the expected SHA-256 digest of the full snapshot file served by the `DownloadUrl`.
+    pub fn full_sha256(&self) -> Option<&str> {
+        self.full_sha256.as_deref()
+    }
+
+    /// Returns the expected SHA-256 digest of the delta snapshot file served by the `DownloadUrl`.
+    pub fn delta_sha256(&self) -> Option<&str> {
+        self.delta_sha256.as_deref()
+    }
+}
+
+/// A download URL can either be configured as a plain string or as a table with expected digests.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DownloadUrlBuilder {
+    Url(String),
+    WithDigests {
+        url: String,
+        full_sha256: Option<String>,
+        delta_sha256: Option<String>,
+    },
+}
+
+impl From<DownloadUrlBuilder> for DownloadUrl {
+    fn from(builder: DownloadUrlBuilder) -> Self {
+        match builder {
+            DownloadUrlBuilder::Url(url) => DownloadUrl::new(url),
+            DownloadUrlBuilder::WithDigests {
+                url,
+                full_sha256,
+                delta_sha256,
+            } => DownloadUrl {
+                url,
+                full_sha256: full_sha256.map(|digest| digest.to_lowercase()),
+                delta_sha256: delta_sha256.map(|digest| digest.to_lowercase()),
+            },
+        }
+    }
+}
+
 /// Builder for a `SnapshotConfig`.
 #[derive(Default, Deserialize)]
 pub struct SnapshotConfigBuilder {
     full_path: Option<String>,
     delta_path: Option<String>,
-    download_urls: Option<Vec<String>>,
+    download_urls: Option<Vec<DownloadUrl>>,
     depth: Option<u32>,
     interval_synced: Option<u32>,
     interval_unsynced: Option<u32>,
@@ -41,7 +117,7 @@ impl SnapshotConfigBuilder {
     }
 
     // Sets the download URLs of the `SnapshotConfigBuilder`.
-    pub fn download_urls(mut self, download_urls: Vec<String>) -> Self {
+    pub fn download_urls(mut self, download_urls: Vec<DownloadUrl>) -> Self {
         self.download_urls.replace(download_urls);
         self
     }
@@ -82,7 +158,7 @@ impl SnapshotConfigBuilder {
 pub struct SnapshotConfig {
     full_path: PathBuf,
     delta_path: Option<PathBuf>,
-    download_urls: Vec<String>,
+    download_urls: Vec<DownloadUrl>,
     depth: u32,
     interval_synced: u32,
     interval_unsynced: u32,
@@ -105,7 +181,7 @@ impl SnapshotConfig {
     }
 
     /// Returns the download URLs of the `SnapshotConfig`.
-    pub fn download_urls(&self) -> &[String] {
+    pub fn download_urls(&self) -> &[DownloadUrl] {
         &self.download_urls
     }
 
@@ -1,13 +1,61 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::workers::snapshot::error::Error;
+use crate::{
+    types::snapshot::SnapshotKind,
+    workers::snapshot::{config::DownloadUrl, error::Error},
+};
 
 use log::{error, info, warn};
+use sha2::{Digest, Sha256};
 
-use std::{fs::File, io::copy, path::Path};
+use std::{
+    fs::File,
+    io::{copy, BufReader},
+    path::Path,
+};
 
-pub(crate) async fn download_snapshot_file(file_path: &Path, download_urls: &[String]) -> Result<(), Error> {
+fn expected_digest(download_url: &DownloadUrl, kind: SnapshotKind) -> Option<&str> {
+    match kind {
+        SnapshotKind::Full => download_url.full_sha256(),
+        SnapshotKind::Delta => download_url.delta_sha256(),
+    }
+}
+
+/// Checks a snapshot file against the digests expected by the download sources.
+///
+/// Returns whether the file could be verified, i.e. whether any digest is configured for this kind of snapshot, and
+/// fails if it matches none of them.
+pub(crate) fn check_snapshot_digest(
+    file_path: &Path,
+    download_urls: &[DownloadUrl],
+    kind: SnapshotKind,
+) -> Result<bool, Error> {
+    let expected = download_urls
+        .iter()
+        .filter_map(|download_url| expected_digest(download_url, kind))
+        .collect::<Vec<&str>>();
+
+    if expected.is_empty() {
+        return Ok(false);
+    }
+
+    let mut hasher = Sha256::new();
+    copy(&mut BufReader::new(File::open(file_path)?), &mut hasher)?;
+    let digest = hex::encode(hasher.finalize());
+
+    if !expected.contains(&digest.as_str()) {
+        return Err(Error::DigestMismatch(file_path.to_string_lossy().to_string(), digest));
+    }
+
+    Ok(true)
+}
+
+pub(crate) async fn download_snapshot_file(
+    file_path: &Path,
+    download_urls: &[DownloadUrl],
+    kind: SnapshotKind,
+) -> Result<(), Error> {
     let file_name = file_path
         .file_name()
         .ok_or_else(|| Error::InvalidFilePath(file_path.to_string_lossy().to_string()))?;
@@ -19,19 +67,44 @@ pub(crate) async fn download_snapshot_file(file_path: &Path, download_urls: &[St
     )
     .map_err(|_| Error::InvalidFilePath(file_path.to_string_lossy().to_string()))?;
 
-    for url in download_urls {
-        let url = url.to_owned() + &file_name.to_string_lossy();
+    for download_url in download_urls {
+        let url = download_url.url().to_owned() + &file_name.to_string_lossy();
 
         info!("Downloading snapshot file {}...", url);
         match reqwest::get(&url).await.and_then(|res| res.error_for_status()) {
-            Ok(res) => match File::create(file_path) {
-                // TODO unwrap
-                Ok(mut file) => match copy(&mut res.bytes().await.unwrap().as_ref(), &mut file) {
-                    Ok(_) => break,
-                    Err(e) => warn!("Copying snapshot file failed: {:?}.", e),
-                },
-                Err(e) => warn!("Creating snapshot file failed: {:?}.", e),
-            },
+            Ok(res) => {
+                let bytes = match res.bytes().await {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        warn!("Reading snapshot file failed: {:?}.", e);
+                        continue;
+                    }
+                };
+
+                if let Some(expected) = expected_digest(download_url, kind) {
+                    let digest = hex::encode(Sha256::digest(&bytes));
+
+                    if digest != expected {
+                        warn!(
+                            "Snapshot file {} has digest {} instead of the expected {}.",
+                            url, digest, expected
+                        );
+                        continue;
+                    }
+                }
+
+                match File::create(file_path) {
+                    Ok(mut file) => match copy(&mut bytes.as_ref(), &mut file) {
+                        Ok(_) => break,
+                        Err(e) => {
+                            warn!("Copying snapshot file failed: {:?}.", e);
+                            // A partially written file must not be mistaken for a downloaded one.
+                            let _ = std::fs::remove_file(file_path);
+                        }
+                    },
+                    Err(e) => warn!("Creating snapshot file failed: {:?}.", e),
+                }
+            }
             Err(e) => match e.status() {
                 Some(status) => warn!("Downloading snapshot file failed with status code {}.", status),
                 None => warn!("Downloading snapshot file failed: {:?}.", e),
@@ -46,3 +119,56 @@ pub(crate) async fn download_snapshot_file(file_path: &Path, download_urls: &[St
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    fn snapshot_file(name: &str, content: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("bee-ledger-{}-{}", std::process::id(), name));
+        File::create(&path).unwrap().write_all(content).unwrap();
+        path
+    }
+
+    #[test]
+    fn digest_is_not_checked_without_expectations() {
+        let path = snapshot_file("unchecked", b"snapshot");
+        let download_urls = vec![DownloadUrl::new("https://example.com/".to_string())];
+
+        assert!(!check_snapshot_digest(&path, &download_urls, SnapshotKind::Full).unwrap());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn digest_matches_one_of_the_sources() {
+        let path = snapshot_file("matching", b"snapshot");
+        let download_urls = vec![
+            DownloadUrl::new("https://example.com/".to_string()).with_full_sha256("00".repeat(32)),
+            DownloadUrl::new("https://example.org/".to_string())
+                .with_full_sha256(hex::encode(Sha256::digest(b"snapshot")).to_uppercase()),
+        ];
+
+        assert!(check_snapshot_digest(&path, &download_urls, SnapshotKind::Full).unwrap());
+        // The delta digests are not configured.
+        assert!(!check_snapshot_digest(&path, &download_urls, SnapshotKind::Delta).unwrap());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn digest_mismatch_is_rejected() {
+        let path = snapshot_file("tampered", b"tampered snapshot");
+        let download_urls = vec![DownloadUrl::new("https://example.com/".to_string())
+            .with_delta_sha256(hex::encode(Sha256::digest(b"snapshot")))];
+
+        assert!(matches!(
+            check_snapshot_digest(&path, &download_urls, SnapshotKind::Delta),
+            Err(Error::DigestMismatch(..))
+        ));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -3,7 +3,7 @@
 
 use crate::types::{snapshot::SnapshotKind, Error as TypeError};
 
-use bee_message::{milestone::MilestoneIndex, Error as MessageError};
+use bee_message::{milestone::MilestoneIndex, payload::milestone::MilestoneValidationError, Error as MessageError};
 
 use thiserror::Error;
 
@@ -45,6 +45,18 @@ pub enum Error {
     OnlyDeltaSnapshotFileExists,
     #[error("Unexpected milestine diff index: {0:?}")]
     UnexpectedDiffIndex(MilestoneIndex),
+    #[error("Invalid milestone {0} in snapshot: {1:?}")]
+    InvalidMilestone(MilestoneIndex, MilestoneValidationError),
+    #[error("Missing milestone {0} in snapshot")]
+    MissingMilestone(MilestoneIndex),
+    #[error("Snapshot file {0} has digest {1} which is not among the expected ones")]
+    DigestMismatch(String, String),
+    #[error(
+        "Snapshot at index {0} contains no milestone to validate its ledger state, configure the expected digest of the file"
+    )]
+    UnverifiableSnapshot(MilestoneIndex),
+    #[error("Unexpected bytes remaining at the end of the snapshot file")]
+    RemainingBytes,
     #[error("Storage operation failed: {0}")]
     StorageBackend(Box<dyn std::error::Error + Send + 'static>),
     #[error("")]
@@ -6,11 +6,15 @@ use crate::{
         snapshot::{
             DeltaSnapshotHeader, FullSnapshotHeader, MilestoneDiff, SnapshotHeader, SnapshotInfo, SnapshotKind,
         },
-        BalanceDiffs, CreatedOutput, TreasuryOutput,
+        BalanceDiffs, CreatedOutput, KeyManager, TreasuryOutput,
     },
     workers::{
         consensus::worker::migration_from_milestone,
-        snapshot::{config::SnapshotConfig, download::download_snapshot_file, error::Error},
+        snapshot::{
+            config::SnapshotConfig,
+            download::{check_snapshot_digest, download_snapshot_file},
+            error::Error,
+        },
         storage::{self, apply_balance_diffs, apply_milestone, create_output, rollback_milestone, StorageBackend},
     },
 };
@@ -29,9 +33,9 @@ use chrono::{offset::TimeZone, Utc};
 use log::{info, warn};
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fs::{File, OpenOptions},
-    io::BufReader,
+    io::{BufRead, BufReader},
     path::Path,
 };
 
@@ -41,6 +45,36 @@ fn snapshot_reader(path: &Path) -> Result<BufReader<File>, Error> {
     ))
 }
 
+fn check_remaining_bytes<R: BufRead>(reader: &mut R) -> Result<(), Error> {
+    if !reader.fill_buf()?.is_empty() {
+        return Err(Error::RemainingBytes);
+    }
+
+    Ok(())
+}
+
+fn check_milestones(
+    ledger_index: MilestoneIndex,
+    sep_index: MilestoneIndex,
+    milestones: &HashSet<MilestoneIndex>,
+    digest_verified: bool,
+) -> Result<(), Error> {
+    // The milestone diffs lead from the lowest to the highest of the ledger and SEP indexes, the milestone at the lowest
+    // index is the base of these diffs and is therefore not part of the snapshot.
+    let index = std::cmp::max(ledger_index, sep_index);
+
+    if ledger_index == sep_index {
+        // Without any milestone diff, nothing in the file proves its ledger state, only its digest can.
+        if !digest_verified {
+            return Err(Error::UnverifiableSnapshot(index));
+        }
+    } else if !milestones.contains(&index) {
+        return Err(Error::MissingMilestone(index));
+    }
+
+    Ok(())
+}
+
 async fn import_solid_entry_points<R: Read, B: StorageBackend>(
     reader: &mut R,
     storage: &B,
@@ -90,10 +124,21 @@ async fn import_milestone_diffs<R: Read, B: StorageBackend>(
     reader: &mut R,
     storage: &B,
     milestone_diff_count: u64,
-) -> Result<(), Error> {
+    key_manager: &KeyManager,
+) -> Result<HashSet<MilestoneIndex>, Error> {
+    let mut milestones = HashSet::new();
+
     for _ in 0..milestone_diff_count {
         let diff = MilestoneDiff::unpack(reader)?;
         let index = diff.milestone().essence().index();
+
+        diff.milestone()
+            .validate(
+                &key_manager.get_public_keys(index).into_iter().collect::<Vec<String>>(),
+                key_manager.min_threshold(),
+            )
+            .map_err(|e| Error::InvalidMilestone(index, e))?;
+
         // Unwrap is fine because we just inserted the ledger index.
         // TODO unwrap
         let ledger_index = *storage::fetch_ledger_index(&*storage).await.unwrap().unwrap();
@@ -132,9 +177,20 @@ async fn import_milestone_diffs<R: Read, B: StorageBackend>(
         match index {
             index if index == MilestoneIndex(ledger_index + 1) => {
                 // TODO unwrap until we merge both crates
-                apply_milestone(&*storage, index, diff.created(), &consumed, &balance_diffs, &migration)
-                    .await
-                    .unwrap();
+                // The address history and the indexation entries only cover milestones confirmed by the node itself.
+                apply_milestone(
+                    &*storage,
+                    index,
+                    diff.created(),
+                    &consumed,
+                    &balance_diffs,
+                    &migration,
+                    &[],
+                    false,
+                    &[],
+                )
+                .await
+                .unwrap();
             }
             index if index == MilestoneIndex(ledger_index) => {
                 // TODO unwrap until we merge both crates
@@ -144,12 +200,20 @@ async fn import_milestone_diffs<R: Read, B: StorageBackend>(
             }
             _ => return Err(Error::UnexpectedDiffIndex(index)),
         }
+
+        milestones.insert(index);
     }
 
-    Ok(())
+    Ok(milestones)
 }
 
-async fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id: u64) -> Result<(), Error> {
+async fn import_full_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    network_id: u64,
+    key_manager: &KeyManager,
+    digest_verified: bool,
+) -> Result<(), Error> {
     info!("Importing full snapshot file {}...", &path.to_string_lossy());
 
     let mut reader = snapshot_reader(path)?;
@@ -207,9 +271,11 @@ async fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, netwo
 
     import_solid_entry_points(&mut reader, storage, full_header.sep_count(), header.sep_index()).await?;
     import_outputs(&mut reader, storage, full_header.output_count()).await?;
-    import_milestone_diffs(&mut reader, storage, full_header.milestone_diff_count()).await?;
+    let milestones =
+        import_milestone_diffs(&mut reader, storage, full_header.milestone_diff_count(), key_manager).await?;
 
-    // TODO check nothing left
+    check_milestones(header.ledger_index(), header.sep_index(), &milestones, digest_verified)?;
+    check_remaining_bytes(&mut reader)?;
 
     info!(
         "Imported full snapshot file from {} with sep index {}, ledger index {}, {} solid entry points, {} outputs and {} milestone diffs.",
@@ -224,7 +290,13 @@ async fn import_full_snapshot<B: StorageBackend>(storage: &B, path: &Path, netwo
     Ok(())
 }
 
-async fn import_delta_snapshot<B: StorageBackend>(storage: &B, path: &Path, network_id: u64) -> Result<(), Error> {
+async fn import_delta_snapshot<B: StorageBackend>(
+    storage: &B,
+    path: &Path,
+    network_id: u64,
+    key_manager: &KeyManager,
+    digest_verified: bool,
+) -> Result<(), Error> {
     info!("Importing delta snapshot file {}...", &path.to_string_lossy());
 
     let mut reader = snapshot_reader(path)?;
@@ -271,9 +343,11 @@ async fn import_delta_snapshot<B: StorageBackend>(storage: &B, path: &Path, netw
     .map_err(|e| Error::Consumer(Box::new(e)))?;
 
     import_solid_entry_points(&mut reader, storage, delta_header.sep_count(), header.sep_index()).await?;
-    import_milestone_diffs(&mut reader, storage, delta_header.milestone_diff_count()).await?;
+    let milestones =
+        import_milestone_diffs(&mut reader, storage, delta_header.milestone_diff_count(), key_manager).await?;
 
-    // TODO check nothing left
+    check_milestones(header.ledger_index(), header.sep_index(), &milestones, digest_verified)?;
+    check_remaining_bytes(&mut reader)?;
 
     info!(
         "Imported delta snapshot file from {} with sep index {}, ledger index {}, {} solid entry points and {} milestone diffs.",
@@ -291,6 +365,7 @@ pub(crate) async fn import_snapshots<B: StorageBackend>(
     storage: &B,
     network_id: u64,
     config: &SnapshotConfig,
+    key_manager: &KeyManager,
 ) -> Result<(), Error> {
     let full_exists = config.full_path().exists();
     let delta_exists = config.delta_path().map_or(false, Path::exists);
@@ -300,15 +375,17 @@ pub(crate) async fn import_snapshots<B: StorageBackend>(
     }
 
     if !full_exists {
-        download_snapshot_file(config.full_path(), config.download_urls()).await?;
+        download_snapshot_file(config.full_path(), config.download_urls(), SnapshotKind::Full).await?;
     }
 
     // We are sure that the full snapshot file exists from now on.
-    import_full_snapshot(storage, config.full_path(), network_id).await?;
+    // Downloaded files were already checked but local ones may have been tampered with.
+    let full_verified = check_snapshot_digest(config.full_path(), config.download_urls(), SnapshotKind::Full)?;
+    import_full_snapshot(storage, config.full_path(), network_id, key_manager, full_verified).await?;
 
     if let Some(delta_path) = config.delta_path() {
         if !delta_exists {
-            if download_snapshot_file(delta_path, config.download_urls())
+            if download_snapshot_file(delta_path, config.download_urls(), SnapshotKind::Delta)
                 .await
                 .is_err()
             {
@@ -317,9 +394,38 @@ pub(crate) async fn import_snapshots<B: StorageBackend>(
         }
 
         if Path::exists(delta_path) {
-            import_delta_snapshot(storage, delta_path, network_id).await?;
+            let delta_verified = check_snapshot_digest(delta_path, config.download_urls(), SnapshotKind::Delta)?;
+            import_delta_snapshot(storage, delta_path, network_id, key_manager, delta_verified).await?;
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highest_index_milestone_is_required() {
+        let milestones = [MilestoneIndex(11), MilestoneIndex(12)].iter().copied().collect();
+
+        assert!(check_milestones(MilestoneIndex(12), MilestoneIndex(10), &milestones, false).is_ok());
+        assert!(check_milestones(MilestoneIndex(10), MilestoneIndex(12), &milestones, false).is_ok());
+        assert!(matches!(
+            check_milestones(MilestoneIndex(13), MilestoneIndex(10), &milestones, true),
+            Err(Error::MissingMilestone(MilestoneIndex(13)))
+        ));
+    }
+
+    #[test]
+    fn snapshot_without_milestone_requires_a_digest() {
+        let milestones = HashSet::new();
+
+        assert!(matches!(
+            check_milestones(MilestoneIndex(10), MilestoneIndex(10), &milestones, false),
+            Err(Error::UnverifiableSnapshot(MilestoneIndex(10)))
+        ));
+        assert!(check_milestones(MilestoneIndex(10), MilestoneIndex(10), &milestones, true).is_ok());
+    }
+}
@@ -1,10 +1,14 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::workers::{
-    error::Error,
-    snapshot::{config::SnapshotConfig, error::Error as SnapshotError, import::import_snapshots},
-    storage::{self, StorageBackend},
+use crate::{
+    types::KeyManager,
+    workers::{
+        error::Error,
+        snapshot::{config::SnapshotConfig, error::Error as SnapshotError, import::import_snapshots},
+        state_hash::compute_ledger_state_hash,
+        storage::{self, StorageBackend},
+    },
 };
 
 use bee_message::milestone::MilestoneIndex;
@@ -16,7 +20,7 @@ use async_trait::async_trait;
 
 use chrono::{offset::TimeZone, Utc};
 use futures::stream::StreamExt;
-use log::info;
+use log::{error, info};
 
 use std::any::TypeId;
 
@@ -27,7 +31,7 @@ impl<N: Node> Worker<N> for SnapshotWorker
 where
     N::Backend: StorageBackend,
 {
-    type Config = (u64, SnapshotConfig);
+    type Config = (u64, SnapshotConfig, KeyManager);
     type Error = Error;
 
     fn dependencies() -> &'static [TypeId] {
@@ -35,14 +39,18 @@ where
     }
 
     async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
-        let (network_id, snapshot_config) = config;
+        let (network_id, snapshot_config, key_manager) = config;
 
         let tangle = node.resource::<MsTangle<N::Backend>>();
         let storage = node.storage();
 
         match storage::fetch_snapshot_info(&*storage).await? {
             None => {
-                if let Err(e) = import_snapshots(&*storage, network_id, &snapshot_config).await {
+                if let Err(e) = import_snapshots(&*storage, network_id, &snapshot_config, &key_manager).await {
+                    error!(
+                        "Importing snapshots failed, the database is marked as corrupted: {}.",
+                        e
+                    );
                     (*storage)
                         .set_health(StorageHealth::Corrupted)
                         .await
@@ -81,6 +89,18 @@ where
         let ledger_index = storage::fetch_ledger_index(&*storage).await.unwrap().unwrap();
         let snapshot_info = storage::fetch_snapshot_info(&*storage).await?.unwrap();
 
+        // The ledger state hash is initialised after a snapshot import, or on databases created before it was maintained.
+        if storage::fetch_ledger_state_hash(&*storage, MilestoneIndex(*ledger_index))
+            .await?
+            .is_none()
+        {
+            let ledger_state_hash = compute_ledger_state_hash(&*storage).await?;
+
+            storage::insert_ledger_state_hash(&*storage, MilestoneIndex(*ledger_index), &ledger_state_hash).await?;
+
+            info!("Ledger state hash at index {}: {}.", *ledger_index, ledger_state_hash);
+        }
+
         tangle.update_snapshot_index(snapshot_info.snapshot_index());
         tangle.update_pruning_index(snapshot_info.pruning_index());
         tangle.update_solid_milestone_index(MilestoneIndex(*ledger_index));
@@ -0,0 +1,65 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    types::{CreatedOutput, LedgerStateHash, TreasuryOutput, Unspent, LEDGER_STATE_HASH_LENGTH},
+    workers::{
+        error::Error,
+        storage::{self, StorageBackend},
+    },
+};
+
+use bee_common::packable::Packable;
+use bee_message::output::OutputId;
+use bee_storage::access::AsStream;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use futures::StreamExt;
+
+/// Output domain separation prefix.
+const OUTPUT_HASH_PREFIX: u8 = 0x00;
+/// Treasury output domain separation prefix.
+const TREASURY_OUTPUT_HASH_PREFIX: u8 = 0x01;
+
+/// Computes the hash of an unspent output as an element of the ledger state hash.
+pub(crate) fn output_hash(output_id: &OutputId, output: &CreatedOutput) -> [u8; LEDGER_STATE_HASH_LENGTH] {
+    let mut hasher = Blake2b256::new();
+
+    hasher.update([OUTPUT_HASH_PREFIX]);
+    hasher.update(output_id.pack_new());
+    hasher.update(output.pack_new());
+
+    hasher.finalize().into()
+}
+
+/// Computes the hash of an unspent treasury output as an element of the ledger state hash.
+pub(crate) fn treasury_output_hash(treasury_output: &TreasuryOutput) -> [u8; LEDGER_STATE_HASH_LENGTH] {
+    let mut hasher = Blake2b256::new();
+
+    hasher.update([TREASURY_OUTPUT_HASH_PREFIX]);
+    hasher.update(treasury_output.pack_new());
+
+    hasher.finalize().into()
+}
+
+/// Computes the ledger state hash from scratch by going through the whole ledger state.
+pub(crate) async fn compute_ledger_state_hash<B: StorageBackend>(storage: &B) -> Result<LedgerStateHash, Error> {
+    let mut hash = LedgerStateHash::default();
+    let mut stream = AsStream::<Unspent, ()>::stream(storage)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+
+    while let Some((output_id, _)) = stream.next().await {
+        let output = storage::fetch_output(storage, &*output_id)
+            .await?
+            .ok_or(Error::MissingUnspentOutput(output_id))?;
+
+        hash.insert(&output_hash(&*output_id, &output));
+    }
+
+    hash.insert(&treasury_output_hash(
+        &storage::fetch_unspent_treasury_output(storage).await?,
+    ));
+
+    Ok(hash)
+}
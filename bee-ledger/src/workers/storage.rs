@@ -3,22 +3,28 @@
 
 use crate::{
     types::{
-        snapshot::SnapshotInfo, Balance, BalanceDiffs, ConsumedOutput, CreatedOutput, LedgerIndex, Migration,
-        OutputDiff, Receipt, TreasuryDiff, TreasuryOutput, Unspent,
+        snapshot::SnapshotInfo, AddressHistoryEntry, Balance, BalanceDiffs, ConsumedOutput, CreatedOutput,
+        IndexationEntry, LedgerIndex, LedgerStateHash, Migration, OptionalIndex, OutputDiff, Receipt, TreasuryDiff,
+        TreasuryOutput, Unspent,
+    },
+    workers::{
+        address_history::address_history_entries,
+        error::Error,
+        state_hash::{output_hash, treasury_output_hash},
     },
-    workers::error::Error,
 };
 
 use bee_message::{
     address::{Address, Ed25519Address},
-    milestone::MilestoneIndex,
+    milestone::{Milestone, MilestoneIndex},
     output::{Output, OutputId},
+    MessageId,
 };
 use bee_storage::{
     access::{AsStream, Batch, BatchBuilder, Exist, Fetch, Insert, Truncate},
     backend,
 };
-use bee_tangle::solid_entry_point::SolidEntryPoint;
+use bee_tangle::{metadata::MessageMetadata, solid_entry_point::SolidEntryPoint};
 
 use std::collections::HashMap;
 
@@ -31,18 +37,32 @@ pub trait StorageBackend:
     + Batch<(), LedgerIndex>
     + Batch<MilestoneIndex, OutputDiff>
     + Batch<(Ed25519Address, OutputId), ()>
+    + Batch<(Ed25519Address, AddressHistoryEntry), ()>
+    + Batch<IndexationEntry, ()>
+    + Batch<(MilestoneIndex, IndexationEntry), ()>
     + Batch<Address, Balance>
     + Batch<(MilestoneIndex, Receipt), ()>
     + Batch<(bool, TreasuryOutput), ()>
+    + Batch<MessageId, MessageMetadata>
+    + Batch<MilestoneIndex, LedgerStateHash>
+    + Batch<OptionalIndex, MilestoneIndex>
     + Exist<Unspent, ()>
     + Fetch<(), SnapshotInfo>
     + Fetch<OutputId, CreatedOutput>
+    + Fetch<OutputId, ConsumedOutput>
     + Fetch<(), LedgerIndex>
     + Fetch<Address, Balance>
     + Fetch<bool, Vec<TreasuryOutput>>
+    + Fetch<MilestoneIndex, OutputDiff>
+    + Fetch<MilestoneIndex, Milestone>
+    + Fetch<MessageId, MessageMetadata>
+    + Fetch<MilestoneIndex, LedgerStateHash>
+    + Fetch<OptionalIndex, MilestoneIndex>
+    + Fetch<MilestoneIndex, Vec<IndexationEntry>>
     + Insert<(), SnapshotInfo>
     + Insert<(), LedgerIndex>
     + Insert<(bool, TreasuryOutput), ()>
+    + Insert<MilestoneIndex, LedgerStateHash>
     + Truncate<SolidEntryPoint, MilestoneIndex>
     + for<'a> AsStream<'a, Unspent, ()>
     + for<'a> AsStream<'a, Address, Balance>
@@ -60,18 +80,32 @@ impl<T> StorageBackend for T where
         + Batch<(), LedgerIndex>
         + Batch<MilestoneIndex, OutputDiff>
         + Batch<(Ed25519Address, OutputId), ()>
+        + Batch<(Ed25519Address, AddressHistoryEntry), ()>
+        + Batch<IndexationEntry, ()>
+        + Batch<(MilestoneIndex, IndexationEntry), ()>
         + Batch<Address, Balance>
         + Batch<(MilestoneIndex, Receipt), ()>
         + Batch<(bool, TreasuryOutput), ()>
+        + Batch<MessageId, MessageMetadata>
+        + Batch<MilestoneIndex, LedgerStateHash>
+        + Batch<OptionalIndex, MilestoneIndex>
         + Exist<Unspent, ()>
         + Fetch<(), SnapshotInfo>
         + Fetch<OutputId, CreatedOutput>
+        + Fetch<OutputId, ConsumedOutput>
         + Fetch<(), LedgerIndex>
         + Fetch<Address, Balance>
         + Fetch<bool, Vec<TreasuryOutput>>
+        + Fetch<MilestoneIndex, OutputDiff>
+        + Fetch<MilestoneIndex, Milestone>
+        + Fetch<MessageId, MessageMetadata>
+        + Fetch<MilestoneIndex, LedgerStateHash>
+        + Fetch<OptionalIndex, MilestoneIndex>
+        + Fetch<MilestoneIndex, Vec<IndexationEntry>>
         + Insert<(), SnapshotInfo>
         + Insert<(), LedgerIndex>
         + Insert<(bool, TreasuryOutput), ()>
+        + Insert<MilestoneIndex, LedgerStateHash>
         + Truncate<SolidEntryPoint, MilestoneIndex>
         + for<'a> AsStream<'a, Unspent, ()>
         + for<'a> AsStream<'a, Address, Balance>
@@ -238,19 +272,26 @@ pub(crate) async fn apply_balance_diffs_batch<B: StorageBackend>(
     Ok(())
 }
 
-pub(crate) async fn apply_milestone<B: StorageBackend>(
+/// Applies the ledger changes of a confirmed milestone along with the metadata of the messages it references in a single
+/// batch.
+pub async fn apply_milestone<B: StorageBackend>(
     storage: &B,
     index: MilestoneIndex,
     created_outputs: &HashMap<OutputId, CreatedOutput>,
     consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
     balance_diffs: &BalanceDiffs,
     migration: &Option<Migration>,
+    referenced_messages: &[(MessageId, MessageMetadata)],
+    address_history: bool,
+    indexation_entries: &[IndexationEntry],
 ) -> Result<(), Error> {
     let mut batch = B::batch_begin();
 
     let mut created_output_ids = Vec::with_capacity(created_outputs.len());
     let mut consumed_output_ids = Vec::with_capacity(consumed_outputs.len());
 
+    // The ledger index is the confirmed milestone index kept by the node, the one of the tangle only being a cache of it
+    // restored on startup, so it is committed along with the changes it confirms.
     insert_ledger_index_batch(storage, &mut batch, &index.into())?;
 
     for (output_id, output) in created_outputs.iter() {
@@ -265,6 +306,12 @@ pub(crate) async fn apply_milestone<B: StorageBackend>(
 
     apply_balance_diffs_batch(storage, &mut batch, balance_diffs).await?;
 
+    if address_history {
+        insert_address_history_batch(storage, &mut batch, index, created_outputs, consumed_outputs)?;
+    }
+
+    insert_indexation_entries_batch(storage, &mut batch, index, indexation_entries)?;
+
     let treasury_diff = if let Some(migration) = migration {
         insert_receipt_batch(storage, &mut batch, migration.receipt())?;
         insert_treasury_output_batch(storage, &mut batch, migration.created_treasury())?;
@@ -286,6 +333,32 @@ pub(crate) async fn apply_milestone<B: StorageBackend>(
     )
     .map_err(|e| Error::Storage(Box::new(e)))?;
 
+    // The ledger state hash is only maintained incrementally once it has been initialised after the snapshot import.
+    if let Some(mut hash) = fetch_ledger_state_hash(storage, index - 1).await? {
+        for (output_id, output) in created_outputs.iter() {
+            hash.insert(&output_hash(output_id, output));
+        }
+
+        for (output_id, (created_output, _)) in consumed_outputs.iter() {
+            hash.remove(&output_hash(output_id, created_output));
+        }
+
+        if let Some(migration) = migration {
+            hash.remove(&treasury_output_hash(migration.consumed_treasury()));
+            hash.insert(&treasury_output_hash(migration.created_treasury()));
+        }
+
+        Batch::<MilestoneIndex, LedgerStateHash>::batch_insert(storage, &mut batch, &index, &hash)
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    // The metadata of the referenced messages is committed along with the ledger changes so that a crash can't leave
+    // confirmed outputs with unreferenced messages.
+    for (message_id, metadata) in referenced_messages.iter() {
+        Batch::<MessageId, MessageMetadata>::batch_insert(storage, &mut batch, message_id, metadata)
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
     storage
         .batch_commit(batch, true)
         .await
@@ -317,6 +390,9 @@ pub(crate) async fn rollback_milestone<B: StorageBackend>(
     balance_diffs.negate();
     apply_balance_diffs_batch(storage, &mut batch, &balance_diffs).await?;
 
+    // Deleting entries that were never recorded is harmless, so this doesn't depend on the address history being enabled.
+    delete_address_history_batch(storage, &mut batch, index, created_outputs, consumed_outputs)?;
+
     if let Some(migration) = migration {
         delete_receipt_batch(storage, &mut batch, migration.receipt())?;
         delete_treasury_output_batch(storage, &mut batch, migration.created_treasury())?;
@@ -325,6 +401,8 @@ pub(crate) async fn rollback_milestone<B: StorageBackend>(
 
     Batch::<MilestoneIndex, OutputDiff>::batch_delete(storage, &mut batch, &index)
         .map_err(|e| Error::Storage(Box::new(e)))?;
+    Batch::<MilestoneIndex, LedgerStateHash>::batch_delete(storage, &mut batch, &index)
+        .map_err(|e| Error::Storage(Box::new(e)))?;
 
     storage
         .batch_commit(batch, true)
@@ -332,6 +410,156 @@ pub(crate) async fn rollback_milestone<B: StorageBackend>(
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
+pub(crate) fn insert_address_history_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    index: MilestoneIndex,
+    created_outputs: &HashMap<OutputId, CreatedOutput>,
+    consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
+) -> Result<(), Error> {
+    for (address, entry) in address_history_entries(
+        index,
+        created_outputs.iter(),
+        consumed_outputs
+            .values()
+            .map(|(created_output, consumed_output)| (created_output, consumed_output)),
+    )? {
+        Batch::<(Ed25519Address, AddressHistoryEntry), ()>::batch_insert(storage, batch, &(address, entry), &())
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn delete_address_history_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut <B as BatchBuilder>::Batch,
+    index: MilestoneIndex,
+    created_outputs: &HashMap<OutputId, CreatedOutput>,
+    consumed_outputs: &HashMap<OutputId, (CreatedOutput, ConsumedOutput)>,
+) -> Result<(), Error> {
+    for (address, entry) in address_history_entries(
+        index,
+        created_outputs.iter(),
+        consumed_outputs
+            .values()
+            .map(|(created_output, consumed_output)| (created_output, consumed_output)),
+    )? {
+        Batch::<(Ed25519Address, AddressHistoryEntry), ()>::batch_delete(storage, batch, &(address, entry))
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+/// Deletes the address history entries of the milestones in `(start, end]`, recomputed from their output diffs, and
+/// records `end` as the new address history pruning index.
+pub(crate) async fn prune_address_history<B: StorageBackend>(
+    storage: &B,
+    start: MilestoneIndex,
+    end: MilestoneIndex,
+) -> Result<(), Error> {
+    let mut batch = B::batch_begin();
+
+    for index in *start + 1..=*end {
+        let index = MilestoneIndex(index);
+        let diff = match fetch_output_diff(storage, index).await? {
+            Some(diff) => diff,
+            None => continue,
+        };
+
+        let mut created_outputs = HashMap::with_capacity(diff.created_outputs().len());
+        let mut consumed_outputs = HashMap::with_capacity(diff.consumed_outputs().len());
+
+        for output_id in diff.created_outputs() {
+            let created_output = fetch_output(storage, output_id)
+                .await?
+                .ok_or(Error::MissingCreatedOutput(*output_id))?;
+
+            created_outputs.insert(*output_id, created_output);
+        }
+
+        for output_id in diff.consumed_outputs() {
+            let created_output = fetch_output(storage, output_id)
+                .await?
+                .ok_or(Error::MissingCreatedOutput(*output_id))?;
+            let consumed_output = fetch_consumed_output(storage, output_id)
+                .await?
+                .ok_or(Error::MissingConsumedOutput(*output_id))?;
+
+            consumed_outputs.insert(*output_id, (created_output, consumed_output));
+        }
+
+        delete_address_history_batch(storage, &mut batch, index, &created_outputs, &consumed_outputs)?;
+    }
+
+    Batch::<OptionalIndex, MilestoneIndex>::batch_insert(storage, &mut batch, &OptionalIndex::AddressHistory, &end)
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+
+    storage
+        .batch_commit(batch, true)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) fn insert_indexation_entries_batch<B: StorageBackend>(
+    storage: &B,
+    batch: &mut B::Batch,
+    index: MilestoneIndex,
+    entries: &[IndexationEntry],
+) -> Result<(), Error> {
+    for entry in entries.iter() {
+        Batch::<IndexationEntry, ()>::batch_insert(storage, batch, entry, &())
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+        Batch::<(MilestoneIndex, IndexationEntry), ()>::batch_insert(storage, batch, &(index, entry.clone()), &())
+            .map_err(|e| Error::Storage(Box::new(e)))?;
+    }
+
+    Ok(())
+}
+
+/// Deletes the indexation entries of the messages referenced by the milestones in `(start, end]`, looked up by
+/// milestone, and records `end` as the new indexation pruning index.
+pub async fn prune_indexation<B: StorageBackend>(
+    storage: &B,
+    start: MilestoneIndex,
+    end: MilestoneIndex,
+) -> Result<(), Error> {
+    let mut batch = B::batch_begin();
+
+    for index in *start + 1..=*end {
+        let index = MilestoneIndex(index);
+        let entries = Fetch::<MilestoneIndex, Vec<IndexationEntry>>::fetch(storage, &index)
+            .await
+            .map_err(|e| Error::Storage(Box::new(e)))?
+            .unwrap_or_default();
+
+        for entry in entries {
+            Batch::<IndexationEntry, ()>::batch_delete(storage, &mut batch, &entry)
+                .map_err(|e| Error::Storage(Box::new(e)))?;
+            Batch::<(MilestoneIndex, IndexationEntry), ()>::batch_delete(storage, &mut batch, &(index, entry))
+                .map_err(|e| Error::Storage(Box::new(e)))?;
+        }
+    }
+
+    Batch::<OptionalIndex, MilestoneIndex>::batch_insert(storage, &mut batch, &OptionalIndex::Indexation, &end)
+        .map_err(|e| Error::Storage(Box::new(e)))?;
+
+    storage
+        .batch_commit(batch, true)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) async fn fetch_pruning_index<B: StorageBackend>(
+    storage: &B,
+    optional_index: OptionalIndex,
+) -> Result<Option<MilestoneIndex>, Error> {
+    Fetch::<OptionalIndex, MilestoneIndex>::fetch(storage, &optional_index)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
 pub(crate) async fn fetch_balance<B: StorageBackend>(storage: &B, address: &Address) -> Result<Option<Balance>, Error> {
     Fetch::<Address, Balance>::fetch(storage, address)
         .await
@@ -392,6 +620,74 @@ pub(crate) fn delete_receipt_batch<B: StorageBackend>(
     .map_err(|e| Error::Storage(Box::new(e)))
 }
 
+pub(crate) async fn fetch_output_diff<B: StorageBackend>(
+    storage: &B,
+    index: MilestoneIndex,
+) -> Result<Option<OutputDiff>, Error> {
+    Fetch::<MilestoneIndex, OutputDiff>::fetch(storage, &index)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+/// Fetches the outputs created and consumed by a confirmed milestone, along with whether they were consumed.
+pub async fn fetch_milestone_outputs<B: StorageBackend>(
+    storage: &B,
+    index: MilestoneIndex,
+) -> Result<Vec<(OutputId, CreatedOutput, bool)>, Error> {
+    let diff = match fetch_output_diff(storage, index).await? {
+        Some(diff) => diff,
+        None => return Ok(Vec::new()),
+    };
+    let mut outputs = Vec::with_capacity(diff.created_outputs().len() + diff.consumed_outputs().len());
+
+    for (output_ids, is_spent) in [(diff.created_outputs(), false), (diff.consumed_outputs(), true)].iter() {
+        for output_id in output_ids.iter() {
+            if let Some(output) = fetch_output(storage, output_id).await? {
+                outputs.push((*output_id, output, *is_spent));
+            }
+        }
+    }
+
+    Ok(outputs)
+}
+
+pub async fn fetch_ledger_state_hash<B: StorageBackend>(
+    storage: &B,
+    index: MilestoneIndex,
+) -> Result<Option<LedgerStateHash>, Error> {
+    Fetch::<MilestoneIndex, LedgerStateHash>::fetch(storage, &index)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) async fn insert_ledger_state_hash<B: StorageBackend>(
+    storage: &B,
+    index: MilestoneIndex,
+    hash: &LedgerStateHash,
+) -> Result<(), Error> {
+    Insert::<MilestoneIndex, LedgerStateHash>::insert(storage, &index, hash)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) async fn fetch_milestone<B: StorageBackend>(
+    storage: &B,
+    index: MilestoneIndex,
+) -> Result<Option<Milestone>, Error> {
+    Fetch::<MilestoneIndex, Milestone>::fetch(storage, &index)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
+pub(crate) async fn fetch_message_metadata<B: StorageBackend>(
+    storage: &B,
+    message_id: &MessageId,
+) -> Result<Option<MessageMetadata>, Error> {
+    Fetch::<MessageId, MessageMetadata>::fetch(storage, message_id)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
 pub(crate) async fn insert_snapshot_info<B: StorageBackend>(
     storage: &B,
     snapshot_info: &SnapshotInfo,
@@ -416,6 +712,15 @@ pub(crate) async fn fetch_output<B: StorageBackend>(
         .map_err(|e| Error::Storage(Box::new(e)))
 }
 
+pub(crate) async fn fetch_consumed_output<B: StorageBackend>(
+    storage: &B,
+    output_id: &OutputId,
+) -> Result<Option<ConsumedOutput>, Error> {
+    Fetch::<OutputId, ConsumedOutput>::fetch(storage, output_id)
+        .await
+        .map_err(|e| Error::Storage(Box::new(e)))
+}
+
 pub(crate) async fn is_output_unspent<B: StorageBackend>(storage: &B, output_id: &OutputId) -> Result<bool, Error> {
     Exist::<Unspent, ()>::exist(storage, &(*output_id).into())
         .await
@@ -0,0 +1,110 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "workers")]
+
+use bee_ledger::{
+    types::{snapshot::SnapshotInfo, BalanceDiffs, LedgerIndex, OutputDiff},
+    workers::{consensus::validate_confirmation_state, error::Error, storage::apply_milestone},
+};
+use bee_message::{
+    milestone::{Milestone, MilestoneIndex},
+    MessageId,
+};
+use bee_storage::{
+    access::{Fetch, Insert},
+    backend::StorageBackend,
+};
+use bee_storage_memory::{config::MemoryConfigBuilder, storage::Storage};
+use bee_tangle::metadata::MessageMetadata;
+
+use futures::executor::block_on;
+
+use std::collections::HashMap;
+
+fn milestone_message_id() -> MessageId {
+    MessageId::new([0x2a; 32])
+}
+
+async fn storage() -> Storage {
+    let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+
+    Insert::<(), SnapshotInfo>::insert(
+        &storage,
+        &(),
+        &SnapshotInfo::new(0, MilestoneIndex(1), MilestoneIndex(1), MilestoneIndex(1), 0),
+    )
+    .await
+    .unwrap();
+    Insert::<MilestoneIndex, Milestone>::insert(
+        &storage,
+        &MilestoneIndex(2),
+        &Milestone::new(milestone_message_id(), 0),
+    )
+    .await
+    .unwrap();
+
+    storage
+}
+
+async fn confirm(storage: &Storage) {
+    let mut metadata = MessageMetadata::arrived();
+    metadata.reference(0);
+
+    apply_milestone(
+        storage,
+        MilestoneIndex(2),
+        &HashMap::new(),
+        &HashMap::new(),
+        &BalanceDiffs::new(),
+        &None,
+        &[(milestone_message_id(), metadata)],
+        false,
+        &[],
+    )
+    .await
+    .unwrap();
+}
+
+#[test]
+fn milestone_is_committed_at_once() {
+    block_on(async {
+        let storage = storage().await;
+
+        confirm(&storage).await;
+
+        assert_eq!(
+            Fetch::<(), LedgerIndex>::fetch(&storage, &()).await.unwrap(),
+            Some(LedgerIndex(MilestoneIndex(2)))
+        );
+        assert!(Fetch::<MilestoneIndex, OutputDiff>::fetch(&storage, &MilestoneIndex(2))
+            .await
+            .unwrap()
+            .is_some());
+        assert!(
+            Fetch::<MessageId, MessageMetadata>::fetch(&storage, &milestone_message_id())
+                .await
+                .unwrap()
+                .unwrap()
+                .flags()
+                .is_referenced()
+        );
+        assert!(validate_confirmation_state(&storage).await.is_ok());
+    });
+}
+
+#[test]
+fn partial_confirmation_is_detected() {
+    block_on(async {
+        let storage = storage().await;
+
+        Insert::<(), LedgerIndex>::insert(&storage, &(), &MilestoneIndex(2).into())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            validate_confirmation_state(&storage).await,
+            Err(Error::InconsistentConfirmation(MilestoneIndex(2)))
+        ));
+    });
+}
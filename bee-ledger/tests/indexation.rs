@@ -0,0 +1,123 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::{
+    types::{BalanceDiffs, IndexationEntry, IndexationPage, IndexationPageEntries, OptionalIndex},
+    workers::storage::{apply_milestone, prune_indexation},
+};
+use bee_message::{milestone::MilestoneIndex, MessageId};
+use bee_storage::{access::Fetch, backend::StorageBackend};
+use bee_storage_memory::{config::MemoryConfigBuilder, storage::Storage};
+
+use futures::executor::block_on;
+
+use std::collections::HashMap;
+
+fn entry(index: &[u8], milestone_index: u32) -> IndexationEntry {
+    IndexationEntry::new(
+        index.into(),
+        MilestoneIndex(milestone_index),
+        u64::from(milestone_index) * 10,
+        MessageId::new([milestone_index as u8; 32]),
+    )
+}
+
+async fn confirm(storage: &Storage, index: u32, entries: &[IndexationEntry]) {
+    apply_milestone(
+        storage,
+        MilestoneIndex(index),
+        &HashMap::new(),
+        &HashMap::new(),
+        &BalanceDiffs::new(),
+        &None,
+        &[],
+        false,
+        entries,
+    )
+    .await
+    .unwrap();
+}
+
+async fn fetch(storage: &Storage, page: IndexationPage) -> Vec<IndexationEntry> {
+    Fetch::<IndexationPage, IndexationPageEntries>::fetch(storage, &page)
+        .await
+        .unwrap()
+        .unwrap()
+        .into_entries()
+}
+
+fn prefix(prefix: &[u8]) -> IndexationPage {
+    IndexationPage::prefix(prefix.into(), 0, usize::MAX)
+}
+
+#[test]
+fn entries_are_stored_by_index_and_milestone() {
+    block_on(async {
+        let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+
+        confirm(&storage, 1, &[entry(b"bee", 1), entry(b"ab", 1)]).await;
+        confirm(&storage, 2, &[entry(b"bef", 2)]).await;
+
+        assert_eq!(
+            fetch(
+                &storage,
+                IndexationPage::index(b"bee".to_vec().into_boxed_slice(), 0, 10)
+            )
+            .await,
+            vec![entry(b"bee", 1)]
+        );
+        assert_eq!(
+            fetch(&storage, prefix(b"be")).await,
+            vec![entry(b"bee", 1), entry(b"bef", 2)]
+        );
+        assert_eq!(
+            Fetch::<MilestoneIndex, Vec<IndexationEntry>>::fetch(&storage, &MilestoneIndex(1))
+                .await
+                .unwrap()
+                .unwrap(),
+            vec![entry(b"ab", 1), entry(b"bee", 1)]
+        );
+    });
+}
+
+#[test]
+fn pruning_removes_the_entries_of_the_milestones_and_records_the_index() {
+    block_on(async {
+        let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+
+        for index in 1..=4 {
+            confirm(&storage, index, &[entry(b"bee", index)]).await;
+        }
+
+        prune_indexation(&storage, MilestoneIndex(1), MilestoneIndex(3))
+            .await
+            .unwrap();
+
+        // Entries of milestones up to the start were already pruned and are left alone.
+        assert_eq!(
+            fetch(&storage, prefix(b"")).await,
+            vec![entry(b"bee", 1), entry(b"bee", 4)]
+        );
+        assert_eq!(
+            fetch(
+                &storage,
+                IndexationPage::index(b"bee".to_vec().into_boxed_slice(), 0, 10)
+            )
+            .await,
+            vec![entry(b"bee", 1), entry(b"bee", 4)]
+        );
+        assert!(
+            Fetch::<MilestoneIndex, Vec<IndexationEntry>>::fetch(&storage, &MilestoneIndex(2))
+                .await
+                .unwrap()
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            Fetch::<OptionalIndex, MilestoneIndex>::fetch(&storage, &OptionalIndex::Indexation)
+                .await
+                .unwrap(),
+            Some(MilestoneIndex(3))
+        );
+    });
+}
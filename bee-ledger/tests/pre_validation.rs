@@ -0,0 +1,172 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "workers")]
+
+use bee_ledger::{
+    types::{ConsumedOutput, CreatedOutput, Unspent},
+    workers::consensus::validate_transaction,
+};
+use bee_ledger_types::types::ConflictReason;
+use bee_message::{
+    address::{Address, Ed25519Address},
+    input::{Input, UtxoInput},
+    milestone::MilestoneIndex,
+    output::{Output, OutputId, SignatureLockedSingleOutput},
+    payload::transaction::{Essence, RegularEssence, TransactionId, TransactionPayload},
+    signature::{Ed25519Signature, SignatureUnlock},
+    unlock::{UnlockBlock, UnlockBlocks},
+    MessageId,
+};
+use bee_storage::{access::Insert, backend::StorageBackend};
+use bee_storage_memory::{config::MemoryConfigBuilder, storage::Storage};
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::SecretKey,
+};
+use futures::executor::block_on;
+
+const AMOUNT: u64 = 1_000_000;
+
+fn output_id() -> OutputId {
+    OutputId::new(TransactionId::new([0x2a; 32]), 0).unwrap()
+}
+
+fn address(key: &SecretKey) -> Address {
+    let mut address = [0u8; 32];
+    address.copy_from_slice(&Blake2b256::digest(&key.public_key().to_compressed_bytes()));
+
+    Address::Ed25519(Ed25519Address::new(address))
+}
+
+/// Builds a transaction spending the output with the key, signing the essence with the signer.
+fn transaction(key: &SecretKey, signer: &SecretKey, amount: u64) -> TransactionPayload {
+    let essence = RegularEssence::builder()
+        .add_input(Input::Utxo(
+            UtxoInput::new(*output_id().transaction_id(), output_id().index()).unwrap(),
+        ))
+        .add_output(Output::SignatureLockedSingle(
+            SignatureLockedSingleOutput::new(address(key), amount).unwrap(),
+        ))
+        .finish()
+        .unwrap();
+    let essence = Essence::Regular(essence);
+    let signature = signer.sign(&essence.hash()).to_bytes();
+
+    TransactionPayload::builder()
+        .with_essence(essence)
+        .with_unlock_blocks(
+            UnlockBlocks::new(vec![UnlockBlock::Signature(SignatureUnlock::Ed25519(
+                Ed25519Signature::new(signer.public_key().to_compressed_bytes(), signature),
+            ))])
+            .unwrap(),
+        )
+        .finish()
+        .unwrap()
+}
+
+/// Starts a storage holding the output of the key, spent or not.
+async fn storage(key: &SecretKey, spent: bool) -> Storage {
+    let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+    let output = SignatureLockedSingleOutput::new(address(key), AMOUNT).unwrap();
+
+    Insert::<OutputId, CreatedOutput>::insert(
+        &storage,
+        &output_id(),
+        &CreatedOutput::new(MessageId::new([0x2a; 32]), Output::SignatureLockedSingle(output)),
+    )
+    .await
+    .unwrap();
+
+    if spent {
+        Insert::<OutputId, ConsumedOutput>::insert(
+            &storage,
+            &output_id(),
+            &ConsumedOutput::new(TransactionId::new([0x2b; 32]), MilestoneIndex(1)),
+        )
+        .await
+        .unwrap();
+    } else {
+        Insert::<Unspent, ()>::insert(&storage, &Unspent::new(output_id()), &())
+            .await
+            .unwrap();
+    }
+
+    storage
+}
+
+#[test]
+fn valid_transaction() {
+    block_on(async {
+        let key = SecretKey::generate().unwrap();
+        let storage = storage(&key, false).await;
+
+        assert_eq!(
+            validate_transaction(&storage, &transaction(&key, &key, AMOUNT))
+                .await
+                .unwrap(),
+            ConflictReason::None
+        );
+    });
+}
+
+#[test]
+fn spent_input() {
+    block_on(async {
+        let key = SecretKey::generate().unwrap();
+        let storage = storage(&key, true).await;
+
+        assert_eq!(
+            validate_transaction(&storage, &transaction(&key, &key, AMOUNT))
+                .await
+                .unwrap(),
+            ConflictReason::InputUtxoAlreadySpent
+        );
+    });
+}
+
+#[test]
+fn unknown_input() {
+    block_on(async {
+        let key = SecretKey::generate().unwrap();
+        let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+
+        assert_eq!(
+            validate_transaction(&storage, &transaction(&key, &key, AMOUNT))
+                .await
+                .unwrap(),
+            ConflictReason::InputUtxoNotFound
+        );
+    });
+}
+
+#[test]
+fn invalid_signature() {
+    block_on(async {
+        let key = SecretKey::generate().unwrap();
+        let storage = storage(&key, false).await;
+
+        assert_eq!(
+            validate_transaction(&storage, &transaction(&key, &SecretKey::generate().unwrap(), AMOUNT))
+                .await
+                .unwrap(),
+            ConflictReason::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn amount_mismatch() {
+    block_on(async {
+        let key = SecretKey::generate().unwrap();
+        let storage = storage(&key, false).await;
+
+        assert_eq!(
+            validate_transaction(&storage, &transaction(&key, &key, AMOUNT - 1))
+                .await
+                .unwrap(),
+            ConflictReason::InputOutputSumMismatch
+        );
+    });
+}
@@ -1,7 +1,10 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{config::NodeConfigBuilder, tools::Tool};
+use crate::{
+    config::{Error, LayeredConfig, NodeConfigBuilder},
+    tools::Tool,
+};
 
 use bee_common::logger::{LoggerConfigBuilder, LOGGER_STDOUT_NAME};
 use bee_storage::backend::StorageBackend;
@@ -11,8 +14,25 @@ use structopt::StructOpt;
 
 #[derive(Clone, Debug, StructOpt)]
 pub struct CliArgs {
-    #[structopt(short = "c", long = "config", help = "Path of the configuration file")]
-    config: Option<String>,
+    #[structopt(
+        short = "c",
+        long = "config",
+        number_of_values = 1,
+        help = "Path of a configuration file, can be repeated to merge files in order"
+    )]
+    config: Vec<String>,
+    #[structopt(
+        short = "s",
+        long = "set",
+        number_of_values = 1,
+        help = "Overrides a configuration value, e.g. `--set rest_api.binding_port=14266`, can be repeated"
+    )]
+    set: Vec<String>,
+    #[structopt(
+        long = "print-config",
+        help = "Prints the effective configuration with secrets redacted"
+    )]
+    print_config: bool,
     #[structopt(
         short = "l",
         long = "log-level",
@@ -36,8 +56,37 @@ impl CliArgs {
         Self::from_args()
     }
 
-    pub fn config(&self) -> Option<&String> {
-        self.config.as_ref()
+    pub fn config(&self) -> &[String] {
+        &self.config
+    }
+
+    pub fn set(&self) -> &[String] {
+        &self.set
+    }
+
+    pub fn print_config(&self) -> bool {
+        self.print_config
+    }
+
+    /// Merges the configuration files, then the `BEE_` environment variables, then the `--set` overrides.
+    /// The default path is used when no configuration file is given.
+    pub fn layered_config(&self, default_path: &str) -> Result<LayeredConfig, Error> {
+        let mut layered = LayeredConfig::new();
+
+        if self.config.is_empty() {
+            layered = layered.with_file(default_path)?;
+        }
+        for path in self.config.iter() {
+            layered = layered.with_file(path)?;
+        }
+
+        layered = layered.with_env_vars(std::env::vars())?;
+
+        for expression in self.set.iter() {
+            layered = layered.with_override(expression)?;
+        }
+
+        Ok(layered)
     }
 
     pub fn log_level(&self) -> Option<&LevelFilter> {
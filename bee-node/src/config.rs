@@ -1,19 +1,37 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod layered;
+
+pub use layered::{LayeredConfig, ENV_PREFIX, ENV_SEPARATOR};
+
 #[cfg(feature = "dashboard")]
-use crate::plugins::dashboard::config::{DashboardConfig, DashboardConfigBuilder};
+use crate::plugins::dashboard::config::{DashboardConfig, DashboardConfigBuilder, DashboardConfigError};
+#[cfg(unix)]
+use crate::plugins::host::config::{PluginHostConfig, PluginHostConfigBuilder, PluginHostConfigError};
 
-use crate::plugins::mqtt::config::{MqttConfig, MqttConfigBuilder};
+use crate::{
+    node::{ShutdownConfig, ShutdownConfigBuilder},
+    plugins::{
+        mqtt::config::{MqttConfig, MqttConfigBuilder},
+        version_checker::config::{VersionCheckerConfig, VersionCheckerConfigBuilder, VersionCheckerConfigError},
+    },
+};
 
 use bee_common::logger::{LoggerConfig, LoggerConfigBuilder};
 use bee_ledger::workers::{
-    pruning::config::{PruningConfig, PruningConfigBuilder},
+    config::{LedgerConfig, LedgerConfigBuilder},
+    pruning::{
+        config::{PruningConfig, PruningConfigBuilder},
+        minimum_pruning_delay,
+    },
     snapshot::config::{SnapshotConfig, SnapshotConfigBuilder},
 };
-use bee_network::{Keypair, NetworkConfig, NetworkConfigBuilder, PeerId, PublicKey};
+use bee_network::{
+    config::Error as NetworkConfigError, Keypair, NetworkConfig, NetworkConfigBuilder, PeerId, PublicKey,
+};
 use bee_protocol::workers::config::{ProtocolConfig, ProtocolConfigBuilder};
-use bee_rest_api::endpoints::config::{RestApiConfig, RestApiConfigBuilder};
+use bee_rest_api::endpoints::config::{Error as RestApiConfigError, RestApiConfig, RestApiConfigBuilder};
 use bee_storage::backend::StorageBackend;
 use bee_tangle::config::{TangleConfig, TangleConfigBuilder};
 
@@ -21,20 +39,96 @@ use crypto::hashes::{blake2b::Blake2b256, Digest};
 use serde::Deserialize;
 use thiserror::Error;
 
-use std::{convert::TryInto, fs, path::Path};
+use std::{
+    convert::TryInto,
+    fmt, fs,
+    net::{IpAddr, SocketAddr},
+    path::Path,
+};
+
+pub const DEFAULT_CONFIG_PATH: &str = "./config.toml";
 
 const DEFAULT_ALIAS: &str = "bee";
 const DEFAULT_BECH32_HRP: &str = "iota";
 const DEFAULT_NETWORK_ID: &str = "iota";
 
+/// The top-level keys of the node config, i.e. the fields of `NodeConfigBuilder`.
+const CONFIG_KEYS: &[&str] = &[
+    "identity",
+    "alias",
+    "bech32_hrp",
+    "network_id",
+    "logger",
+    "network",
+    "protocol",
+    "rest_api",
+    "ledger",
+    "snapshot",
+    "pruning",
+    "storage",
+    "tangle",
+    "shutdown",
+    "mqtt",
+    "version_checker",
+    "dashboard",
+    "plugin_host",
+];
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Reading the specified config file failed: {0}.")]
     ConfigFileReadFailure(#[from] std::io::Error),
     #[error("Deserializing the node config builder failed: {0}.")]
     NodeConfigBuilderCreationFailure(#[from] toml::de::Error),
+    #[error("Invalid configuration override: {0}.")]
+    InvalidOverride(String),
+    #[error("Unknown configuration environment variable: {0}.")]
+    UnknownEnvVar(String),
+}
+
+/// A problem of a section of the node config, or between sections.
+#[derive(Debug, Error)]
+pub enum ValidationError {
+    #[error("identity: invalid length {0}, expected 128 hexadecimal characters")]
+    IdentityLength(usize),
+    #[error("identity: {0}")]
+    IdentityDecoding(String),
+    #[error("network: {0:?}")]
+    Network(NetworkConfigError),
+    #[error("rest_api: {0}")]
+    RestApi(RestApiConfigError),
+    #[error("version_checker: {0}")]
+    VersionChecker(VersionCheckerConfigError),
+    #[cfg(feature = "dashboard")]
+    #[error("dashboard: {0}")]
+    Dashboard(DashboardConfigError),
+    #[cfg(unix)]
+    #[error("plugin_host: {0}")]
+    PluginHost(PluginHostConfigError),
+    #[error("pruning: delay {delay} is lower than {minimum}, the minimum for a snapshot depth of {depth}")]
+    PruningDelay { delay: u32, depth: u32, minimum: u32 },
+    #[error("{first} and {second} both bind {address}")]
+    PortCollision {
+        first: &'static str,
+        second: &'static str,
+        address: SocketAddr,
+    },
 }
 
+/// All the problems of a node config.
+#[derive(Debug)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let errors = self.0.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        write!(f, "{}", errors.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
 #[derive(Default, Deserialize)]
 pub struct NodeConfigBuilder<B: StorageBackend> {
     pub(crate) identity: Option<String>,
@@ -45,13 +139,18 @@ pub struct NodeConfigBuilder<B: StorageBackend> {
     pub(crate) network: Option<NetworkConfigBuilder>,
     pub(crate) protocol: Option<ProtocolConfigBuilder>,
     pub(crate) rest_api: Option<RestApiConfigBuilder>,
+    pub(crate) ledger: Option<LedgerConfigBuilder>,
     pub(crate) snapshot: Option<SnapshotConfigBuilder>,
     pub(crate) pruning: Option<PruningConfigBuilder>,
     pub(crate) storage: Option<B::ConfigBuilder>,
     pub(crate) tangle: Option<TangleConfigBuilder>,
+    pub(crate) shutdown: Option<ShutdownConfigBuilder>,
     pub(crate) mqtt: Option<MqttConfigBuilder>,
+    pub(crate) version_checker: Option<VersionCheckerConfigBuilder>,
     #[cfg(feature = "dashboard")]
     pub(crate) dashboard: Option<DashboardConfigBuilder>,
+    #[cfg(unix)]
+    pub(crate) plugin_host: Option<PluginHostConfigBuilder>,
 }
 
 impl<B: StorageBackend> NodeConfigBuilder<B> {
@@ -63,23 +162,20 @@ impl<B: StorageBackend> NodeConfigBuilder<B> {
         }
     }
 
-    pub fn finish(self) -> NodeConfig<B> {
-        let (identity, identity_string, new) = if let Some(identity_string) = self.identity {
-            if identity_string.len() == 128 {
-                let mut decoded = [0u8; 64];
-                hex::decode_to_slice(&identity_string[..], &mut decoded).expect("error decoding identity");
-                let identity = Keypair::decode(&mut decoded).expect("error decoding identity");
-                (identity, identity_string, false)
-            } else if identity_string.is_empty() {
-                generate_random_identity()
-            } else {
-                panic!("invalid identity string length");
-            }
-        } else {
-            generate_random_identity()
-        };
+    /// Builds the node config, reporting the problems of every section and between sections at once.
+    pub fn finish(self) -> Result<NodeConfig<B>, ValidationErrors> {
+        let mut errors = Vec::new();
 
-        let node_id = PeerId::from_public_key(PublicKey::Ed25519(identity.public()));
+        let identity = match self.identity {
+            Some(identity_string) if !identity_string.is_empty() => match decode_identity(&identity_string) {
+                Ok(identity) => Some((identity, identity_string, false)),
+                Err(e) => {
+                    errors.push(e);
+                    None
+                }
+            },
+            _ => Some(generate_random_identity()),
+        };
 
         let network_id_string = self.network_id.unwrap_or_else(|| DEFAULT_NETWORK_ID.to_string());
         let network_id_numeric = u64::from_le_bytes(
@@ -88,33 +184,114 @@ impl<B: StorageBackend> NodeConfigBuilder<B> {
                 .unwrap(),
         );
 
-        NodeConfig {
-            identity: (identity, identity_string, new),
+        let network = self
+            .network
+            .unwrap_or_default()
+            .finish()
+            .map_err(|e| errors.push(ValidationError::Network(e)))
+            .ok();
+        let rest_api = self
+            .rest_api
+            .unwrap_or_default()
+            .finish()
+            .map_err(|e| errors.push(ValidationError::RestApi(e)))
+            .ok();
+        let version_checker = self
+            .version_checker
+            .unwrap_or_default()
+            .finish()
+            .map_err(|e| errors.push(ValidationError::VersionChecker(e)))
+            .ok();
+        #[cfg(feature = "dashboard")]
+        let dashboard = self
+            .dashboard
+            .unwrap_or_default()
+            .finish()
+            .map_err(|e| errors.push(ValidationError::Dashboard(e)))
+            .ok();
+        #[cfg(unix)]
+        let plugin_host = self
+            .plugin_host
+            .unwrap_or_default()
+            .finish()
+            .map_err(|e| errors.push(ValidationError::PluginHost(e)))
+            .ok();
+
+        let snapshot = self.snapshot.unwrap_or_default().finish();
+        let pruning = self.pruning.unwrap_or_default().finish();
+
+        // The checks between sections run on the sections that are valid, so that every problem is reported at once.
+        errors.extend(pruning_delay_error(&snapshot, &pruning));
+
+        let mut bindings = Vec::new();
+        if let Some(rest_api) = rest_api.as_ref() {
+            bindings.push(("rest_api", rest_api.binding_socket_addr()));
+        }
+        if let Some(address) = network
+            .as_ref()
+            .and_then(|network| multiaddr_socket_addr(&network.bind_multiaddr.to_string()))
+        {
+            bindings.push(("network", address));
+        }
+        #[cfg(feature = "dashboard")]
+        if let Some(dashboard) = dashboard.as_ref() {
+            bindings.push(("dashboard", dashboard.binding_socket_addr()));
+        }
+        errors.extend(port_collision_errors(&bindings));
+
+        let (identity, network, rest_api, version_checker) = match (identity, network, rest_api, version_checker) {
+            (Some(identity), Some(network), Some(rest_api), Some(version_checker)) if errors.is_empty() => {
+                (identity, network, rest_api, version_checker)
+            }
+            _ => return Err(ValidationErrors(errors)),
+        };
+        #[cfg(feature = "dashboard")]
+        // Unwrapping is fine since a dashboard error would have returned.
+        let dashboard = dashboard.unwrap();
+        #[cfg(unix)]
+        // Unwrapping is fine since a plugin host error would have returned.
+        let plugin_host = plugin_host.unwrap();
+
+        let node_id = PeerId::from_public_key(PublicKey::Ed25519(identity.0.public()));
+
+        Ok(NodeConfig {
+            identity,
             node_id,
             alias: self.alias.unwrap_or_else(|| DEFAULT_ALIAS.to_owned()),
             bech32_hrp: self.bech32_hrp.unwrap_or_else(|| DEFAULT_BECH32_HRP.to_owned()),
             network_id: (network_id_string, network_id_numeric),
             logger: self.logger.unwrap_or_default().finish(),
-            // TODO: Create specific error types for each config section, e.g.
-            // Error::NetworkConfigError(bee_network::config::Error)
-            network: self
-                .network
-                .unwrap_or_default()
-                .finish()
-                .expect("faulty network configuration"),
+            network,
             protocol: self.protocol.unwrap_or_default().finish(),
-            rest_api: self.rest_api.unwrap_or_default().finish(),
-            snapshot: self.snapshot.unwrap_or_default().finish(),
-            pruning: self.pruning.unwrap_or_default().finish(),
+            rest_api,
+            ledger: self.ledger.unwrap_or_default().finish(),
+            snapshot,
+            pruning,
             storage: self.storage.unwrap_or_default().into(),
             tangle: self.tangle.unwrap_or_default().finish(),
+            shutdown: self.shutdown.unwrap_or_default().finish(),
             mqtt: self.mqtt.unwrap_or_default().finish(),
+            version_checker,
             #[cfg(feature = "dashboard")]
-            dashboard: self.dashboard.unwrap_or_default().finish(),
-        }
+            dashboard,
+            #[cfg(unix)]
+            plugin_host,
+        })
     }
 }
 
+fn decode_identity(identity_string: &str) -> Result<Keypair, ValidationError> {
+    if identity_string.len() != 128 {
+        return Err(ValidationError::IdentityLength(identity_string.len()));
+    }
+
+    let mut decoded = [0u8; 64];
+    hex::decode_to_slice(identity_string, &mut decoded)
+        .map_err(|e| ValidationError::IdentityDecoding(e.to_string()))?;
+
+    Keypair::decode(&mut decoded).map_err(|e| ValidationError::IdentityDecoding(format!("{:?}", e)))
+}
+
 fn generate_random_identity() -> (Keypair, String, bool) {
     let identity = Keypair::generate();
     let encoded = identity.encode();
@@ -132,13 +309,18 @@ pub struct NodeConfig<B: StorageBackend> {
     pub network: NetworkConfig,
     pub protocol: ProtocolConfig,
     pub rest_api: RestApiConfig,
+    pub ledger: LedgerConfig,
     pub snapshot: SnapshotConfig,
     pub pruning: PruningConfig,
     pub storage: B::Config,
     pub tangle: TangleConfig,
+    pub shutdown: ShutdownConfig,
     pub mqtt: MqttConfig,
+    pub version_checker: VersionCheckerConfig,
     #[cfg(feature = "dashboard")]
     pub dashboard: DashboardConfig,
+    #[cfg(unix)]
+    pub plugin_host: PluginHostConfig,
 }
 
 impl<B: StorageBackend> Clone for NodeConfig<B> {
@@ -153,13 +335,193 @@ impl<B: StorageBackend> Clone for NodeConfig<B> {
             network: self.network.clone(),
             protocol: self.protocol.clone(),
             rest_api: self.rest_api.clone(),
+            ledger: self.ledger.clone(),
             snapshot: self.snapshot.clone(),
             pruning: self.pruning.clone(),
             storage: self.storage.clone(),
             tangle: self.tangle.clone(),
+            shutdown: self.shutdown.clone(),
             mqtt: self.mqtt.clone(),
+            version_checker: self.version_checker.clone(),
             #[cfg(feature = "dashboard")]
             dashboard: self.dashboard.clone(),
+            #[cfg(unix)]
+            plugin_host: self.plugin_host.clone(),
+        }
+    }
+}
+
+/// Returns an error if pruning is enabled with a delay lower than the minimum one of the snapshot depth.
+fn pruning_delay_error(snapshot: &SnapshotConfig, pruning: &PruningConfig) -> Option<ValidationError> {
+    let minimum = minimum_pruning_delay(snapshot.depth());
+
+    if pruning.enabled() && pruning.delay() < minimum {
+        Some(ValidationError::PruningDelay {
+            delay: pruning.delay(),
+            depth: snapshot.depth(),
+            minimum,
+        })
+    } else {
+        None
+    }
+}
+
+/// Returns an error for every pair of named socket addresses that bind the same port.
+fn port_collision_errors(bindings: &[(&'static str, SocketAddr)]) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+
+    for (i, (first, first_address)) in bindings.iter().enumerate() {
+        for (second, second_address) in bindings[i + 1..].iter() {
+            if socket_addrs_collide(first_address, second_address) {
+                errors.push(ValidationError::PortCollision {
+                    first: *first,
+                    second: *second,
+                    address: *second_address,
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+/// Extracts the socket address of an `/ip4/.../tcp/...` or `/ip6/.../tcp/...` multiaddress.
+fn multiaddr_socket_addr(multiaddr: &str) -> Option<SocketAddr> {
+    let segments = multiaddr.split('/').collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        ["", "ip4", ip, "tcp", port] | ["", "ip6", ip, "tcp", port] => {
+            Some(SocketAddr::new(ip.parse::<IpAddr>().ok()?, port.parse().ok()?))
         }
+        _ => None,
+    }
+}
+
+fn socket_addrs_collide(a: &SocketAddr, b: &SocketAddr) -> bool {
+    a.port() == b.port() && (a.ip() == b.ip() || a.ip().is_unspecified() || b.ip().is_unspecified())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::storage::Backend;
+
+    fn finish(toml: &str) -> Result<NodeConfig<Backend>, ValidationErrors> {
+        toml::from_str::<NodeConfigBuilder<Backend>>(toml).unwrap().finish()
+    }
+
+    fn pruning(toml: &str) -> PruningConfig {
+        toml::from_str::<PruningConfigBuilder>(toml).unwrap().finish()
+    }
+
+    fn snapshot(toml: &str) -> SnapshotConfig {
+        toml::from_str::<SnapshotConfigBuilder>(toml).unwrap().finish()
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        assert!(finish("").is_ok());
+    }
+
+    #[test]
+    fn section_and_cross_section_errors_are_reported_at_once() {
+        let errors = finish("identity = \"abc\"\n[snapshot]\ndepth = 50\n[pruning]\nenabled = true\ndelay = 1")
+            .err()
+            .unwrap()
+            .0;
+
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], ValidationError::IdentityLength(3)));
+        assert!(matches!(
+            errors[1],
+            ValidationError::PruningDelay {
+                delay: 1,
+                depth: 50,
+                ..
+            }
+        ));
+    }
+
+    #[cfg(feature = "dashboard")]
+    #[test]
+    fn port_collisions_are_reported_with_section_errors() {
+        let errors = finish("identity = \"abc\"\n[rest_api]\nbinding_port = 8081\n[dashboard]\nport = 8081")
+            .err()
+            .unwrap()
+            .0;
+
+        assert!(matches!(errors[0], ValidationError::IdentityLength(3)));
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            ValidationError::PortCollision {
+                first: "rest_api",
+                second: "dashboard",
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn pruning_delay_lower_than_the_minimum_is_rejected() {
+        let snapshot = snapshot("depth = 50");
+        let minimum = minimum_pruning_delay(50);
+
+        assert!(matches!(
+            pruning_delay_error(&snapshot, &pruning(&format!("enabled = true\ndelay = {}", minimum - 1))),
+            Some(ValidationError::PruningDelay { delay, depth: 50, minimum: m }) if delay == minimum - 1 && m == minimum
+        ));
+        assert!(pruning_delay_error(&snapshot, &pruning(&format!("enabled = true\ndelay = {}", minimum))).is_none());
+    }
+
+    #[test]
+    fn pruning_delay_is_ignored_when_pruning_is_disabled() {
+        assert!(pruning_delay_error(&snapshot("depth = 50"), &pruning("enabled = false\ndelay = 1")).is_none());
+    }
+
+    #[test]
+    fn port_collisions_include_unspecified_addresses() {
+        let bindings = [
+            ("rest_api", "0.0.0.0:14265".parse().unwrap()),
+            ("network", "127.0.0.1:14265".parse().unwrap()),
+            ("dashboard", "127.0.0.1:8081".parse().unwrap()),
+        ];
+
+        let errors = port_collision_errors(&bindings);
+
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ValidationError::PortCollision {
+                first: "rest_api",
+                second: "network",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn different_addresses_or_ports_do_not_collide() {
+        let bindings = [
+            ("rest_api", "127.0.0.1:14265".parse().unwrap()),
+            ("network", "127.0.0.2:14265".parse().unwrap()),
+            ("dashboard", "127.0.0.1:8081".parse().unwrap()),
+        ];
+
+        assert!(port_collision_errors(&bindings).is_empty());
+    }
+
+    #[test]
+    fn multiaddr_socket_addr_supports_tcp_addresses_only() {
+        assert_eq!(
+            multiaddr_socket_addr("/ip4/0.0.0.0/tcp/15600"),
+            Some("0.0.0.0:15600".parse().unwrap())
+        );
+        assert_eq!(
+            multiaddr_socket_addr("/ip6/::1/tcp/15600"),
+            Some("[::1]:15600".parse().unwrap())
+        );
+        assert_eq!(multiaddr_socket_addr("/dns/localhost/tcp/15600"), None);
+        assert_eq!(multiaddr_socket_addr("/ip4/0.0.0.0/udp/15600"), None);
     }
 }
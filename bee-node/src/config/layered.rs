@@ -0,0 +1,519 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{Error, NodeConfigBuilder, CONFIG_KEYS, DEFAULT_ALIAS, DEFAULT_BECH32_HRP, DEFAULT_NETWORK_ID};
+
+use bee_common::logger::LOGGER_STDOUT_NAME;
+use bee_storage::backend::StorageBackend;
+
+use log::{warn, LevelFilter};
+use toml::{value::Table, Value};
+
+use std::{fs, path::Path};
+
+/// Prefix of the environment variables overriding configuration values.
+pub const ENV_PREFIX: &str = "BEE_";
+/// Separator of the path segments of an environment variable, e.g. `BEE_REST_API__BINDING_PORT`.
+pub const ENV_SEPARATOR: &str = "__";
+
+const REDACTED: &str = "<redacted>";
+const REDACTED_KEYS: &[&str] = &["identity", "password_salt", "password_hash"];
+const REDACTED_KEY_PARTS: &[&str] = &["secret", "token"];
+
+/// A configuration built from layers merged in order: files, environment variables and `key.path=value` overrides.
+/// Later layers take precedence over earlier ones.
+#[derive(Clone, Debug)]
+pub struct LayeredConfig(Value);
+
+impl Default for LayeredConfig {
+    fn default() -> Self {
+        Self(Value::Table(Table::new()))
+    }
+}
+
+impl LayeredConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merges a TOML configuration file on top of the current layers.
+    pub fn with_file<P: AsRef<Path>>(mut self, path: P) -> Result<Self, Error> {
+        let value = toml::from_str::<Value>(&fs::read_to_string(path)?)?;
+
+        merge(&mut self.0, value);
+
+        Ok(self)
+    }
+
+    /// Applies the variables starting with `BEE_`, with path segments separated by `__` and lowercased.
+    /// A variable naming a path under an unknown section of the node config is rejected, while other unknown variables,
+    /// like `BEE_HOME`, are ignored.
+    pub fn with_env_vars<I: IntoIterator<Item = (String, String)>>(mut self, vars: I) -> Result<Self, Error> {
+        for (name, value) in vars {
+            if let Some(path) = name.strip_prefix(ENV_PREFIX) {
+                let path = path
+                    .split(ENV_SEPARATOR)
+                    .map(|segment| segment.to_lowercase())
+                    .collect::<Vec<_>>();
+
+                if !CONFIG_KEYS.contains(&path[0].as_str()) {
+                    if path.len() > 1 {
+                        return Err(Error::UnknownEnvVar(name.clone()));
+                    }
+                    warn!(
+                        "Ignoring environment variable {}, which is not a configuration value.",
+                        name
+                    );
+                    continue;
+                }
+                if path.iter().any(String::is_empty) {
+                    return Err(Error::InvalidOverride(name.clone()));
+                }
+
+                set(&mut self.0, &path, parse_value(&value)).map_err(|_| Error::InvalidOverride(name.clone()))?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Applies a `key.path=value` override, the value being parsed as TOML and falling back to a string.
+    pub fn with_override(mut self, expression: &str) -> Result<Self, Error> {
+        let (path, value) = match expression.split_once('=') {
+            Some((path, value)) if !path.trim().is_empty() => (path.trim(), value.trim()),
+            _ => return Err(Error::InvalidOverride(expression.to_owned())),
+        };
+        let path = path.split('.').map(str::to_owned).collect::<Vec<_>>();
+
+        set(&mut self.0, &path, parse_value(value)).map_err(|_| Error::InvalidOverride(expression.to_owned()))?;
+
+        Ok(self)
+    }
+
+    /// Sets the level of the stdout logger output, as the `--log-level` argument does.
+    pub fn with_log_level(mut self, level: LevelFilter) -> Result<Self, Error> {
+        let level_filter = Value::String(level.to_string().to_lowercase());
+        let outputs = self
+            .0
+            .as_table_mut()
+            .and_then(|config| {
+                config
+                    .entry("logger")
+                    .or_insert_with(|| Value::Table(Table::new()))
+                    .as_table_mut()
+            })
+            .and_then(|logger| {
+                logger
+                    .entry("outputs")
+                    .or_insert_with(|| Value::Array(Vec::new()))
+                    .as_array_mut()
+            })
+            .ok_or_else(|| Error::InvalidOverride("--log-level".to_owned()))?;
+
+        match outputs
+            .iter_mut()
+            .filter_map(Value::as_table_mut)
+            .find(|output| output.get("name").and_then(Value::as_str) == Some(LOGGER_STDOUT_NAME))
+        {
+            Some(output) => {
+                output.insert("level_filter".to_owned(), level_filter);
+            }
+            None => {
+                let mut output = Table::new();
+                output.insert("name".to_owned(), Value::String(LOGGER_STDOUT_NAME.to_owned()));
+                output.insert("level_filter".to_owned(), level_filter);
+                outputs.push(Value::Table(output));
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Fills the values of the node itself that are missing with their defaults.
+    pub fn with_defaults(mut self) -> Self {
+        for (key, default) in [
+            ("alias", DEFAULT_ALIAS),
+            ("bech32_hrp", DEFAULT_BECH32_HRP),
+            ("network_id", DEFAULT_NETWORK_ID),
+        ] {
+            if let Some(config) = self.0.as_table_mut() {
+                config.entry(key).or_insert_with(|| Value::String(default.to_owned()));
+            }
+        }
+
+        self
+    }
+
+    /// Returns the merged configuration as TOML, with secrets redacted.
+    pub fn redacted(&self) -> String {
+        let mut value = self.0.clone();
+
+        redact(&mut value);
+
+        // Serializing a value that was deserialized from TOML can't fail.
+        toml::to_string_pretty(&value).unwrap_or_default()
+    }
+
+    /// Returns the value at a dotted path, e.g. `rest_api.allowed_ips`.
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        path.split('.').try_fold(&self.0, |value, key| value.get(key))
+    }
+
+    /// Returns the dotted paths of the values that differ from the other configuration, down to the leaf values so that
+    /// a section only present in one of them is reported key by key. Arrays are compared as a whole.
+    pub fn diff(&self, other: &Self) -> Vec<String> {
+        let mut paths = Vec::new();
+
+        diff(Some(&self.0), Some(&other.0), "", &mut paths);
+
+        paths
+    }
+
+    /// Sets the value at a dotted path to the one of the other configuration, removing it if the other has none.
+    pub fn apply(&mut self, other: &Self, path: &str) {
+        let segments = path.split('.').map(str::to_owned).collect::<Vec<_>>();
+
+        match other.get(path) {
+            Some(value) => {
+                // Paths come from a diff of tables so setting can't fail.
+                let _ = set(&mut self.0, &segments, value.clone());
+            }
+            None => {
+                if let Some((key, parents)) = segments.split_last() {
+                    if let Some(table) = parents
+                        .iter()
+                        .try_fold(&mut self.0, |value, parent| value.get_mut(parent.as_str()))
+                        .and_then(Value::as_table_mut)
+                    {
+                        table.remove(key);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn into_builder<B: StorageBackend>(self) -> Result<NodeConfigBuilder<B>, Error> {
+        self.0.try_into().map_err(Error::NodeConfigBuilderCreationFailure)
+    }
+}
+
+fn merge(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Table(base), Value::Table(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+fn diff(a: Option<&Value>, b: Option<&Value>, path: &str, paths: &mut Vec<String>) {
+    let empty = Table::new();
+
+    match (a, b) {
+        (Some(Value::Table(a)), Some(Value::Table(b))) => diff_tables(a, b, path, paths),
+        // A missing table is compared as an empty one to report its leaf values.
+        (Some(Value::Table(a)), None) => diff_tables(a, &empty, path, paths),
+        (None, Some(Value::Table(b))) => diff_tables(&empty, b, path, paths),
+        (a, b) if a != b => paths.push(path.to_owned()),
+        _ => {}
+    }
+}
+
+fn diff_tables(a: &Table, b: &Table, path: &str, paths: &mut Vec<String>) {
+    for key in a.keys().chain(b.keys().filter(|key| !a.contains_key(*key))) {
+        let child = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", path, key)
+        };
+
+        diff(a.get(key), b.get(key), &child, paths);
+    }
+}
+
+fn set(base: &mut Value, path: &[String], value: Value) -> Result<(), ()> {
+    match path.split_first() {
+        Some((key, [])) => {
+            base.as_table_mut().ok_or(())?.insert(key.clone(), value);
+            Ok(())
+        }
+        Some((key, rest)) => set(
+            base.as_table_mut()
+                .ok_or(())?
+                .entry(key.clone())
+                .or_insert_with(|| Value::Table(Table::new())),
+            rest,
+            value,
+        ),
+        None => Err(()),
+    }
+}
+
+fn parse_value(value: &str) -> Value {
+    toml::from_str::<Table>(&format!("value = {}", value))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(value.to_owned()))
+}
+
+fn redact(value: &mut Value) {
+    match value {
+        Value::Table(table) => {
+            for (key, value) in table.iter_mut() {
+                if REDACTED_KEYS.contains(&key.as_str()) || REDACTED_KEY_PARTS.iter().any(|part| key.contains(part)) {
+                    *value = Value::String(REDACTED.to_owned());
+                } else {
+                    redact(value);
+                }
+            }
+        }
+        Value::Array(array) => array.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(toml: &str) -> LayeredConfig {
+        LayeredConfig(toml::from_str::<Value>(toml).unwrap())
+    }
+
+    fn env_vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn merge_overrides_values_and_keeps_the_others() {
+        let mut base =
+            toml::from_str::<Value>("[rest_api]\nbinding_port = 14265\nfeature_proof_of_work = true").unwrap();
+        let layer = toml::from_str::<Value>("alias = \"node\"\n[rest_api]\nbinding_port = 14266").unwrap();
+
+        merge(&mut base, layer);
+
+        assert_eq!(
+            base,
+            toml::from_str::<Value>("alias = \"node\"\n[rest_api]\nbinding_port = 14266\nfeature_proof_of_work = true")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn merge_replaces_arrays_as_a_whole() {
+        let mut base = toml::from_str::<Value>("[rest_api]\nallowed_ips = [\"127.0.0.1\", \"::1\"]").unwrap();
+        let layer = toml::from_str::<Value>("[rest_api]\nallowed_ips = [\"0.0.0.0\"]").unwrap();
+
+        merge(&mut base, layer);
+
+        assert_eq!(
+            base,
+            toml::from_str::<Value>("[rest_api]\nallowed_ips = [\"0.0.0.0\"]").unwrap()
+        );
+    }
+
+    #[test]
+    fn set_creates_missing_tables() {
+        let mut base = Value::Table(Table::new());
+
+        set(
+            &mut base,
+            &["rest_api".to_owned(), "binding_port".to_owned()],
+            Value::Integer(14266),
+        )
+        .unwrap();
+
+        assert_eq!(
+            base,
+            toml::from_str::<Value>("[rest_api]\nbinding_port = 14266").unwrap()
+        );
+    }
+
+    #[test]
+    fn set_below_a_value_fails() {
+        let mut base = toml::from_str::<Value>("alias = \"node\"").unwrap();
+
+        assert!(set(
+            &mut base,
+            &["alias".to_owned(), "name".to_owned()],
+            Value::Boolean(true)
+        )
+        .is_err());
+        assert!(set(&mut base, &[], Value::Boolean(true)).is_err());
+    }
+
+    #[test]
+    fn parse_value_parses_toml_and_falls_back_to_strings() {
+        assert_eq!(parse_value("14266"), Value::Integer(14266));
+        assert_eq!(parse_value("true"), Value::Boolean(true));
+        assert_eq!(
+            parse_value("[\"127.0.0.1\"]"),
+            Value::Array(vec![Value::String("127.0.0.1".to_owned())])
+        );
+        assert_eq!(parse_value("\"node\""), Value::String("node".to_owned()));
+        assert_eq!(parse_value("node"), Value::String("node".to_owned()));
+    }
+
+    #[test]
+    fn redact_hides_secrets_at_any_depth() {
+        let mut value = toml::from_str::<Value>(
+            "identity = \"key\"\n[dashboard.auth]\nuser = \"admin\"\npassword_hash = \"hash\"\npassword_salt = \"salt\"\n\
+             [[mqtt.brokers]]\napi_token = \"token\"",
+        )
+        .unwrap();
+
+        redact(&mut value);
+
+        assert_eq!(
+            value,
+            toml::from_str::<Value>(
+                "identity = \"<redacted>\"\n[dashboard.auth]\nuser = \"admin\"\npassword_hash = \"<redacted>\"\n\
+                 password_salt = \"<redacted>\"\n[[mqtt.brokers]]\napi_token = \"<redacted>\""
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn diff_reports_changed_added_and_removed_paths() {
+        let old = config("alias = \"node\"\n[rest_api]\nbinding_port = 14265\nallowed_ips = [\"::1\"]");
+        let new = config("network_id = \"testnet\"\n[rest_api]\nbinding_port = 14265\nallowed_ips = [\"0.0.0.0\"]");
+
+        let mut paths = old.diff(&new);
+        paths.sort();
+
+        assert_eq!(paths, vec!["alias", "network_id", "rest_api.allowed_ips"]);
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn diff_reports_the_leaf_values_of_added_and_removed_sections() {
+        let old = config("[dashboard]\nport = 8081");
+        let new = config("[rest_api]\nallowed_ips = [\"::1\"]\n[rest_api.jwt]\nsalt = \"salt\"");
+
+        let mut paths = old.diff(&new);
+        paths.sort();
+
+        assert_eq!(
+            paths,
+            vec!["dashboard.port", "rest_api.allowed_ips", "rest_api.jwt.salt"]
+        );
+    }
+
+    #[test]
+    fn apply_sets_and_removes_diffed_paths() {
+        let mut old = config("alias = \"node\"\n[dashboard]\nport = 8081");
+        let new = config("[rest_api]\nallowed_ips = [\"::1\"]\n[dashboard]\nport = 8082");
+
+        for path in old.diff(&new) {
+            old.apply(&new, &path);
+        }
+
+        assert!(old.diff(&new).is_empty());
+        assert_eq!(old.get("alias"), None);
+    }
+
+    #[test]
+    fn env_vars_override_values() {
+        let config = LayeredConfig::new()
+            .with_env_vars(env_vars(&[
+                ("BEE_REST_API__BINDING_PORT", "14266"),
+                ("BEE_ALIAS", "node"),
+                ("PATH", "/usr/bin"),
+            ]))
+            .unwrap();
+
+        assert_eq!(config.get("rest_api.binding_port"), Some(&Value::Integer(14266)));
+        assert_eq!(config.get("alias"), Some(&Value::String("node".to_owned())));
+        assert_eq!(config.get("path"), None);
+    }
+
+    #[test]
+    fn unknown_env_vars_are_rejected() {
+        assert!(matches!(
+            LayeredConfig::new().with_env_vars(env_vars(&[("BEE_REST_APII__BINDING_PORT", "14266")])),
+            Err(Error::UnknownEnvVar(name)) if name == "BEE_REST_APII__BINDING_PORT"
+        ));
+        assert!(matches!(
+            LayeredConfig::new().with_env_vars(env_vars(&[("BEE_REST_API____BINDING_PORT", "14266")])),
+            Err(Error::InvalidOverride(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_env_vars_without_path_are_ignored() {
+        let config = LayeredConfig::new()
+            .with_env_vars(env_vars(&[("BEE_HOME", "/opt/bee"), ("BEE_VERSION", "0.1.0")]))
+            .unwrap();
+
+        assert_eq!(config.get("home"), None);
+        assert_eq!(config.get("version"), None);
+    }
+
+    #[test]
+    fn overrides_are_applied_last() {
+        let config = LayeredConfig::new()
+            .with_env_vars(env_vars(&[("BEE_REST_API__BINDING_PORT", "14266")]))
+            .unwrap()
+            .with_override("rest_api.binding_port=14267")
+            .unwrap();
+
+        assert_eq!(config.get("rest_api.binding_port"), Some(&Value::Integer(14267)));
+        assert!(matches!(
+            LayeredConfig::new().with_override("=14267"),
+            Err(Error::InvalidOverride(_))
+        ));
+    }
+
+    #[test]
+    fn log_level_sets_the_stdout_output() {
+        let stdout_level = |config: &LayeredConfig| {
+            config
+                .get("logger.outputs")
+                .and_then(Value::as_array)
+                .and_then(|outputs| {
+                    outputs
+                        .iter()
+                        .find(|output| output.get("name").and_then(Value::as_str) == Some(LOGGER_STDOUT_NAME))
+                })
+                .and_then(|output| output.get("level_filter"))
+                .cloned()
+        };
+        let config = config(&format!(
+            "[[logger.outputs]]\nname = \"{}\"\nlevel_filter = \"info\"\n[[logger.outputs]]\nname = \"file\"",
+            LOGGER_STDOUT_NAME
+        ))
+        .with_log_level(LevelFilter::Debug)
+        .unwrap();
+
+        assert_eq!(stdout_level(&config), Some(Value::String("debug".to_owned())));
+        assert_eq!(
+            config.get("logger.outputs").and_then(Value::as_array).map(Vec::len),
+            Some(2)
+        );
+
+        let config = LayeredConfig::new().with_log_level(LevelFilter::Warn).unwrap();
+
+        assert_eq!(stdout_level(&config), Some(Value::String("warn".to_owned())));
+    }
+
+    #[test]
+    fn defaults_fill_missing_values_only() {
+        let config = config("alias = \"node\"").with_defaults();
+
+        assert_eq!(config.get("alias"), Some(&Value::String("node".to_owned())));
+        assert_eq!(
+            config.get("network_id"),
+            Some(&Value::String(DEFAULT_NETWORK_ID.to_owned()))
+        );
+    }
+}
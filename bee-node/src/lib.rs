@@ -8,12 +8,12 @@ mod cli;
 mod config;
 mod constants;
 mod node;
-mod storage;
+pub mod storage;
 
 pub mod plugins;
 pub mod tools;
 
 pub use banner::print_banner_and_version;
 pub use cli::CliArgs;
-pub use config::NodeConfigBuilder;
-pub use node::{BeeNode as Node, BeeNodeBuilder as NodeBuilder, Error};
+pub use config::{LayeredConfig, NodeConfigBuilder, ValidationError, ValidationErrors, DEFAULT_CONFIG_PATH};
+pub use node::{BeeNode as Node, BeeNodeBuilder as NodeBuilder, Error, SupervisionPolicy, WorkerFailed};
@@ -2,29 +2,74 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_common::logger::logger_init;
-use bee_node::{plugins, print_banner_and_version, tools, CliArgs, NodeBuilder, NodeConfigBuilder};
+use bee_node::{
+    plugins, print_banner_and_version,
+    storage::Backend,
+    tools::{self, Tool},
+    CliArgs, NodeBuilder, DEFAULT_CONFIG_PATH,
+};
 use bee_runtime::node::NodeBuilder as _;
-use bee_storage_rocksdb::storage::Storage as Rocksdb;
 
 use log::error;
 
-const CONFIG_PATH: &str = "./config.toml";
-
 #[tokio::main(flavor = "multi_thread")]
 async fn main() {
     let cli = CliArgs::new();
 
-    let config = match NodeConfigBuilder::from_file(cli.config().unwrap_or(&CONFIG_PATH.to_owned())) {
-        Ok(builder) => builder.with_cli_args(cli.clone()).finish(),
-        Err(e) => panic!("Failed to create the node config builder: {}", e),
+    // Checking the config reports its problems, so it can't require a valid config to run.
+    if let Some(tool @ Tool::Config(_)) = cli.tool() {
+        if let Err(e) = tools::exec(tool, &cli) {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let layered_config = match cli.layered_config(DEFAULT_CONFIG_PATH) {
+        Ok(layered_config) => layered_config,
+        Err(e) => panic!("Failed to load the node config: {}", e),
     };
 
+    let config = match layered_config.clone().into_builder::<Backend>() {
+        Ok(builder) => match builder.with_cli_args(cli.clone()).finish() {
+            Ok(config) => config,
+            Err(errors) => {
+                for error in errors.0.iter() {
+                    eprintln!("{}", error);
+                }
+                eprintln!("Invalid node config: found {} problem(s).", errors.0.len());
+                std::process::exit(1);
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed to create the node config builder: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // The config is printed once validated, with the defaults of the node and the `--log-level` override.
+    if cli.print_config() {
+        let effective_config = match cli.log_level() {
+            Some(level) => layered_config.clone().with_log_level(*level),
+            None => Ok(layered_config.clone()),
+        };
+
+        match effective_config {
+            Ok(effective_config) => print!("{}", effective_config.with_defaults().redacted()),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     if let Err(e) = logger_init(config.logger.clone()) {
         panic!("Failed to initialise the logger: {}", e);
     }
 
     if let Some(tool) = cli.tool() {
-        if let Err(e) = tools::exec(tool) {
+        if let Err(e) = tools::exec(tool, &cli) {
             error!("Tool execution failed: {}", e);
         }
         return;
@@ -36,8 +81,13 @@ async fn main() {
         return;
     }
 
-    match NodeBuilder::<Rocksdb>::new(config) {
-        Ok(builder) => match builder.with_plugin::<plugins::Mps>().finish().await {
+    match NodeBuilder::<Backend>::new(config) {
+        Ok(builder) => match builder
+            .with_config_reload(cli, layered_config)
+            .with_plugin::<plugins::Mps>()
+            .finish()
+            .await
+        {
             Ok(node) => {
                 if let Err(e) = node.run().await {
                     error!("Failed to run node: {}", e)
@@ -3,15 +3,29 @@
 
 #[cfg(feature = "dashboard")]
 use crate::plugins::Dashboard;
+#[cfg(unix)]
+use crate::plugins::PluginHost;
 
 use crate::{
-    config::NodeConfig,
+    cli::CliArgs,
+    config::{LayeredConfig, NodeConfig},
     constants::{BEE_GIT_COMMIT, BEE_VERSION},
-    node::{BeeNode, Error},
+    node::{reload::ConfigReloadWorker, BeeNode, Error, SupervisionPolicy, WorkerRestart},
     plugins::{self, Mqtt, VersionChecker},
     storage::StorageBackend,
 };
 
+use bee_ledger::types::KeyManager;
+use bee_rest_api::{
+    endpoints::routes::{
+        api::v1::{
+            config_reload::{ConfigReloadRequest, ConfigReloader},
+            info::UpdateStatus,
+        },
+        health::WorkerStatuses,
+    },
+    types::dtos::WorkerStatusDto,
+};
 use bee_runtime::{
     event::Bus,
     node::{Node, NodeBuilder, NodeInfo},
@@ -28,18 +42,26 @@ use log::{debug, info, warn};
 use futures::future::select_all;
 #[cfg(unix)]
 use tokio::signal::unix::{signal, Signal, SignalKind};
+use tokio::sync::mpsc;
 
 use std::{
     any::{type_name, Any, TypeId},
     collections::{HashMap, HashSet},
     marker::PhantomData,
     pin::Pin,
+    time::Duration,
 };
 
-type WorkerStart<N> = dyn for<'a> FnOnce(&'a mut N) -> Pin<Box<dyn Future<Output = ()> + 'a>>;
-type WorkerStop<N> = dyn for<'a> FnOnce(&'a mut N) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> + Send;
+type WorkerStart<N> = dyn for<'a> FnOnce(&'a mut N) -> Pin<Box<dyn Future<Output = Result<(), String>> + 'a>>;
+type WorkerStop<N> =
+    dyn for<'a> FnOnce(&'a mut N) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> + Send;
 type ResourceRegister<N> = dyn for<'a> FnOnce(&'a mut N);
 
+const PLUGIN_SUPERVISION_POLICY: SupervisionPolicy = SupervisionPolicy::Restart {
+    max_restarts: 5,
+    backoff: Duration::from_secs(1),
+};
+
 fn shutdown_procedure(sender: oneshot::Sender<()>) {
     warn!("Gracefully shutting down the node, this may take some time.");
 
@@ -90,8 +112,11 @@ pub struct BeeNodeBuilder<B: StorageBackend> {
     deps: HashMap<TypeId, &'static [TypeId], FxBuildHasher>,
     worker_starts: HashMap<TypeId, Box<WorkerStart<BeeNode<B>>>>,
     worker_stops: HashMap<TypeId, Box<WorkerStop<BeeNode<B>>>>,
+    worker_restarts: HashMap<TypeId, Box<WorkerRestart<BeeNode<B>>>>,
+    worker_policies: HashMap<TypeId, SupervisionPolicy>,
     worker_names: HashMap<TypeId, &'static str>,
     resource_registers: Vec<Box<ResourceRegister<BeeNode<B>>>>,
+    config_reload_receiver: Option<mpsc::UnboundedReceiver<ConfigReloadRequest>>,
     config: NodeConfig<B>,
 }
 
@@ -110,6 +135,47 @@ impl<B: StorageBackend> BeeNodeBuilder<B> {
     pub fn with_plugin_cfg<P: plugins::Plugin>(self, config: P::Config) -> Self {
         self.with_worker_cfg::<plugins::PluginWorker<P>>(config)
     }
+
+    /// Adds a worker whose tasks are supervised according to the given policy.
+    /// With a restart policy, the worker is restarted with a clone of its configuration.
+    pub fn with_supervised_worker_cfg<W: Worker<BeeNode<B>> + 'static>(
+        mut self,
+        config: W::Config,
+        policy: SupervisionPolicy,
+    ) -> Self
+    where
+        W::Config: Clone + Send + 'static,
+    {
+        if let SupervisionPolicy::Restart { .. } = policy {
+            let restart_config = config.clone();
+            self.worker_restarts.insert(
+                TypeId::of::<W>(),
+                Box::new(move |node| {
+                    let config = restart_config.clone();
+                    Box::pin(async move {
+                        if let Some(worker) = node.remove_worker::<W>() {
+                            worker.stop(node).await.map_err(|e| format!("{:?}", e))?;
+                        }
+                        debug!("Starting worker {}...", type_name::<W>());
+                        let worker = W::start(node, config).await.map_err(|e| format!("{:?}", e))?;
+                        node.add_worker(worker);
+                        Ok(())
+                    })
+                }),
+            );
+        }
+        self.worker_policies.insert(TypeId::of::<W>(), policy);
+        self.with_worker_cfg::<W>(config)
+    }
+
+    /// Reloads the configuration layered from the CLI arguments on `SIGHUP` or on request of the REST API.
+    /// Without it, reload requests of the REST API fail.
+    pub fn with_config_reload(mut self, cli: CliArgs, layered_config: LayeredConfig) -> Self {
+        match self.config_reload_receiver.take() {
+            Some(receiver) => self.with_worker_cfg::<ConfigReloadWorker<B>>((cli, layered_config, receiver)),
+            None => self,
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -128,15 +194,23 @@ impl<B: StorageBackend> NodeBuilder<BeeNode<B>> for BeeNodeBuilder<B> {
             version,
         };
 
+        let (config_reload_sender, config_reload_receiver) = mpsc::unbounded_channel();
+
         Ok(Self {
             deps: HashMap::default(),
             worker_starts: HashMap::default(),
             worker_stops: HashMap::default(),
+            worker_restarts: HashMap::default(),
+            worker_policies: HashMap::default(),
             worker_names: HashMap::default(),
             resource_registers: Vec::default(),
+            config_reload_receiver: Some(config_reload_receiver),
             config: config.clone(),
         }
         .with_resource(node_info)
+        .with_resource(WorkerStatuses::default())
+        .with_resource(UpdateStatus::default())
+        .with_resource(ConfigReloader::new(config_reload_sender))
         // TODO block ? Make new async ?
         .with_resource(
             futures::executor::block_on(B::start(config.storage)).map_err(|e| Error::StorageBackend(Box::new(e)))?,
@@ -158,10 +232,9 @@ impl<B: StorageBackend> NodeBuilder<BeeNode<B>> for BeeNodeBuilder<B> {
             Box::new(|node| {
                 Box::pin(async move {
                     debug!("Starting worker {}...", type_name::<W>());
-                    match W::start(node, config).await {
-                        Ok(w) => node.add_worker(w),
-                        Err(e) => panic!("Worker `{}` failed to start: {:?}.", type_name::<W>(), e),
-                    }
+                    let worker = W::start(node, config).await.map_err(|e| format!("{:?}", e))?;
+                    node.add_worker(worker);
+                    Ok(())
                 })
             }),
         );
@@ -169,10 +242,11 @@ impl<B: StorageBackend> NodeBuilder<BeeNode<B>> for BeeNodeBuilder<B> {
             TypeId::of::<W>(),
             Box::new(|node| {
                 Box::pin(async move {
-                    match node.remove_worker::<W>().stop(node).await {
-                        Ok(()) => {}
-                        Err(e) => panic!("Worker `{}` failed to stop: {:?}.", type_name::<W>(), e),
+                    // A worker that failed to restart is no longer part of the node.
+                    if let Some(worker) = node.remove_worker::<W>() {
+                        worker.stop(node).await.map_err(|e| format!("{:?}", e))?;
                     }
+                    Ok(())
                 })
             }),
         );
@@ -220,8 +294,18 @@ impl<B: StorageBackend> NodeBuilder<BeeNode<B>> for BeeNodeBuilder<B> {
         let this = this.with_resource(shutdown_listener());
 
         info!("Initializing ledger...");
-        let this =
-            bee_ledger::workers::init::<BeeNode<B>>(this, network_id, config.snapshot.clone(), config.pruning.clone());
+        let key_manager = KeyManager::new(
+            config.protocol.coordinator().public_key_count(),
+            config.protocol.coordinator().public_key_ranges().into(),
+        );
+        let this = bee_ledger::workers::init::<BeeNode<B>>(
+            this,
+            network_id,
+            config.ledger.clone(),
+            config.snapshot.clone(),
+            config.pruning.clone(),
+            key_manager,
+        );
 
         info!("Initializing protocol layer...");
         let this = bee_protocol::workers::init::<BeeNode<B>>(config.protocol.clone(), network_id, events, this);
@@ -230,6 +314,7 @@ impl<B: StorageBackend> NodeBuilder<BeeNode<B>> for BeeNodeBuilder<B> {
         let this = bee_rest_api::endpoints::init::<BeeNode<B>>(
             config.rest_api.clone(),
             config.protocol.clone(),
+            config.ledger.clone(),
             config.network_id.clone(),
             config.bech32_hrp.clone(),
             this,
@@ -239,18 +324,32 @@ impl<B: StorageBackend> NodeBuilder<BeeNode<B>> for BeeNodeBuilder<B> {
         info!("Initializing tangle...");
         let this = bee_tangle::init::<BeeNode<B>>(&config.tangle, this);
 
-        let mut this = this.with_worker::<VersionChecker>();
-        this = this.with_worker_cfg::<Mqtt>(config.mqtt);
+        // Plugins aren't needed for the node to operate, so a failure of theirs doesn't stop it.
+        let mut this =
+            this.with_supervised_worker_cfg::<VersionChecker>(config.version_checker, SupervisionPolicy::Degrade);
+        this = this.with_supervised_worker_cfg::<Mqtt>(config.mqtt, PLUGIN_SUPERVISION_POLICY);
         #[cfg(feature = "dashboard")]
         {
-            this = this.with_worker_cfg::<Dashboard>(config.dashboard);
+            this = this.with_supervised_worker_cfg::<Dashboard>(config.dashboard, PLUGIN_SUPERVISION_POLICY);
+        }
+        #[cfg(unix)]
+        {
+            this = this.with_supervised_worker_cfg::<PluginHost>(config.plugin_host, PLUGIN_SUPERVISION_POLICY);
         }
 
+        let (task_exit_sender, task_exit_receiver) = mpsc::unbounded_channel();
+
         let mut node = BeeNode {
             workers: Map::new(),
             tasks: HashMap::new(),
+            next_task_id: 0,
+            task_exit_sender,
+            task_exit_receiver: Some(task_exit_receiver),
             resources: Map::new(),
             worker_stops: this.worker_stops,
+            worker_restarts: this.worker_restarts,
+            worker_policies: this.worker_policies,
+            worker_restart_counts: HashMap::new(),
             worker_order: TopologicalOrder::sort(this.deps),
             worker_names: this.worker_names,
             phantom: PhantomData,
@@ -272,7 +371,14 @@ impl<B: StorageBackend> NodeBuilder<BeeNode<B>> for BeeNodeBuilder<B> {
         }
 
         for id in node.worker_order.clone() {
-            this.worker_starts.remove(&id).unwrap()(&mut node).await;
+            // Unwrap is fine since id is from the list of workers.
+            let worker_name = *node.worker_names.get(&id).unwrap();
+
+            this.worker_starts.remove(&id).unwrap()(&mut node)
+                .await
+                .map_err(|e| Error::WorkerStartFailed(worker_name, e))?;
+            node.resource::<WorkerStatuses>()
+                .set(worker_name, WorkerStatusDto::Running);
         }
 
         info!("Initialized.");
@@ -324,3 +430,68 @@ impl TopologicalOrder {
         this.order
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Consensus;
+    struct Solidifier;
+    struct Processor;
+    struct Api;
+
+    fn position(order: &[TypeId], id: TypeId) -> usize {
+        order.iter().position(|&other| other == id).unwrap()
+    }
+
+    fn order() -> Vec<TypeId> {
+        let mut graph = HashMap::default();
+
+        graph.insert(TypeId::of::<Consensus>(), &[][..]);
+        graph.insert(
+            TypeId::of::<Solidifier>(),
+            vec![TypeId::of::<Consensus>()].leak() as &[_],
+        );
+        graph.insert(
+            TypeId::of::<Processor>(),
+            vec![TypeId::of::<Solidifier>(), TypeId::of::<Consensus>()].leak() as &[_],
+        );
+        graph.insert(TypeId::of::<Api>(), vec![TypeId::of::<Processor>()].leak() as &[_]);
+
+        TopologicalOrder::sort(graph)
+    }
+
+    #[test]
+    fn dependencies_start_first() {
+        let order = order();
+
+        assert_eq!(order.len(), 4);
+        assert!(position(&order, TypeId::of::<Consensus>()) < position(&order, TypeId::of::<Solidifier>()));
+        assert!(position(&order, TypeId::of::<Solidifier>()) < position(&order, TypeId::of::<Processor>()));
+        assert!(position(&order, TypeId::of::<Processor>()) < position(&order, TypeId::of::<Api>()));
+    }
+
+    #[test]
+    fn processor_stops_before_consensus() {
+        let stop_order = order().into_iter().rev().collect::<Vec<_>>();
+
+        assert!(position(&stop_order, TypeId::of::<Processor>()) < position(&stop_order, TypeId::of::<Consensus>()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cyclic dependency detected.")]
+    fn cyclic_dependencies_are_detected() {
+        let mut graph = HashMap::default();
+
+        graph.insert(
+            TypeId::of::<Processor>(),
+            vec![TypeId::of::<Consensus>()].leak() as &[_],
+        );
+        graph.insert(
+            TypeId::of::<Consensus>(),
+            vec![TypeId::of::<Processor>()].leak() as &[_],
+        );
+
+        TopologicalOrder::sort(graph);
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+
+use std::{collections::HashMap, time::Duration};
+
+const DEFAULT_TIMEOUT: u64 = 60;
+const DEFAULT_WORKER_TIMEOUT: u64 = 20;
+
+#[derive(Default, Deserialize)]
+pub struct ShutdownConfigBuilder {
+    timeout: Option<u64>,
+    worker_timeout: Option<u64>,
+    worker_timeouts: Option<HashMap<String, u64>>,
+}
+
+impl ShutdownConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn timeout(mut self, timeout: u64) -> Self {
+        self.timeout.replace(timeout);
+        self
+    }
+
+    pub fn worker_timeout(mut self, worker_timeout: u64) -> Self {
+        self.worker_timeout.replace(worker_timeout);
+        self
+    }
+
+    pub fn finish(self) -> ShutdownConfig {
+        ShutdownConfig {
+            timeout: Duration::from_secs(self.timeout.unwrap_or(DEFAULT_TIMEOUT)),
+            worker_timeout: Duration::from_secs(self.worker_timeout.unwrap_or(DEFAULT_WORKER_TIMEOUT)),
+            worker_timeouts: self
+                .worker_timeouts
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(worker, timeout)| (worker, Duration::from_secs(timeout)))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ShutdownConfig {
+    timeout: Duration,
+    worker_timeout: Duration,
+    worker_timeouts: HashMap<String, Duration>,
+}
+
+impl ShutdownConfig {
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the stop deadline of a worker, overridden by its full type name or the last segment of its path.
+    pub fn worker_timeout(&self, worker: &str) -> Duration {
+        self.worker_timeouts
+            .iter()
+            .find(|(name, _)| worker == name.as_str() || worker.ends_with(&format!("::{}", name)))
+            .map_or(self.worker_timeout, |(_, timeout)| *timeout)
+    }
+}
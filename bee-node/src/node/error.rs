@@ -15,4 +15,7 @@ pub enum Error {
 
     #[error("Network initialization failed. Cause: {}", .0)]
     NetworkInitializationFailed(#[from] bee_network::Error),
+
+    #[error("Worker `{0}` failed to start: {1}.")]
+    WorkerStartFailed(&'static str, String),
 }
@@ -2,19 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod builder;
+mod config;
 mod error;
+mod reload;
+mod supervision;
 
 pub use builder::BeeNodeBuilder;
+pub use config::{ShutdownConfig, ShutdownConfigBuilder};
 pub use error::Error;
+pub use supervision::{SupervisionPolicy, WorkerFailed};
 
 use crate::{config::NodeConfig, storage::StorageBackend};
+use supervision::{failure_reason, restart_delay, stability_window, wait_restart_delay, Restarts, TaskExit};
 
+use bee_rest_api::{endpoints::routes::health::WorkerStatuses, types::dtos::WorkerStatusDto};
 use bee_runtime::{event::Bus, node::Node, resource::ResourceHandle, worker::Worker};
 
 use anymap::{any::Any as AnyMapAny, Map};
 use async_trait::async_trait;
-use futures::{channel::oneshot, future::Future};
-use log::{debug, info, warn};
+use futures::{
+    channel::oneshot,
+    future::{self, AbortHandle, Either, Future},
+};
+use log::{debug, error, info, warn};
+use tokio::{
+    sync::mpsc,
+    task::{JoinError, JoinHandle},
+    time::{self, Instant},
+};
 
 use std::{
     any::{type_name, Any, TypeId},
@@ -22,23 +37,32 @@ use std::{
     marker::PhantomData,
     ops::Deref,
     pin::Pin,
+    time::Duration,
 };
 
-type WorkerStop<N> = dyn for<'a> FnOnce(&'a mut N) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> + Send;
+type WorkerStop<N> =
+    dyn for<'a> FnOnce(&'a mut N) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send + 'a>> + Send;
+pub(crate) type WorkerRestart<N> =
+    dyn for<'a> Fn(&'a mut N) -> Pin<Box<dyn Future<Output = Result<(), String>> + 'a>> + Send;
+
+struct Task {
+    id: u64,
+    shutdown: oneshot::Sender<()>,
+    abort: AbortHandle,
+    handle: JoinHandle<Result<(), JoinError>>,
+}
 
-#[allow(clippy::type_complexity)]
 pub struct BeeNode<B> {
     workers: Map<dyn AnyMapAny + Send + Sync>,
-    tasks: HashMap<
-        TypeId,
-        Vec<(
-            oneshot::Sender<()>,
-            // TODO Result ?
-            Box<dyn Future<Output = Result<(), tokio::task::JoinError>> + Send + Sync + Unpin>,
-        )>,
-    >,
+    tasks: HashMap<TypeId, Vec<Task>>,
+    next_task_id: u64,
+    task_exit_sender: mpsc::UnboundedSender<TaskExit>,
+    task_exit_receiver: Option<mpsc::UnboundedReceiver<TaskExit>>,
     resources: Map<dyn AnyMapAny + Send + Sync>,
     worker_stops: HashMap<TypeId, Box<WorkerStop<Self>>>,
+    worker_restarts: HashMap<TypeId, Box<WorkerRestart<Self>>>,
+    worker_policies: HashMap<TypeId, SupervisionPolicy>,
+    worker_restart_counts: HashMap<TypeId, Restarts>,
     worker_order: Vec<TypeId>,
     worker_names: HashMap<TypeId, &'static str>,
     phantom: PhantomData<B>,
@@ -49,28 +73,187 @@ impl<B: StorageBackend> BeeNode<B> {
         self.workers.insert(worker);
     }
 
-    fn remove_worker<W: Worker<Self> + Send + Sync>(&mut self) -> W {
-        self.workers
-            .remove()
-            .unwrap_or_else(|| panic!("Failed to remove worker `{}`", type_name::<W>()))
+    fn remove_worker<W: Worker<Self> + Send + Sync>(&mut self) -> Option<W> {
+        self.workers.remove()
     }
 
     pub fn config(&self) -> impl Deref<Target = NodeConfig<B>> + Clone {
         self.resource()
     }
 
+    fn set_worker_status(&self, worker_id: TypeId, status: WorkerStatusDto) {
+        // Unwrap is fine since worker_id is from the list of workers.
+        self.resource::<WorkerStatuses>()
+            .set(self.worker_names.get(&worker_id).unwrap(), status);
+    }
+
+    /// Signals the tasks of a worker to shut down and waits for them until the deadline, after which the remaining
+    /// ones are aborted. Returns whether all of them stopped in time.
+    async fn stop_tasks(&mut self, worker_id: TypeId, deadline: Instant) -> bool {
+        // Unwrap is fine since worker_id is from the list of workers.
+        let worker_name = *self.worker_names.get(&worker_id).unwrap();
+        let mut handles = Vec::new();
+        let mut stopped = true;
+
+        for task in self.tasks.remove(&worker_id).unwrap_or_default() {
+            let _ = task.shutdown.send(());
+            handles.push((task.abort, task.handle));
+        }
+
+        for (abort, mut handle) in handles {
+            match time::timeout_at(deadline, &mut handle).await {
+                Ok(Ok(Ok(()))) => {}
+                Ok(Ok(Err(e))) | Ok(Err(e)) => warn!("Task of worker {} failed: {}.", worker_name, e),
+                Err(_) => {
+                    handle.abort();
+                    abort.abort();
+                    stopped = false;
+                }
+            }
+        }
+
+        stopped
+    }
+
+    /// Applies the supervision policy of the worker whose task failed and returns whether the node should keep running.
+    async fn supervise(&mut self, exit: TaskExit, shutdown: &mut oneshot::Receiver<()>) -> bool {
+        // Exits of tasks that have since been stopped, e.g. by a restart of their worker, are stale.
+        if !self
+            .tasks
+            .get(&exit.worker)
+            .map_or(false, |tasks| tasks.iter().any(|task| task.id == exit.task))
+        {
+            return true;
+        }
+
+        // Unwrap is fine since the task was spawned by a worker of the list.
+        let worker_name = *self.worker_names.get(&exit.worker).unwrap();
+
+        error!("Worker {} failed: {}.", worker_name, exit.reason);
+        self.resource::<Bus>().dispatch(WorkerFailed {
+            worker: worker_name,
+            reason: exit.reason,
+        });
+
+        match self.worker_policies.get(&exit.worker).copied().unwrap_or_default() {
+            SupervisionPolicy::Restart { max_restarts, backoff } => {
+                self.restart(exit.worker, max_restarts, backoff, shutdown).await
+            }
+            SupervisionPolicy::Degrade => {
+                warn!("Worker {} is degraded.", worker_name);
+                self.set_worker_status(exit.worker, WorkerStatusDto::Degraded);
+                true
+            }
+            SupervisionPolicy::Stop => {
+                error!("Stopping the node because of the failure of worker {}.", worker_name);
+                self.set_worker_status(exit.worker, WorkerStatusDto::Stopped);
+                false
+            }
+        }
+    }
+
+    /// Restarts a worker until it succeeds or exceeds its restarts, and returns whether the node should keep running,
+    /// which it should not if it was asked to shut down while waiting to restart. The restarts of a worker that ran for
+    /// a stability window since its last restart are forgotten, so that only repeated failures degrade it.
+    async fn restart(
+        &mut self,
+        worker_id: TypeId,
+        max_restarts: usize,
+        backoff: Duration,
+        shutdown: &mut oneshot::Receiver<()>,
+    ) -> bool {
+        // Unwrap is fine since worker_id is from the list of workers.
+        let worker_name = *self.worker_names.get(&worker_id).unwrap();
+
+        self.worker_restart_counts
+            .entry(worker_id)
+            .or_default()
+            .forget_if_stable(Instant::now(), stability_window(backoff, max_restarts));
+
+        loop {
+            let restarts = self.worker_restart_counts.entry(worker_id).or_default();
+
+            if restarts.count() >= max_restarts {
+                warn!(
+                    "Worker {} exceeded its {} restarts and is degraded.",
+                    worker_name, max_restarts
+                );
+                self.set_worker_status(worker_id, WorkerStatusDto::Degraded);
+                return true;
+            }
+
+            let attempt = restarts.attempt();
+            let delay = restart_delay(backoff, attempt);
+
+            warn!(
+                "Restarting worker {} in {:?}, attempt {}/{}.",
+                worker_name, delay, attempt, max_restarts
+            );
+            self.set_worker_status(worker_id, WorkerStatusDto::Restarting);
+
+            let deadline = Instant::now() + self.config().shutdown.worker_timeout(worker_name);
+            if !self.stop_tasks(worker_id, deadline).await {
+                warn!(
+                    "Tasks of worker {} failed to stop in time and were aborted.",
+                    worker_name
+                );
+            }
+            self.resource::<Bus>().remove_listeners_by_id(worker_id);
+
+            if !wait_restart_delay(delay, shutdown).await {
+                debug!("Not restarting worker {} since the node is shutting down.", worker_name);
+                return false;
+            }
+
+            // Unwrap is fine since only workers with a restart policy are supervised this way.
+            let restart = self.worker_restarts.remove(&worker_id).unwrap();
+            let result = restart(self).await;
+            self.worker_restarts.insert(worker_id, restart);
+
+            match result {
+                Ok(()) => {
+                    info!("Restarted worker {}.", worker_name);
+                    self.worker_restart_counts
+                        .entry(worker_id)
+                        .or_default()
+                        .succeeded(Instant::now());
+                    self.set_worker_status(worker_id, WorkerStatusDto::Running);
+                    return true;
+                }
+                Err(e) => error!("Worker {} failed to restart: {}.", worker_name, e),
+            }
+        }
+    }
+
     #[allow(missing_docs)]
     pub async fn run(mut self) -> Result<(), Error> {
         info!("Running.");
 
         // Unwrapping is fine because the builder added this resource.
-        if let Err(e) = self.remove_resource::<oneshot::Receiver<()>>().unwrap().await {
-            warn!("Awaiting shutdown failed: {:?}", e);
+        let mut shutdown = self.remove_resource::<oneshot::Receiver<()>>().unwrap();
+        // Unwrapping is fine because the receiver is only taken here.
+        let mut task_exits = self.task_exit_receiver.take().unwrap();
+
+        loop {
+            tokio::select! {
+                result = &mut shutdown => {
+                    if let Err(e) = result {
+                        warn!("Awaiting shutdown failed: {:?}", e);
+                    }
+                    break;
+                }
+                // The node holds a sender so the channel can't be closed.
+                Some(exit) = task_exits.recv() => {
+                    if !self.supervise(exit, &mut shutdown).await {
+                        break;
+                    }
+                }
+            }
         }
 
         info!("Stopping...");
 
-        self.stop().await.expect("Failed to properly stop node");
+        self.stop().await?;
 
         info!("Stopped.");
 
@@ -85,19 +268,69 @@ impl<B: StorageBackend> Node for BeeNode<B> {
     type Error = Error;
 
     async fn stop(mut self) -> Result<(), Self::Error> {
+        let shutdown_config = self.config().shutdown.clone();
+        let deadline = Instant::now() + shutdown_config.timeout();
+        let mut timed_out = Vec::new();
+        let mut abandoned = Vec::new();
+        let mut failed = Vec::new();
+
+        // Workers are stopped in reverse topological order so that the ones feeding the ledger and the storage, like
+        // the processor, drain their in-flight messages before the ones they depend on stop.
         for worker_id in self.worker_order.clone().into_iter().rev() {
             // Unwrap is fine since worker_id is from the list of workers.
-            debug!("Stopping worker {}...", self.worker_names.get(&worker_id).unwrap());
-            for (shutdown, task_fut) in self.tasks.remove(&worker_id).unwrap_or_default() {
-                let _ = shutdown.send(());
-                // TODO: Should we handle this error?
-                let _ = task_fut.await;
+            let worker_name = *self.worker_names.get(&worker_id).unwrap();
+            // Once the shutdown deadline has passed, the tasks of the remaining workers are aborted right away.
+            let deadline_exceeded = Instant::now() >= deadline;
+            let worker_deadline = deadline.min(Instant::now() + shutdown_config.worker_timeout(worker_name));
+
+            debug!("Stopping worker {}...", worker_name);
+
+            let tasks_stopped = self.stop_tasks(worker_id, worker_deadline).await;
+            // Unwrap is fine since worker_id is from the list of workers.
+            let worker_stop = self.worker_stops.remove(&worker_id).unwrap();
+            let worker_stopped = match time::timeout_at(worker_deadline, worker_stop(&mut self)).await {
+                Ok(Ok(())) => true,
+                Ok(Err(e)) => {
+                    error!("Worker {} failed to stop: {}.", worker_name, e);
+                    failed.push(worker_name);
+                    true
+                }
+                Err(_) => false,
+            };
+
+            if !tasks_stopped || !worker_stopped {
+                if deadline_exceeded {
+                    abandoned.push(worker_name);
+                } else {
+                    warn!("Worker {} failed to stop in time.", worker_name);
+                    timed_out.push(worker_name);
+                }
             }
 
-            self.worker_stops.remove(&worker_id).unwrap()(&mut self).await;
             self.resource::<Bus>().remove_listeners_by_id(worker_id);
         }
 
+        if timed_out.is_empty() && abandoned.is_empty() && failed.is_empty() {
+            info!("All workers stopped in time.");
+        }
+        if !failed.is_empty() {
+            warn!("{} worker(s) failed to stop: {}.", failed.len(), failed.join(", "));
+        }
+        if !timed_out.is_empty() {
+            warn!(
+                "{} worker(s) failed to stop in time: {}.",
+                timed_out.len(),
+                timed_out.join(", ")
+            );
+        }
+        if !abandoned.is_empty() {
+            warn!(
+                "The shutdown deadline was exceeded, {} worker(s) were abandoned: {}.",
+                abandoned.len(),
+                abandoned.join(", ")
+            );
+        }
+
         // Unwrapping is fine since the node register the backend itself.
         self.remove_resource::<B>()
             .unwrap()
@@ -130,12 +363,45 @@ impl<B: StorageBackend> Node for BeeNode<B> {
         G: FnOnce(oneshot::Receiver<()>) -> F,
         F: Future<Output = ()> + Send + 'static,
     {
-        let (tx, rx) = oneshot::channel();
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let (task_shutdown_tx, task_shutdown_rx) = oneshot::channel();
+        let (task, abort) = future::abortable(g(task_shutdown_rx));
+        // An aborted task is one that failed to stop in time, which is reported by the shutdown itself.
+        let task = tokio::spawn(async move {
+            let _ = task.await;
+        });
+        let task_exit_sender = self.task_exit_sender.clone();
+        let worker = TypeId::of::<W>();
+        let id = self.next_task_id;
 
-        self.tasks
-            .entry(TypeId::of::<W>())
-            .or_default()
-            .push((tx, Box::new(tokio::spawn(g(rx)))));
+        self.next_task_id += 1;
+
+        // Watches the task: a shutdown is forwarded to it, while a panic is reported to the supervisor.
+        let handle = tokio::spawn(async move {
+            match future::select(shutdown_rx, task).await {
+                Either::Left((_, task)) => {
+                    let _ = task_shutdown_tx.send(());
+                    task.await
+                }
+                Either::Right((result, _)) => {
+                    if let Some(reason) = failure_reason(&result) {
+                        let _ = task_exit_sender.send(TaskExit {
+                            worker,
+                            task: id,
+                            reason,
+                        });
+                    }
+                    result
+                }
+            }
+        });
+
+        self.tasks.entry(worker).or_default().push(Task {
+            id,
+            shutdown: shutdown_tx,
+            abort,
+            handle,
+        });
     }
 
     fn worker<W>(&self) -> Option<&W>
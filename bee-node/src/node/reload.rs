@@ -0,0 +1,375 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    cli::CliArgs,
+    config::{LayeredConfig, DEFAULT_CONFIG_PATH},
+    node::BeeNode,
+    storage::StorageBackend,
+};
+
+use bee_network::{
+    Command::{AddPeer, RemovePeer},
+    Multiaddr, NetworkCommandSender, PeerId, PeerRelation, Protocol,
+};
+use bee_rest_api::{
+    endpoints::{permission::AccessList, routes::api::v1::config_reload::ConfigReloadRequest, ApiWorker},
+    types::responses::ConfigReloadResponse,
+};
+use bee_runtime::{node::Node, worker::Worker};
+
+use async_trait::async_trait;
+use log::{error, info, warn, LevelFilter};
+#[cfg(unix)]
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::mpsc;
+
+use std::{any::TypeId, collections::HashMap, convert::Infallible, marker::PhantomData};
+
+const PUBLIC_ROUTES_PATH: &str = "rest_api.public_routes";
+const ALLOWED_IPS_PATH: &str = "rest_api.allowed_ips";
+const LOGGER_OUTPUTS_PATH: &str = "logger.outputs";
+const PEERS_PATH: &str = "network.peering.peers";
+
+/// Reloads the configuration on `SIGHUP` or on request of the REST API, applies the changes of the access lists, log
+/// levels and static peers live and reports the other changes as requiring a restart.
+pub struct ConfigReloadWorker<B> {
+    phantom: PhantomData<B>,
+}
+
+#[async_trait]
+impl<B: StorageBackend> Worker<BeeNode<B>> for ConfigReloadWorker<B> {
+    type Config = (CliArgs, LayeredConfig, mpsc::UnboundedReceiver<ConfigReloadRequest>);
+    type Error = Infallible;
+
+    fn dependencies() -> &'static [TypeId] {
+        vec![TypeId::of::<ApiWorker>()].leak()
+    }
+
+    async fn start(node: &mut BeeNode<B>, config: Self::Config) -> Result<Self, Self::Error> {
+        let (cli, current, mut requests) = config;
+        let access_list = node.resource::<AccessList>();
+        let network_command_sender = node.resource::<NetworkCommandSender>();
+
+        node.spawn::<Self, _, _>(|mut shutdown| async move {
+            info!("Running.");
+
+            let mut reloader = Reloader::<B> {
+                startup_log_level: max_log_level(&current, &cli),
+                cli,
+                current,
+                phantom: PhantomData,
+            };
+
+            #[cfg(unix)]
+            let mut hangup = match signal(SignalKind::hangup()) {
+                Ok(hangup) => Some(hangup),
+                Err(e) => {
+                    error!("Listening to SIGHUP failed: {}.", e);
+                    None
+                }
+            };
+
+            loop {
+                #[cfg(unix)]
+                let hangup_received = async {
+                    match hangup.as_mut() {
+                        Some(hangup) => hangup.recv().await,
+                        None => futures::future::pending().await,
+                    }
+                };
+                #[cfg(not(unix))]
+                let hangup_received = futures::future::pending::<Option<()>>();
+
+                tokio::select! {
+                    _ = &mut shutdown => break,
+                    Some(()) = hangup_received => {
+                        info!("Received SIGHUP, reloading the configuration.");
+                        if let Err(e) = reloader.reload(&access_list, &network_command_sender) {
+                            error!("Reloading the configuration failed: {}", e);
+                        }
+                    }
+                    Some(request) = requests.recv() => {
+                        info!("Reloading the configuration on request.");
+                        let result = reloader.reload(&access_list, &network_command_sender);
+                        if let Err(e) = &result {
+                            error!("Reloading the configuration failed: {}", e);
+                        }
+                        // The requester may have gone away, which is fine.
+                        let _ = request.send(result);
+                    }
+                }
+            }
+
+            info!("Stopped.");
+        });
+
+        Ok(Self { phantom: PhantomData })
+    }
+}
+
+struct Reloader<B> {
+    cli: CliArgs,
+    current: LayeredConfig,
+    startup_log_level: LevelFilter,
+    phantom: PhantomData<B>,
+}
+
+impl<B: StorageBackend> Reloader<B> {
+    fn reload(
+        &mut self,
+        access_list: &AccessList,
+        network_command_sender: &NetworkCommandSender,
+    ) -> Result<ConfigReloadResponse, String> {
+        let reloaded = self
+            .cli
+            .layered_config(DEFAULT_CONFIG_PATH)
+            .map_err(|e| e.to_string())?;
+        // Nothing is applied unless the whole configuration is valid.
+        let config = reloaded
+            .clone()
+            .into_builder::<B>()
+            .map_err(|e| e.to_string())?
+            .with_cli_args(self.cli.clone())
+            .finish()
+            .map_err(|e| e.to_string())?;
+        let log_level = max_log_level(&reloaded, &self.cli);
+
+        let (applied, restart_required) = self
+            .current
+            .diff(&reloaded)
+            .into_iter()
+            .partition::<Vec<_>, _>(|path| is_applied_live(path, log_level, self.startup_log_level));
+
+        for path in applied.iter() {
+            match path.as_str() {
+                PUBLIC_ROUTES_PATH | ALLOWED_IPS_PATH => access_list.update(
+                    config.rest_api.public_routes().clone(),
+                    config.rest_api.allowed_ips().clone(),
+                ),
+                LOGGER_OUTPUTS_PATH => log::set_max_level(log_level),
+                PEERS_PATH => update_peers(&self.current, &reloaded, network_command_sender),
+                _ => {}
+            }
+            self.current.apply(&reloaded, path);
+            info!("Applied configuration change of `{}`.", path);
+        }
+
+        for path in restart_required.iter() {
+            warn!("Configuration change of `{}` requires a restart.", path);
+        }
+
+        Ok(ConfigReloadResponse {
+            applied,
+            restart_required,
+        })
+    }
+}
+
+/// Returns whether a change of the configuration path can be applied without a restart.
+fn is_applied_live(path: &str, log_level: LevelFilter, startup_log_level: LevelFilter) -> bool {
+    match path {
+        PUBLIC_ROUTES_PATH | ALLOWED_IPS_PATH | PEERS_PATH => true,
+        // The outputs filter their records with the levels they were started with, so only a global maximum up to the
+        // startup one can be applied.
+        LOGGER_OUTPUTS_PATH => log_level <= startup_log_level,
+        _ => false,
+    }
+}
+
+fn max_log_level(config: &LayeredConfig, cli: &CliArgs) -> LevelFilter {
+    config
+        .get(LOGGER_OUTPUTS_PATH)
+        .and_then(|outputs| outputs.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|output| output.get("level_filter")?.as_str()?.parse::<LevelFilter>().ok())
+        .chain(cli.log_level().copied())
+        .max()
+        .unwrap_or(LevelFilter::Info)
+}
+
+/// Returns the aliases of the static peers, by address.
+fn peers(config: &LayeredConfig) -> HashMap<String, Option<String>> {
+    config
+        .get(PEERS_PATH)
+        .and_then(|peers| peers.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|peer| {
+            Some((
+                peer.get("address")?.as_str()?.to_owned(),
+                peer.get("alias").and_then(|alias| alias.as_str()).map(str::to_owned),
+            ))
+        })
+        .collect()
+}
+
+fn peer(address: &str) -> Option<(PeerId, Multiaddr)> {
+    let mut multiaddr = address.parse::<Multiaddr>().ok()?;
+
+    match multiaddr.pop()? {
+        Protocol::P2p(multihash) => Some((PeerId::from_multihash(multihash).ok()?, multiaddr)),
+        _ => None,
+    }
+}
+
+/// The changes between two sets of static peers.
+#[derive(Debug, Default, PartialEq)]
+struct PeerChanges {
+    removed: Vec<String>,
+    added: Vec<(String, Option<String>)>,
+    realiased: Vec<String>,
+}
+
+fn peer_changes(current: &HashMap<String, Option<String>>, reloaded: &HashMap<String, Option<String>>) -> PeerChanges {
+    let mut changes = PeerChanges::default();
+
+    for address in current.keys().filter(|address| !reloaded.contains_key(*address)) {
+        changes.removed.push(address.clone());
+    }
+
+    for (address, alias) in reloaded.iter() {
+        match current.get(address) {
+            None => changes.added.push((address.clone(), alias.clone())),
+            // Connected peers keep their alias, there is no need to drop them for it.
+            Some(current_alias) if current_alias != alias => changes.realiased.push(address.clone()),
+            Some(_) => {}
+        }
+    }
+
+    changes
+}
+
+fn update_peers(current: &LayeredConfig, reloaded: &LayeredConfig, network_command_sender: &NetworkCommandSender) {
+    let changes = peer_changes(&peers(current), &peers(reloaded));
+
+    for address in changes.removed.iter() {
+        match peer(address) {
+            Some((peer_id, _)) => {
+                if let Err(e) = network_command_sender.send(RemovePeer { peer_id }) {
+                    warn!("Failed to remove peer {}: {}.", address, e);
+                }
+            }
+            None => warn!("Invalid peer address {}.", address),
+        }
+    }
+
+    for (address, alias) in changes.added {
+        match peer(&address) {
+            Some((peer_id, multiaddr)) => {
+                if let Err(e) = network_command_sender.send(AddPeer {
+                    peer_id,
+                    multiaddr,
+                    alias,
+                    relation: PeerRelation::Known,
+                }) {
+                    warn!("Failed to add peer {}: {}.", address, e);
+                }
+            }
+            None => warn!("Invalid peer address {}.", address),
+        }
+    }
+
+    for address in changes.realiased.iter() {
+        warn!("The alias change of peer {} requires a restart.", address);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "/ip4/127.0.0.1/tcp/15600/p2p/12D3KooWJWEKvSFbben74C7H4YtKjhPMTDxd7gP7zxWSUEeF27st";
+
+    fn peers(peers: &[(&str, Option<&str>)]) -> HashMap<String, Option<String>> {
+        peers
+            .iter()
+            .map(|(address, alias)| (address.to_string(), alias.map(str::to_owned)))
+            .collect()
+    }
+
+    #[test]
+    fn added_and_removed_peers_are_reported() {
+        let changes = peer_changes(
+            &peers(&[("/ip4/127.0.0.1/tcp/15600", None), ("/ip4/127.0.0.2/tcp/15600", None)]),
+            &peers(&[
+                ("/ip4/127.0.0.2/tcp/15600", None),
+                ("/ip4/127.0.0.3/tcp/15600", Some("peer")),
+            ]),
+        );
+
+        assert_eq!(
+            changes,
+            PeerChanges {
+                removed: vec!["/ip4/127.0.0.1/tcp/15600".to_owned()],
+                added: vec![("/ip4/127.0.0.3/tcp/15600".to_owned(), Some("peer".to_owned()))],
+                realiased: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn alias_changes_keep_the_peer() {
+        let changes = peer_changes(
+            &peers(&[("/ip4/127.0.0.1/tcp/15600", Some("old"))]),
+            &peers(&[("/ip4/127.0.0.1/tcp/15600", Some("new"))]),
+        );
+
+        assert_eq!(
+            changes,
+            PeerChanges {
+                removed: vec![],
+                added: vec![],
+                realiased: vec!["/ip4/127.0.0.1/tcp/15600".to_owned()],
+            }
+        );
+    }
+
+    #[test]
+    fn unchanged_peers_are_not_reported() {
+        let peers = peers(&[("/ip4/127.0.0.1/tcp/15600", Some("peer"))]);
+
+        assert_eq!(peer_changes(&peers, &peers), PeerChanges::default());
+    }
+
+    #[test]
+    fn peer_address_must_end_with_a_peer_id() {
+        let (_, multiaddr) = peer(ADDRESS).unwrap();
+
+        assert_eq!(multiaddr.to_string(), "/ip4/127.0.0.1/tcp/15600");
+        assert!(peer("/ip4/127.0.0.1/tcp/15600").is_none());
+        assert!(peer("not an address").is_none());
+    }
+
+    #[test]
+    fn live_changes_are_classified() {
+        assert!(is_applied_live(
+            PUBLIC_ROUTES_PATH,
+            LevelFilter::Info,
+            LevelFilter::Info
+        ));
+        assert!(is_applied_live(ALLOWED_IPS_PATH, LevelFilter::Info, LevelFilter::Info));
+        assert!(is_applied_live(PEERS_PATH, LevelFilter::Info, LevelFilter::Info));
+        assert!(is_applied_live(
+            LOGGER_OUTPUTS_PATH,
+            LevelFilter::Warn,
+            LevelFilter::Info
+        ));
+        assert!(!is_applied_live(
+            LOGGER_OUTPUTS_PATH,
+            LevelFilter::Debug,
+            LevelFilter::Info
+        ));
+        assert!(!is_applied_live(
+            "rest_api.binding_port",
+            LevelFilter::Info,
+            LevelFilter::Info
+        ));
+        assert!(!is_applied_live(
+            "network.peering",
+            LevelFilter::Info,
+            LevelFilter::Info
+        ));
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::channel::oneshot;
+use tokio::{
+    task::JoinError,
+    time::{self, Instant},
+};
+
+use std::{any::TypeId, time::Duration};
+
+/// Defines how the node reacts when a task of a worker panics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SupervisionPolicy {
+    /// Stops and restarts the worker, waiting `backoff` before the first attempt and doubling it for every subsequent
+    /// one. The worker is degraded once `max_restarts` is exceeded, the restarts being forgotten after it ran for
+    /// `backoff * 2^max_restarts` since the last one.
+    Restart { max_restarts: usize, backoff: Duration },
+    /// Marks the worker as degraded and keeps the node running.
+    Degrade,
+    /// Stops the node.
+    Stop,
+}
+
+impl Default for SupervisionPolicy {
+    // The core workers, like the ledger, the tangle or the storage, can't be trusted anymore after a panic.
+    fn default() -> Self {
+        Self::Stop
+    }
+}
+
+/// An event that indicates that a task of a worker panicked.
+#[derive(Clone, Debug)]
+pub struct WorkerFailed {
+    /// The name of the failed worker.
+    pub worker: &'static str,
+    /// The reason of the failure.
+    pub reason: String,
+}
+
+pub(crate) struct TaskExit {
+    pub(crate) worker: TypeId,
+    pub(crate) task: u64,
+    pub(crate) reason: String,
+}
+
+/// Returns the reason of the failure of a task, if it failed. A task that returns on its own is simply done.
+pub(crate) fn failure_reason(result: &Result<(), JoinError>) -> Option<String> {
+    result.as_ref().err().map(ToString::to_string)
+}
+
+/// Returns the delay before the given restart attempt, starting at 1.
+pub(crate) fn restart_delay(backoff: Duration, attempt: usize) -> Duration {
+    backoff.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1) as u32))
+}
+
+/// Returns how long a restarted worker has to run before its restarts are forgotten, i.e. twice the delay before its
+/// last allowed restart.
+pub(crate) fn stability_window(backoff: Duration, max_restarts: usize) -> Duration {
+    restart_delay(backoff, max_restarts.saturating_add(1))
+}
+
+/// The restarts of a worker, counted towards its maximum until it runs for a stability window after the last one.
+#[derive(Default)]
+pub(crate) struct Restarts {
+    count: usize,
+    running_since: Option<Instant>,
+}
+
+impl Restarts {
+    /// Forgets the restarts if the worker has been running for the window since it was last restarted.
+    pub(crate) fn forget_if_stable(&mut self, now: Instant, window: Duration) {
+        if let Some(running_since) = self.running_since {
+            if now.saturating_duration_since(running_since) >= window {
+                *self = Self::default();
+            }
+        }
+    }
+
+    /// Returns the number of restarts counted towards the maximum.
+    pub(crate) fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Counts a restart attempt and returns its number, starting at 1.
+    pub(crate) fn attempt(&mut self) -> usize {
+        self.count += 1;
+        self.running_since = None;
+        self.count
+    }
+
+    /// Records that the worker was successfully restarted and is running.
+    pub(crate) fn succeeded(&mut self, now: Instant) {
+        self.running_since = Some(now);
+    }
+}
+
+/// Waits for the delay before a restart and returns whether it elapsed before the node was asked to shut down.
+pub(crate) async fn wait_restart_delay(delay: Duration, shutdown: &mut oneshot::Receiver<()>) -> bool {
+    tokio::select! {
+        _ = time::sleep(delay) => true,
+        _ = shutdown => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_workers_stop_the_node_by_default() {
+        assert_eq!(SupervisionPolicy::default(), SupervisionPolicy::Stop);
+    }
+
+    #[test]
+    fn restart_delay_doubles() {
+        let backoff = Duration::from_secs(1);
+
+        assert_eq!(restart_delay(backoff, 1), Duration::from_secs(1));
+        assert_eq!(restart_delay(backoff, 2), Duration::from_secs(2));
+        assert_eq!(restart_delay(backoff, 4), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn restart_delay_saturates() {
+        assert_eq!(restart_delay(Duration::from_secs(1), 100), Duration::MAX);
+    }
+
+    #[test]
+    fn stability_window_outlasts_the_longest_restart_delay() {
+        assert_eq!(stability_window(Duration::from_secs(1), 3), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn restarts_are_counted() {
+        let mut restarts = Restarts::default();
+
+        assert_eq!(restarts.attempt(), 1);
+        assert_eq!(restarts.attempt(), 2);
+        assert_eq!(restarts.count(), 2);
+    }
+
+    #[test]
+    fn restarts_are_forgotten_after_a_stable_run() {
+        let window = Duration::from_secs(8);
+        let now = Instant::now();
+        let mut restarts = Restarts::default();
+
+        restarts.attempt();
+        restarts.attempt();
+        restarts.succeeded(now);
+        restarts.forget_if_stable(now + window, window);
+
+        assert_eq!(restarts.count(), 0);
+        assert_eq!(restarts.attempt(), 1);
+    }
+
+    #[test]
+    fn restarts_are_kept_after_a_short_run() {
+        let window = Duration::from_secs(8);
+        let now = Instant::now();
+        let mut restarts = Restarts::default();
+
+        restarts.attempt();
+        restarts.succeeded(now);
+        restarts.forget_if_stable(now + window / 2, window);
+
+        assert_eq!(restarts.count(), 1);
+    }
+
+    #[test]
+    fn failed_restarts_are_never_forgotten() {
+        let window = Duration::from_secs(8);
+        let mut restarts = Restarts::default();
+
+        restarts.attempt();
+        restarts.forget_if_stable(Instant::now() + window * 2, window);
+
+        assert_eq!(restarts.count(), 1);
+    }
+
+    #[tokio::test]
+    async fn returning_task_is_not_a_failure() {
+        let result = tokio::spawn(async {}).await;
+
+        assert_eq!(failure_reason(&result), None);
+    }
+
+    #[tokio::test]
+    async fn panicking_task_is_a_failure() {
+        let result = tokio::spawn(async { panic!("failure") }).await;
+
+        assert!(failure_reason(&result).is_some());
+    }
+
+    #[tokio::test]
+    async fn restart_delay_elapses_without_shutdown() {
+        let (_shutdown_tx, mut shutdown) = oneshot::channel();
+
+        assert!(wait_restart_delay(Duration::from_millis(1), &mut shutdown).await);
+    }
+
+    #[tokio::test]
+    async fn shutdown_interrupts_restart_delay() {
+        let (shutdown_tx, mut shutdown) = oneshot::channel();
+
+        shutdown_tx.send(()).unwrap();
+
+        assert!(!wait_restart_delay(Duration::from_secs(3600), &mut shutdown).await);
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::plugins::dashboard::{config::DashboardAuthConfig, rejection::CustomRejection};
+use crate::plugins::dashboard::{config::DashboardAuthConfig, rejection::CustomRejection, session::Sessions};
 
 use bee_common::auth::{jwt::JsonWebToken, password};
 
@@ -19,20 +19,18 @@ pub struct AuthResponse {
 pub(crate) async fn auth(
     node_id: String,
     config: DashboardAuthConfig,
+    sessions: Sessions,
     body: JsonValue,
 ) -> Result<impl Reply, Rejection> {
     let jwt_json = &body["jwt"];
 
     if !jwt_json.is_null() {
-        let jwt = JsonWebToken::from(
-            jwt_json
-                .as_str()
-                .ok_or_else(|| reject::custom(CustomRejection::InvalidJwt))?
-                .to_owned(),
-        );
-        return match jwt.validate(node_id, config.user().to_owned(), AUDIENCE_CLAIM.to_owned(), b"secret") {
-            Ok(_) => Ok(warp::reply::json(&AuthResponse { jwt: jwt.to_string() })),
-            Err(_) => Err(reject::custom(CustomRejection::InvalidJwt)),
+        let jwt = jwt_json
+            .as_str()
+            .ok_or_else(|| reject::custom(CustomRejection::InvalidJwt))?;
+        return match sessions.validate(&node_id, jwt).await {
+            Some(_) => Ok(warp::reply::json(&AuthResponse { jwt: jwt.to_owned() })),
+            None => Err(reject::custom(CustomRejection::InvalidJwt)),
         };
     }
 
@@ -46,9 +44,9 @@ pub(crate) async fn auth(
             .ok_or_else(|| reject::custom(CustomRejection::BadRequest("Invalid user provided")))?
     };
 
-    if user != config.user() {
-        return Err(reject::custom(CustomRejection::InvalidCredentials));
-    }
+    let user_config = config
+        .user(user)
+        .ok_or_else(|| reject::custom(CustomRejection::InvalidCredentials))?;
 
     let password_json = &body["password"];
 
@@ -60,24 +58,35 @@ pub(crate) async fn auth(
             .ok_or_else(|| reject::custom(CustomRejection::BadRequest("Invalid password provided")))?
     };
 
-    if !password::password_verify(
-        password.as_bytes(),
-        &hex::decode(config.password_salt()).unwrap(),
-        &hex::decode(config.password_hash()).unwrap(),
-    )
-    .map_err(|_| reject::custom(CustomRejection::InternalError))?
+    // The salt and hash are validated by the config, so a failure to decode them is an internal error.
+    let password_salt =
+        hex::decode(user_config.password_salt()).map_err(|_| reject::custom(CustomRejection::InternalError))?;
+    let password_hash =
+        hex::decode(user_config.password_hash()).map_err(|_| reject::custom(CustomRejection::InternalError))?;
+
+    if !password::password_verify(password.as_bytes(), &password_salt, &password_hash)
+        .map_err(|_| reject::custom(CustomRejection::InternalError))?
     {
         return Err(reject::custom(CustomRejection::InvalidCredentials));
     }
 
     let jwt = JsonWebToken::new(
         node_id,
-        config.user().to_owned(),
+        user_config.user().to_owned(),
         AUDIENCE_CLAIM.to_owned(),
         config.session_timeout(),
         b"secret",
     )
     .map_err(|_| reject::custom(CustomRejection::InternalError))?;
 
+    sessions
+        .open(
+            &jwt,
+            user_config.user().to_owned(),
+            user_config.role(),
+            config.session_timeout(),
+        )
+        .await;
+
     Ok(warp::reply::json(&AuthResponse { jwt: jwt.to_string() }))
 }
@@ -2,19 +2,119 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use serde::Deserialize;
+use thiserror::Error;
+
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
+};
 
 const DEFAULT_SESSION_TIMEOUT: u64 = 86400;
 const DEFAULT_USER: &str = "admin";
 const DEFAULT_PASSWORD_SALT: &str = "0000000000000000000000000000000000000000000000000000000000000000";
 const DEFAULT_PASSWORD_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+const DEFAULT_BINDING_IP_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
 const DEFAULT_PORT: u16 = 8081;
 
+#[derive(Debug, Error)]
+pub enum DashboardConfigError {
+    #[error("missing {0} of a user")]
+    MissingUserField(&'static str),
+    #[error("invalid {0} of user {1}: expected hexadecimal characters")]
+    InvalidUserField(&'static str, String),
+    #[error("duplicate user {0}")]
+    DuplicateUser(String),
+    #[error("missing TLS certificate path")]
+    MissingTlsCertPath,
+    #[error("missing TLS key path")]
+    MissingTlsKeyPath,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardRole {
+    ReadOnly,
+    PeerAdmin,
+}
+
+#[derive(Default, Deserialize)]
+pub struct DashboardUserConfigBuilder {
+    user: Option<String>,
+    password_salt: Option<String>,
+    password_hash: Option<String>,
+    role: Option<DashboardRole>,
+}
+
+impl DashboardUserConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> Result<DashboardUserConfig, DashboardConfigError> {
+        DashboardUserConfig {
+            user: self.user.ok_or(DashboardConfigError::MissingUserField("user"))?,
+            password_salt: self
+                .password_salt
+                .ok_or(DashboardConfigError::MissingUserField("password_salt"))?,
+            password_hash: self
+                .password_hash
+                .ok_or(DashboardConfigError::MissingUserField("password_hash"))?,
+            role: self.role.unwrap_or(DashboardRole::ReadOnly),
+        }
+        .validate()
+    }
+}
+
+#[derive(Clone)]
+pub struct DashboardUserConfig {
+    user: String,
+    password_salt: String,
+    password_hash: String,
+    role: DashboardRole,
+}
+
+impl DashboardUserConfig {
+    pub fn build() -> DashboardUserConfigBuilder {
+        DashboardUserConfigBuilder::new()
+    }
+
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    pub fn password_salt(&self) -> &str {
+        &self.password_salt
+    }
+
+    pub fn password_hash(&self) -> &str {
+        &self.password_hash
+    }
+
+    pub fn role(&self) -> DashboardRole {
+        self.role
+    }
+
+    // The salt and hash are decoded on every login, so they are checked once here.
+    fn validate(self) -> Result<Self, DashboardConfigError> {
+        if hex::decode(&self.password_salt).is_err() {
+            return Err(DashboardConfigError::InvalidUserField("password_salt", self.user));
+        }
+        if hex::decode(&self.password_hash).is_err() {
+            return Err(DashboardConfigError::InvalidUserField("password_hash", self.user));
+        }
+
+        Ok(self)
+    }
+}
+
 #[derive(Default, Deserialize)]
 pub struct DashboardAuthConfigBuilder {
     session_timeout: Option<u64>,
+    // Single user of older configs, granted the peer admin role.
     user: Option<String>,
     password_salt: Option<String>,
     password_hash: Option<String>,
+    users: Option<Vec<DashboardUserConfigBuilder>>,
 }
 
 impl DashboardAuthConfigBuilder {
@@ -22,22 +122,38 @@ impl DashboardAuthConfigBuilder {
         Self::default()
     }
 
-    pub fn finish(self) -> DashboardAuthConfig {
-        DashboardAuthConfig {
-            session_timeout: self.session_timeout.unwrap_or(DEFAULT_SESSION_TIMEOUT),
-            user: self.user.unwrap_or_else(|| DEFAULT_USER.to_owned()),
-            password_salt: self.password_salt.unwrap_or_else(|| DEFAULT_PASSWORD_SALT.to_owned()),
-            password_hash: self.password_hash.unwrap_or_else(|| DEFAULT_PASSWORD_HASH.to_owned()),
+    pub fn finish(self) -> Result<DashboardAuthConfig, DashboardConfigError> {
+        let users = match self.users {
+            Some(users) => users
+                .into_iter()
+                .map(DashboardUserConfigBuilder::finish)
+                .collect::<Result<Vec<_>, _>>()?,
+            None => vec![DashboardUserConfig {
+                user: self.user.unwrap_or_else(|| DEFAULT_USER.to_owned()),
+                password_salt: self.password_salt.unwrap_or_else(|| DEFAULT_PASSWORD_SALT.to_owned()),
+                password_hash: self.password_hash.unwrap_or_else(|| DEFAULT_PASSWORD_HASH.to_owned()),
+                role: DashboardRole::PeerAdmin,
+            }
+            .validate()?],
+        };
+
+        for (i, user) in users.iter().enumerate() {
+            if users[..i].iter().any(|other| other.user == user.user) {
+                return Err(DashboardConfigError::DuplicateUser(user.user.clone()));
+            }
         }
+
+        Ok(DashboardAuthConfig {
+            session_timeout: self.session_timeout.unwrap_or(DEFAULT_SESSION_TIMEOUT),
+            users,
+        })
     }
 }
 
 #[derive(Clone)]
 pub struct DashboardAuthConfig {
     session_timeout: u64,
-    user: String,
-    password_salt: String,
-    password_hash: String,
+    users: Vec<DashboardUserConfig>,
 }
 
 impl DashboardAuthConfig {
@@ -49,22 +165,59 @@ impl DashboardAuthConfig {
         self.session_timeout
     }
 
-    pub fn user(&self) -> &str {
-        &self.user
+    pub fn users(&self) -> &[DashboardUserConfig] {
+        &self.users
     }
 
-    pub fn password_salt(&self) -> &str {
-        &self.password_salt
+    pub fn user(&self, user: &str) -> Option<&DashboardUserConfig> {
+        self.users.iter().find(|config| config.user() == user)
     }
+}
 
-    pub fn password_hash(&self) -> &str {
-        &self.password_hash
+#[derive(Default, Deserialize)]
+pub struct DashboardTlsConfigBuilder {
+    cert_path: Option<String>,
+    key_path: Option<String>,
+}
+
+impl DashboardTlsConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> Result<DashboardTlsConfig, DashboardConfigError> {
+        Ok(DashboardTlsConfig {
+            cert_path: PathBuf::from(self.cert_path.ok_or(DashboardConfigError::MissingTlsCertPath)?),
+            key_path: PathBuf::from(self.key_path.ok_or(DashboardConfigError::MissingTlsKeyPath)?),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct DashboardTlsConfig {
+    cert_path: PathBuf,
+    key_path: PathBuf,
+}
+
+impl DashboardTlsConfig {
+    pub fn build() -> DashboardTlsConfigBuilder {
+        DashboardTlsConfigBuilder::new()
+    }
+
+    pub fn cert_path(&self) -> &Path {
+        &self.cert_path
+    }
+
+    pub fn key_path(&self) -> &Path {
+        &self.key_path
     }
 }
 
 #[derive(Default, Deserialize)]
 pub struct DashboardConfigBuilder {
+    binding_ip_addr: Option<IpAddr>,
     port: Option<u16>,
+    tls: Option<DashboardTlsConfigBuilder>,
     auth: Option<DashboardAuthConfigBuilder>,
 }
 
@@ -73,17 +226,22 @@ impl DashboardConfigBuilder {
         Self::default()
     }
 
-    pub fn finish(self) -> DashboardConfig {
-        DashboardConfig {
-            port: self.port.unwrap_or(DEFAULT_PORT),
-            auth: self.auth.unwrap_or_default().finish(),
-        }
+    pub fn finish(self) -> Result<DashboardConfig, DashboardConfigError> {
+        Ok(DashboardConfig {
+            binding_socket_addr: SocketAddr::new(
+                self.binding_ip_addr.unwrap_or(DEFAULT_BINDING_IP_ADDR),
+                self.port.unwrap_or(DEFAULT_PORT),
+            ),
+            tls: self.tls.map(DashboardTlsConfigBuilder::finish).transpose()?,
+            auth: self.auth.unwrap_or_default().finish()?,
+        })
     }
 }
 
 #[derive(Clone)]
 pub struct DashboardConfig {
-    port: u16,
+    binding_socket_addr: SocketAddr,
+    tls: Option<DashboardTlsConfig>,
     auth: DashboardAuthConfig,
 }
 
@@ -92,11 +250,56 @@ impl DashboardConfig {
         DashboardConfigBuilder::new()
     }
 
-    pub fn port(&self) -> u16 {
-        self.port
+    pub fn binding_socket_addr(&self) -> SocketAddr {
+        self.binding_socket_addr
+    }
+
+    pub fn tls(&self) -> Option<&DashboardTlsConfig> {
+        self.tls.as_ref()
     }
 
     pub fn auth(&self) -> &DashboardAuthConfig {
         &self.auth
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(password_salt: &str, password_hash: &str) -> DashboardUserConfigBuilder {
+        DashboardUserConfigBuilder {
+            user: Some("user".to_owned()),
+            password_salt: Some(password_salt.to_owned()),
+            password_hash: Some(password_hash.to_owned()),
+            role: None,
+        }
+    }
+
+    #[test]
+    fn hexadecimal_password_is_accepted() {
+        assert!(user(DEFAULT_PASSWORD_SALT, DEFAULT_PASSWORD_HASH).finish().is_ok());
+    }
+
+    #[test]
+    fn non_hexadecimal_password_is_rejected() {
+        assert!(matches!(
+            user("salt", DEFAULT_PASSWORD_HASH).finish(),
+            Err(DashboardConfigError::InvalidUserField("password_salt", _))
+        ));
+        assert!(matches!(
+            user(DEFAULT_PASSWORD_SALT, "hash").finish(),
+            Err(DashboardConfigError::InvalidUserField("password_hash", _))
+        ));
+    }
+
+    #[test]
+    fn legacy_user_is_validated() {
+        let config = DashboardAuthConfigBuilder {
+            password_salt: Some("salt".to_owned()),
+            ..Default::default()
+        };
+
+        assert!(config.finish().is_err());
+    }
+}
@@ -7,6 +7,7 @@ mod asset;
 mod auth;
 mod rejection;
 mod routes;
+mod session;
 mod websocket;
 mod workers;
 
@@ -14,6 +15,7 @@ use crate::{
     config::NodeConfig,
     plugins::dashboard::{
         config::DashboardConfig,
+        session::Sessions,
         websocket::{
             responses::{milestone, milestone_info, sync_status, WsEvent},
             WsUsers,
@@ -44,7 +46,6 @@ use warp::ws::Message;
 use std::{
     any::{Any, TypeId},
     convert::Infallible,
-    net::{IpAddr, Ipv4Addr, SocketAddr},
 };
 
 const CONFIRMED_THRESHOLD: u32 = 5;
@@ -116,6 +117,8 @@ where
 
         // Keep track of all connected users, key is usize, value is a websocket sender.
         let users = WsUsers::default();
+        // Keep track of all open sessions, so that they can be revoked.
+        let sessions = Sessions::default();
 
         // Register event handlers
         {
@@ -177,19 +180,36 @@ where
                 config.auth().clone(),
                 rest_api_config.clone(),
                 users.clone(),
+                sessions,
             );
 
-            info!("Dashboard available at http://localhost:{}.", config.port());
-
-            let (_, server) = warp::serve(routes).bind_with_graceful_shutdown(
-                // TODO the whole address needs to be a config
-                SocketAddr::new(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), config.port()),
-                async {
-                    shutdown.await.ok();
+            let shutdown = async {
+                shutdown.await.ok();
+            };
+
+            match config.tls() {
+                None => {
+                    match warp::serve(routes).try_bind_with_graceful_shutdown(config.binding_socket_addr(), shutdown) {
+                        Ok((addr, server)) => {
+                            info!("Dashboard available at http://{}.", addr);
+                            server.await;
+                        }
+                        Err(e) => error!("Serving the dashboard failed: {}.", e),
+                    }
+                }
+                Some(tls) => match warp::serve(routes)
+                    .tls()
+                    .cert_path(tls.cert_path())
+                    .key_path(tls.key_path())
+                    .try_bind_with_graceful_shutdown(config.binding_socket_addr(), shutdown)
+                {
+                    Ok((addr, server)) => {
+                        info!("Dashboard available at https://{}.", addr);
+                        server.await;
+                    }
+                    Err(e) => error!("Serving the dashboard over TLS failed: {}.", e),
                 },
-            );
-
-            server.await;
+            }
 
             let mut readies = Vec::new();
 
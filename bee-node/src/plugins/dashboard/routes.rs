@@ -4,23 +4,28 @@
 use crate::{
     plugins::dashboard::{
         asset::Asset,
-        auth::{auth, AUDIENCE_CLAIM},
-        config::DashboardAuthConfig,
+        auth::auth,
+        config::{DashboardAuthConfig, DashboardRole},
         rejection::CustomRejection,
+        session::{Session, Sessions},
         websocket::{user_connected, WsUsers},
     },
     storage::StorageBackend,
 };
 
-use bee_common::auth::jwt::JsonWebToken;
 use bee_rest_api::endpoints::config::RestApiConfig;
 use bee_runtime::resource::ResourceHandle;
 use bee_tangle::MsTangle;
 
-use log::debug;
+use log::{debug, info};
+use serde_json::json;
 use warp::{
     filters::header::headers_cloned,
-    http::header::{HeaderMap, HeaderValue, AUTHORIZATION},
+    http::{
+        self,
+        header::{HeaderMap, HeaderValue, AUTHORIZATION},
+        Method,
+    },
     path::FullPath,
     reject,
     reply::Response,
@@ -28,7 +33,10 @@ use warp::{
 };
 use warp_reverse_proxy::reverse_proxy_filter;
 
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
 const BEARER: &str = "Bearer ";
+const AUDIT_TARGET: &str = "dashboard::audit";
 
 async fn serve_index() -> Result<impl Reply, Rejection> {
     serve_asset("index.html")
@@ -78,13 +86,13 @@ pub(crate) fn ws_routes<B: StorageBackend>(
     tangle: ResourceHandle<MsTangle<B>>,
     users: WsUsers,
     node_id: String,
-    auth_config: DashboardAuthConfig,
+    sessions: Sessions,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     let storage_filter = warp::any().map(move || storage.clone());
     let tangle_filter = warp::any().map(move || tangle.clone());
     let users_filter = warp::any().map(move || users.clone());
     let node_id_filter = warp::any().map(move || node_id.clone());
-    let auth_config_filter = warp::any().map(move || auth_config.clone());
+    let sessions_filter = warp::any().map(move || sessions.clone());
 
     warp::path("ws")
         .and(warp::ws())
@@ -92,102 +100,172 @@ pub(crate) fn ws_routes<B: StorageBackend>(
         .and(tangle_filter)
         .and(users_filter)
         .and(node_id_filter)
-        .and(auth_config_filter)
-        .map(|ws: warp::ws::Ws, storage, tangle, users, node_id, auth_config| {
+        .and(sessions_filter)
+        .map(|ws: warp::ws::Ws, storage, tangle, users, node_id, sessions| {
             // This will call our function if the handshake succeeds.
-            ws.on_upgrade(move |socket| user_connected(socket, storage, tangle, users, node_id, auth_config))
+            ws.on_upgrade(move |socket| user_connected(socket, storage, tangle, users, node_id, sessions))
         })
 }
 
+/// Returns the address the node reaches its own REST API at, with the scheme of its TLS config.
+fn rest_api_address(rest_api_config: &RestApiConfig) -> String {
+    let mut address = rest_api_config.binding_socket_addr();
+
+    // An unspecified binding address accepts local connections on the loopback address of its family.
+    if address.ip().is_unspecified() {
+        address.set_ip(match address.ip() {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+        });
+    }
+
+    let scheme = if rest_api_config.tls().is_some() {
+        "https"
+    } else {
+        "http"
+    };
+
+    format!("{}://{}/", scheme, address)
+}
+
 pub(crate) fn api_routes(
     node_id: String,
-    auth_config: DashboardAuthConfig,
+    sessions: Sessions,
     rest_api_config: RestApiConfig,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let proxy_address = rest_api_address(&rest_api_config);
+
     let allowed_routes = warp::get()
         .and(warp::path!("api" / "v1" / "info" / ..))
         .or(warp::get().and(warp::path!("api" / "v1" / "messages" / ..)))
         .or(warp::get().and(warp::path!("api" / "v1" / "outputs" / ..)))
         .or(warp::get().and(warp::path!("api" / "v1" / "addresses" / ..)))
         .or(warp::get().and(warp::path!("api" / "v1" / "milestones" / ..)))
-        .or(auth_filter(node_id.clone(), auth_config.clone())
+        .or(auth_filter(node_id.clone(), sessions.clone(), DashboardRole::ReadOnly)
             .and(warp::get())
-            .and(warp::path!("api" / "v1" / "peers" / ..)))
-        .or(auth_filter(node_id.clone(), auth_config.clone())
-            .and(warp::post())
-            .and(warp::path!("api" / "v1" / "peers" / ..)))
-        .or(auth_filter(node_id, auth_config)
-            .and(warp::delete())
             .and(warp::path!("api" / "v1" / "peers" / ..)));
 
+    // Peer changes are audited, with the status returned by the REST API.
+    let peer_admin_routes = auth_filter(node_id, sessions, DashboardRole::PeerAdmin)
+        .and(warp::post().or(warp::delete()).unify())
+        .and(warp::path!("api" / "v1" / "peers" / ..))
+        .and(warp::method())
+        .and(warp::path::full())
+        .and(reverse_proxy_filter("".to_string(), proxy_address.clone()))
+        .map(
+            |session: Session, method: Method, path: FullPath, res: http::Response<_>| {
+                info!(
+                    target: AUDIT_TARGET,
+                    "{} {} by {}: {}",
+                    method,
+                    path.as_str(),
+                    session.user,
+                    res.status()
+                );
+                res
+            },
+        );
+
     allowed_routes
-        .and(reverse_proxy_filter(
-            "".to_string(),
-            "http://localhost:".to_owned() + &rest_api_config.binding_socket_addr().port().to_string() + "/",
-        ))
+        .and(reverse_proxy_filter("".to_string(), proxy_address))
         .map(|_, res| res)
+        .or(peer_admin_routes)
 }
 
+/// Extracts the session of the bearer token of a request, rejecting it unless the session has at least the given role.
 pub fn auth_filter(
     node_id: String,
-    auth_config: DashboardAuthConfig,
-) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    sessions: Sessions,
+    role: DashboardRole,
+) -> impl Filter<Extract = (Session,), Error = Rejection> + Clone {
     let node_id_filter = warp::any().map(move || node_id.clone());
-    let auth_config_filter = warp::any().map(move || auth_config.clone());
+    let sessions_filter = warp::any().map(move || sessions.clone());
 
-    headers_cloned()
-        .map(move |headers: HeaderMap<HeaderValue>| (headers))
-        .and(node_id_filter)
-        .and(auth_config_filter)
-        .and_then(
-            move |headers: HeaderMap<HeaderValue>, node_id: String, auth_config: DashboardAuthConfig| async move {
-                let header = match headers.get(AUTHORIZATION) {
-                    Some(v) => v,
-                    None => return Err(reject::custom(CustomRejection::Forbidden)),
-                };
-                let auth_header = match std::str::from_utf8(header.as_bytes()) {
-                    Ok(v) => v,
-                    Err(_) => return Err(reject::custom(CustomRejection::Forbidden)),
-                };
-                if !auth_header.starts_with(BEARER) {
-                    return Err(reject::custom(CustomRejection::Forbidden));
-                }
-
-                let jwt = JsonWebToken::from(auth_header.trim_start_matches(BEARER).to_owned());
-
-                if jwt
-                    .validate(
-                        node_id.clone(),
-                        auth_config.user().to_owned(),
-                        AUDIENCE_CLAIM.to_owned(),
-                        b"secret",
-                    )
-                    .is_err()
-                {
-                    return Err(reject::custom(CustomRejection::Forbidden));
-                }
-
-                Ok(())
-            },
-        )
-        .untuple_one()
+    bearer_filter().and(node_id_filter).and(sessions_filter).and_then(
+        move |jwt: String, node_id: String, sessions: Sessions| async move {
+            match sessions.validate(&node_id, &jwt).await {
+                Some(session) if session.role >= role => Ok(session),
+                _ => Err(reject::custom(CustomRejection::Forbidden)),
+            }
+        },
+    )
+}
+
+fn bearer_filter() -> impl Filter<Extract = (String,), Error = Rejection> + Clone {
+    headers_cloned().and_then(|headers: HeaderMap<HeaderValue>| async move {
+        let header = match headers.get(AUTHORIZATION) {
+            Some(v) => v,
+            None => return Err(reject::custom(CustomRejection::Forbidden)),
+        };
+        let auth_header = match std::str::from_utf8(header.as_bytes()) {
+            Ok(v) => v,
+            Err(_) => return Err(reject::custom(CustomRejection::Forbidden)),
+        };
+        if !auth_header.starts_with(BEARER) {
+            return Err(reject::custom(CustomRejection::Forbidden));
+        }
+
+        Ok(auth_header.trim_start_matches(BEARER).to_owned())
+    })
 }
 
 pub(crate) fn auth_route(
     node_id: String,
     auth_config: DashboardAuthConfig,
+    sessions: Sessions,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     let node_id_filter = warp::any().map(move || node_id.clone());
     let auth_config_filter = warp::any().map(move || auth_config.clone());
+    let sessions_filter = warp::any().map(move || sessions.clone());
 
     warp::post()
         .and(warp::path("auth"))
+        .and(warp::path::end())
         .and(node_id_filter)
         .and(auth_config_filter)
+        .and(sessions_filter)
         .and(warp::body::json())
         .and_then(auth)
 }
 
+pub(crate) fn session_routes(
+    node_id: String,
+    sessions: Sessions,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let sessions_filter = {
+        let sessions = sessions.clone();
+        warp::any().map(move || sessions.clone())
+    };
+
+    // Revokes the session of the request.
+    let logout = warp::post()
+        .and(warp::path!("auth" / "logout"))
+        .and(auth_filter(node_id.clone(), sessions.clone(), DashboardRole::ReadOnly))
+        .and(bearer_filter())
+        .and(sessions_filter.clone())
+        .and_then(|session: Session, jwt: String, sessions: Sessions| async move {
+            sessions.revoke(&jwt).await;
+            info!(target: AUDIT_TARGET, "logout by {}", session.user);
+            Ok::<_, Rejection>(warp::reply::json(&json!({})))
+        });
+
+    // Revokes all the sessions of a user.
+    let revoke_user = warp::delete()
+        .and(warp::path!("auth" / "sessions" / String))
+        .and(auth_filter(node_id, sessions, DashboardRole::PeerAdmin))
+        .and(sessions_filter)
+        .and_then(|user: String, session: Session, sessions: Sessions| async move {
+            let revoked = sessions.revoke_user(&user).await;
+            info!(
+                target: AUDIT_TARGET,
+                "revocation of the {} sessions of {} by {}", revoked, user, session.user
+            );
+            Ok::<_, Rejection>(warp::reply::json(&json!({ "revoked": revoked })))
+        });
+
+    logout.or(revoke_user)
+}
+
 pub(crate) fn routes<B: StorageBackend>(
     storage: ResourceHandle<B>,
     tangle: ResourceHandle<MsTangle<B>>,
@@ -195,11 +273,13 @@ pub(crate) fn routes<B: StorageBackend>(
     auth_config: DashboardAuthConfig,
     rest_api_config: RestApiConfig,
     users: WsUsers,
+    sessions: Sessions,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     index_filter()
         .or(asset_routes())
         .or(page_routes())
-        .or(ws_routes(storage, tangle, users, node_id.clone(), auth_config.clone()))
-        .or(api_routes(node_id.clone(), auth_config.clone(), rest_api_config))
-        .or(auth_route(node_id, auth_config))
+        .or(ws_routes(storage, tangle, users, node_id.clone(), sessions.clone()))
+        .or(api_routes(node_id.clone(), sessions.clone(), rest_api_config))
+        .or(auth_route(node_id.clone(), auth_config, sessions.clone()))
+        .or(session_routes(node_id, sessions))
 }
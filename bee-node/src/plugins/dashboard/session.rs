@@ -0,0 +1,86 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::plugins::dashboard::{auth::AUDIENCE_CLAIM, config::DashboardRole};
+
+use bee_common::auth::jwt::JsonWebToken;
+
+use tokio::sync::RwLock;
+
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Session {
+    pub(crate) user: String,
+    pub(crate) role: DashboardRole,
+    expires_at: u64,
+}
+
+/// The sessions opened by logging into the dashboard, keyed by their token.
+///
+/// A token is only accepted while its session is tracked, which allows revoking it before it expires.
+#[derive(Clone, Default)]
+pub(crate) struct Sessions(Arc<RwLock<HashMap<String, Session>>>);
+
+impl Sessions {
+    pub(crate) async fn open(&self, jwt: &JsonWebToken, user: String, role: DashboardRole, timeout: u64) {
+        let now = now();
+        let mut sessions = self.0.write().await;
+
+        sessions.retain(|_, session| session.expires_at > now);
+        sessions.insert(
+            jwt.to_string(),
+            Session {
+                user,
+                role,
+                expires_at: now + timeout,
+            },
+        );
+    }
+
+    /// Returns the session of a token if the token is valid and its session has neither expired nor been revoked.
+    pub(crate) async fn validate(&self, node_id: &str, jwt: &str) -> Option<Session> {
+        let session = self.0.read().await.get(jwt).cloned()?;
+
+        if session.expires_at <= now() {
+            return None;
+        }
+
+        JsonWebToken::from(jwt.to_owned())
+            .validate(
+                node_id.to_owned(),
+                session.user.clone(),
+                AUDIENCE_CLAIM.to_owned(),
+                b"secret",
+            )
+            .ok()?;
+
+        Some(session)
+    }
+
+    /// Revokes the session of a token, returning whether it was open.
+    pub(crate) async fn revoke(&self, jwt: &str) -> bool {
+        self.0.write().await.remove(jwt).is_some()
+    }
+
+    /// Revokes all the sessions of a user, returning how many were open.
+    pub(crate) async fn revoke_user(&self, user: &str) -> usize {
+        let mut sessions = self.0.write().await;
+        let before = sessions.len();
+
+        sessions.retain(|_, session| session.user != user);
+
+        before - sessions.len()
+    }
+}
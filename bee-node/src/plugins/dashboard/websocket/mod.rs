@@ -10,8 +10,7 @@ use topics::WsTopic;
 
 use crate::{
     plugins::dashboard::{
-        auth::AUDIENCE_CLAIM,
-        config::DashboardAuthConfig,
+        session::Sessions,
         websocket::responses::{
             database_size_metrics::DatabaseSizeMetricsResponse, sync_status::SyncStatusResponse, WsEvent, WsEventInner,
         },
@@ -19,7 +18,6 @@ use crate::{
     storage::StorageBackend,
 };
 
-use bee_common::auth::jwt::JsonWebToken;
 use bee_runtime::{resource::ResourceHandle, shutdown_stream::ShutdownStream};
 use bee_tangle::MsTangle;
 
@@ -70,7 +68,7 @@ pub(crate) async fn user_connected<B: StorageBackend>(
     tangle: ResourceHandle<MsTangle<B>>,
     users: WsUsers,
     node_id: String,
-    auth_config: DashboardAuthConfig,
+    sessions: Sessions,
 ) {
     // Use a counter to assign a new unique ID for this user.
     let user_id = NEXT_USER_ID.fetch_add(1, Ordering::Relaxed);
@@ -113,7 +111,7 @@ pub(crate) async fn user_connected<B: StorageBackend>(
                 break;
             }
         };
-        user_message(user_id, msg, &users, &tangle, &storage, &node_id, &auth_config).await;
+        user_message(user_id, msg, &users, &tangle, &storage, &node_id, &sessions).await;
     }
 
     // ws_rx stream will keep processing as long as the user stays
@@ -131,7 +129,7 @@ async fn user_message<B: StorageBackend>(
     tangle: &MsTangle<B>,
     storage: &B,
     node_id: &str,
-    auth_config: &DashboardAuthConfig,
+    sessions: &Sessions,
 ) {
     if !msg.is_binary() {
         return;
@@ -165,22 +163,14 @@ async fn user_message<B: StorageBackend>(
                     if bytes.len() < 3 {
                         return;
                     }
-                    let jwt = JsonWebToken::from(match String::from_utf8(bytes[2..].to_vec()) {
+                    let jwt = match std::str::from_utf8(&bytes[2..]) {
                         Ok(jwt) => jwt,
                         Err(e) => {
                             error!("Invalid provided JWT: {}", e);
                             return;
                         }
-                    });
-                    if jwt
-                        .validate(
-                            node_id.to_owned(),
-                            auth_config.user().to_owned(),
-                            AUDIENCE_CLAIM.to_owned(),
-                            b"secret",
-                        )
-                        .is_err()
-                    {
+                    };
+                    if sessions.validate(node_id, jwt).await.is_none() {
                         return;
                     }
                 }
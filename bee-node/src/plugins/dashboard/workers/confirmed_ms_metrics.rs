@@ -7,9 +7,9 @@ use crate::{
 };
 
 use bee_ledger::workers::event::MilestoneConfirmed;
+use bee_protocol::types::metrics::ThroughputMetrics;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream};
 
-use bee_protocol::types::metrics::NodeMetrics;
 use futures::StreamExt;
 use log::{debug, error};
 use tokio::sync::mpsc;
@@ -20,7 +20,7 @@ where
     N: Node,
     N::Backend: StorageBackend,
 {
-    let metrics = node.resource::<NodeMetrics>();
+    let throughput_metrics = node.resource::<ThroughputMetrics>();
     let bus = node.bus();
     let users = users.clone();
     let (tx, rx) = mpsc::unbounded_channel::<MilestoneConfirmed>();
@@ -31,40 +31,27 @@ where
         let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
 
         let mut prev_event: Option<MilestoneConfirmed> = None;
-        let mut prev_new_message_count = 0;
 
         while let Some(event) = receiver.next().await {
             if let Some(prev_event) = prev_event {
-
                 let time_diff = event.timestamp - prev_event.timestamp;
 
-                let new_msg_count = metrics.new_messages();
-                let new_msg_diff = new_msg_count - prev_new_message_count;
-                prev_new_message_count = new_msg_count;
-
-                let mut referenced_rate = 0.0;
-                if new_msg_diff > 0 {
-                    referenced_rate = (event.referenced_messages as f64 / new_msg_diff as f64) * 100.0;
-                }
-
                 // to avoid division by zero in case two milestones do have the same timestamp
                 if time_diff > 0 {
                     let metrics = ConfirmedMilestoneMetrics {
                         ms_index: *event.index,
-                        mps: new_msg_diff / time_diff,
-                        rmps: event.referenced_messages as u64 / time_diff,
-                        referenced_rate,
+                        mps: throughput_metrics.messages_per_second() as u64,
+                        rmps: throughput_metrics.referenced_messages_per_second() as u64,
+                        referenced_rate: throughput_metrics.referenced_rate(),
                         time_since_last_ms: time_diff,
                     };
                     broadcast(metrics.into(), &users).await;
-                }  else {
+                } else {
                     error!("Can not calculate milestone confirmation metrics since the time difference between milestone {} and milestone {} is zero.", *event.index - 1, *event.index)
                 }
-
             }
 
             prev_event = Some(event);
-
         }
 
         debug!("Ws ConfirmedMilestoneMetrics topic handler stopped.");
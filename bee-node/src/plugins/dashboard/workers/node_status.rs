@@ -8,7 +8,7 @@ use crate::{
 };
 
 use bee_protocol::{types::metrics::NodeMetrics, workers::PeerManager};
-use bee_rest_api::endpoints::routes::health::is_healthy;
+use bee_rest_api::endpoints::routes::{api::v1::info::UpdateStatus, health::is_healthy};
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream};
 use bee_tangle::MsTangle;
 
@@ -38,6 +38,7 @@ where
     let peer_manager = node.resource::<PeerManager>();
     let node_config = node.resource::<NodeConfig<N::Backend>>();
     let metrics = node.resource::<NodeMetrics>();
+    let update_status = node.resource::<UpdateStatus>();
     let node_info = node.info();
     let users = users.clone();
 
@@ -58,9 +59,14 @@ where
                 is_synced: tangle.is_synced(),
             };
 
+            let update = update_status.get();
             let node_status = NodeStatus {
                 version: node_info.version.clone(),
-                latest_version: node_info.version.clone(),
+                latest_version: update
+                    .as_ref()
+                    .map_or_else(|| node_info.version.clone(), |update| update.latest_version.clone()),
+                update_available: update.as_ref().map_or(false, |update| update.update_available),
+                security_advisory: update.as_ref().map_or(false, |update| update.security_advisory),
                 uptime: uptime.elapsed().as_millis() as u64,
                 node_id: node_config.node_id.to_string(),
                 node_alias: node_config.alias.clone(),
@@ -130,6 +136,8 @@ pub struct PublicNodeStatus {
 pub struct NodeStatus {
     pub version: String,
     pub latest_version: String,
+    pub update_available: bool,
+    pub security_advisory: bool,
     pub uptime: u64,
     pub node_id: String,
     pub node_alias: String,
@@ -0,0 +1,227 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::plugins::host::protocol::{PluginEvent, Response};
+
+use log::{error, warn};
+use serde::Serialize;
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+};
+
+struct Client {
+    name: String,
+    events: HashSet<PluginEvent>,
+    tx: mpsc::Sender<Response>,
+    // Events dropped since the last one the plugin received.
+    dropped: usize,
+}
+
+/// The registered plugins and the events they subscribed to.
+#[derive(Clone, Default)]
+pub(crate) struct Clients {
+    next_id: Arc<AtomicUsize>,
+    clients: Arc<Mutex<HashMap<usize, Client>>>,
+}
+
+impl Clients {
+    fn clients(&self) -> MutexGuard<HashMap<usize, Client>> {
+        // Clients are always left consistent, a panicking holder can't corrupt them.
+        self.clients.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Adds a plugin, unless one with the same name is already registered, and returns its handle and the receiving
+    /// end of its events, buffering up to `buffer_size` of them.
+    pub(crate) fn add(
+        &self,
+        name: &str,
+        events: HashSet<PluginEvent>,
+        buffer_size: usize,
+    ) -> Option<(ClientHandle, mpsc::Receiver<Response>)> {
+        let mut clients = self.clients();
+
+        if clients.values().any(|client| client.name == name) {
+            return None;
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = mpsc::channel(buffer_size);
+
+        clients.insert(
+            id,
+            Client {
+                name: name.to_owned(),
+                events,
+                tx,
+                dropped: 0,
+            },
+        );
+
+        Some((
+            ClientHandle {
+                id,
+                clients: self.clone(),
+            },
+            rx,
+        ))
+    }
+
+    /// Drops all plugins, which terminates their connections.
+    pub(crate) fn clear(&self) {
+        self.clients().clear();
+    }
+
+    /// Returns whether a plugin subscribed to the event, allowing handlers to skip the work of building it otherwise.
+    pub(crate) fn subscribed(&self, event: PluginEvent) -> bool {
+        self.clients().values().any(|client| client.events.contains(&event))
+    }
+
+    /// Sends an event to the plugins that subscribed to it. A plugin that doesn't keep up doesn't hold the node back:
+    /// the events that don't fit in its buffer are dropped and it is told how many once it catches up.
+    pub(crate) fn publish<T: Serialize>(&self, event: PluginEvent, data: &T) {
+        let mut clients = self.clients();
+        let mut subscribed = clients
+            .values_mut()
+            .filter(|client| client.events.contains(&event))
+            .peekable();
+
+        if subscribed.peek().is_none() {
+            return;
+        }
+
+        let response = match serde_json::to_value(data) {
+            Ok(data) => Response::Event { event, data },
+            Err(e) => {
+                error!("Serializing {:?} event failed: {}.", event, e);
+                return;
+            }
+        };
+
+        for client in subscribed {
+            if client.dropped > 0 {
+                match client.tx.try_send(Response::Lagged {
+                    dropped: client.dropped,
+                }) {
+                    Ok(()) => client.dropped = 0,
+                    Err(TrySendError::Full(_)) => {
+                        client.dropped += 1;
+                        continue;
+                    }
+                    // The connection is closing and the client is about to be removed.
+                    Err(TrySendError::Closed(_)) => continue,
+                }
+            }
+
+            if let Err(TrySendError::Full(_)) = client.tx.try_send(response.clone()) {
+                if client.dropped == 0 {
+                    warn!("Plugin {} is lagging behind, dropping events.", client.name);
+                }
+                client.dropped += 1;
+            }
+        }
+    }
+}
+
+/// Handle of a plugin, removing it from the clients when dropped.
+pub(crate) struct ClientHandle {
+    id: usize,
+    clients: Clients,
+}
+
+impl Drop for ClientHandle {
+    fn drop(&mut self) {
+        self.clients.clients().remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::FutureExt;
+
+    fn events(events: &[PluginEvent]) -> HashSet<PluginEvent> {
+        events.iter().copied().collect()
+    }
+
+    fn received(rx: &mut mpsc::Receiver<Response>) -> Option<Response> {
+        rx.recv().now_or_never().flatten()
+    }
+
+    #[test]
+    fn events_are_sent_to_subscribed_plugins_only() {
+        let clients = Clients::default();
+        let (_messages, mut messages_rx) = clients.add("messages", events(&[PluginEvent::Messages]), 10).unwrap();
+        let (_outputs, mut outputs_rx) = clients.add("outputs", events(&[PluginEvent::Outputs]), 10).unwrap();
+
+        clients.publish(PluginEvent::Messages, &1);
+
+        assert!(matches!(
+            received(&mut messages_rx),
+            Some(Response::Event { event: PluginEvent::Messages, data }) if data == 1
+        ));
+        assert!(received(&mut outputs_rx).is_none());
+    }
+
+    #[test]
+    fn lagging_plugins_are_told_how_many_events_were_dropped() {
+        let clients = Clients::default();
+        let (_handle, mut rx) = clients.add("plugin", events(&[PluginEvent::Messages]), 2).unwrap();
+
+        for i in 0..5 {
+            clients.publish(PluginEvent::Messages, &i);
+        }
+
+        assert!(matches!(received(&mut rx), Some(Response::Event { data, .. }) if data == 0));
+        assert!(matches!(received(&mut rx), Some(Response::Event { data, .. }) if data == 1));
+        assert!(received(&mut rx).is_none());
+
+        clients.publish(PluginEvent::Messages, &5);
+
+        assert!(matches!(received(&mut rx), Some(Response::Lagged { dropped: 3 })));
+        assert!(matches!(received(&mut rx), Some(Response::Event { data, .. }) if data == 5));
+        assert!(received(&mut rx).is_none());
+    }
+
+    #[test]
+    fn lag_notices_take_precedence_over_events() {
+        let clients = Clients::default();
+        let (_handle, mut rx) = clients.add("plugin", events(&[PluginEvent::Messages]), 1).unwrap();
+
+        clients.publish(PluginEvent::Messages, &0);
+        clients.publish(PluginEvent::Messages, &1);
+        clients.publish(PluginEvent::Messages, &2);
+
+        assert!(matches!(received(&mut rx), Some(Response::Event { data, .. }) if data == 0));
+
+        // The notice takes the only slot, so the event is dropped too.
+        clients.publish(PluginEvent::Messages, &3);
+
+        assert!(matches!(received(&mut rx), Some(Response::Lagged { dropped: 2 })));
+
+        clients.publish(PluginEvent::Messages, &4);
+
+        assert!(matches!(received(&mut rx), Some(Response::Lagged { dropped: 1 })));
+    }
+
+    #[test]
+    fn plugins_are_registered_once_and_removed_with_their_handle() {
+        let clients = Clients::default();
+        let (handle, _rx) = clients.add("plugin", events(&[PluginEvent::Messages]), 1).unwrap();
+
+        assert!(clients.add("plugin", events(&[PluginEvent::Outputs]), 1).is_none());
+        assert!(clients.subscribed(PluginEvent::Messages));
+        assert!(!clients.subscribed(PluginEvent::Outputs));
+
+        drop(handle);
+
+        assert!(!clients.subscribed(PluginEvent::Messages));
+        assert!(clients.add("plugin", events(&[PluginEvent::Outputs]), 1).is_some());
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::plugins::host::protocol::PluginEvent;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use std::path::{Path, PathBuf};
+
+const DEFAULT_ENABLED: bool = false;
+const DEFAULT_SOCKET_PATH: &str = "./plugins.sock";
+const DEFAULT_SOCKET_MODE: u32 = 0o600;
+const DEFAULT_BUFFER_SIZE: usize = 1000;
+const DEFAULT_QUERIES: bool = true;
+
+#[derive(Debug, Error)]
+pub enum PluginHostConfigError {
+    #[error("missing name of a plugin")]
+    MissingPluginName,
+    #[error("duplicate plugin {0}")]
+    DuplicatePlugin(String),
+    #[error("buffer size must be greater than 0")]
+    NullBufferSize,
+    #[error("invalid socket mode {0:o}, expected permission bits up to 0o777")]
+    InvalidSocketMode(u32),
+}
+
+#[derive(Default, Deserialize)]
+pub struct HostedPluginConfigBuilder {
+    name: Option<String>,
+    events: Option<Vec<PluginEvent>>,
+    queries: Option<bool>,
+}
+
+impl HostedPluginConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> Result<HostedPluginConfig, PluginHostConfigError> {
+        Ok(HostedPluginConfig {
+            name: self.name.ok_or(PluginHostConfigError::MissingPluginName)?,
+            events: self.events.unwrap_or_else(|| PluginEvent::ALL.to_vec()),
+            queries: self.queries.unwrap_or(DEFAULT_QUERIES),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct HostedPluginConfig {
+    name: String,
+    events: Vec<PluginEvent>,
+    queries: bool,
+}
+
+impl HostedPluginConfig {
+    pub fn build() -> HostedPluginConfigBuilder {
+        HostedPluginConfigBuilder::new()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the events the plugin is allowed to subscribe to.
+    pub fn events(&self) -> &[PluginEvent] {
+        &self.events
+    }
+
+    /// Returns whether the plugin is allowed to query the node.
+    pub fn queries(&self) -> bool {
+        self.queries
+    }
+}
+
+#[derive(Default, Deserialize)]
+pub struct PluginHostConfigBuilder {
+    enabled: Option<bool>,
+    socket_path: Option<String>,
+    socket_mode: Option<u32>,
+    buffer_size: Option<usize>,
+    plugins: Option<Vec<HostedPluginConfigBuilder>>,
+}
+
+impl PluginHostConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled.replace(enabled);
+        self
+    }
+
+    pub fn socket_path(mut self, socket_path: String) -> Self {
+        self.socket_path.replace(socket_path);
+        self
+    }
+
+    pub fn socket_mode(mut self, socket_mode: u32) -> Self {
+        self.socket_mode.replace(socket_mode);
+        self
+    }
+
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size.replace(buffer_size);
+        self
+    }
+
+    pub fn finish(self) -> Result<PluginHostConfig, PluginHostConfigError> {
+        let plugins = self
+            .plugins
+            .unwrap_or_default()
+            .into_iter()
+            .map(HostedPluginConfigBuilder::finish)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (i, plugin) in plugins.iter().enumerate() {
+            if plugins[..i].iter().any(|other| other.name == plugin.name) {
+                return Err(PluginHostConfigError::DuplicatePlugin(plugin.name.clone()));
+            }
+        }
+
+        let buffer_size = self.buffer_size.unwrap_or(DEFAULT_BUFFER_SIZE);
+        if buffer_size == 0 {
+            return Err(PluginHostConfigError::NullBufferSize);
+        }
+
+        let socket_mode = self.socket_mode.unwrap_or(DEFAULT_SOCKET_MODE);
+        if socket_mode > 0o777 {
+            return Err(PluginHostConfigError::InvalidSocketMode(socket_mode));
+        }
+
+        Ok(PluginHostConfig {
+            enabled: self.enabled.unwrap_or(DEFAULT_ENABLED),
+            socket_path: PathBuf::from(self.socket_path.unwrap_or_else(|| DEFAULT_SOCKET_PATH.to_owned())),
+            socket_mode,
+            buffer_size,
+            plugins,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct PluginHostConfig {
+    enabled: bool,
+    socket_path: PathBuf,
+    socket_mode: u32,
+    buffer_size: usize,
+    plugins: Vec<HostedPluginConfig>,
+}
+
+impl PluginHostConfig {
+    pub fn build() -> PluginHostConfigBuilder {
+        PluginHostConfigBuilder::new()
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// Returns the permissions of the socket, only its owner can connect by default.
+    pub fn socket_mode(&self) -> u32 {
+        self.socket_mode
+    }
+
+    /// Returns the number of events buffered for a plugin before dropping the next ones.
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    pub fn plugins(&self) -> &[HostedPluginConfig] {
+        &self.plugins
+    }
+
+    pub fn plugin(&self, name: &str) -> Option<&HostedPluginConfig> {
+        self.plugins.iter().find(|config| config.name() == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn socket_is_restricted_to_its_owner_by_default() {
+        assert_eq!(PluginHostConfig::build().finish().unwrap().socket_mode(), 0o600);
+    }
+
+    #[test]
+    fn socket_mode_is_limited_to_permission_bits() {
+        assert_eq!(
+            PluginHostConfig::build()
+                .socket_mode(0o660)
+                .finish()
+                .unwrap()
+                .socket_mode(),
+            0o660
+        );
+        assert!(matches!(
+            PluginHostConfig::build().socket_mode(0o4755).finish(),
+            Err(PluginHostConfigError::InvalidSocketMode(0o4755))
+        ));
+    }
+}
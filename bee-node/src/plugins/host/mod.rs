@@ -0,0 +1,580 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Host of out-of-process plugins, forwarding bus events to them and answering their queries over a Unix socket.
+
+mod clients;
+
+pub mod config;
+pub mod protocol;
+
+use crate::{
+    plugins::host::{
+        clients::{ClientHandle, Clients},
+        config::PluginHostConfig,
+        protocol::{InfoResult, MilestoneEvent, PluginEvent, Query, Request, Response, PROTOCOL_VERSION},
+    },
+    storage::StorageBackend,
+};
+
+use bee_ledger::{
+    types::{ConsumedOutput, CreatedOutput},
+    workers::{event::MilestoneConfirmed, storage::fetch_milestone_outputs},
+};
+use bee_message::{milestone::MilestoneIndex, output::OutputId, MessageId};
+use bee_protocol::workers::{
+    event::{MessageProcessed, MessageSolidified},
+    PendingSpends, TransactionPayloadWorker,
+};
+use bee_rest_api::{
+    endpoints::routes::api::v1::message_metadata::create_message_metadata,
+    types::{
+        dtos::MessageDto,
+        responses::{MilestoneResponse, OutputResponse},
+    },
+};
+use bee_runtime::{node::Node, resource::ResourceHandle, shutdown_stream::ShutdownStream, worker::Worker};
+use bee_storage::access::Fetch;
+use bee_tangle::{event::LatestMilestoneChanged, MsTangle, TangleWorker};
+
+use async_trait::async_trait;
+use futures::{future::Future, stream::StreamExt};
+use log::{debug, error, info, warn};
+use tokio::{
+    io::AsyncWriteExt,
+    net::{unix::OwnedWriteHalf, UnixListener, UnixStream},
+    sync::mpsc,
+    time::timeout,
+};
+use tokio_stream::wrappers::{UnboundedReceiverStream, UnixListenerStream};
+use tokio_util::codec::{FramedRead, LinesCodec, LinesCodecError};
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashSet,
+    convert::Infallible,
+    fs,
+    io::{self, ErrorKind},
+    os::unix::fs::{FileTypeExt, PermissionsExt},
+    path::Path,
+    time::Duration,
+};
+
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Maximum length of a request line, longer ones are discarded.
+const MAX_REQUEST_LENGTH: usize = 64 * 1024;
+
+#[derive(Default)]
+pub struct PluginHost;
+
+fn event_handler<N, E, F, Fut>(node: &mut N, f: F)
+where
+    N: Node,
+    E: Any + Clone + Send + Sync,
+    F: 'static + Fn(E) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send,
+{
+    let bus = node.bus();
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    node.spawn::<PluginHost, _, _>(|shutdown| async move {
+        debug!("Plugin event handler running.");
+
+        let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
+
+        while let Some(event) = receiver.next().await {
+            f(event).await;
+        }
+
+        debug!("Plugin event handler stopped.");
+    });
+
+    bus.add_listener::<PluginHost, E, _>(move |event: &E| {
+        // Handlers are stopped as soon as the shutdown signal is received while listeners are removed with the
+        // worker, failing sends in between are therefore expected.
+        let _ = tx.send(event.clone());
+    });
+}
+
+async fn publish_message_metadata<B: StorageBackend>(
+    tangle: &MsTangle<B>,
+    pending_spends: &PendingSpends,
+    clients: &Clients,
+    message_ids: impl Iterator<Item = MessageId>,
+) {
+    if !clients.subscribed(PluginEvent::Metadata) {
+        return;
+    }
+
+    for message_id in message_ids {
+        if let Some(metadata) = create_message_metadata(message_id, tangle, pending_spends).await {
+            clients.publish(PluginEvent::Metadata, &metadata);
+        }
+    }
+}
+
+async fn publish_outputs<B: StorageBackend>(storage: &B, clients: &Clients, index: MilestoneIndex) {
+    if !clients.subscribed(PluginEvent::Outputs) {
+        return;
+    }
+
+    let outputs = match fetch_milestone_outputs(storage, index).await {
+        Ok(outputs) => outputs,
+        Err(e) => {
+            error!("Fetching outputs of milestone {} failed: {}.", *index, e);
+            return;
+        }
+    };
+
+    for (output_id, output, is_spent) in outputs {
+        clients.publish(
+            PluginEvent::Outputs,
+            &OutputResponse {
+                message_id: output.message_id().to_string(),
+                transaction_id: output_id.transaction_id().to_string(),
+                output_index: output_id.index(),
+                is_spent,
+                output: output.inner().into(),
+            },
+        );
+    }
+}
+
+fn init_event_handlers<N: Node>(node: &mut N, clients: &Clients)
+where
+    N::Backend: StorageBackend,
+{
+    let tangle = node.resource::<MsTangle<N::Backend>>();
+    let storage = node.storage();
+    let pending_spends = node.resource::<PendingSpends>();
+
+    {
+        let clients = clients.clone();
+        let tangle = tangle.clone();
+        event_handler(node, move |event: MessageProcessed| {
+            let clients = clients.clone();
+            let tangle = tangle.clone();
+            async move {
+                if !clients.subscribed(PluginEvent::Messages) {
+                    return;
+                }
+                if let Some(message) = tangle.get(&event.message_id).await.map(|m| (*m).clone()) {
+                    clients.publish(PluginEvent::Messages, &MessageDto::from(&message));
+                }
+            }
+        });
+    }
+    {
+        let clients = clients.clone();
+        event_handler(node, move |event: LatestMilestoneChanged| {
+            let clients = clients.clone();
+            async move {
+                clients.publish(
+                    PluginEvent::Milestones,
+                    &MilestoneEvent {
+                        confirmed: false,
+                        milestone: MilestoneResponse {
+                            milestone_index: *event.index,
+                            message_id: event.milestone.message_id().to_string(),
+                            timestamp: event.milestone.timestamp(),
+                        },
+                    },
+                );
+            }
+        });
+    }
+    {
+        let clients = clients.clone();
+        let tangle = tangle.clone();
+        let pending_spends = pending_spends.clone();
+        event_handler(node, move |event: MilestoneConfirmed| {
+            let clients = clients.clone();
+            let tangle = tangle.clone();
+            let storage = storage.clone();
+            let pending_spends = pending_spends.clone();
+            async move {
+                clients.publish(
+                    PluginEvent::Milestones,
+                    &MilestoneEvent {
+                        confirmed: true,
+                        milestone: MilestoneResponse {
+                            milestone_index: *event.index,
+                            message_id: event.id.to_string(),
+                            timestamp: event.timestamp,
+                        },
+                    },
+                );
+
+                let referenced = event
+                    .included_messages
+                    .iter()
+                    .chain(event.excluded_no_transaction_messages.iter())
+                    .chain(
+                        event
+                            .excluded_conflicting_messages
+                            .iter()
+                            .map(|(message_id, _)| message_id),
+                    )
+                    .copied();
+                publish_message_metadata(&tangle, &pending_spends, &clients, referenced).await;
+                publish_outputs(&*storage, &clients, event.index).await;
+            }
+        });
+    }
+    {
+        let clients = clients.clone();
+        event_handler(node, move |event: MessageSolidified| {
+            let clients = clients.clone();
+            let tangle = tangle.clone();
+            let pending_spends = pending_spends.clone();
+            async move {
+                publish_message_metadata(&tangle, &pending_spends, &clients, std::iter::once(event.message_id)).await;
+            }
+        });
+    }
+}
+
+/// Removes the socket at the path, refusing to remove any other kind of file.
+fn remove_socket(path: &Path) -> io::Result<()> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => fs::remove_file(path),
+        Ok(_) => Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            "the path exists and is not a socket",
+        )),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+async fn write(writer: &mut OwnedWriteHalf, response: &Response) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(response)?;
+
+    line.push(b'\n');
+    writer.write_all(&line).await
+}
+
+fn register(
+    line: &str,
+    config: &PluginHostConfig,
+    clients: &Clients,
+) -> Result<(ClientHandle, mpsc::Receiver<Response>, bool, Vec<PluginEvent>), String> {
+    let (name, protocol_version, events) = match serde_json::from_str::<Request>(line) {
+        Ok(Request::Register {
+            name,
+            protocol_version,
+            events,
+        }) => (name, protocol_version, events),
+        Ok(_) => return Err("expected a register request".to_owned()),
+        Err(e) => return Err(format!("invalid request: {}", e)),
+    };
+
+    if protocol_version != PROTOCOL_VERSION {
+        return Err(format!(
+            "unsupported protocol version {}, expected {}",
+            protocol_version, PROTOCOL_VERSION
+        ));
+    }
+
+    let plugin = config.plugin(&name).ok_or_else(|| format!("unknown plugin {}", name))?;
+    let events = events.unwrap_or_else(|| plugin.events().to_vec());
+
+    if let Some(event) = events.iter().find(|event| !plugin.events().contains(event)) {
+        return Err(format!(
+            "plugin {} is not allowed to subscribe to {:?} events",
+            name, event
+        ));
+    }
+
+    let (handle, rx) = clients
+        .add(
+            &name,
+            events.iter().copied().collect::<HashSet<_>>(),
+            config.buffer_size(),
+        )
+        .ok_or_else(|| format!("plugin {} is already registered", name))?;
+
+    info!("Plugin {} registered for {:?} events.", name, events);
+
+    Ok((handle, rx, plugin.queries(), events))
+}
+
+async fn query<B: StorageBackend>(
+    query: Query,
+    tangle: &MsTangle<B>,
+    pending_spends: &PendingSpends,
+    storage: &B,
+) -> Result<serde_json::Value, String> {
+    let data = match query {
+        Query::Info => serde_json::to_value(InfoResult {
+            latest_milestone_index: *tangle.get_latest_milestone_index(),
+            confirmed_milestone_index: *tangle.get_confirmed_milestone_index(),
+        }),
+        Query::Message { message_id } => {
+            let message_id = message_id
+                .parse::<MessageId>()
+                .map_err(|_| "invalid message id".to_owned())?;
+            let message = tangle
+                .get(&message_id)
+                .await
+                .map(|m| (*m).clone())
+                .ok_or_else(|| "can not find message".to_owned())?;
+
+            serde_json::to_value(MessageDto::from(&message))
+        }
+        Query::MessageMetadata { message_id } => {
+            let message_id = message_id
+                .parse::<MessageId>()
+                .map_err(|_| "invalid message id".to_owned())?;
+            let metadata = create_message_metadata(message_id, tangle, pending_spends)
+                .await
+                .ok_or_else(|| "can not find message".to_owned())?;
+
+            serde_json::to_value(metadata)
+        }
+        Query::Milestone { index } => {
+            let message_id = tangle
+                .get_milestone_message_id(MilestoneIndex(index))
+                .await
+                .ok_or_else(|| "can not find milestone".to_owned())?;
+            let metadata = tangle
+                .get_metadata(&message_id)
+                .await
+                .ok_or_else(|| "can not find metadata for milestone".to_owned())?;
+
+            serde_json::to_value(MilestoneResponse {
+                milestone_index: index,
+                message_id: message_id.to_string(),
+                timestamp: metadata.arrival_timestamp(),
+            })
+        }
+        Query::Output { output_id } => {
+            let output_id = output_id
+                .parse::<OutputId>()
+                .map_err(|_| "invalid output id".to_owned())?;
+            let output = Fetch::<OutputId, CreatedOutput>::fetch(storage, &output_id)
+                .await
+                .map_err(|_| "can not fetch from storage".to_owned())?
+                .ok_or_else(|| "can not find output".to_owned())?;
+            let is_spent = Fetch::<OutputId, ConsumedOutput>::fetch(storage, &output_id)
+                .await
+                .map_err(|_| "can not fetch from storage".to_owned())?
+                .is_some();
+
+            serde_json::to_value(OutputResponse {
+                message_id: output.message_id().to_string(),
+                transaction_id: output_id.transaction_id().to_string(),
+                output_index: output_id.index(),
+                is_spent,
+                output: output.inner().into(),
+            })
+        }
+    };
+
+    data.map_err(|e| format!("serializing the result failed: {}", e))
+}
+
+async fn serve<B: StorageBackend>(
+    stream: UnixStream,
+    config: PluginHostConfig,
+    clients: Clients,
+    tangle: ResourceHandle<MsTangle<B>>,
+    pending_spends: ResourceHandle<PendingSpends>,
+    storage: ResourceHandle<B>,
+) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = FramedRead::new(reader, LinesCodec::new_with_max_length(MAX_REQUEST_LENGTH));
+
+    let line = match timeout(HANDSHAKE_TIMEOUT, lines.next()).await {
+        Ok(Some(Ok(line))) => line,
+        _ => return,
+    };
+
+    let (_handle, mut rx, queries, events) = match register(&line, &config, &clients) {
+        Ok(registration) => registration,
+        Err(reason) => {
+            warn!("Rejected plugin registration: {}.", reason);
+            let _ = write(&mut writer, &Response::Rejected { reason }).await;
+            return;
+        }
+    };
+
+    if write(
+        &mut writer,
+        &Response::Registered {
+            protocol_version: PROTOCOL_VERSION,
+            events,
+        },
+    )
+    .await
+    .is_err()
+    {
+        return;
+    }
+
+    loop {
+        let response = tokio::select! {
+            // The clients are cleared on shutdown, which closes the channel.
+            response = rx.recv() => match response {
+                Some(response) => response,
+                None => break,
+            },
+            line = lines.next() => match line {
+                Some(Ok(line)) => match serde_json::from_str::<Request>(&line) {
+                    Ok(Request::Query { id, query: _ }) if !queries => Response::Error {
+                        id: Some(id),
+                        reason: "queries are not allowed".to_owned(),
+                    },
+                    Ok(Request::Query { id, query }) => match self::query(query, &tangle, &pending_spends, &storage).await {
+                        Ok(data) => Response::Result { id, data },
+                        Err(reason) => Response::Error { id: Some(id), reason },
+                    },
+                    Ok(Request::Register { .. }) => Response::Error {
+                        id: None,
+                        reason: "already registered".to_owned(),
+                    },
+                    Err(e) => Response::Error {
+                        id: None,
+                        reason: format!("invalid request: {}", e),
+                    },
+                },
+                // The codec skips the rest of the line, so the connection can carry on.
+                Some(Err(LinesCodecError::MaxLineLengthExceeded)) => Response::Error {
+                    id: None,
+                    reason: format!("request longer than {} bytes", MAX_REQUEST_LENGTH),
+                },
+                _ => break,
+            },
+        };
+
+        if write(&mut writer, &response).await.is_err() {
+            break;
+        }
+    }
+}
+
+#[async_trait]
+impl<N: Node> Worker<N> for PluginHost
+where
+    N::Backend: StorageBackend,
+{
+    type Config = PluginHostConfig;
+    type Error = Infallible;
+
+    fn dependencies() -> &'static [TypeId] {
+        vec![TypeId::of::<TangleWorker>(), TypeId::of::<TransactionPayloadWorker>()].leak()
+    }
+
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
+        if !config.enabled() {
+            return Ok(Self::default());
+        }
+
+        // A socket left over by a previous run would prevent binding.
+        if let Err(e) = remove_socket(config.socket_path()) {
+            error!(
+                "Removing previous plugin socket {} failed: {}.",
+                config.socket_path().display(),
+                e
+            );
+            return Ok(Self::default());
+        }
+
+        let listener = match UnixListener::bind(config.socket_path()) {
+            Ok(listener) => listener,
+            Err(e) => {
+                error!(
+                    "Binding plugin socket {} failed: {}.",
+                    config.socket_path().display(),
+                    e
+                );
+                return Ok(Self::default());
+            }
+        };
+
+        // Plugins query the node without authentication, so the socket has to be restricted.
+        if let Err(e) = fs::set_permissions(config.socket_path(), fs::Permissions::from_mode(config.socket_mode())) {
+            error!(
+                "Setting permissions of plugin socket {} failed: {}.",
+                config.socket_path().display(),
+                e
+            );
+            let _ = remove_socket(config.socket_path());
+            return Ok(Self::default());
+        }
+
+        let clients = Clients::default();
+        let tangle = node.resource::<MsTangle<N::Backend>>();
+        let pending_spends = node.resource::<PendingSpends>();
+        let storage = node.storage();
+
+        init_event_handlers(node, &clients);
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            info!("Running.");
+
+            let mut connections = ShutdownStream::new(shutdown, UnixListenerStream::new(listener));
+
+            while let Some(connection) = connections.next().await {
+                match connection {
+                    Ok(stream) => {
+                        tokio::spawn(serve(
+                            stream,
+                            config.clone(),
+                            clients.clone(),
+                            tangle.clone(),
+                            pending_spends.clone(),
+                            storage.clone(),
+                        ));
+                    }
+                    Err(e) => warn!("Accepting plugin connection failed: {}.", e),
+                }
+            }
+
+            clients.clear();
+            let _ = remove_socket(config.socket_path());
+
+            info!("Stopped.");
+        });
+
+        Ok(Self::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{os::unix::net::UnixListener as StdUnixListener, path::PathBuf};
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bee-plugin-host-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn stale_socket_is_removed() {
+        let path = temp_path("stale.sock");
+        let _ = fs::remove_file(&path);
+        drop(StdUnixListener::bind(&path).unwrap());
+
+        remove_socket(&path).unwrap();
+
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn missing_socket_is_fine() {
+        assert!(remove_socket(&temp_path("missing.sock")).is_ok());
+    }
+
+    #[test]
+    fn other_files_are_kept() {
+        let path = temp_path("file");
+        fs::write(&path, "data").unwrap();
+
+        assert_eq!(remove_socket(&path).unwrap_err().kind(), ErrorKind::AlreadyExists);
+        assert!(path.exists());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
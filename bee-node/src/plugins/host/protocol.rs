@@ -0,0 +1,226 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Messages exchanged with out-of-process plugins, one JSON object per line.
+//!
+//! A plugin first sends a `register` request and receives either `registered` or `rejected`. It then receives the
+//! `event`s it subscribed to, interleaved with `lagged` notices when events were dropped because it didn't keep up,
+//! and the `result`s or `error`s of its `query` requests.
+
+use bee_rest_api::types::responses::MilestoneResponse;
+
+use serde::{Deserialize, Serialize};
+
+/// Version of the protocol, a plugin registering with another version is rejected.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Kinds of bus events forwarded to the plugins.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginEvent {
+    /// Processed messages.
+    Messages,
+    /// Latest and confirmed milestones.
+    Milestones,
+    /// Created and consumed outputs.
+    Outputs,
+    /// Metadata of solidified and referenced messages.
+    Metadata,
+}
+
+impl PluginEvent {
+    pub const ALL: [PluginEvent; 4] = [Self::Messages, Self::Milestones, Self::Outputs, Self::Metadata];
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum Request {
+    Register {
+        name: String,
+        #[serde(rename = "protocolVersion")]
+        protocol_version: u32,
+        events: Option<Vec<PluginEvent>>,
+    },
+    Query {
+        id: u64,
+        query: Query,
+    },
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum Query {
+    Info,
+    Message {
+        #[serde(rename = "messageId")]
+        message_id: String,
+    },
+    MessageMetadata {
+        #[serde(rename = "messageId")]
+        message_id: String,
+    },
+    Milestone {
+        index: u32,
+    },
+    Output {
+        #[serde(rename = "outputId")]
+        output_id: String,
+    },
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum Response {
+    Registered {
+        #[serde(rename = "protocolVersion")]
+        protocol_version: u32,
+        events: Vec<PluginEvent>,
+    },
+    Rejected {
+        reason: String,
+    },
+    Event {
+        event: PluginEvent,
+        data: serde_json::Value,
+    },
+    Lagged {
+        dropped: usize,
+    },
+    Result {
+        id: u64,
+        data: serde_json::Value,
+    },
+    Error {
+        id: Option<u64>,
+        reason: String,
+    },
+}
+
+/// Result of an info query.
+#[derive(Serialize)]
+pub(crate) struct InfoResult {
+    #[serde(rename = "latestMilestoneIndex")]
+    pub(crate) latest_milestone_index: u32,
+    #[serde(rename = "confirmedMilestoneIndex")]
+    pub(crate) confirmed_milestone_index: u32,
+}
+
+/// Data of a milestones event.
+#[derive(Serialize)]
+pub(crate) struct MilestoneEvent {
+    pub(crate) confirmed: bool,
+    #[serde(flatten)]
+    pub(crate) milestone: MilestoneResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn register_request_is_deserialized() {
+        let request = serde_json::from_value::<Request>(json!({
+            "type": "register",
+            "name": "indexer",
+            "protocolVersion": 1,
+            "events": ["messages", "outputs"],
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            request,
+            Request::Register { name, protocol_version: 1, events: Some(events) }
+                if name == "indexer" && events == vec![PluginEvent::Messages, PluginEvent::Outputs]
+        ));
+    }
+
+    #[test]
+    fn register_request_events_are_optional() {
+        let request = serde_json::from_value::<Request>(json!({
+            "type": "register",
+            "name": "indexer",
+            "protocolVersion": 1,
+        }))
+        .unwrap();
+
+        assert!(matches!(request, Request::Register { events: None, .. }));
+    }
+
+    #[test]
+    fn query_requests_are_deserialized() {
+        let request = serde_json::from_value::<Request>(json!({
+            "type": "query",
+            "id": 7,
+            "query": { "type": "message_metadata", "messageId": "abc" },
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            request,
+            Request::Query { id: 7, query: Query::MessageMetadata { message_id } } if message_id == "abc"
+        ));
+        assert!(matches!(
+            serde_json::from_value::<Request>(json!({ "type": "query", "id": 8, "query": { "type": "info" } })),
+            Ok(Request::Query {
+                id: 8,
+                query: Query::Info
+            })
+        ));
+    }
+
+    #[test]
+    fn unknown_requests_and_events_are_rejected() {
+        assert!(serde_json::from_value::<Request>(json!({ "type": "unregister" })).is_err());
+        assert!(serde_json::from_value::<PluginEvent>(json!("tips")).is_err());
+    }
+
+    #[test]
+    fn responses_are_serialized() {
+        assert_eq!(
+            serde_json::to_value(Response::Registered {
+                protocol_version: PROTOCOL_VERSION,
+                events: vec![PluginEvent::Metadata],
+            })
+            .unwrap(),
+            json!({ "type": "registered", "protocolVersion": 1, "events": ["metadata"] })
+        );
+        assert_eq!(
+            serde_json::to_value(Response::Event {
+                event: PluginEvent::Milestones,
+                data: json!({ "index": 1 }),
+            })
+            .unwrap(),
+            json!({ "type": "event", "event": "milestones", "data": { "index": 1 } })
+        );
+        assert_eq!(
+            serde_json::to_value(Response::Lagged { dropped: 3 }).unwrap(),
+            json!({ "type": "lagged", "dropped": 3 })
+        );
+        assert_eq!(
+            serde_json::to_value(Response::Error {
+                id: None,
+                reason: "invalid request".to_owned(),
+            })
+            .unwrap(),
+            json!({ "type": "error", "id": null, "reason": "invalid request" })
+        );
+    }
+
+    #[test]
+    fn milestone_event_is_flattened() {
+        assert_eq!(
+            serde_json::to_value(MilestoneEvent {
+                confirmed: true,
+                milestone: MilestoneResponse {
+                    milestone_index: 1,
+                    message_id: "abc".to_owned(),
+                    timestamp: 2,
+                },
+            })
+            .unwrap(),
+            json!({ "confirmed": true, "index": 1, "messageId": "abc", "timestamp": 2 })
+        );
+    }
+}
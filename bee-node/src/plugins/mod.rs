@@ -3,12 +3,16 @@
 
 #[cfg(feature = "dashboard")]
 pub mod dashboard;
+#[cfg(unix)]
+pub mod host;
 pub mod mps;
 pub mod mqtt;
 pub mod version_checker;
 
 #[cfg(feature = "dashboard")]
 pub use dashboard::Dashboard;
+#[cfg(unix)]
+pub use host::PluginHost;
 pub use mps::Mps;
 pub use mqtt::Mqtt;
 pub use version_checker::VersionChecker;
@@ -0,0 +1,91 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use reqwest::Url;
+use serde::Deserialize;
+use thiserror::Error;
+
+use std::time::Duration;
+
+const DEFAULT_ENABLED: bool = true;
+const DEFAULT_INTERVAL_SEC: u64 = 3600;
+
+#[derive(Debug, Error)]
+pub enum VersionCheckerConfigError {
+    #[error("invalid release feed url {0}")]
+    InvalidUrl(String),
+    #[error("interval must be greater than 0")]
+    NullInterval,
+}
+
+#[derive(Default, Deserialize)]
+pub struct VersionCheckerConfigBuilder {
+    enabled: Option<bool>,
+    url: Option<String>,
+    interval_sec: Option<u64>,
+}
+
+impl VersionCheckerConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled.replace(enabled);
+        self
+    }
+
+    pub fn url(mut self, url: String) -> Self {
+        self.url.replace(url);
+        self
+    }
+
+    pub fn interval_sec(mut self, interval_sec: u64) -> Self {
+        self.interval_sec.replace(interval_sec);
+        self
+    }
+
+    pub fn finish(self) -> Result<VersionCheckerConfig, VersionCheckerConfigError> {
+        let url = match self.url {
+            Some(url) => Some(Url::parse(&url).map_err(|_| VersionCheckerConfigError::InvalidUrl(url))?),
+            None => None,
+        };
+
+        let interval_sec = self.interval_sec.unwrap_or(DEFAULT_INTERVAL_SEC);
+        if interval_sec == 0 {
+            return Err(VersionCheckerConfigError::NullInterval);
+        }
+
+        Ok(VersionCheckerConfig {
+            enabled: self.enabled.unwrap_or(DEFAULT_ENABLED),
+            url,
+            interval: Duration::from_secs(interval_sec),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct VersionCheckerConfig {
+    enabled: bool,
+    url: Option<Url>,
+    interval: Duration,
+}
+
+impl VersionCheckerConfig {
+    pub fn build() -> VersionCheckerConfigBuilder {
+        VersionCheckerConfigBuilder::new()
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the url of the release feed, the node doesn't check for updates without one.
+    pub fn url(&self) -> Option<&Url> {
+        self.url.as_ref()
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+}
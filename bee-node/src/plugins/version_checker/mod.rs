@@ -0,0 +1,359 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Periodically fetches a release feed and reports whether a newer version, possibly fixing security issues, is
+//! available.
+//!
+//! The feed is a JSON document listing the releases:
+//!
+//! ```json
+//! { "releases": [{ "version": "0.2.0", "security": true }] }
+//! ```
+
+pub mod config;
+
+use crate::constants::BEE_VERSION;
+
+use config::VersionCheckerConfig;
+
+use bee_rest_api::{endpoints::routes::api::v1::info::UpdateStatus, types::dtos::UpdateDto};
+use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use log::{error, info, warn};
+use reqwest::{Client, Url};
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::time::interval;
+use tokio_stream::wrappers::IntervalStream;
+
+use std::{cmp::Ordering, convert::Infallible, str::FromStr, time::Duration};
+
+const REQUEST_TIMEOUT_SEC: u64 = 30;
+
+#[derive(Debug, Error)]
+enum Error {
+    #[error("fetching the release feed failed: {0}")]
+    Fetch(#[from] reqwest::Error),
+    #[error("invalid version {0}")]
+    InvalidVersion(String),
+}
+
+/// A release listed in the release feed.
+#[derive(Deserialize)]
+struct Release {
+    version: String,
+    #[serde(default)]
+    security: bool,
+}
+
+#[derive(Deserialize)]
+struct ReleaseFeed {
+    releases: Vec<Release>,
+}
+
+/// A `major.minor.patch` version with an optional pre-release, ordered as in semantic versioning.
+#[derive(Debug, PartialEq, Eq)]
+struct Version {
+    numbers: (u64, u64, u64),
+    pre_release: Option<String>,
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidVersion(s.to_owned());
+        // Build metadata doesn't take part in the ordering.
+        let version = s.trim_start_matches('v').splitn(2, '+').next().ok_or_else(invalid)?;
+        let mut parts = version.splitn(2, '-');
+        let numbers = parts
+            .next()
+            .ok_or_else(invalid)?
+            .split('.')
+            .map(u64::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid())?;
+
+        match numbers.as_slice() {
+            [major, minor, patch] => Ok(Self {
+                numbers: (*major, *minor, *patch),
+                pre_release: parts.next().map(str::to_owned),
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.numbers
+            .cmp(&other.numbers)
+            .then_with(|| match (&self.pre_release, &other.pre_release) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => cmp_pre_releases(a, b),
+            })
+    }
+}
+
+/// Compares pre-releases identifier by identifier, numeric ones numerically and lower than alphanumeric ones. A
+/// pre-release that is a prefix of the other one is lower.
+fn cmp_pre_releases(a: &str, b: &str) -> Ordering {
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                (Ok(a), Ok(b)) => a.cmp(&b),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => a.cmp(b),
+            },
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+async fn fetch(client: &Client, url: &Url) -> Result<ReleaseFeed, Error> {
+    Ok(client
+        .get(url.clone())
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<ReleaseFeed>()
+        .await?)
+}
+
+/// Compares the releases of the feed with the `current` version. Pre-releases are only considered when running one.
+fn check(feed: &ReleaseFeed, current: &str) -> Result<UpdateDto, Error> {
+    let current_version = current.parse::<Version>()?;
+    let mut newer = feed
+        .releases
+        .iter()
+        .filter_map(|release| match release.version.parse::<Version>() {
+            Ok(version) => Some((version, release)),
+            Err(e) => {
+                warn!("Ignoring release: {}.", e);
+                None
+            }
+        })
+        .filter(|(version, _)| current_version.pre_release.is_some() || version.pre_release.is_none())
+        .filter(|(version, _)| *version > current_version)
+        .collect::<Vec<_>>();
+
+    newer.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(UpdateDto {
+        latest_version: newer
+            .last()
+            .map_or_else(|| current.to_owned(), |(_, release)| release.version.clone()),
+        update_available: !newer.is_empty(),
+        security_advisory: newer.iter().any(|(_, release)| release.security),
+    })
+}
+
+#[derive(Default)]
+pub struct VersionChecker {}
+
+#[async_trait]
+impl<N: Node> Worker<N> for VersionChecker {
+    type Config = VersionCheckerConfig;
+    type Error = Infallible;
+
+    async fn start(node: &mut N, config: Self::Config) -> Result<Self, Self::Error> {
+        let url = match (config.enabled(), config.url()) {
+            (false, _) => {
+                info!("Disabled.");
+                return Ok(Self::default());
+            }
+            (true, None) => {
+                warn!("No release feed configured, not checking for updates.");
+                return Ok(Self::default());
+            }
+            (true, Some(url)) => url.clone(),
+        };
+
+        let client = match Client::builder()
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SEC))
+            .user_agent(format!("bee/{}", BEE_VERSION))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                error!("Creating the HTTP client failed: {}.", e);
+                return Ok(Self::default());
+            }
+        };
+        let update_status = node.resource::<UpdateStatus>();
+
+        node.spawn::<Self, _, _>(|shutdown| async move {
+            info!("Running.");
+
+            let mut ticker = ShutdownStream::new(shutdown, IntervalStream::new(interval(config.interval())));
+            let mut latest_version = None;
+
+            while ticker.next().await.is_some() {
+                let update = match fetch(&client, &url).await.and_then(|feed| check(&feed, BEE_VERSION)) {
+                    Ok(update) => update,
+                    Err(e) => {
+                        warn!("Checking for updates failed: {}.", e);
+                        continue;
+                    }
+                };
+
+                // Only report a release once.
+                if latest_version.as_ref() != Some(&update.latest_version) {
+                    if update.security_advisory {
+                        warn!(
+                            "Version {} is available and fixes security issues, running {}. Updating is strongly \
+                            advised.",
+                            update.latest_version, BEE_VERSION
+                        );
+                    } else if update.update_available {
+                        info!(
+                            "Version {} is available, running {}.",
+                            update.latest_version, BEE_VERSION
+                        );
+                    }
+                    latest_version = Some(update.latest_version.clone());
+                }
+
+                update_status.set(update);
+            }
+
+            info!("Stopped.");
+        });
+
+        Ok(Self::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use warp::Filter;
+
+    fn serve(feed: &'static str) -> Url {
+        let (address, server) =
+            warp::serve(warp::path("releases.json").map(move || feed)).bind_ephemeral(([127, 0, 0, 1], 0));
+
+        tokio::spawn(server);
+
+        Url::parse(&format!("http://{}/releases.json", address)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn update_available() {
+        let url = serve(
+            r#"{"releases": [
+                {"version": "0.1.0"},
+                {"version": "0.2.0", "security": true},
+                {"version": "0.3.0-rc.1"},
+                {"version": "0.2.1"},
+                {"version": "invalid"}
+            ]}"#,
+        );
+        let feed = fetch(&Client::new(), &url).await.unwrap();
+        let update = check(&feed, "0.1.1").unwrap();
+
+        assert_eq!(update.latest_version, "0.2.1");
+        assert!(update.update_available);
+        assert!(update.security_advisory);
+    }
+
+    #[tokio::test]
+    async fn pre_release_update_available() {
+        let url = serve(
+            r#"{"releases": [
+                {"version": "0.2.0-rc.2"},
+                {"version": "0.2.0-rc.10"},
+                {"version": "0.1.0"}
+            ]}"#,
+        );
+        let feed = fetch(&Client::new(), &url).await.unwrap();
+        let update = check(&feed, "0.2.0-rc.1").unwrap();
+
+        assert_eq!(update.latest_version, "0.2.0-rc.10");
+        assert!(update.update_available);
+        assert!(!update.security_advisory);
+    }
+
+    #[test]
+    fn pre_releases_are_ignored_when_running_a_release() {
+        let feed = ReleaseFeed {
+            releases: vec![Release {
+                version: "0.3.0-rc.1".to_owned(),
+                security: true,
+            }],
+        };
+        let update = check(&feed, "0.2.0").unwrap();
+
+        assert_eq!(update.latest_version, "0.2.0");
+        assert!(!update.update_available);
+        assert!(!update.security_advisory);
+    }
+
+    #[test]
+    fn versions_are_ordered_as_in_semantic_versioning() {
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.1.0",
+            "2.0.0",
+        ]
+        .iter()
+        .map(|version| version.parse::<Version>().unwrap())
+        .collect::<Vec<_>>();
+
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{:?} < {:?}", pair[0], pair[1]);
+        }
+        assert_eq!(
+            "v1.0.0-rc.1+build".parse::<Version>().unwrap(),
+            "1.0.0-rc.1".parse::<Version>().unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn up_to_date() {
+        let url = serve(r#"{"releases": [{"version": "0.1.0", "security": true}, {"version": "0.1.1-rc.1"}]}"#);
+        let feed = fetch(&Client::new(), &url).await.unwrap();
+        let update = check(&feed, "0.1.1").unwrap();
+
+        assert_eq!(update.latest_version, "0.1.1");
+        assert!(!update.update_available);
+        assert!(!update.security_advisory);
+    }
+
+    #[tokio::test]
+    async fn invalid_feed() {
+        let url = serve("{}");
+
+        assert!(fetch(&Client::new(), &url.join("missing.json").unwrap()).await.is_err());
+        assert!(fetch(&Client::new(), &url).await.is_err());
+    }
+}
@@ -20,3 +20,23 @@ impl<T> StorageBackend for T where
         + TangleStorageBackend
 {
 }
+
+/// The storage backend the node runs on, selected by the `memory-storage` feature.
+#[cfg(not(feature = "memory-storage"))]
+pub type Backend = bee_storage_rocksdb::storage::Storage;
+/// The storage backend the node runs on, selected by the `memory-storage` feature.
+#[cfg(feature = "memory-storage")]
+pub type Backend = bee_storage_memory::storage::Storage;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_storage_backend<B: StorageBackend>() {}
+
+    #[test]
+    fn backends_provide_all_the_storage_accesses() {
+        assert_storage_backend::<bee_storage_rocksdb::storage::Storage>();
+        assert_storage_backend::<bee_storage_memory::storage::Storage>();
+    }
+}
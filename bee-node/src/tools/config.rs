@@ -0,0 +1,49 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    config::{Error as ConfigLoadingError, DEFAULT_CONFIG_PATH},
+    storage::Backend,
+    CliArgs,
+};
+
+use structopt::StructOpt;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("{0}")]
+    Loading(#[from] ConfigLoadingError),
+    #[error("Found {0} problem(s) in the configuration.")]
+    Invalid(usize),
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub enum ConfigTool {
+    /// Checks the configuration, as layered by the `--config`, `--set` and environment overrides, and reports every
+    /// problem at once.
+    Check,
+}
+
+pub fn exec(tool: &ConfigTool, cli: &CliArgs) -> Result<(), ConfigError> {
+    match tool {
+        ConfigTool::Check => {
+            let builder = cli
+                .layered_config(DEFAULT_CONFIG_PATH)?
+                .into_builder::<Backend>()?
+                .with_cli_args(cli.clone());
+
+            match builder.finish() {
+                Ok(_) => println!("The configuration is valid."),
+                Err(errors) => {
+                    for error in errors.0.iter() {
+                        println!("{}", error);
+                    }
+                    return Err(ConfigError::Invalid(errors.0.len()));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,418 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use bee_ledger::types::{
+    Balance, BalanceDiffs, ConsumedOutput, CreatedOutput, Error as LedgerError, TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    constants::IOTA_SUPPLY,
+    output::{dust_outputs_max, Output, OutputId},
+};
+use bee_storage::{
+    access::{AsStream, Exist, Fetch},
+    backend::StorageBackend,
+};
+use bee_storage_rocksdb::{config::RocksDbConfigBuilder, error::Error as BackendError, storage::Storage};
+
+use futures::{executor, stream::StreamExt};
+use structopt::StructOpt;
+use thiserror::Error;
+
+use std::collections::HashSet;
+
+#[derive(Debug, Error)]
+pub enum LedgerCheckError {
+    #[error("Storage backend error: {0}")]
+    StorageBackend(#[from] BackendError),
+    #[error("Ledger error: {0}")]
+    Ledger(#[from] LedgerError),
+    #[error("Ledger integrity check failed with {0} discrepancies")]
+    Discrepancies(usize),
+}
+
+#[derive(Clone, Debug, StructOpt)]
+pub struct LedgerCheckTool {
+    path: String,
+}
+
+#[derive(Default)]
+struct Report {
+    discrepancies: usize,
+}
+
+impl Report {
+    fn discrepancy(&mut self, description: String) {
+        self.discrepancies += 1;
+        println!("Discrepancy:\t\t\t{}", description);
+    }
+}
+
+fn output_address(output: &Output) -> Option<&Address> {
+    match output {
+        Output::SignatureLockedSingle(output) => Some(output.address()),
+        Output::SignatureLockedDustAllowance(output) => Some(output.address()),
+        _ => None,
+    }
+}
+
+async fn check_unspent_outputs(
+    storage: &Storage,
+    report: &mut Report,
+) -> Result<(BalanceDiffs, u64, usize), LedgerCheckError> {
+    let mut balances = BalanceDiffs::new();
+    let mut supply: u128 = 0;
+    let mut count = 0;
+    let mut stream = AsStream::<Unspent, ()>::stream(storage).await?;
+
+    while let Some((unspent, _)) = stream.next().await {
+        count += 1;
+
+        let output = match Fetch::<OutputId, CreatedOutput>::fetch(storage, unspent.id()).await? {
+            Some(output) => output,
+            None => {
+                report.discrepancy(format!("unspent output {} has no created output", unspent.id()));
+                continue;
+            }
+        };
+
+        match output_address(output.inner()) {
+            Some(Address::Ed25519(address)) => {
+                if !Exist::<(Ed25519Address, OutputId), ()>::exist(storage, &(*address, *unspent.id())).await? {
+                    report.discrepancy(format!(
+                        "unspent output {} is not indexed by its address {}",
+                        unspent.id(),
+                        address
+                    ));
+                }
+            }
+            Some(address) => {
+                report.discrepancy(format!(
+                    "unspent output {} has an unsupported address kind {}",
+                    unspent.id(),
+                    address.kind()
+                ));
+                continue;
+            }
+            None => {
+                report.discrepancy(format!(
+                    "unspent output {} has an unsupported output kind {}",
+                    unspent.id(),
+                    output.inner().kind()
+                ));
+                continue;
+            }
+        }
+
+        match output.inner() {
+            Output::SignatureLockedSingle(output) => supply += output.amount() as u128,
+            Output::SignatureLockedDustAllowance(output) => supply += output.amount() as u128,
+            _ => {}
+        }
+
+        balances.output_add(output.inner())?;
+    }
+
+    if supply > u64::MAX as u128 {
+        report.discrepancy(format!("unspent outputs supply {} overflows", supply));
+    }
+
+    Ok((balances, supply as u64, count))
+}
+
+/// Checks that every created output is either unspent or consumed, which the unspent outputs alone can't tell.
+async fn check_created_outputs(storage: &Storage, report: &mut Report) -> Result<usize, LedgerCheckError> {
+    let mut count = 0;
+    let mut stream = AsStream::<OutputId, CreatedOutput>::stream(storage).await?;
+
+    while let Some((output_id, _)) = stream.next().await {
+        count += 1;
+
+        let unspent = Exist::<Unspent, ()>::exist(storage, &Unspent::new(output_id)).await?;
+        let consumed = Exist::<OutputId, ConsumedOutput>::exist(storage, &output_id).await?;
+
+        match (unspent, consumed) {
+            (true, true) => report.discrepancy(format!("created output {} is both unspent and consumed", output_id)),
+            (false, false) => {
+                report.discrepancy(format!("created output {} is neither unspent nor consumed", output_id))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(count)
+}
+
+async fn check_address_index(storage: &Storage, report: &mut Report) -> Result<usize, LedgerCheckError> {
+    let mut count = 0;
+    let mut stream = AsStream::<(Ed25519Address, OutputId), ()>::stream(storage).await?;
+
+    while let Some(((address, output_id), _)) = stream.next().await {
+        count += 1;
+
+        match Fetch::<OutputId, CreatedOutput>::fetch(storage, &output_id).await? {
+            Some(output) => match output_address(output.inner()) {
+                Some(Address::Ed25519(output_address)) if *output_address == address => {}
+                _ => report.discrepancy(format!(
+                    "output {} is indexed by address {} but is not owned by it",
+                    output_id, address
+                )),
+            },
+            None => report.discrepancy(format!(
+                "output {} is indexed by address {} but has no created output",
+                output_id, address
+            )),
+        }
+    }
+
+    Ok(count)
+}
+
+async fn check_balances(
+    storage: &Storage,
+    balances: &BalanceDiffs,
+    report: &mut Report,
+) -> Result<usize, LedgerCheckError> {
+    let mut count = 0;
+    let mut seen = HashSet::new();
+    let mut stream = AsStream::<Address, Balance>::stream(storage).await?;
+
+    while let Some((address, balance)) = stream.next().await {
+        count += 1;
+
+        match balances.get(&address) {
+            Some(expected)
+                if expected.amount() as u64 == balance.amount()
+                    && expected.dust_allowance() as u64 == balance.dust_allowance()
+                    && expected.dust_outputs() as u64 == balance.dust_outputs() => {}
+            Some(expected) => report.discrepancy(format!(
+                "balance of address {:?} is ({}, {}, {}) but unspent outputs amount to ({}, {}, {})",
+                address,
+                balance.amount(),
+                balance.dust_allowance(),
+                balance.dust_outputs(),
+                expected.amount(),
+                expected.dust_allowance(),
+                expected.dust_outputs()
+            )),
+            None => report.discrepancy(format!(
+                "balance of address {:?} is {} but it owns no unspent output",
+                address,
+                balance.amount()
+            )),
+        }
+
+        if balance.dust_outputs() > dust_outputs_max(balance.dust_allowance()) {
+            report.discrepancy(format!(
+                "address {:?} holds {} dust outputs but only {} are allowed",
+                address,
+                balance.dust_outputs(),
+                dust_outputs_max(balance.dust_allowance())
+            ));
+        }
+
+        seen.insert(address);
+    }
+
+    for (address, expected) in balances.iter() {
+        if expected.amount() != 0 && !seen.contains(address) {
+            report.discrepancy(format!(
+                "address {:?} owns unspent outputs amounting to {} but has no balance",
+                address,
+                expected.amount()
+            ));
+        }
+    }
+
+    Ok(count)
+}
+
+async fn check_treasury(storage: &Storage, report: &mut Report) -> Result<u64, LedgerCheckError> {
+    let outputs = Fetch::<bool, Vec<TreasuryOutput>>::fetch(storage, &false)
+        .await?
+        .unwrap_or_default();
+
+    match outputs.len() {
+        1 => Ok(outputs[0].inner().amount()),
+        len => {
+            report.discrepancy(format!("{} unspent treasury outputs instead of exactly one", len));
+            Ok(outputs.iter().map(|output| output.inner().amount()).sum())
+        }
+    }
+}
+
+async fn exec_inner(storage: &Storage) -> Result<(), LedgerCheckError> {
+    let mut report = Report::default();
+
+    let (balances, supply, unspent_count) = check_unspent_outputs(storage, &mut report).await?;
+    let created_count = check_created_outputs(storage, &mut report).await?;
+    let index_count = check_address_index(storage, &mut report).await?;
+    let balance_count = check_balances(storage, &balances, &mut report).await?;
+    let treasury = check_treasury(storage, &mut report).await?;
+
+    if supply as u128 + treasury as u128 != IOTA_SUPPLY as u128 {
+        report.discrepancy(format!(
+            "unspent outputs supply {} plus treasury {} does not equal the total supply {}",
+            supply, treasury, IOTA_SUPPLY
+        ));
+    }
+
+    println!("Created outputs:\t\t{}", created_count);
+    println!("Unspent outputs:\t\t{}", unspent_count);
+    println!("Indexed address outputs:\t{}", index_count);
+    println!("Address balances:\t\t{}", balance_count);
+    println!("Unspent outputs supply:\t\t{}", supply);
+    println!("Treasury amount:\t\t{}", treasury);
+    println!("Discrepancies:\t\t\t{}", report.discrepancies);
+
+    if report.discrepancies != 0 {
+        return Err(LedgerCheckError::Discrepancies(report.discrepancies));
+    }
+
+    Ok(())
+}
+
+pub fn exec(tool: &LedgerCheckTool) -> Result<(), LedgerCheckError> {
+    executor::block_on(async {
+        let storage = Storage::start(RocksDbConfigBuilder::default().with_path(tool.path.clone()).finish()).await?;
+        let res = exec_inner(&storage).await;
+
+        storage.shutdown().await?;
+
+        res
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bee_message::{
+        milestone::MilestoneIndex, output::SignatureLockedSingleOutput, payload::transaction::TransactionId, MessageId,
+    };
+    use bee_storage::access::Insert;
+
+    use std::path::PathBuf;
+
+    struct TestStorage {
+        storage: Option<Storage>,
+        path: PathBuf,
+    }
+
+    impl TestStorage {
+        async fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("bee-ledger-check-{}-{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            let storage = Storage::start(
+                RocksDbConfigBuilder::default()
+                    .with_path(path.to_string_lossy().into_owned())
+                    .finish(),
+            )
+            .await
+            .unwrap();
+
+            Self {
+                storage: Some(storage),
+                path,
+            }
+        }
+
+        fn storage(&self) -> &Storage {
+            self.storage.as_ref().unwrap()
+        }
+
+        async fn shutdown(mut self) {
+            self.storage.take().unwrap().shutdown().await.unwrap();
+        }
+    }
+
+    impl Drop for TestStorage {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+
+    fn output_id(index: u16) -> OutputId {
+        OutputId::new(TransactionId::new([0x2a; 32]), index).unwrap()
+    }
+
+    async fn insert_created_output(storage: &Storage, output_id: &OutputId) {
+        let output =
+            SignatureLockedSingleOutput::new(Address::Ed25519(Ed25519Address::new([0x2a; 32])), 1_000_000).unwrap();
+
+        Insert::<OutputId, CreatedOutput>::insert(
+            storage,
+            output_id,
+            &CreatedOutput::new(MessageId::new([0x2a; 32]), Output::SignatureLockedSingle(output)),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn created_outputs_are_unspent_or_consumed() {
+        executor::block_on(async {
+            let test_storage = TestStorage::new("created").await;
+            let storage = test_storage.storage();
+            let consumed = ConsumedOutput::new(TransactionId::new([0x2a; 32]), MilestoneIndex(1));
+
+            for index in 0..4 {
+                insert_created_output(storage, &output_id(index)).await;
+            }
+            // Unspent.
+            Insert::<Unspent, ()>::insert(storage, &Unspent::new(output_id(0)), &())
+                .await
+                .unwrap();
+            // Consumed.
+            Insert::<OutputId, ConsumedOutput>::insert(storage, &output_id(1), &consumed)
+                .await
+                .unwrap();
+            // Both unspent and consumed.
+            Insert::<Unspent, ()>::insert(storage, &Unspent::new(output_id(2)), &())
+                .await
+                .unwrap();
+            Insert::<OutputId, ConsumedOutput>::insert(storage, &output_id(2), &consumed)
+                .await
+                .unwrap();
+            // Neither unspent nor consumed, the output with index 3 being left out.
+
+            let mut report = Report::default();
+
+            assert_eq!(check_created_outputs(storage, &mut report).await.unwrap(), 4);
+            assert_eq!(report.discrepancies, 2);
+
+            test_storage.shutdown().await;
+        });
+    }
+
+    #[test]
+    fn orphaned_unspent_outputs_are_reported() {
+        executor::block_on(async {
+            let test_storage = TestStorage::new("unspent").await;
+            let storage = test_storage.storage();
+
+            insert_created_output(storage, &output_id(0)).await;
+            Insert::<(Ed25519Address, OutputId), ()>::insert(
+                storage,
+                &(Ed25519Address::new([0x2a; 32]), output_id(0)),
+                &(),
+            )
+            .await
+            .unwrap();
+            Insert::<Unspent, ()>::insert(storage, &Unspent::new(output_id(0)), &())
+                .await
+                .unwrap();
+            Insert::<Unspent, ()>::insert(storage, &Unspent::new(output_id(1)), &())
+                .await
+                .unwrap();
+
+            let mut report = Report::default();
+            let (_, supply, count) = check_unspent_outputs(storage, &mut report).await.unwrap();
+
+            assert_eq!((supply, count), (1_000_000, 2));
+            assert_eq!(report.discrepancies, 1);
+
+            test_storage.shutdown().await;
+        });
+    }
+}
@@ -1,20 +1,28 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod config;
 mod ed25519;
+mod ledger_check;
 mod p2p_identity;
 mod password;
 mod rocksdb;
 mod snapshot_info;
 
+use crate::CliArgs;
+
 use structopt::StructOpt;
 use thiserror::Error;
 
 #[non_exhaustive]
 #[derive(Clone, Debug, StructOpt)]
 pub enum Tool {
+    /// Checks the node configuration.
+    Config(config::ConfigTool),
     /// Generates Ed25519 public/private keys and addresses.
     Ed25519(ed25519::Ed25519Tool),
+    /// Verifies the integrity of the ledger state of a database.
+    LedgerCheck(ledger_check::LedgerCheckTool),
     /// Generates a p2p identity.
     P2pIdentity(p2p_identity::P2pIdentityTool),
     /// Rocksdb database analyser.
@@ -27,9 +35,13 @@ pub enum Tool {
 
 #[derive(Debug, Error)]
 pub enum ToolError {
+    #[error("{0}")]
+    Config(#[from] config::ConfigError),
     #[error("{0}")]
     Ed25519(#[from] ed25519::Ed25519Error),
     #[error("{0}")]
+    LedgerCheck(#[from] ledger_check::LedgerCheckError),
+    #[error("{0}")]
     Rocksdb(#[from] rocksdb::RocksdbError),
     #[error("{0}")]
     SnapshotInfo(#[from] snapshot_info::SnapshotInfoError),
@@ -37,9 +49,11 @@ pub enum ToolError {
     Password(#[from] password::PasswordError),
 }
 
-pub fn exec(tool: &Tool) -> Result<(), ToolError> {
+pub fn exec(tool: &Tool, cli: &CliArgs) -> Result<(), ToolError> {
     match tool {
+        Tool::Config(tool) => config::exec(tool, cli)?,
         Tool::Ed25519(tool) => ed25519::exec(tool)?,
+        Tool::LedgerCheck(tool) => ledger_check::exec(tool)?,
         Tool::P2pIdentity(tool) => p2p_identity::exec(tool),
         Tool::Rocksdb(tool) => rocksdb::exec(tool)?,
         Tool::SnapshotInfo(tool) => snapshot_info::exec(tool)?,
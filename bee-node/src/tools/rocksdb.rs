@@ -2,14 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryPage, Balance, ConsumedOutput, CreatedOutput,
+    IndexationEntry, IndexationPage, IndexationPageEntries, LedgerIndex, LedgerStateHash, OptionalIndex, OutputDiff,
+    Receipt, TreasuryOutput, Unspent,
 };
 use bee_message::{
     address::{Address, Ed25519Address},
     milestone::{Milestone, MilestoneIndex},
     output::OutputId,
-    payload::indexation::{IndexationPayload, PaddedIndex},
+    payload::indexation::{IndexationPayload, PaddedIndex, INDEXATION_PADDED_INDEX_LENGTH},
     Message, MessageId,
 };
 use bee_storage::{
@@ -27,7 +28,7 @@ use futures::{executor, stream::StreamExt};
 use structopt::StructOpt;
 use thiserror::Error;
 
-use std::str::FromStr;
+use std::{convert::TryFrom, str::FromStr};
 
 #[derive(Clone, Debug, StructOpt)]
 pub enum RocksdbCommand {
@@ -199,6 +200,62 @@ async fn exec_inner(tool: &RocksdbTool, storage: &Storage) -> Result<(), Rocksdb
                 }
             }
         },
+        CF_ED25519_ADDRESS_TO_HISTORY => match &tool.command {
+            RocksdbCommand::Fetch { key } => {
+                let key = Ed25519Address::from_str(key).map_err(|_| RocksdbError::InvalidKey(key.clone()))?;
+                let value = Fetch::<AddressHistoryPage, Vec<AddressHistoryEntry>>::fetch(
+                    storage,
+                    &AddressHistoryPage::new(key, MilestoneIndex(0), 0, usize::MAX),
+                )
+                .await?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            RocksdbCommand::Stream => {
+                let mut stream = AsStream::<(Ed25519Address, AddressHistoryEntry), ()>::stream(storage).await?;
+
+                while let Some((key, value)) = stream.next().await {
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
+        CF_RAW_INDEX_TO_MESSAGE_ID => match &tool.command {
+            RocksdbCommand::Fetch { key } => {
+                let key = hex::decode(key.clone())
+                    .ok()
+                    .filter(|index| (1..=INDEXATION_PADDED_INDEX_LENGTH).contains(&index.len()))
+                    .ok_or_else(|| RocksdbError::InvalidKey(key.clone()))?;
+                let value = Fetch::<IndexationPage, IndexationPageEntries>::fetch(
+                    storage,
+                    &IndexationPage::index(key.clone().into_boxed_slice(), 0, usize::MAX),
+                )
+                .await?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            RocksdbCommand::Stream => {
+                let mut stream = AsStream::<IndexationEntry, ()>::stream(storage).await?;
+
+                while let Some((key, value)) = stream.next().await {
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
+        CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY => match &tool.command {
+            RocksdbCommand::Fetch { key } => {
+                let key = MilestoneIndex(u32::from_str(key).map_err(|_| RocksdbError::InvalidKey(key.clone()))?);
+                let value = Fetch::<MilestoneIndex, Vec<IndexationEntry>>::fetch(storage, &key).await?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            RocksdbCommand::Stream => {
+                let mut stream = AsStream::<(MilestoneIndex, IndexationEntry), ()>::stream(storage).await?;
+
+                while let Some((key, value)) = stream.next().await {
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
         CF_LEDGER_INDEX => match &tool.command {
             RocksdbCommand::Fetch { key: _key } => return Err(RocksdbError::UnsupportedCommand),
             RocksdbCommand::Stream => {
@@ -326,6 +383,39 @@ async fn exec_inner(tool: &RocksdbTool, storage: &Storage) -> Result<(), Rocksdb
                 }
             }
         },
+        CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH => match &tool.command {
+            RocksdbCommand::Fetch { key } => {
+                let key = MilestoneIndex(u32::from_str(key).map_err(|_| RocksdbError::InvalidKey(key.clone()))?);
+                let value = Fetch::<MilestoneIndex, LedgerStateHash>::fetch(storage, &key).await?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            RocksdbCommand::Stream => {
+                let mut stream = AsStream::<MilestoneIndex, LedgerStateHash>::stream(storage).await?;
+
+                while let Some((key, value)) = stream.next().await {
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
+        CF_OPTIONAL_INDEX_TO_PRUNING_INDEX => match &tool.command {
+            RocksdbCommand::Fetch { key } => {
+                let key = u8::from_str(key)
+                    .ok()
+                    .and_then(|kind| OptionalIndex::try_from(kind).ok())
+                    .ok_or_else(|| RocksdbError::InvalidKey(key.clone()))?;
+                let value = Fetch::<OptionalIndex, MilestoneIndex>::fetch(storage, &key).await?;
+
+                println!("Key: {:?}\nValue: {:?}\n", key, value);
+            }
+            RocksdbCommand::Stream => {
+                let mut stream = AsStream::<OptionalIndex, MilestoneIndex>::stream(storage).await?;
+
+                while let Some((key, value)) = stream.next().await {
+                    println!("Key: {:?}\nValue: {:?}\n", key, value);
+                }
+            }
+        },
 
         _ => return Err(RocksdbError::UnknownColumnFamily(tool.column_family[..].to_owned())),
     }
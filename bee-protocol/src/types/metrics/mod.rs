@@ -3,6 +3,8 @@
 
 pub mod node;
 pub mod peer;
+pub mod throughput;
 
 pub use node::NodeMetrics;
 pub use peer::PeerMetrics;
+pub use throughput::ThroughputMetrics;
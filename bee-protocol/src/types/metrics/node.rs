@@ -35,8 +35,15 @@ pub struct NodeMetrics {
     milestone_payload: AtomicU64,
     indexation_payload: AtomicU64,
 
+    pre_validated_transactions: AtomicU64,
+    rejected_transactions: AtomicU64,
+
     snapshots: AtomicU64,
     prunings: AtomicU64,
+
+    rest_api_requests: AtomicU64,
+    rest_api_requests_rate_limited: AtomicU64,
+    rest_api_bodies_too_large: AtomicU64,
 }
 
 impl NodeMetrics {
@@ -230,6 +237,22 @@ impl NodeMetrics {
         self.indexation_payload.fetch_add(value, Ordering::SeqCst)
     }
 
+    pub fn pre_validated_transactions(&self) -> u64 {
+        self.pre_validated_transactions.load(Ordering::Relaxed)
+    }
+
+    pub fn pre_validated_transactions_inc(&self) -> u64 {
+        self.pre_validated_transactions.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn rejected_transactions(&self) -> u64 {
+        self.rejected_transactions.load(Ordering::Relaxed)
+    }
+
+    pub fn rejected_transactions_inc(&self) -> u64 {
+        self.rejected_transactions.fetch_add(1, Ordering::SeqCst)
+    }
+
     pub fn snapshots(&self) -> u64 {
         self.snapshots.load(Ordering::Relaxed)
     }
@@ -245,6 +268,30 @@ impl NodeMetrics {
     pub fn prunings_inc(&self, value: u64) -> u64 {
         self.prunings.fetch_add(value, Ordering::SeqCst)
     }
+
+    pub fn rest_api_requests(&self) -> u64 {
+        self.rest_api_requests.load(Ordering::Relaxed)
+    }
+
+    pub fn rest_api_requests_inc(&self) -> u64 {
+        self.rest_api_requests.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn rest_api_requests_rate_limited(&self) -> u64 {
+        self.rest_api_requests_rate_limited.load(Ordering::Relaxed)
+    }
+
+    pub fn rest_api_requests_rate_limited_inc(&self) -> u64 {
+        self.rest_api_requests_rate_limited.fetch_add(1, Ordering::SeqCst)
+    }
+
+    pub fn rest_api_bodies_too_large(&self) -> u64 {
+        self.rest_api_bodies_too_large.load(Ordering::Relaxed)
+    }
+
+    pub fn rest_api_bodies_too_large_inc(&self) -> u64 {
+        self.rest_api_bodies_too_large.fetch_add(1, Ordering::SeqCst)
+    }
 }
 
 #[cfg(test)]
@@ -278,8 +325,13 @@ mod tests {
         assert_eq!(metrics.transaction_payload(), 0);
         assert_eq!(metrics.milestone_payload(), 0);
         assert_eq!(metrics.indexation_payload(), 0);
+        assert_eq!(metrics.pre_validated_transactions(), 0);
+        assert_eq!(metrics.rejected_transactions(), 0);
         assert_eq!(metrics.snapshots(), 0);
         assert_eq!(metrics.prunings(), 0);
+        assert_eq!(metrics.rest_api_requests(), 0);
+        assert_eq!(metrics.rest_api_requests_rate_limited(), 0);
+        assert_eq!(metrics.rest_api_bodies_too_large(), 0);
 
         metrics.invalid_packets_inc();
         metrics.milestone_requests_received_inc();
@@ -303,8 +355,13 @@ mod tests {
         metrics.transaction_payload_inc(1);
         metrics.milestone_payload_inc(1);
         metrics.indexation_payload_inc(1);
+        metrics.pre_validated_transactions_inc();
+        metrics.rejected_transactions_inc();
         metrics.snapshots_inc(1);
         metrics.prunings_inc(1);
+        metrics.rest_api_requests_inc();
+        metrics.rest_api_requests_rate_limited_inc();
+        metrics.rest_api_bodies_too_large_inc();
 
         assert_eq!(metrics.invalid_packets(), 1);
         assert_eq!(metrics.milestone_requests_received(), 1);
@@ -328,7 +385,12 @@ mod tests {
         assert_eq!(metrics.transaction_payload(), 1);
         assert_eq!(metrics.milestone_payload(), 1);
         assert_eq!(metrics.indexation_payload(), 1);
+        assert_eq!(metrics.pre_validated_transactions(), 1);
+        assert_eq!(metrics.rejected_transactions(), 1);
         assert_eq!(metrics.snapshots(), 1);
         assert_eq!(metrics.prunings(), 1);
+        assert_eq!(metrics.rest_api_requests(), 1);
+        assert_eq!(metrics.rest_api_requests_rate_limited(), 1);
+        assert_eq!(metrics.rest_api_bodies_too_large(), 1);
     }
 }
@@ -0,0 +1,154 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+// Number of per-second message samples kept in the window.
+const MPS_WINDOW: usize = 60;
+// Number of confirmed milestones kept in the window.
+const MILESTONE_WINDOW: usize = 10;
+
+#[derive(Clone, Copy)]
+struct MilestoneSample {
+    timestamp: u64,
+    referenced_messages: u64,
+    total_new_messages: u64,
+}
+
+#[derive(Default)]
+struct Inner {
+    new_messages: VecDeque<u64>,
+    total_new_messages: u64,
+    milestones: VecDeque<MilestoneSample>,
+}
+
+impl Inner {
+    // Sum of the referenced messages and time span covered by the milestone window. The referenced messages of the
+    // oldest milestone are not accounted for as they were issued before the window started.
+    fn milestone_window(&self) -> Option<(&MilestoneSample, &MilestoneSample, u64)> {
+        if self.milestones.len() < 2 {
+            return None;
+        }
+
+        let first = self.milestones.front()?;
+        let last = self.milestones.back()?;
+        let referenced_messages = self.milestones.iter().skip(1).map(|m| m.referenced_messages).sum();
+
+        Some((first, last, referenced_messages))
+    }
+}
+
+/// Rolling window statistics about the throughput of the node.
+#[derive(Default)]
+pub struct ThroughputMetrics {
+    inner: Mutex<Inner>,
+}
+
+impl ThroughputMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn inner(&self) -> MutexGuard<Inner> {
+        // The statistics are always left consistent, a panicking holder can't corrupt them.
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Records the number of new messages received during the last second.
+    pub fn new_messages_sample(&self, new_messages: u64) {
+        let mut inner = self.inner();
+
+        if inner.new_messages.len() == MPS_WINDOW {
+            inner.new_messages.pop_front();
+        }
+        inner.new_messages.push_back(new_messages);
+        inner.total_new_messages += new_messages;
+    }
+
+    /// Records a confirmed milestone along with the number of messages it referenced.
+    pub fn milestone_sample(&self, timestamp: u64, referenced_messages: u64) {
+        let mut inner = self.inner();
+
+        if inner.milestones.len() == MILESTONE_WINDOW {
+            inner.milestones.pop_front();
+        }
+        let total_new_messages = inner.total_new_messages;
+        inner.milestones.push_back(MilestoneSample {
+            timestamp,
+            referenced_messages,
+            total_new_messages,
+        });
+    }
+
+    /// Returns the average number of new messages per second.
+    pub fn messages_per_second(&self) -> f64 {
+        let inner = self.inner();
+
+        if inner.new_messages.is_empty() {
+            return 0.0;
+        }
+
+        inner.new_messages.iter().sum::<u64>() as f64 / inner.new_messages.len() as f64
+    }
+
+    /// Returns the average number of messages referenced by milestones per second.
+    pub fn referenced_messages_per_second(&self) -> f64 {
+        let inner = self.inner();
+
+        match inner.milestone_window() {
+            Some((first, last, referenced_messages)) if last.timestamp > first.timestamp => {
+                referenced_messages as f64 / (last.timestamp - first.timestamp) as f64
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the percentage of new messages that were referenced by milestones.
+    pub fn referenced_rate(&self) -> f64 {
+        let inner = self.inner();
+
+        match inner.milestone_window() {
+            Some((first, last, referenced_messages)) if last.total_new_messages > first.total_new_messages => {
+                referenced_messages as f64 / (last.total_new_messages - first.total_new_messages) as f64 * 100.0
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_statistics_are_zero() {
+        let metrics = ThroughputMetrics::new();
+
+        metrics.milestone_sample(10, 100);
+
+        assert_eq!(metrics.messages_per_second(), 0.0);
+        assert_eq!(metrics.referenced_messages_per_second(), 0.0);
+        assert_eq!(metrics.referenced_rate(), 0.0);
+    }
+
+    #[test]
+    fn statistics_over_window() {
+        let metrics = ThroughputMetrics::new();
+
+        metrics.milestone_sample(0, 1000);
+        for _ in 0..10 {
+            metrics.new_messages_sample(10);
+        }
+        metrics.milestone_sample(10, 50);
+        for _ in 0..MPS_WINDOW {
+            metrics.new_messages_sample(20);
+        }
+
+        assert_eq!(metrics.messages_per_second(), 20.0);
+        assert_eq!(metrics.referenced_messages_per_second(), 5.0);
+        assert_eq!(metrics.referenced_rate(), 50.0);
+    }
+}
@@ -1,7 +1,14 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-pub mod key_manager;
-pub mod key_range;
 pub mod metrics;
 pub mod peer;
+
+#[deprecated(note = "moved to `bee_ledger::types::KeyManager`")]
+pub mod key_manager {
+    pub use bee_ledger::types::KeyManager;
+}
+#[deprecated(note = "moved to `bee_ledger::types::MilestoneKeyRange`")]
+pub mod key_range {
+    pub use bee_ledger::types::MilestoneKeyRange;
+}
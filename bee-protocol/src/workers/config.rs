@@ -1,13 +1,13 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::types::key_range::MilestoneKeyRange;
-
+use bee_ledger::types::MilestoneKeyRange;
 use bee_message::milestone::MilestoneIndex;
 
 use serde::Deserialize;
 
 const DEFAULT_MINIMUM_POW_SCORE: f64 = 4000.0;
+const DEFAULT_TRANSACTION_PRE_VALIDATION: bool = false;
 const DEFAULT_COO_PUBLIC_KEY_COUNT: usize = 2;
 const DEFAULT_COO_PUBLIC_KEY_RANGES: [(&str, MilestoneIndex, MilestoneIndex); 0] = [];
 const DEFAULT_MESSAGE_WORKER_CACHE: usize = 10000;
@@ -30,6 +30,7 @@ struct ProtocolWorkersConfigBuilder {
 #[derive(Default, Deserialize)]
 pub struct ProtocolConfigBuilder {
     minimum_pow_score: Option<f64>,
+    transaction_pre_validation: Option<bool>,
     coordinator: ProtocolCoordinatorConfigBuilder,
     workers: ProtocolWorkersConfigBuilder,
 }
@@ -44,6 +45,11 @@ impl ProtocolConfigBuilder {
         self
     }
 
+    pub fn transaction_pre_validation(mut self, transaction_pre_validation: bool) -> Self {
+        self.transaction_pre_validation.replace(transaction_pre_validation);
+        self
+    }
+
     pub fn coo_public_key_ranges(mut self, coo_public_key_ranges: Vec<MilestoneKeyRange>) -> Self {
         self.coordinator.public_key_ranges.replace(coo_public_key_ranges);
         self
@@ -67,6 +73,9 @@ impl ProtocolConfigBuilder {
     pub fn finish(self) -> ProtocolConfig {
         ProtocolConfig {
             minimum_pow_score: self.minimum_pow_score.unwrap_or(DEFAULT_MINIMUM_POW_SCORE),
+            transaction_pre_validation: self
+                .transaction_pre_validation
+                .unwrap_or(DEFAULT_TRANSACTION_PRE_VALIDATION),
             coordinator: ProtocolCoordinatorConfig {
                 public_key_count: self
                     .coordinator
@@ -97,6 +106,16 @@ pub struct ProtocolCoordinatorConfig {
     pub(crate) public_key_ranges: Vec<MilestoneKeyRange>,
 }
 
+impl ProtocolCoordinatorConfig {
+    pub fn public_key_count(&self) -> usize {
+        self.public_key_count
+    }
+
+    pub fn public_key_ranges(&self) -> &[MilestoneKeyRange] {
+        &self.public_key_ranges
+    }
+}
+
 #[derive(Clone)]
 pub struct ProtocolWorkersConfig {
     pub(crate) message_worker_cache: usize,
@@ -107,6 +126,7 @@ pub struct ProtocolWorkersConfig {
 #[derive(Clone)]
 pub struct ProtocolConfig {
     pub(crate) minimum_pow_score: f64,
+    pub(crate) transaction_pre_validation: bool,
     pub(crate) coordinator: ProtocolCoordinatorConfig,
     pub(crate) workers: ProtocolWorkersConfig,
 }
@@ -120,6 +140,11 @@ impl ProtocolConfig {
         self.minimum_pow_score
     }
 
+    /// Returns whether transactions are checked against the ledger state before being broadcast.
+    pub fn transaction_pre_validation(&self) -> bool {
+        self.transaction_pre_validation
+    }
+
     pub fn coordinator(&self) -> &ProtocolCoordinatorConfig {
         &self.coordinator
     }
@@ -12,8 +12,8 @@ pub(crate) use hash_cache::HashCache;
 pub(crate) use hasher::{HasherWorker, HasherWorkerEvent};
 pub(crate) use payload::{
     IndexationPayloadWorker, IndexationPayloadWorkerEvent, MilestonePayloadWorker, PayloadWorker, PayloadWorkerEvent,
-    TransactionPayloadWorker,
 };
+pub use payload::{PendingSpends, TransactionPayloadWorker};
 pub(crate) use processor::{ProcessorWorker, ProcessorWorkerEvent};
 pub use submitter::{MessageSubmitterError, MessageSubmitterWorker, MessageSubmitterWorkerEvent};
 pub(crate) use unreferenced_inserter::{UnreferencedMessageInserterWorker, UnreferencedMessageInserterWorkerEvent};
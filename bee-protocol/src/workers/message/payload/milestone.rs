@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    types::{key_manager::KeyManager, metrics::NodeMetrics},
+    types::metrics::NodeMetrics,
     workers::{
         config::ProtocolConfig, helper, peer::PeerManager, storage::StorageBackend, MetricsWorker,
         MilestoneRequesterWorker, MilestoneSolidifierWorker, MilestoneSolidifierWorkerEvent, PeerManagerResWorker,
@@ -10,6 +10,7 @@ use crate::{
     },
 };
 
+use bee_ledger::types::KeyManager;
 use bee_message::{
     milestone::{Milestone, MilestoneIndex},
     payload::{
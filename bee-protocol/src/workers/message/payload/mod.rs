@@ -7,7 +7,8 @@ mod transaction;
 
 pub(crate) use indexation::{IndexationPayloadWorker, IndexationPayloadWorkerEvent};
 pub(crate) use milestone::{MilestonePayloadWorker, MilestonePayloadWorkerEvent};
-pub(crate) use transaction::{TransactionPayloadWorker, TransactionPayloadWorkerEvent};
+pub(crate) use transaction::TransactionPayloadWorkerEvent;
+pub use transaction::{PendingSpends, TransactionPayloadWorker};
 
 use crate::workers::storage::StorageBackend;
 
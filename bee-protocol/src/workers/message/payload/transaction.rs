@@ -6,7 +6,11 @@ use crate::{
     workers::{storage::StorageBackend, IndexationPayloadWorker, IndexationPayloadWorkerEvent, MetricsWorker},
 };
 
+use bee_ledger::workers::event::MilestoneConfirmed;
 use bee_message::{
+    input::Input,
+    milestone::MilestoneIndex,
+    output::OutputId,
     payload::{transaction::Essence, Payload},
     MessageId,
 };
@@ -19,18 +23,146 @@ use log::{debug, error, info};
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
-use std::{any::TypeId, convert::Infallible};
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    convert::Infallible,
+    sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+#[derive(Default)]
+struct PendingSpendsInner {
+    // The pending messages spending each output.
+    spends: HashMap<OutputId, Vec<MessageId>>,
+    // The outputs spent by each pending message and the latest milestone index at its arrival.
+    messages: HashMap<MessageId, (Vec<OutputId>, MilestoneIndex)>,
+    // The outputs spent by messages referenced since and the index of the milestone that referenced them.
+    spent: HashMap<OutputId, MilestoneIndex>,
+}
+
+impl PendingSpendsInner {
+    fn remove(&mut self, message_id: &MessageId) {
+        if let Some((output_ids, _)) = self.messages.remove(message_id) {
+            for output_id in output_ids {
+                if let Some(message_ids) = self.spends.get_mut(&output_id) {
+                    message_ids.retain(|id| id != message_id);
+                    if message_ids.is_empty() {
+                        self.spends.remove(&output_id);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The transactions not referenced by a milestone yet, indexed by the outputs they spend, which reveals double spends
+/// ahead of their confirmation.
+#[derive(Default)]
+pub struct PendingSpends(RwLock<PendingSpendsInner>);
+
+impl PendingSpends {
+    fn read(&self) -> RwLockReadGuard<PendingSpendsInner> {
+        // The pool is always left consistent, a panicking holder can't corrupt it.
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<PendingSpendsInner> {
+        self.0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the pending messages spending an output.
+    pub fn get(&self, output_id: &OutputId) -> Vec<MessageId> {
+        self.read().spends.get(output_id).cloned().unwrap_or_default()
+    }
+
+    /// Returns whether a pending message spends an output that another pending message also spends, or that a
+    /// referenced message already spent, or `None` if the message isn't pending.
+    pub fn is_conflicting(&self, message_id: &MessageId) -> Option<bool> {
+        let inner = self.read();
+        let (output_ids, _) = inner.messages.get(message_id)?;
+
+        Some(output_ids.iter().any(|output_id| {
+            inner.spent.contains_key(output_id) || inner.spends.get(output_id).map_or(false, |ids| ids.len() > 1)
+        }))
+    }
+
+    /// Returns the number of pending messages.
+    pub fn len(&self) -> usize {
+        self.read().messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read().messages.is_empty()
+    }
+
+    /// Adds a pending message and returns whether it spends an output that another pending message, or a referenced
+    /// one, already spends.
+    pub(crate) fn insert(&self, message_id: MessageId, output_ids: Vec<OutputId>, index: MilestoneIndex) -> bool {
+        let mut inner = self.write();
+
+        if inner.messages.contains_key(&message_id) {
+            return false;
+        }
+
+        let mut conflicting = false;
+        for output_id in output_ids.iter() {
+            let message_ids = inner.spends.entry(*output_id).or_default();
+            conflicting |= !message_ids.is_empty();
+            message_ids.push(message_id);
+            conflicting |= inner.spent.contains_key(output_id);
+        }
+        inner.messages.insert(message_id, (output_ids, index));
+
+        conflicting
+    }
+
+    /// Removes a message, once referenced by a milestone without being applied to the ledger.
+    pub(crate) fn remove(&self, message_id: &MessageId) {
+        self.write().remove(message_id);
+    }
+
+    /// Removes a message, once included by a milestone, and records its outputs as spent so that the other pending
+    /// messages spending them are known to conflict.
+    pub(crate) fn confirm(&self, message_id: &MessageId, index: MilestoneIndex) {
+        let mut inner = self.write();
+
+        if let Some((output_ids, _)) = inner.messages.get(message_id).cloned() {
+            inner.remove(message_id);
+            for output_id in output_ids {
+                inner.spent.insert(output_id, index);
+            }
+        }
+    }
+
+    /// Removes the messages that arrived, and forgets the outputs that were spent, at or before a milestone index.
+    pub(crate) fn prune(&self, index: MilestoneIndex) {
+        let mut inner = self.write();
+        inner.spent.retain(|_, spent_index| *spent_index > index);
+
+        let message_ids = inner
+            .messages
+            .iter()
+            .filter(|(_, (_, arrival_index))| *arrival_index <= index)
+            .map(|(message_id, _)| *message_id)
+            .collect::<Vec<_>>();
+
+        for message_id in message_ids.iter() {
+            inner.remove(message_id);
+        }
+    }
+}
 
 #[derive(Debug)]
 pub(crate) struct TransactionPayloadWorkerEvent(pub(crate) MessageId);
 
-pub(crate) struct TransactionPayloadWorker {
+pub struct TransactionPayloadWorker {
     pub(crate) tx: mpsc::UnboundedSender<TransactionPayloadWorkerEvent>,
 }
 
 async fn process<B: StorageBackend>(
     tangle: &MsTangle<B>,
     metrics: &NodeMetrics,
+    pending_spends: &PendingSpends,
     indexation_payload_worker: &mpsc::UnboundedSender<IndexationPayloadWorkerEvent>,
     message_id: MessageId,
 ) {
@@ -43,6 +175,30 @@ async fn process<B: StorageBackend>(
         metrics.transaction_payload_inc(1);
 
         if let Essence::Regular(essence) = transaction.essence() {
+            // Messages requested while synchronizing may already be referenced.
+            let referenced = tangle
+                .get_metadata(&message_id)
+                .await
+                .map_or(false, |metadata| metadata.milestone_index().is_some());
+
+            if !referenced {
+                let output_ids = essence
+                    .inputs()
+                    .iter()
+                    .filter_map(|input| match input {
+                        Input::Utxo(input) => Some(*input.output_id()),
+                        _ => None,
+                    })
+                    .collect();
+
+                if pending_spends.insert(message_id, output_ids, tangle.get_latest_milestone_index()) {
+                    debug!(
+                        "Message {} spends outputs already spent by pending messages.",
+                        message_id
+                    );
+                }
+            }
+
             if let Some(Payload::Indexation(_)) = essence.payload() {
                 if let Err(e) = indexation_payload_worker.send(IndexationPayloadWorkerEvent(message_id)) {
                     error!(
@@ -80,6 +236,23 @@ where
         let tangle = node.resource::<MsTangle<N::Backend>>();
         let indexation_payload_worker = node.worker::<IndexationPayloadWorker>().unwrap().tx.clone();
         let metrics = node.resource::<NodeMetrics>();
+        let below_max_depth = tangle.config().below_max_depth();
+
+        node.register_resource(PendingSpends::default());
+        let pending_spends = node.resource::<PendingSpends>();
+
+        node.bus().add_listener::<Self, MilestoneConfirmed, _>(move |event| {
+            for message_id in event.included_messages.iter() {
+                pending_spends.confirm(message_id, event.index);
+            }
+            for (message_id, _) in event.excluded_conflicting_messages.iter() {
+                pending_spends.remove(message_id);
+            }
+            // Messages below max depth can't be referenced anymore.
+            pending_spends.prune(MilestoneIndex(event.index.saturating_sub(below_max_depth)));
+        });
+
+        let pending_spends = node.resource::<PendingSpends>();
 
         node.spawn::<Self, _, _>(|shutdown| async move {
             info!("Running.");
@@ -87,7 +260,14 @@ where
             let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
 
             while let Some(TransactionPayloadWorkerEvent(message_id)) = receiver.next().await {
-                process(&tangle, &metrics, &indexation_payload_worker, message_id).await;
+                process(
+                    &tangle,
+                    &metrics,
+                    &pending_spends,
+                    &indexation_payload_worker,
+                    message_id,
+                )
+                .await;
             }
 
             // Before the worker completely stops, the receiver needs to be drained for transaction payloads to be
@@ -97,7 +277,14 @@ where
             let mut count: usize = 0;
 
             while let Some(Some(TransactionPayloadWorkerEvent(message_id))) = receiver.next().now_or_never() {
-                process(&tangle, &metrics, &indexation_payload_worker, message_id).await;
+                process(
+                    &tangle,
+                    &metrics,
+                    &pending_spends,
+                    &indexation_payload_worker,
+                    message_id,
+                )
+                .await;
                 count += 1;
             }
 
@@ -109,3 +296,105 @@ where
         Ok(Self { tx })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bee_message::payload::transaction::TransactionId;
+
+    fn message_id(byte: u8) -> MessageId {
+        MessageId::new([byte; 32])
+    }
+
+    fn output_id(index: u16) -> OutputId {
+        OutputId::new(TransactionId::new([0x2a; 32]), index).unwrap()
+    }
+
+    #[test]
+    fn insert_reports_double_spends() {
+        let pending_spends = PendingSpends::default();
+
+        assert!(!pending_spends.insert(message_id(1), vec![output_id(0), output_id(1)], MilestoneIndex(1)));
+        assert!(!pending_spends.insert(message_id(2), vec![output_id(2)], MilestoneIndex(1)));
+        assert!(pending_spends.insert(message_id(3), vec![output_id(1)], MilestoneIndex(1)));
+
+        assert_eq!(pending_spends.len(), 3);
+        assert_eq!(pending_spends.get(&output_id(1)), vec![message_id(1), message_id(3)]);
+        assert_eq!(pending_spends.is_conflicting(&message_id(1)), Some(true));
+        assert_eq!(pending_spends.is_conflicting(&message_id(2)), Some(false));
+        assert_eq!(pending_spends.is_conflicting(&message_id(3)), Some(true));
+        assert_eq!(pending_spends.is_conflicting(&message_id(4)), None);
+    }
+
+    #[test]
+    fn insert_ignores_known_messages() {
+        let pending_spends = PendingSpends::default();
+
+        pending_spends.insert(message_id(1), vec![output_id(0)], MilestoneIndex(1));
+
+        assert!(!pending_spends.insert(message_id(1), vec![output_id(0)], MilestoneIndex(1)));
+        assert_eq!(pending_spends.get(&output_id(0)), vec![message_id(1)]);
+    }
+
+    #[test]
+    fn remove_clears_the_conflict() {
+        let pending_spends = PendingSpends::default();
+
+        pending_spends.insert(message_id(1), vec![output_id(0)], MilestoneIndex(1));
+        pending_spends.insert(message_id(2), vec![output_id(0)], MilestoneIndex(1));
+        pending_spends.remove(&message_id(1));
+
+        assert_eq!(pending_spends.get(&output_id(0)), vec![message_id(2)]);
+        assert_eq!(pending_spends.is_conflicting(&message_id(1)), None);
+        assert_eq!(pending_spends.is_conflicting(&message_id(2)), Some(false));
+
+        pending_spends.remove(&message_id(2));
+
+        assert!(pending_spends.is_empty());
+        assert!(pending_spends.get(&output_id(0)).is_empty());
+    }
+
+    #[test]
+    fn confirm_marks_the_other_spenders_as_conflicting() {
+        let pending_spends = PendingSpends::default();
+
+        pending_spends.insert(message_id(1), vec![output_id(0)], MilestoneIndex(1));
+        pending_spends.insert(message_id(2), vec![output_id(0)], MilestoneIndex(1));
+        pending_spends.confirm(&message_id(1), MilestoneIndex(2));
+
+        assert_eq!(pending_spends.is_conflicting(&message_id(1)), None);
+        assert_eq!(pending_spends.is_conflicting(&message_id(2)), Some(true));
+        assert!(pending_spends.insert(message_id(3), vec![output_id(0)], MilestoneIndex(2)));
+    }
+
+    #[test]
+    fn confirm_ignores_unknown_messages() {
+        let pending_spends = PendingSpends::default();
+
+        pending_spends.confirm(&message_id(1), MilestoneIndex(2));
+
+        assert!(!pending_spends.insert(message_id(2), vec![output_id(0)], MilestoneIndex(2)));
+    }
+
+    #[test]
+    fn prune_removes_old_messages_and_spent_outputs() {
+        let pending_spends = PendingSpends::default();
+
+        pending_spends.insert(message_id(1), vec![output_id(0)], MilestoneIndex(1));
+        pending_spends.insert(message_id(2), vec![output_id(1)], MilestoneIndex(2));
+        pending_spends.insert(message_id(3), vec![output_id(2)], MilestoneIndex(3));
+        pending_spends.insert(message_id(4), vec![output_id(2)], MilestoneIndex(3));
+        pending_spends.confirm(&message_id(3), MilestoneIndex(3));
+        pending_spends.prune(MilestoneIndex(2));
+
+        assert_eq!(pending_spends.is_conflicting(&message_id(1)), None);
+        assert_eq!(pending_spends.is_conflicting(&message_id(2)), None);
+        assert_eq!(pending_spends.is_conflicting(&message_id(4)), Some(true));
+
+        pending_spends.prune(MilestoneIndex(3));
+
+        assert!(pending_spends.is_empty());
+        assert!(!pending_spends.insert(message_id(5), vec![output_id(2)], MilestoneIndex(4)));
+    }
+}
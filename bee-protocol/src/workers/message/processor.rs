@@ -17,14 +17,19 @@ use crate::{
 };
 
 use bee_common::packable::Packable;
-use bee_message::{Message, MessageId};
+use bee_ledger::workers::consensus::{validate_transaction, ConsensusWorker};
+use bee_ledger_types::types::ConflictReason;
+use bee_message::{
+    payload::{transaction::TransactionPayload, Payload},
+    Message, MessageId,
+};
 use bee_network::PeerId;
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
 use bee_tangle::{metadata::MessageMetadata, MsTangle, TangleWorker};
 
 use async_trait::async_trait;
-use futures::{channel::oneshot::Sender, stream::StreamExt};
-use log::{error, info, trace, warn};
+use futures::{channel::oneshot::Sender, future::FutureExt, stream::StreamExt};
+use log::{debug, error, info, trace, warn};
 use tokio::{sync::mpsc, task};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
@@ -59,6 +64,9 @@ where
             TypeId::of::<PeerManagerResWorker>(),
             TypeId::of::<PayloadWorker>(),
             TypeId::of::<UnreferencedMessageInserterWorker>(),
+            // Not used directly, but it makes the processor stop, and drain its in-flight messages, before the
+            // consensus does.
+            TypeId::of::<ConsensusWorker>(),
         ]
         .leak()
     }
@@ -73,6 +81,7 @@ where
         let unreferenced_inserted_worker = node.worker::<UnreferencedMessageInserterWorker>().unwrap().tx.clone();
 
         let tangle = node.resource::<MsTangle<N::Backend>>();
+        let storage = node.storage();
         let requested_messages = node.resource::<RequestedMessages>();
         let metrics = node.resource::<NodeMetrics>();
         let peer_manager = node.resource::<PeerManager>();
@@ -86,6 +95,7 @@ where
             let mut receiver = ShutdownStream::new(shutdown, UnboundedReceiverStream::new(rx));
 
             let (tx, rx) = async_channel::unbounded();
+            let mut tasks = Vec::with_capacity(16);
 
             for _ in 0..16 {
                 let rx = rx.clone();
@@ -95,13 +105,14 @@ where
                 let payload_worker = payload_worker.clone();
                 let unreferenced_inserted_worker = unreferenced_inserted_worker.clone();
                 let tangle = tangle.clone();
+                let storage = storage.clone();
                 let requested_messages = requested_messages.clone();
                 let metrics = metrics.clone();
                 let peer_manager = peer_manager.clone();
                 let bus = bus.clone();
                 let config = config.clone();
 
-                task::spawn(async move {
+                tasks.push(task::spawn(async move {
                     while let Ok(ProcessorWorkerEvent {
                         pow_score,
                         from,
@@ -148,6 +159,13 @@ where
                         // TODO try to avoid that
                         let parents = message.parents().iter().copied().collect::<Vec<MessageId>>();
 
+                        let transaction = match message.payload() {
+                            Some(Payload::Transaction(transaction)) if config.0.transaction_pre_validation => {
+                                Some(transaction.clone())
+                            }
+                            _ => None,
+                        };
+
                         // store message
                         let inserted = tangle.insert(message, message_id, metadata).await.is_some();
 
@@ -204,11 +222,23 @@ where
                             }
                             None => {
                                 // Message was not requested.
-                                if let Err(e) = broadcaster.send(BroadcasterWorkerEvent {
-                                    source: from,
-                                    message: message_packet,
-                                }) {
-                                    warn!("Broadcasting message failed: {}.", e);
+                                // The ledger state lags behind while syncing, so the inputs can't be checked.
+                                let valid = match transaction {
+                                    Some(transaction) if tangle.is_synced() => {
+                                        pre_validate(&tangle, &*storage, &metrics, &message_id, &transaction).await
+                                    }
+                                    _ => true,
+                                };
+
+                                // Conflicting transactions are kept for the white flag to rule on but not gossiped
+                                // further.
+                                if valid {
+                                    if let Err(e) = broadcaster.send(BroadcasterWorkerEvent {
+                                        source: from,
+                                        message: message_packet,
+                                    }) {
+                                        warn!("Broadcasting message failed: {}.", e);
+                                    }
                                 }
                                 if let Err(e) =
                                     unreferenced_inserted_worker.send(UnreferencedMessageInserterWorkerEvent(
@@ -232,13 +262,32 @@ where
                             }
                         }
                     }
-                });
+                }));
             }
 
             while let Some(event) = receiver.next().await {
                 let _ = tx.send(event).await;
             }
 
+            // Before the worker completely stops, the receiver needs to be drained and the in-flight messages processed
+            // for them to be stored. Closing the channel lets the processing tasks stop once it is empty.
+
+            let (_, mut receiver) = receiver.split();
+            let mut count: usize = 0;
+
+            while let Some(Some(event)) = receiver.next().now_or_never() {
+                let _ = tx.send(event).await;
+                count += 1;
+            }
+
+            drop(tx);
+
+            for task in tasks {
+                let _ = task.await;
+            }
+
+            debug!("Drained {} messages.", count);
+
             info!("Stopped.");
         });
 
@@ -246,6 +295,42 @@ where
     }
 }
 
+/// Checks a transaction against the ledger state, flags its message as conflicting and returns `false` if it provably
+/// conflicts. A transaction that can't be checked, or spending outputs unknown to the ledger that may be created by
+/// transactions not confirmed yet, is given the benefit of the doubt.
+async fn pre_validate<B: StorageBackend>(
+    tangle: &MsTangle<B>,
+    storage: &B,
+    metrics: &NodeMetrics,
+    message_id: &MessageId,
+    transaction: &TransactionPayload,
+) -> bool {
+    metrics.pre_validated_transactions_inc();
+
+    match validate_transaction(storage, transaction).await {
+        Ok(
+            conflict @ (ConflictReason::InputUtxoAlreadySpent
+            | ConflictReason::InvalidSignature
+            | ConflictReason::InputOutputSumMismatch),
+        ) => {
+            debug!("Not broadcasting conflicting message {}: {:?}.", message_id, conflict);
+            metrics.rejected_transactions_inc();
+            tangle
+                .update_metadata(message_id, |metadata| metadata.set_conflict(conflict))
+                .await;
+            false
+        }
+        Ok(_) => true,
+        Err(e) => {
+            warn!(
+                "Pre-validating the transaction of message {} failed: {}.",
+                message_id, e
+            );
+            true
+        }
+    }
+}
+
 fn invalid_message(
     error: String,
     metrics: &NodeMetrics,
@@ -1,7 +1,10 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::types::metrics::NodeMetrics;
+use crate::{
+    types::metrics::{NodeMetrics, ThroughputMetrics},
+    workers::event::MpsMetricsUpdated,
+};
 
 use bee_ledger::workers::event::{MilestoneConfirmed, PrunedIndex, SnapshottedIndex};
 use bee_runtime::{node::Node, shutdown_stream::ShutdownStream, worker::Worker};
@@ -26,6 +29,7 @@ impl<N: Node> Worker<N> for MetricsWorker {
 
     async fn start(node: &mut N, _config: Self::Config) -> Result<Self, Self::Error> {
         node.register_resource(NodeMetrics::new());
+        node.register_resource(ThroughputMetrics::new());
 
         let metrics = node.resource::<NodeMetrics>();
         node.bus().add_listener::<Self, MilestoneConfirmed, _>(move |event| {
@@ -38,6 +42,16 @@ impl<N: Node> Worker<N> for MetricsWorker {
             metrics.receipt_inc(event.receipt as u64);
         });
 
+        let throughput = node.resource::<ThroughputMetrics>();
+        node.bus().add_listener::<Self, MilestoneConfirmed, _>(move |event| {
+            throughput.milestone_sample(event.timestamp, event.referenced_messages as u64);
+        });
+
+        let throughput = node.resource::<ThroughputMetrics>();
+        node.bus().add_listener::<Self, MpsMetricsUpdated, _>(move |event| {
+            throughput.new_messages_sample(event.new);
+        });
+
         let metrics = node.resource::<NodeMetrics>();
         node.bus().add_listener::<Self, SnapshottedIndex, _>(move |_| {
             metrics.snapshots_inc(1);
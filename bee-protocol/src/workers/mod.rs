@@ -27,10 +27,12 @@ pub(crate) use heartbeater::HeartbeaterWorker;
 pub(crate) use index_updater::{IndexUpdaterWorker, IndexUpdaterWorkerEvent};
 pub(crate) use message::{
     HasherWorker, HasherWorkerEvent, IndexationPayloadWorker, IndexationPayloadWorkerEvent, MilestonePayloadWorker,
-    PayloadWorker, PayloadWorkerEvent, ProcessorWorker, TransactionPayloadWorker, UnreferencedMessageInserterWorker,
+    PayloadWorker, PayloadWorkerEvent, ProcessorWorker, UnreferencedMessageInserterWorker,
     UnreferencedMessageInserterWorkerEvent,
 };
-pub use message::{MessageSubmitterError, MessageSubmitterWorker, MessageSubmitterWorkerEvent};
+pub use message::{
+    MessageSubmitterError, MessageSubmitterWorker, MessageSubmitterWorkerEvent, PendingSpends, TransactionPayloadWorker,
+};
 pub use metrics::MetricsWorker;
 pub(crate) use mps::MpsWorker;
 pub use peer::{PeerManager, PeerManagerResWorker};
@@ -0,0 +1,194 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    codec::Encode,
+    storage::{Storage, StorageBackend},
+    tables::*,
+};
+
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::access::{Batch, BatchBuilder};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+enum BatchOperation {
+    Insert {
+        table: &'static str,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    },
+    Delete {
+        table: &'static str,
+        key: Vec<u8>,
+    },
+}
+
+#[derive(Default)]
+pub struct StorageBatch {
+    operations: Vec<BatchOperation>,
+}
+
+#[async_trait::async_trait]
+impl BatchBuilder for Storage {
+    type Batch = StorageBatch;
+
+    async fn batch_commit(&self, batch: Self::Batch, _durability: bool) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut tables = self.write()?;
+
+        // All tables are checked before anything is written so that a batch is either fully applied or not at all.
+        for operation in batch.operations.iter() {
+            match operation {
+                BatchOperation::Insert { table, .. } | BatchOperation::Delete { table, .. } => {
+                    tables.table(*table)?;
+                }
+            }
+        }
+
+        for operation in batch.operations {
+            match operation {
+                BatchOperation::Insert { table, key, value } => {
+                    tables.table_mut(table)?.insert(key, value);
+                }
+                BatchOperation::Delete { table, key } => {
+                    tables.table_mut(table)?.remove(&key);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_batch {
+    ($key:ty, $value:ty, $table:expr) => {
+        impl Batch<$key, $value> for Storage {
+            fn batch_insert(
+                &self,
+                batch: &mut Self::Batch,
+                key: &$key,
+                value: &$value,
+            ) -> Result<(), <Self as StorageBackend>::Error> {
+                batch.operations.push(BatchOperation::Insert {
+                    table: $table,
+                    key: key.encode_new(),
+                    value: value.encode_new(),
+                });
+
+                Ok(())
+            }
+
+            fn batch_delete(&self, batch: &mut Self::Batch, key: &$key) -> Result<(), <Self as StorageBackend>::Error> {
+                batch.operations.push(BatchOperation::Delete {
+                    table: $table,
+                    key: key.encode_new(),
+                });
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_batch!(MessageId, Message, TABLE_MESSAGE_ID_TO_MESSAGE);
+impl_batch!(MessageId, MessageMetadata, TABLE_MESSAGE_ID_TO_METADATA);
+impl_batch!((MessageId, MessageId), (), TABLE_MESSAGE_ID_TO_MESSAGE_ID);
+impl_batch!((PaddedIndex, MessageId), (), TABLE_INDEX_TO_MESSAGE_ID);
+impl_batch!(OutputId, CreatedOutput, TABLE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_batch!(OutputId, ConsumedOutput, TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_batch!(Unspent, (), TABLE_OUTPUT_ID_UNSPENT);
+impl_batch!((Ed25519Address, OutputId), (), TABLE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_batch!(
+    (Ed25519Address, AddressHistoryEntry),
+    (),
+    TABLE_ED25519_ADDRESS_TO_HISTORY
+);
+impl_batch!(IndexationEntry, (), TABLE_RAW_INDEX_TO_MESSAGE_ID);
+impl_batch!(
+    (MilestoneIndex, IndexationEntry),
+    (),
+    TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
+impl_batch!((), LedgerIndex, TABLE_LEDGER_INDEX);
+impl_batch!(OptionalIndex, MilestoneIndex, TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX);
+impl_batch!(MilestoneIndex, Milestone, TABLE_MILESTONE_INDEX_TO_MILESTONE);
+impl_batch!((), SnapshotInfo, TABLE_SNAPSHOT_INFO);
+impl_batch!(
+    SolidEntryPoint,
+    MilestoneIndex,
+    TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
+);
+impl_batch!(MilestoneIndex, OutputDiff, TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_batch!(Address, Balance, TABLE_ADDRESS_TO_BALANCE);
+impl_batch!(
+    (MilestoneIndex, UnreferencedMessage),
+    (),
+    TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE
+);
+impl_batch!((MilestoneIndex, Receipt), (), TABLE_MILESTONE_INDEX_TO_RECEIPT);
+impl_batch!((bool, TreasuryOutput), (), TABLE_SPENT_TO_TREASURY_OUTPUT);
+impl_batch!(
+    MilestoneIndex,
+    LedgerStateHash,
+    TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::MemoryConfigBuilder;
+
+    use bee_storage::access::Fetch;
+
+    use futures::executor::block_on;
+
+    #[test]
+    fn batch_is_applied_on_commit_only() {
+        block_on(async {
+            let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+            let mut batch = Storage::batch_begin();
+
+            Batch::<(), LedgerIndex>::batch_insert(&storage, &mut batch, &(), &MilestoneIndex(42).into()).unwrap();
+            Batch::<MilestoneIndex, OutputDiff>::batch_insert(
+                &storage,
+                &mut batch,
+                &MilestoneIndex(42),
+                &OutputDiff::new(Vec::new(), Vec::new(), None),
+            )
+            .unwrap();
+
+            assert!(Fetch::<(), LedgerIndex>::fetch(&storage, &()).await.unwrap().is_none());
+            assert!(
+                Fetch::<MilestoneIndex, OutputDiff>::fetch(&storage, &MilestoneIndex(42))
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+
+            storage.batch_commit(batch, true).await.unwrap();
+
+            assert_eq!(
+                *Fetch::<(), LedgerIndex>::fetch(&storage, &()).await.unwrap().unwrap(),
+                42
+            );
+            assert!(
+                Fetch::<MilestoneIndex, OutputDiff>::fetch(&storage, &MilestoneIndex(42))
+                    .await
+                    .unwrap()
+                    .is_some()
+            );
+        });
+    }
+}
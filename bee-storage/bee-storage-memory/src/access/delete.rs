@@ -0,0 +1,80 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    codec::Encode,
+    storage::{Storage, StorageBackend},
+    tables::*,
+};
+
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::access::Delete;
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+macro_rules! impl_delete {
+    ($key:ty, $value:ty, $table:expr) => {
+        #[async_trait::async_trait]
+        impl Delete<$key, $value> for Storage {
+            async fn delete(&self, key: &$key) -> Result<(), <Self as StorageBackend>::Error> {
+                self.write()?.table_mut($table)?.remove(&key.encode_new());
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_delete!(MessageId, Message, TABLE_MESSAGE_ID_TO_MESSAGE);
+impl_delete!(MessageId, MessageMetadata, TABLE_MESSAGE_ID_TO_METADATA);
+impl_delete!((MessageId, MessageId), (), TABLE_MESSAGE_ID_TO_MESSAGE_ID);
+impl_delete!((PaddedIndex, MessageId), (), TABLE_INDEX_TO_MESSAGE_ID);
+impl_delete!(OutputId, CreatedOutput, TABLE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_delete!(OutputId, ConsumedOutput, TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_delete!(Unspent, (), TABLE_OUTPUT_ID_UNSPENT);
+impl_delete!((Ed25519Address, OutputId), (), TABLE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_delete!(
+    (Ed25519Address, AddressHistoryEntry),
+    (),
+    TABLE_ED25519_ADDRESS_TO_HISTORY
+);
+impl_delete!(IndexationEntry, (), TABLE_RAW_INDEX_TO_MESSAGE_ID);
+impl_delete!(
+    (MilestoneIndex, IndexationEntry),
+    (),
+    TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
+impl_delete!((), LedgerIndex, TABLE_LEDGER_INDEX);
+impl_delete!(OptionalIndex, MilestoneIndex, TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX);
+impl_delete!(MilestoneIndex, Milestone, TABLE_MILESTONE_INDEX_TO_MILESTONE);
+impl_delete!((), SnapshotInfo, TABLE_SNAPSHOT_INFO);
+impl_delete!(
+    SolidEntryPoint,
+    MilestoneIndex,
+    TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
+);
+impl_delete!(MilestoneIndex, OutputDiff, TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_delete!(Address, Balance, TABLE_ADDRESS_TO_BALANCE);
+impl_delete!(
+    (MilestoneIndex, UnreferencedMessage),
+    (),
+    TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE
+);
+impl_delete!((MilestoneIndex, Receipt), (), TABLE_MILESTONE_INDEX_TO_RECEIPT);
+impl_delete!((bool, TreasuryOutput), (), TABLE_SPENT_TO_TREASURY_OUTPUT);
+impl_delete!(
+    MilestoneIndex,
+    LedgerStateHash,
+    TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH
+);
@@ -0,0 +1,78 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    codec::Encode,
+    storage::{Storage, StorageBackend},
+    tables::*,
+};
+
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::access::Exist;
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+macro_rules! impl_exist {
+    ($key:ty, $value:ty, $table:expr) => {
+        #[async_trait::async_trait]
+        impl Exist<$key, $value> for Storage {
+            async fn exist(&self, key: &$key) -> Result<bool, <Self as StorageBackend>::Error> {
+                Ok(self.read()?.table($table)?.contains_key(&key.encode_new()))
+            }
+        }
+    };
+}
+
+impl_exist!(MessageId, Message, TABLE_MESSAGE_ID_TO_MESSAGE);
+impl_exist!(MessageId, MessageMetadata, TABLE_MESSAGE_ID_TO_METADATA);
+impl_exist!((MessageId, MessageId), (), TABLE_MESSAGE_ID_TO_MESSAGE_ID);
+impl_exist!((PaddedIndex, MessageId), (), TABLE_INDEX_TO_MESSAGE_ID);
+impl_exist!(OutputId, CreatedOutput, TABLE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_exist!(OutputId, ConsumedOutput, TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_exist!(Unspent, (), TABLE_OUTPUT_ID_UNSPENT);
+impl_exist!((Ed25519Address, OutputId), (), TABLE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_exist!(
+    (Ed25519Address, AddressHistoryEntry),
+    (),
+    TABLE_ED25519_ADDRESS_TO_HISTORY
+);
+impl_exist!(IndexationEntry, (), TABLE_RAW_INDEX_TO_MESSAGE_ID);
+impl_exist!(
+    (MilestoneIndex, IndexationEntry),
+    (),
+    TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
+impl_exist!((), LedgerIndex, TABLE_LEDGER_INDEX);
+impl_exist!(OptionalIndex, MilestoneIndex, TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX);
+impl_exist!(MilestoneIndex, Milestone, TABLE_MILESTONE_INDEX_TO_MILESTONE);
+impl_exist!((), SnapshotInfo, TABLE_SNAPSHOT_INFO);
+impl_exist!(
+    SolidEntryPoint,
+    MilestoneIndex,
+    TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
+);
+impl_exist!(MilestoneIndex, OutputDiff, TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_exist!(Address, Balance, TABLE_ADDRESS_TO_BALANCE);
+impl_exist!(
+    (MilestoneIndex, UnreferencedMessage),
+    (),
+    TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE
+);
+impl_exist!((MilestoneIndex, Receipt), (), TABLE_MILESTONE_INDEX_TO_RECEIPT);
+impl_exist!((bool, TreasuryOutput), (), TABLE_SPENT_TO_TREASURY_OUTPUT);
+impl_exist!(
+    MilestoneIndex,
+    LedgerStateHash,
+    TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH
+);
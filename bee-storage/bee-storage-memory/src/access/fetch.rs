@@ -0,0 +1,399 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    codec::{Decode, Encode},
+    storage::{prefix_iter, Storage, StorageBackend},
+    tables::*,
+};
+
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryPage, Balance, ConsumedOutput, CreatedOutput,
+    IndexationEntry, IndexationPage, IndexationPageEntries, LedgerIndex, LedgerStateHash, OptionalIndex, OutputDiff,
+    Receipt, TreasuryOutput,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::access::Fetch;
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+macro_rules! impl_fetch {
+    ($key:ty, $value:ty, $table:expr) => {
+        #[async_trait::async_trait]
+        impl Fetch<$key, $value> for Storage {
+            async fn fetch(&self, key: &$key) -> Result<Option<$value>, <Self as StorageBackend>::Error> {
+                Ok(self
+                    .read()?
+                    .table($table)?
+                    .get(&key.encode_new())
+                    .map(|value| <$value>::decode(&mut value.as_slice())))
+            }
+        }
+    };
+}
+
+/// Implements a fetch of all the values stored as the second part of `($key, $value)` tuple keys sharing the same
+/// `$key` prefix, optionally capped by a limit of the config.
+macro_rules! impl_fetch_prefix {
+    (@limit $self:ident) => {
+        usize::MAX
+    };
+    (@limit $self:ident, $limit:ident) => {
+        $self.config.$limit
+    };
+    ($key:ty, $value:ty, $table:expr $(, $limit:ident)?) => {
+        #[async_trait::async_trait]
+        impl Fetch<$key, Vec<$value>> for Storage {
+            async fn fetch(&self, key: &$key) -> Result<Option<Vec<$value>>, <Self as StorageBackend>::Error> {
+                let prefix = key.encode_new();
+                let tables = self.read()?;
+
+                Ok(Some(
+                    prefix_iter(tables.table($table)?, &prefix)
+                        .map(|(key, _)| <($key, $value)>::decode(&mut key.as_slice()).1)
+                        .take(impl_fetch_prefix!(@limit self $(, $limit)?))
+                        .collect(),
+                ))
+            }
+        }
+    };
+}
+
+/// Implements the fetch of the values of several keys under a single lock, returned in the order of the keys.
+macro_rules! impl_multi_fetch {
+    ($key:ty, $value:ty, $table:expr) => {
+        #[async_trait::async_trait]
+        impl Fetch<Vec<$key>, Vec<Option<$value>>> for Storage {
+            async fn fetch(
+                &self,
+                keys: &Vec<$key>,
+            ) -> Result<Option<Vec<Option<$value>>>, <Self as StorageBackend>::Error> {
+                let tables = self.read()?;
+                let table = tables.table($table)?;
+
+                Ok(Some(
+                    keys.iter()
+                        .map(|key| {
+                            table
+                                .get(&key.encode_new())
+                                .map(|value| <$value>::decode(&mut value.as_slice()))
+                        })
+                        .collect(),
+                ))
+            }
+        }
+    };
+}
+
+impl_fetch!(MessageId, Message, TABLE_MESSAGE_ID_TO_MESSAGE);
+impl_fetch!(MessageId, MessageMetadata, TABLE_MESSAGE_ID_TO_METADATA);
+impl_fetch!(OutputId, CreatedOutput, TABLE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_fetch!(OutputId, ConsumedOutput, TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_fetch!((), LedgerIndex, TABLE_LEDGER_INDEX);
+impl_fetch!(OptionalIndex, MilestoneIndex, TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX);
+impl_fetch!(MilestoneIndex, Milestone, TABLE_MILESTONE_INDEX_TO_MILESTONE);
+impl_fetch!((), SnapshotInfo, TABLE_SNAPSHOT_INFO);
+impl_fetch!(
+    SolidEntryPoint,
+    MilestoneIndex,
+    TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
+);
+impl_fetch!(MilestoneIndex, OutputDiff, TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_fetch!(
+    MilestoneIndex,
+    LedgerStateHash,
+    TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH
+);
+impl_fetch!(Address, Balance, TABLE_ADDRESS_TO_BALANCE);
+
+impl_fetch_prefix!(MessageId, MessageId, TABLE_MESSAGE_ID_TO_MESSAGE_ID, fetch_edge_limit);
+impl_fetch_prefix!(PaddedIndex, MessageId, TABLE_INDEX_TO_MESSAGE_ID, fetch_index_limit);
+impl_fetch_prefix!(
+    Ed25519Address,
+    OutputId,
+    TABLE_ED25519_ADDRESS_TO_OUTPUT_ID,
+    fetch_output_id_limit
+);
+impl_fetch_prefix!(
+    MilestoneIndex,
+    UnreferencedMessage,
+    TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE
+);
+impl_fetch_prefix!(MilestoneIndex, Receipt, TABLE_MILESTONE_INDEX_TO_RECEIPT);
+impl_fetch_prefix!(
+    MilestoneIndex,
+    IndexationEntry,
+    TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
+impl_fetch_prefix!(bool, TreasuryOutput, TABLE_SPENT_TO_TREASURY_OUTPUT);
+
+impl_multi_fetch!(MessageId, Message, TABLE_MESSAGE_ID_TO_MESSAGE);
+impl_multi_fetch!(OutputId, CreatedOutput, TABLE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_multi_fetch!(OutputId, ConsumedOutput, TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+
+#[async_trait::async_trait]
+impl Fetch<IndexationPage, IndexationPageEntries> for Storage {
+    async fn fetch(
+        &self,
+        page: &IndexationPage,
+    ) -> Result<Option<IndexationPageEntries>, <Self as StorageBackend>::Error> {
+        let tables = self.read()?;
+
+        // Entries of a prefix span several indexes, so they are looked up in milestone order instead.
+        let entries = if page.is_prefix() {
+            page.select(
+                tables
+                    .table(TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY)?
+                    .range(page.seek_key()..)
+                    .map(|(key, _)| <(MilestoneIndex, IndexationEntry)>::decode(&mut key.as_slice()).1),
+            )
+        } else {
+            let index_key = page.index_key();
+
+            page.select(
+                tables
+                    .table(TABLE_RAW_INDEX_TO_MESSAGE_ID)?
+                    .range(page.seek_key()..)
+                    .take_while(|(key, _)| key.starts_with(&index_key))
+                    .map(|(key, _)| IndexationEntry::decode(&mut key.as_slice())),
+            )
+        };
+
+        Ok(Some(entries))
+    }
+}
+
+#[async_trait::async_trait]
+impl Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>> for Storage {
+    async fn fetch(
+        &self,
+        page: &AddressHistoryPage,
+    ) -> Result<Option<Vec<AddressHistoryEntry>>, <Self as StorageBackend>::Error> {
+        let tables = self.read()?;
+        let address = page.address().as_ref();
+
+        Ok(Some(
+            tables
+                .table(TABLE_ED25519_ADDRESS_TO_HISTORY)?
+                .range(page.seek_key()..)
+                .take_while(|(key, _)| key.starts_with(address))
+                .skip(page.skip())
+                .take(page.limit())
+                .map(|(key, _)| <(Ed25519Address, AddressHistoryEntry)>::decode(&mut key.as_slice()).1)
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::MemoryConfigBuilder;
+
+    use bee_message::payload::transaction::TransactionId;
+    use bee_storage::access::Insert;
+
+    use futures::executor::block_on;
+
+    #[test]
+    fn fetch_prefix() {
+        block_on(async {
+            let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+            let output_id = |index| OutputId::new(TransactionId::new([0x2a; 32]), index).unwrap();
+
+            for (address, index) in [([1; 32], 256), ([1; 32], 1), ([2; 32], 0), ([0; 32], 0)].iter() {
+                Insert::<(Ed25519Address, OutputId), ()>::insert(
+                    &storage,
+                    &(Ed25519Address::new(*address), output_id(*index)),
+                    &(),
+                )
+                .await
+                .unwrap();
+            }
+
+            assert_eq!(
+                Fetch::<Ed25519Address, Vec<OutputId>>::fetch(&storage, &Ed25519Address::new([1; 32]))
+                    .await
+                    .unwrap()
+                    .unwrap(),
+                vec![output_id(1), output_id(256)]
+            );
+            assert!(
+                Fetch::<Ed25519Address, Vec<OutputId>>::fetch(&storage, &Ed25519Address::new([3; 32]))
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .is_empty()
+            );
+        });
+    }
+
+    #[test]
+    fn fetch_address_history_page() {
+        block_on(async {
+            let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+            let address = Ed25519Address::new([1; 32]);
+            let entry = |index| AddressHistoryEntry::new(MilestoneIndex(index), TransactionId::new([0x2a; 32]), 1);
+
+            for (address, index) in [([1; 32], 256), ([1; 32], 1), ([1; 32], 2), ([2; 32], 0), ([0; 32], 3)].iter() {
+                Insert::<(Ed25519Address, AddressHistoryEntry), ()>::insert(
+                    &storage,
+                    &(Ed25519Address::new(*address), entry(*index)),
+                    &(),
+                )
+                .await
+                .unwrap();
+            }
+
+            let page = |from, skip, limit| {
+                let storage = &storage;
+                async move {
+                    Fetch::<AddressHistoryPage, Vec<AddressHistoryEntry>>::fetch(
+                        storage,
+                        &AddressHistoryPage::new(address, MilestoneIndex(from), skip, limit),
+                    )
+                    .await
+                    .unwrap()
+                    .unwrap()
+                }
+            };
+
+            assert_eq!(page(0, 0, 10).await, vec![entry(1), entry(2), entry(256)]);
+            assert_eq!(page(2, 0, 10).await, vec![entry(2), entry(256)]);
+            assert_eq!(page(0, 1, 1).await, vec![entry(2)]);
+            assert!(page(257, 0, 10).await.is_empty());
+        });
+    }
+
+    #[test]
+    fn fetch_indexation_page() {
+        block_on(async {
+            let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+            let entry = |index: &[u8], milestone_index| {
+                IndexationEntry::new(
+                    index.into(),
+                    MilestoneIndex(milestone_index),
+                    u64::from(milestone_index) * 10,
+                    MessageId::new([0x2a; 32]),
+                )
+            };
+
+            for (index, milestone_index) in [
+                (&b"bee"[..], 256),
+                (b"bee", 1),
+                (b"be", 2),
+                (b"bee\0", 3),
+                (b"bef", 4),
+                (b"ab", 5),
+            ]
+            .iter()
+            {
+                let entry = entry(*index, *milestone_index);
+
+                Insert::<IndexationEntry, ()>::insert(&storage, &entry, &())
+                    .await
+                    .unwrap();
+                Insert::<(MilestoneIndex, IndexationEntry), ()>::insert(
+                    &storage,
+                    &(entry.milestone_index(), entry.clone()),
+                    &(),
+                )
+                .await
+                .unwrap();
+            }
+
+            let fetch = |page| {
+                let storage = &storage;
+                async move {
+                    Fetch::<IndexationPage, IndexationPageEntries>::fetch(storage, &page)
+                        .await
+                        .unwrap()
+                        .unwrap()
+                        .into_entries()
+                }
+            };
+            let bee = |skip, limit| IndexationPage::index(b"bee".to_vec().into_boxed_slice(), skip, limit);
+            let be = |skip, limit| IndexationPage::prefix(b"be".to_vec().into_boxed_slice(), skip, limit);
+
+            assert_eq!(fetch(bee(0, 10)).await, vec![entry(b"bee", 1), entry(b"bee", 256)]);
+            assert_eq!(fetch(bee(1, 10)).await, vec![entry(b"bee", 256)]);
+            assert_eq!(
+                fetch(bee(0, 10).with_milestones(MilestoneIndex(2), MilestoneIndex(256))).await,
+                vec![entry(b"bee", 256)]
+            );
+            assert!(fetch(bee(0, 10).with_timestamps(20, 2550)).await.is_empty());
+            assert_eq!(
+                fetch(be(0, 10)).await,
+                vec![
+                    entry(b"bee", 1),
+                    entry(b"be", 2),
+                    entry(b"bee\0", 3),
+                    entry(b"bef", 4),
+                    entry(b"bee", 256)
+                ]
+            );
+            assert_eq!(
+                fetch(be(1, 2).with_timestamps(10, 40)).await,
+                vec![entry(b"be", 2), entry(b"bee\0", 3)]
+            );
+            assert_eq!(
+                fetch(be(0, 10).with_milestones(MilestoneIndex(3), MilestoneIndex(4))).await,
+                vec![entry(b"bee\0", 3), entry(b"bef", 4)]
+            );
+            assert!(fetch(IndexationPage::index(b"b".to_vec().into_boxed_slice(), 0, 10))
+                .await
+                .is_empty());
+            // The entry of another index is visited too.
+            assert_eq!(
+                Fetch::<IndexationPage, IndexationPageEntries>::fetch(&storage, &be(0, 10).with_max_visited(5))
+                    .await
+                    .unwrap(),
+                Some(IndexationPageEntries::new(
+                    vec![entry(b"bee", 1), entry(b"be", 2), entry(b"bee\0", 3), entry(b"bef", 4)],
+                    true
+                ))
+            );
+        });
+    }
+
+    #[test]
+    fn fetch_indexation_entries_of_milestone() {
+        block_on(async {
+            let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+            let entry = |index: &[u8], milestone_index| {
+                IndexationEntry::new(
+                    index.into(),
+                    MilestoneIndex(milestone_index),
+                    0,
+                    MessageId::new([0x2a; 32]),
+                )
+            };
+
+            for entry in [entry(b"bee", 1), entry(b"ab", 1), entry(b"bee", 256)].iter() {
+                Insert::<(MilestoneIndex, IndexationEntry), ()>::insert(
+                    &storage,
+                    &(entry.milestone_index(), entry.clone()),
+                    &(),
+                )
+                .await
+                .unwrap();
+            }
+
+            assert_eq!(
+                Fetch::<MilestoneIndex, Vec<IndexationEntry>>::fetch(&storage, &MilestoneIndex(1))
+                    .await
+                    .unwrap()
+                    .unwrap(),
+                vec![entry(b"ab", 1), entry(b"bee", 1)]
+            );
+        });
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    codec::Encode,
+    storage::{Storage, StorageBackend},
+    tables::*,
+};
+
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::access::Insert;
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+macro_rules! impl_insert {
+    ($key:ty, $value:ty, $table:expr) => {
+        #[async_trait::async_trait]
+        impl Insert<$key, $value> for Storage {
+            async fn insert(&self, key: &$key, value: &$value) -> Result<(), <Self as StorageBackend>::Error> {
+                self.write()?
+                    .table_mut($table)?
+                    .insert(key.encode_new(), value.encode_new());
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_insert!(MessageId, Message, TABLE_MESSAGE_ID_TO_MESSAGE);
+impl_insert!(MessageId, MessageMetadata, TABLE_MESSAGE_ID_TO_METADATA);
+impl_insert!((MessageId, MessageId), (), TABLE_MESSAGE_ID_TO_MESSAGE_ID);
+impl_insert!((PaddedIndex, MessageId), (), TABLE_INDEX_TO_MESSAGE_ID);
+impl_insert!(OutputId, CreatedOutput, TABLE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_insert!(OutputId, ConsumedOutput, TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_insert!(Unspent, (), TABLE_OUTPUT_ID_UNSPENT);
+impl_insert!((Ed25519Address, OutputId), (), TABLE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_insert!(
+    (Ed25519Address, AddressHistoryEntry),
+    (),
+    TABLE_ED25519_ADDRESS_TO_HISTORY
+);
+impl_insert!(IndexationEntry, (), TABLE_RAW_INDEX_TO_MESSAGE_ID);
+impl_insert!(
+    (MilestoneIndex, IndexationEntry),
+    (),
+    TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
+impl_insert!((), LedgerIndex, TABLE_LEDGER_INDEX);
+impl_insert!(OptionalIndex, MilestoneIndex, TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX);
+impl_insert!(MilestoneIndex, Milestone, TABLE_MILESTONE_INDEX_TO_MILESTONE);
+impl_insert!((), SnapshotInfo, TABLE_SNAPSHOT_INFO);
+impl_insert!(
+    SolidEntryPoint,
+    MilestoneIndex,
+    TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
+);
+impl_insert!(MilestoneIndex, OutputDiff, TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_insert!(Address, Balance, TABLE_ADDRESS_TO_BALANCE);
+impl_insert!(
+    (MilestoneIndex, UnreferencedMessage),
+    (),
+    TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE
+);
+impl_insert!((MilestoneIndex, Receipt), (), TABLE_MILESTONE_INDEX_TO_RECEIPT);
+impl_insert!((bool, TreasuryOutput), (), TABLE_SPENT_TO_TREASURY_OUTPUT);
+impl_insert!(
+    MilestoneIndex,
+    LedgerStateHash,
+    TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH
+);
@@ -0,0 +1,10 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod batch;
+pub mod delete;
+pub mod exist;
+pub mod fetch;
+pub mod insert;
+pub mod stream;
+pub mod truncate;
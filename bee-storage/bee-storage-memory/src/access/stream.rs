@@ -0,0 +1,136 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    codec::Decode,
+    storage::{Storage, StorageBackend},
+    tables::*,
+};
+
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::access::AsStream;
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+use futures::stream::{self, Iter};
+
+use std::vec::IntoIter;
+
+/// A stream over a snapshot of a table, taken when the stream is created.
+pub type StorageStream<K, V> = Iter<IntoIter<(K, V)>>;
+
+macro_rules! impl_stream {
+    ($key:ty, $value:ty, $table:expr) => {
+        #[async_trait::async_trait]
+        impl<'a> AsStream<'a, $key, $value> for Storage {
+            type Stream = StorageStream<$key, $value>;
+
+            async fn stream(&'a self) -> Result<Self::Stream, <Self as StorageBackend>::Error> {
+                let items = self
+                    .read()?
+                    .table($table)?
+                    .iter()
+                    .map(|(key, value)| {
+                        (
+                            <$key>::decode(&mut key.as_slice()),
+                            <$value>::decode(&mut value.as_slice()),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                Ok(stream::iter(items))
+            }
+        }
+    };
+}
+
+impl_stream!(MessageId, Message, TABLE_MESSAGE_ID_TO_MESSAGE);
+impl_stream!(MessageId, MessageMetadata, TABLE_MESSAGE_ID_TO_METADATA);
+impl_stream!((MessageId, MessageId), (), TABLE_MESSAGE_ID_TO_MESSAGE_ID);
+impl_stream!((PaddedIndex, MessageId), (), TABLE_INDEX_TO_MESSAGE_ID);
+impl_stream!(OutputId, CreatedOutput, TABLE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_stream!(OutputId, ConsumedOutput, TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_stream!(Unspent, (), TABLE_OUTPUT_ID_UNSPENT);
+impl_stream!((Ed25519Address, OutputId), (), TABLE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_stream!(
+    (Ed25519Address, AddressHistoryEntry),
+    (),
+    TABLE_ED25519_ADDRESS_TO_HISTORY
+);
+impl_stream!(IndexationEntry, (), TABLE_RAW_INDEX_TO_MESSAGE_ID);
+impl_stream!(
+    (MilestoneIndex, IndexationEntry),
+    (),
+    TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
+impl_stream!((), LedgerIndex, TABLE_LEDGER_INDEX);
+impl_stream!(OptionalIndex, MilestoneIndex, TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX);
+impl_stream!(MilestoneIndex, Milestone, TABLE_MILESTONE_INDEX_TO_MILESTONE);
+impl_stream!((), SnapshotInfo, TABLE_SNAPSHOT_INFO);
+impl_stream!(
+    SolidEntryPoint,
+    MilestoneIndex,
+    TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
+);
+impl_stream!(MilestoneIndex, OutputDiff, TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_stream!(Address, Balance, TABLE_ADDRESS_TO_BALANCE);
+impl_stream!(
+    (MilestoneIndex, UnreferencedMessage),
+    (),
+    TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE
+);
+impl_stream!((MilestoneIndex, Receipt), (), TABLE_MILESTONE_INDEX_TO_RECEIPT);
+impl_stream!((bool, TreasuryOutput), (), TABLE_SPENT_TO_TREASURY_OUTPUT);
+impl_stream!(
+    MilestoneIndex,
+    LedgerStateHash,
+    TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::MemoryConfigBuilder;
+
+    use bee_storage::access::Insert;
+
+    use futures::{executor::block_on, StreamExt};
+
+    #[test]
+    fn stream_in_numeric_key_order() {
+        block_on(async {
+            let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+
+            for index in [256, 1, 65536, 255].iter() {
+                Insert::<MilestoneIndex, OutputDiff>::insert(
+                    &storage,
+                    &MilestoneIndex(*index),
+                    &OutputDiff::new(Vec::new(), Vec::new(), None),
+                )
+                .await
+                .unwrap();
+            }
+
+            let indexes = AsStream::<MilestoneIndex, OutputDiff>::stream(&storage)
+                .await
+                .unwrap()
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>()
+                .await;
+
+            assert_eq!(indexes, vec![1, 255, 256, 65536]);
+        });
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    storage::{Storage, StorageBackend},
+    tables::*,
+};
+
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::indexation::PaddedIndex,
+    Message, MessageId,
+};
+use bee_storage::access::Truncate;
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+macro_rules! impl_truncate {
+    ($key:ty, $value:ty, $table:expr) => {
+        #[async_trait::async_trait]
+        impl Truncate<$key, $value> for Storage {
+            async fn truncate(&self) -> Result<(), <Self as StorageBackend>::Error> {
+                self.write()?.table_mut($table)?.clear();
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_truncate!(MessageId, Message, TABLE_MESSAGE_ID_TO_MESSAGE);
+impl_truncate!(MessageId, MessageMetadata, TABLE_MESSAGE_ID_TO_METADATA);
+impl_truncate!((MessageId, MessageId), (), TABLE_MESSAGE_ID_TO_MESSAGE_ID);
+impl_truncate!((PaddedIndex, MessageId), (), TABLE_INDEX_TO_MESSAGE_ID);
+impl_truncate!(OutputId, CreatedOutput, TABLE_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_truncate!(OutputId, ConsumedOutput, TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT);
+impl_truncate!(Unspent, (), TABLE_OUTPUT_ID_UNSPENT);
+impl_truncate!((Ed25519Address, OutputId), (), TABLE_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_truncate!(
+    (Ed25519Address, AddressHistoryEntry),
+    (),
+    TABLE_ED25519_ADDRESS_TO_HISTORY
+);
+impl_truncate!(IndexationEntry, (), TABLE_RAW_INDEX_TO_MESSAGE_ID);
+impl_truncate!(
+    (MilestoneIndex, IndexationEntry),
+    (),
+    TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
+impl_truncate!((), LedgerIndex, TABLE_LEDGER_INDEX);
+impl_truncate!(OptionalIndex, MilestoneIndex, TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX);
+impl_truncate!(MilestoneIndex, Milestone, TABLE_MILESTONE_INDEX_TO_MILESTONE);
+impl_truncate!((), SnapshotInfo, TABLE_SNAPSHOT_INFO);
+impl_truncate!(
+    SolidEntryPoint,
+    MilestoneIndex,
+    TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX
+);
+impl_truncate!(MilestoneIndex, OutputDiff, TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF);
+impl_truncate!(Address, Balance, TABLE_ADDRESS_TO_BALANCE);
+impl_truncate!(
+    (MilestoneIndex, UnreferencedMessage),
+    (),
+    TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE
+);
+impl_truncate!((MilestoneIndex, Receipt), (), TABLE_MILESTONE_INDEX_TO_RECEIPT);
+impl_truncate!((bool, TreasuryOutput), (), TABLE_SPENT_TO_TREASURY_OUTPUT);
+impl_truncate!(
+    MilestoneIndex,
+    LedgerStateHash,
+    TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::MemoryConfigBuilder;
+
+    use bee_message::payload::transaction::TransactionId;
+    use bee_storage::access::{Delete, Exist, Insert};
+
+    use futures::executor::block_on;
+
+    #[test]
+    fn delete_and_truncate() {
+        block_on(async {
+            let storage = Storage::start(MemoryConfigBuilder::new().finish()).await.unwrap();
+            let unspent = |index| Unspent::new(OutputId::new(TransactionId::new([0x2a; 32]), index).unwrap());
+
+            for index in 0..3 {
+                Insert::<Unspent, ()>::insert(&storage, &unspent(index), &())
+                    .await
+                    .unwrap();
+            }
+
+            Delete::<Unspent, ()>::delete(&storage, &unspent(1)).await.unwrap();
+
+            assert!(Exist::<Unspent, ()>::exist(&storage, &unspent(0)).await.unwrap());
+            assert!(!Exist::<Unspent, ()>::exist(&storage, &unspent(1)).await.unwrap());
+
+            Truncate::<Unspent, ()>::truncate(&storage).await.unwrap();
+
+            assert!(!Exist::<Unspent, ()>::exist(&storage, &unspent(0)).await.unwrap());
+            assert!(!Exist::<Unspent, ()>::exist(&storage, &unspent(2)).await.unwrap());
+        });
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions between the stored types and the raw bytes kept in the tables.
+
+use bee_common::packable::Packable;
+use bee_ledger::types::{
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
+};
+use bee_message::{
+    address::{Address, Ed25519Address},
+    milestone::{Milestone, MilestoneIndex},
+    output::OutputId,
+    payload::{
+        indexation::{PaddedIndex, INDEXATION_PADDED_INDEX_LENGTH},
+        transaction::{TransactionId, TRANSACTION_ID_LENGTH},
+    },
+    Message, MessageId,
+};
+use bee_tangle::{
+    metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
+};
+
+use std::convert::TryInto;
+
+pub(crate) trait Encode {
+    fn encode(&self, buf: &mut Vec<u8>);
+
+    fn encode_new(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.encode(&mut buf);
+        buf
+    }
+}
+
+pub(crate) trait Decode: Sized {
+    /// Decodes a value from the beginning of `bytes` and advances it past the consumed bytes.
+    fn decode(bytes: &mut &[u8]) -> Self;
+}
+
+macro_rules! impl_packable_codec {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Encode for $ty {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    // Packing to bytes can't fail.
+                    self.pack(buf).unwrap();
+                }
+            }
+
+            impl Decode for $ty {
+                fn decode(bytes: &mut &[u8]) -> Self {
+                    // Unpacking from storage is fine.
+                    <$ty>::unpack_unchecked(bytes).unwrap()
+                }
+            }
+        )+
+    };
+}
+
+impl_packable_codec!(
+    Address,
+    AddressHistoryEntry,
+    Balance,
+    bool,
+    ConsumedOutput,
+    CreatedOutput,
+    Ed25519Address,
+    IndexationEntry,
+    LedgerIndex,
+    LedgerStateHash,
+    Message,
+    MessageId,
+    MessageMetadata,
+    Milestone,
+    OptionalIndex,
+    OutputDiff,
+    Receipt,
+    SnapshotInfo,
+    SolidEntryPoint,
+    TreasuryOutput,
+);
+
+impl Encode for () {
+    fn encode(&self, _: &mut Vec<u8>) {}
+}
+
+impl Decode for () {
+    fn decode(_: &mut &[u8]) -> Self {}
+}
+
+// Integers of keys are encoded in big endian, unlike when packed, so that the bytewise ordered tables iterate over them in
+// numeric order.
+
+impl Encode for MilestoneIndex {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.to_be_bytes());
+    }
+}
+
+impl Decode for MilestoneIndex {
+    fn decode(bytes: &mut &[u8]) -> Self {
+        let (index, rest) = bytes.split_at(std::mem::size_of::<u32>());
+        *bytes = rest;
+        // Decoding from storage is fine.
+        MilestoneIndex(u32::from_be_bytes(index.try_into().unwrap()))
+    }
+}
+
+impl Encode for OutputId {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.transaction_id().as_ref());
+        buf.extend_from_slice(&self.index().to_be_bytes());
+    }
+}
+
+impl Decode for OutputId {
+    fn decode(bytes: &mut &[u8]) -> Self {
+        let (transaction_id, rest) = bytes.split_at(TRANSACTION_ID_LENGTH);
+        let (index, rest) = rest.split_at(std::mem::size_of::<u16>());
+        *bytes = rest;
+        // Decoding from storage is fine.
+        OutputId::new(
+            TransactionId::new(transaction_id.try_into().unwrap()),
+            u16::from_be_bytes(index.try_into().unwrap()),
+        )
+        .unwrap()
+    }
+}
+
+impl Encode for Unspent {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.id().encode(buf);
+    }
+}
+
+impl Decode for Unspent {
+    fn decode(bytes: &mut &[u8]) -> Self {
+        Unspent::new(OutputId::decode(bytes))
+    }
+}
+
+impl Encode for PaddedIndex {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_ref());
+    }
+}
+
+impl Decode for PaddedIndex {
+    fn decode(bytes: &mut &[u8]) -> Self {
+        let (index, rest) = bytes.split_at(INDEXATION_PADDED_INDEX_LENGTH);
+        *bytes = rest;
+        // Unpacking from storage is fine.
+        PaddedIndex::new(index.try_into().unwrap())
+    }
+}
+
+impl Encode for UnreferencedMessage {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_ref());
+    }
+}
+
+impl Decode for UnreferencedMessage {
+    fn decode(bytes: &mut &[u8]) -> Self {
+        UnreferencedMessage::from(MessageId::decode(bytes))
+    }
+}
+
+impl<A: Encode, B: Encode> Encode for (A, B) {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.0.encode(buf);
+        self.1.encode(buf);
+    }
+}
+
+impl<A: Decode, B: Decode> Decode for (A, B) {
+    fn decode(bytes: &mut &[u8]) -> Self {
+        let a = A::decode(bytes);
+        let b = B::decode(bytes);
+
+        (a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_keys_are_ordered_numerically() {
+        assert!(MilestoneIndex(255).encode_new() < MilestoneIndex(256).encode_new());
+
+        let output_id = |index| OutputId::new(TransactionId::new([0x2a; 32]), index).unwrap();
+
+        assert!(output_id(255).encode_new() < output_id(256).encode_new());
+        assert!(Unspent::new(output_id(1)).encode_new() < Unspent::new(output_id(2)).encode_new());
+    }
+
+    #[test]
+    fn encode_decode() {
+        let output_id = OutputId::new(TransactionId::new([0x2a; 32]), 42).unwrap();
+        let key = (MilestoneIndex(42), output_id).encode_new();
+
+        assert_eq!(
+            <(MilestoneIndex, OutputId)>::decode(&mut key.as_slice()),
+            (MilestoneIndex(42), output_id)
+        );
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+
+const DEFAULT_FETCH_EDGE_LIMIT: usize = 1_000;
+const DEFAULT_FETCH_INDEX_LIMIT: usize = 1_000;
+const DEFAULT_FETCH_OUTPUT_ID_LIMIT: usize = 1_000;
+
+#[derive(Default, Deserialize)]
+pub struct MemoryConfigBuilder {
+    fetch_edge_limit: Option<usize>,
+    fetch_index_limit: Option<usize>,
+    fetch_output_id_limit: Option<usize>,
+}
+
+impl MemoryConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn finish(self) -> MemoryConfig {
+        MemoryConfig::from(self)
+    }
+}
+
+impl From<MemoryConfigBuilder> for MemoryConfig {
+    fn from(builder: MemoryConfigBuilder) -> Self {
+        MemoryConfig {
+            fetch_edge_limit: builder.fetch_edge_limit.unwrap_or(DEFAULT_FETCH_EDGE_LIMIT),
+            fetch_index_limit: builder.fetch_index_limit.unwrap_or(DEFAULT_FETCH_INDEX_LIMIT),
+            fetch_output_id_limit: builder.fetch_output_id_limit.unwrap_or(DEFAULT_FETCH_OUTPUT_ID_LIMIT),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MemoryConfig {
+    pub(crate) fetch_edge_limit: usize,
+    pub(crate) fetch_index_limit: usize,
+    pub(crate) fetch_output_id_limit: usize,
+}
@@ -0,0 +1,12 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Unknown table {0}")]
+    UnknownTable(&'static str),
+    #[error("Storage lock poisoned")]
+    PoisonedLock,
+}
@@ -0,0 +1,10 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod access;
+pub mod config;
+pub mod error;
+pub mod storage;
+pub mod tables;
+
+mod codec;
@@ -0,0 +1,115 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    config::{MemoryConfig, MemoryConfigBuilder},
+    error::Error,
+    tables::TABLES,
+};
+
+pub use bee_storage::{
+    access::{Fetch, Insert},
+    backend::StorageBackend,
+    health::StorageHealth,
+};
+
+use async_trait::async_trait;
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+pub(crate) type Table = BTreeMap<Vec<u8>, Vec<u8>>;
+
+pub(crate) struct Tables {
+    inner: HashMap<&'static str, Table>,
+}
+
+impl Tables {
+    fn new() -> Self {
+        Self {
+            inner: TABLES.iter().map(|table| (*table, Table::new())).collect(),
+        }
+    }
+
+    pub(crate) fn table(&self, table: &'static str) -> Result<&Table, Error> {
+        self.inner.get(table).ok_or(Error::UnknownTable(table))
+    }
+
+    pub(crate) fn table_mut(&mut self, table: &'static str) -> Result<&mut Table, Error> {
+        self.inner.get_mut(table).ok_or(Error::UnknownTable(table))
+    }
+
+    fn size(&self) -> usize {
+        self.inner
+            .values()
+            .flat_map(|table| table.iter())
+            .map(|(key, value)| key.len() + value.len())
+            .sum()
+    }
+}
+
+/// Iterates over the entries of a table whose keys start with the given prefix, in key order.
+pub(crate) fn prefix_iter<'a>(table: &'a Table, prefix: &'a [u8]) -> impl Iterator<Item = (&'a Vec<u8>, &'a Vec<u8>)> {
+    table
+        .range(prefix.to_vec()..)
+        .take_while(move |(key, _)| key.starts_with(prefix))
+}
+
+pub struct Storage {
+    pub(crate) config: MemoryConfig,
+    inner: RwLock<Tables>,
+    health: RwLock<Option<StorageHealth>>,
+}
+
+impl Storage {
+    pub(crate) fn read(&self) -> Result<RwLockReadGuard<Tables>, Error> {
+        self.inner.read().map_err(|_| Error::PoisonedLock)
+    }
+
+    pub(crate) fn write(&self) -> Result<RwLockWriteGuard<Tables>, Error> {
+        self.inner.write().map_err(|_| Error::PoisonedLock)
+    }
+}
+
+#[async_trait]
+impl StorageBackend for Storage {
+    type ConfigBuilder = MemoryConfigBuilder;
+    type Config = MemoryConfig;
+    type Error = Error;
+
+    async fn start(config: Self::Config) -> Result<Self, Self::Error> {
+        let storage = Storage {
+            config,
+            inner: RwLock::new(Tables::new()),
+            health: RwLock::new(None),
+        };
+
+        storage.set_health(StorageHealth::Idle).await?;
+
+        Ok(storage)
+    }
+
+    async fn shutdown(self) -> Result<(), Self::Error> {
+        if self.get_health().await? != Some(StorageHealth::Corrupted) {
+            self.set_health(StorageHealth::Healthy).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn size(&self) -> Result<Option<usize>, Self::Error> {
+        Ok(Some(self.read()?.size()))
+    }
+
+    async fn get_health(&self) -> Result<Option<StorageHealth>, Self::Error> {
+        Ok(*self.health.read().map_err(|_| Error::PoisonedLock)?)
+    }
+
+    async fn set_health(&self, health: StorageHealth) -> Result<(), Self::Error> {
+        *self.health.write().map_err(|_| Error::PoisonedLock)? = Some(health);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2020-2021 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+pub const TABLE_MESSAGE_ID_TO_MESSAGE: &str = "message_id_to_message";
+pub const TABLE_MESSAGE_ID_TO_METADATA: &str = "message_id_to_metadata";
+pub const TABLE_MESSAGE_ID_TO_MESSAGE_ID: &str = "message_id_to_message_id";
+pub const TABLE_INDEX_TO_MESSAGE_ID: &str = "index_to_message_id";
+pub const TABLE_OUTPUT_ID_TO_CREATED_OUTPUT: &str = "output_id_to_created_output";
+pub const TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT: &str = "output_id_to_consumed_output";
+pub const TABLE_OUTPUT_ID_UNSPENT: &str = "output_id_unspent";
+pub const TABLE_ED25519_ADDRESS_TO_OUTPUT_ID: &str = "ed25519_address_to_output_id";
+pub const TABLE_ED25519_ADDRESS_TO_HISTORY: &str = "ed25519_address_to_history";
+pub const TABLE_RAW_INDEX_TO_MESSAGE_ID: &str = "raw_index_to_message_id";
+pub const TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY: &str = "milestone_index_to_indexation_entry";
+pub const TABLE_LEDGER_INDEX: &str = "ledger_index";
+pub const TABLE_MILESTONE_INDEX_TO_MILESTONE: &str = "milestone_index_to_milestone";
+pub const TABLE_SNAPSHOT_INFO: &str = "snapshot_info";
+pub const TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX: &str = "solid_entry_point_to_milestone_index";
+pub const TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF: &str = "milestone_index_to_output_diff";
+pub const TABLE_ADDRESS_TO_BALANCE: &str = "address_to_balance";
+pub const TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE: &str = "milestone_index_to_unreferenced_message";
+pub const TABLE_MILESTONE_INDEX_TO_RECEIPT: &str = "milestone_index_to_receipt";
+pub const TABLE_SPENT_TO_TREASURY_OUTPUT: &str = "spent_to_treasury_output";
+pub const TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH: &str = "milestone_index_to_ledger_state_hash";
+pub const TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX: &str = "optional_index_to_pruning_index";
+
+pub(crate) const TABLES: &[&str] = &[
+    TABLE_MESSAGE_ID_TO_MESSAGE,
+    TABLE_MESSAGE_ID_TO_METADATA,
+    TABLE_MESSAGE_ID_TO_MESSAGE_ID,
+    TABLE_INDEX_TO_MESSAGE_ID,
+    TABLE_OUTPUT_ID_TO_CREATED_OUTPUT,
+    TABLE_OUTPUT_ID_TO_CONSUMED_OUTPUT,
+    TABLE_OUTPUT_ID_UNSPENT,
+    TABLE_ED25519_ADDRESS_TO_OUTPUT_ID,
+    TABLE_ED25519_ADDRESS_TO_HISTORY,
+    TABLE_RAW_INDEX_TO_MESSAGE_ID,
+    TABLE_MILESTONE_INDEX_TO_INDEXATION_ENTRY,
+    TABLE_LEDGER_INDEX,
+    TABLE_MILESTONE_INDEX_TO_MILESTONE,
+    TABLE_SNAPSHOT_INFO,
+    TABLE_SOLID_ENTRY_POINT_TO_MILESTONE_INDEX,
+    TABLE_MILESTONE_INDEX_TO_OUTPUT_DIFF,
+    TABLE_ADDRESS_TO_BALANCE,
+    TABLE_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE,
+    TABLE_MILESTONE_INDEX_TO_RECEIPT,
+    TABLE_SPENT_TO_TREASURY_OUTPUT,
+    TABLE_MILESTONE_INDEX_TO_LEDGER_STATE_HASH,
+    TABLE_OPTIONAL_INDEX_TO_PRUNING_INDEX,
+];
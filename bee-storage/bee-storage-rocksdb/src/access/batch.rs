@@ -8,8 +8,8 @@ use crate::{
 
 use bee_common::packable::Packable;
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_message::{
     address::{Address, Ed25519Address},
@@ -287,6 +287,92 @@ impl Batch<(Ed25519Address, OutputId), ()> for Storage {
     }
 }
 
+impl Batch<(Ed25519Address, AddressHistoryEntry), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (address, entry): &(Ed25519Address, AddressHistoryEntry),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(address.as_ref());
+        batch.key_buf.extend_from_slice(&entry.pack_new());
+
+        Ok(batch
+            .inner
+            .put_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_HISTORY)?, &batch.key_buf, []))
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (address, entry): &(Ed25519Address, AddressHistoryEntry),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(address.as_ref());
+        batch.key_buf.extend_from_slice(&entry.pack_new());
+
+        Ok(batch
+            .inner
+            .delete_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_HISTORY)?, &batch.key_buf))
+    }
+}
+
+impl Batch<(MilestoneIndex, IndexationEntry), ()> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        (index, entry): &(MilestoneIndex, IndexationEntry),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&index.to_be_bytes());
+        batch.key_buf.extend_from_slice(&entry.pack_new());
+
+        Ok(batch.inner.put_cf(
+            self.cf_handle(CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY)?,
+            &batch.key_buf,
+            [],
+        ))
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        (index, entry): &(MilestoneIndex, IndexationEntry),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        batch.key_buf.extend_from_slice(&index.to_be_bytes());
+        batch.key_buf.extend_from_slice(&entry.pack_new());
+
+        Ok(batch
+            .inner
+            .delete_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY)?, &batch.key_buf))
+    }
+}
+
+impl Batch<IndexationEntry, ()> for Storage {
+    fn batch_insert(&self, batch: &mut Self::Batch, entry: &IndexationEntry, (): &()) -> Result<(), Self::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        entry.pack(&mut batch.key_buf).unwrap();
+
+        Ok(batch
+            .inner
+            .put_cf(self.cf_handle(CF_RAW_INDEX_TO_MESSAGE_ID)?, &batch.key_buf, []))
+    }
+
+    fn batch_delete(&self, batch: &mut Self::Batch, entry: &IndexationEntry) -> Result<(), Self::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        entry.pack(&mut batch.key_buf).unwrap();
+
+        Ok(batch
+            .inner
+            .delete_cf(self.cf_handle(CF_RAW_INDEX_TO_MESSAGE_ID)?, &batch.key_buf))
+    }
+}
+
 impl Batch<(), LedgerIndex> for Storage {
     fn batch_insert(
         &self,
@@ -549,3 +635,75 @@ impl Batch<(bool, TreasuryOutput), ()> for Storage {
             .delete_cf(self.cf_handle(CF_SPENT_TO_TREASURY_OUTPUT)?, &batch.key_buf))
     }
 }
+
+impl Batch<MilestoneIndex, LedgerStateHash> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        index: &MilestoneIndex,
+        hash: &LedgerStateHash,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.key_buf).unwrap();
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        hash.pack(&mut batch.value_buf).unwrap();
+
+        Ok(batch.inner.put_cf(
+            self.cf_handle(CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH)?,
+            &batch.key_buf,
+            &batch.value_buf,
+        ))
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        index: &MilestoneIndex,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.key_buf).unwrap();
+
+        Ok(batch
+            .inner
+            .delete_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH)?, &batch.key_buf))
+    }
+}
+
+impl Batch<OptionalIndex, MilestoneIndex> for Storage {
+    fn batch_insert(
+        &self,
+        batch: &mut Self::Batch,
+        optional_index: &OptionalIndex,
+        index: &MilestoneIndex,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        optional_index.pack(&mut batch.key_buf).unwrap();
+        batch.value_buf.clear();
+        // Packing to bytes can't fail.
+        index.pack(&mut batch.value_buf).unwrap();
+
+        Ok(batch.inner.put_cf(
+            self.cf_handle(CF_OPTIONAL_INDEX_TO_PRUNING_INDEX)?,
+            &batch.key_buf,
+            &batch.value_buf,
+        ))
+    }
+
+    fn batch_delete(
+        &self,
+        batch: &mut Self::Batch,
+        optional_index: &OptionalIndex,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        batch.key_buf.clear();
+        // Packing to bytes can't fail.
+        optional_index.pack(&mut batch.key_buf).unwrap();
+
+        Ok(batch
+            .inner
+            .delete_cf(self.cf_handle(CF_OPTIONAL_INDEX_TO_PRUNING_INDEX)?, &batch.key_buf))
+    }
+}
@@ -8,8 +8,8 @@ use crate::{
 
 use bee_common::packable::Packable;
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_message::{
     address::{Address, Ed25519Address},
@@ -108,6 +108,45 @@ impl Delete<(Ed25519Address, OutputId), ()> for Storage {
     }
 }
 
+#[async_trait::async_trait]
+impl Delete<(Ed25519Address, AddressHistoryEntry), ()> for Storage {
+    async fn delete(
+        &self,
+        (address, entry): &(Ed25519Address, AddressHistoryEntry),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = address.as_ref().to_vec();
+        key.extend_from_slice(&entry.pack_new());
+
+        Ok(self
+            .inner
+            .delete_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_HISTORY)?, key)?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Delete<IndexationEntry, ()> for Storage {
+    async fn delete(&self, entry: &IndexationEntry) -> Result<(), <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .delete_cf(self.cf_handle(CF_RAW_INDEX_TO_MESSAGE_ID)?, entry.pack_new())?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Delete<(MilestoneIndex, IndexationEntry), ()> for Storage {
+    async fn delete(
+        &self,
+        (index, entry): &(MilestoneIndex, IndexationEntry),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = index.to_be_bytes().to_vec();
+        key.extend_from_slice(&entry.pack_new());
+
+        Ok(self
+            .inner
+            .delete_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY)?, key)?)
+    }
+}
+
 #[async_trait::async_trait]
 impl Delete<(), LedgerIndex> for Storage {
     async fn delete(&self, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
@@ -201,3 +240,23 @@ impl Delete<(bool, TreasuryOutput), ()> for Storage {
             .delete_cf(self.cf_handle(CF_SPENT_TO_TREASURY_OUTPUT)?, key)?)
     }
 }
+
+#[async_trait::async_trait]
+impl Delete<MilestoneIndex, LedgerStateHash> for Storage {
+    async fn delete(&self, index: &MilestoneIndex) -> Result<(), <Self as StorageBackend>::Error> {
+        Ok(self.inner.delete_cf(
+            self.cf_handle(CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH)?,
+            index.pack_new(),
+        )?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Delete<OptionalIndex, MilestoneIndex> for Storage {
+    async fn delete(&self, optional_index: &OptionalIndex) -> Result<(), <Self as StorageBackend>::Error> {
+        Ok(self.inner.delete_cf(
+            self.cf_handle(CF_OPTIONAL_INDEX_TO_PRUNING_INDEX)?,
+            optional_index.pack_new(),
+        )?)
+    }
+}
@@ -8,8 +8,8 @@ use crate::{
 
 use bee_common::packable::Packable;
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_message::{
     address::{Address, Ed25519Address},
@@ -118,6 +118,48 @@ impl Exist<(Ed25519Address, OutputId), ()> for Storage {
     }
 }
 
+#[async_trait::async_trait]
+impl Exist<(Ed25519Address, AddressHistoryEntry), ()> for Storage {
+    async fn exist(
+        &self,
+        (address, entry): &(Ed25519Address, AddressHistoryEntry),
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = address.as_ref().to_vec();
+        key.extend_from_slice(&entry.pack_new());
+
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_HISTORY)?, key)?
+            .is_some())
+    }
+}
+
+#[async_trait::async_trait]
+impl Exist<IndexationEntry, ()> for Storage {
+    async fn exist(&self, entry: &IndexationEntry) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_RAW_INDEX_TO_MESSAGE_ID)?, entry.pack_new())?
+            .is_some())
+    }
+}
+
+#[async_trait::async_trait]
+impl Exist<(MilestoneIndex, IndexationEntry), ()> for Storage {
+    async fn exist(
+        &self,
+        (index, entry): &(MilestoneIndex, IndexationEntry),
+    ) -> Result<bool, <Self as StorageBackend>::Error> {
+        let mut key = index.to_be_bytes().to_vec();
+        key.extend_from_slice(&entry.pack_new());
+
+        Ok(self
+            .inner
+            .get_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY)?, key)?
+            .is_some())
+    }
+}
+
 #[async_trait::async_trait]
 impl Exist<(), LedgerIndex> for Storage {
     async fn exist(&self, (): &()) -> Result<bool, <Self as StorageBackend>::Error> {
@@ -219,3 +261,29 @@ impl Exist<(bool, TreasuryOutput), ()> for Storage {
             .is_some())
     }
 }
+
+#[async_trait::async_trait]
+impl Exist<MilestoneIndex, LedgerStateHash> for Storage {
+    async fn exist(&self, index: &MilestoneIndex) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(
+                self.cf_handle(CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH)?,
+                index.pack_new(),
+            )?
+            .is_some())
+    }
+}
+
+#[async_trait::async_trait]
+impl Exist<OptionalIndex, MilestoneIndex> for Storage {
+    async fn exist(&self, optional_index: &OptionalIndex) -> Result<bool, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(
+                self.cf_handle(CF_OPTIONAL_INDEX_TO_PRUNING_INDEX)?,
+                optional_index.pack_new(),
+            )?
+            .is_some())
+    }
+}
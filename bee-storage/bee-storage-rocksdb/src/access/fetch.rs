@@ -9,7 +9,9 @@ use crate::{
 
 use bee_common::packable::Packable;
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
+    snapshot::SnapshotInfo, AddressHistoryEntry, AddressHistoryPage, Balance, ConsumedOutput, CreatedOutput,
+    IndexationEntry, IndexationPage, IndexationPageEntries, LedgerIndex, LedgerStateHash, OptionalIndex, OutputDiff,
+    Receipt, TreasuryOutput,
 };
 use bee_message::{
     address::{Address, Ed25519Address, ED25519_ADDRESS_LENGTH},
@@ -23,6 +25,8 @@ use bee_tangle::{
     metadata::MessageMetadata, solid_entry_point::SolidEntryPoint, unreferenced_message::UnreferencedMessage,
 };
 
+use rocksdb::{Direction, IteratorMode};
+
 use std::convert::{TryFrom, TryInto};
 
 #[async_trait::async_trait]
@@ -133,6 +137,100 @@ impl Fetch<Ed25519Address, Vec<OutputId>> for Storage {
     }
 }
 
+#[async_trait::async_trait]
+impl Fetch<AddressHistoryPage, Vec<AddressHistoryEntry>> for Storage {
+    async fn fetch(
+        &self,
+        page: &AddressHistoryPage,
+    ) -> Result<Option<Vec<AddressHistoryEntry>>, <Self as StorageBackend>::Error> {
+        let address = page.address().as_ref();
+
+        Ok(Some(
+            self.inner
+                .iterator_cf(
+                    self.cf_handle(CF_ED25519_ADDRESS_TO_HISTORY)?,
+                    IteratorMode::From(&page.seek_key(), Direction::Forward),
+                )
+                .take_while(|(key, _)| key.starts_with(address))
+                .skip(page.skip())
+                .take(page.limit())
+                .map(|(key, _)| {
+                    let (_, mut entry) = key.split_at(ED25519_ADDRESS_LENGTH);
+                    // Unpacking from storage is fine.
+                    AddressHistoryEntry::unpack_unchecked(&mut entry).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl Fetch<IndexationPage, IndexationPageEntries> for Storage {
+    async fn fetch(
+        &self,
+        page: &IndexationPage,
+    ) -> Result<Option<IndexationPageEntries>, <Self as StorageBackend>::Error> {
+        // Entries of a prefix span several indexes, so they are looked up in milestone order instead.
+        let entries = if page.is_prefix() {
+            page.select(
+                self.inner
+                    .iterator_cf(
+                        self.cf_handle(CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY)?,
+                        IteratorMode::From(&page.seek_key(), Direction::Forward),
+                    )
+                    .map(|(key, _)| {
+                        let (_, mut entry) = key.split_at(std::mem::size_of::<MilestoneIndex>());
+                        // Unpacking from storage is fine.
+                        IndexationEntry::unpack_unchecked(&mut entry).unwrap()
+                    }),
+            )
+        } else {
+            let index_key = page.index_key();
+
+            page.select(
+                self.inner
+                    .iterator_cf(
+                        self.cf_handle(CF_RAW_INDEX_TO_MESSAGE_ID)?,
+                        IteratorMode::From(&page.seek_key(), Direction::Forward),
+                    )
+                    .take_while(|(key, _)| key.starts_with(&index_key))
+                    .map(|(key, _)| {
+                        // Unpacking from storage is fine.
+                        IndexationEntry::unpack_unchecked(&mut key.as_ref()).unwrap()
+                    }),
+            )
+        };
+
+        Ok(Some(entries))
+    }
+}
+
+#[async_trait::async_trait]
+impl Fetch<MilestoneIndex, Vec<IndexationEntry>> for Storage {
+    async fn fetch(
+        &self,
+        index: &MilestoneIndex,
+    ) -> Result<Option<Vec<IndexationEntry>>, <Self as StorageBackend>::Error> {
+        let prefix = index.to_be_bytes();
+
+        // Without a prefix extractor, a prefix iterator doesn't stop at the end of the prefix.
+        Ok(Some(
+            self.inner
+                .iterator_cf(
+                    self.cf_handle(CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY)?,
+                    IteratorMode::From(&prefix, Direction::Forward),
+                )
+                .take_while(|(key, _)| key.starts_with(&prefix))
+                .map(|(key, _)| {
+                    let (_, mut entry) = key.split_at(std::mem::size_of::<MilestoneIndex>());
+                    // Unpacking from storage is fine.
+                    IndexationEntry::unpack_unchecked(&mut entry).unwrap()
+                })
+                .collect(),
+        ))
+    }
+}
+
 #[async_trait::async_trait]
 impl Fetch<(), LedgerIndex> for Storage {
     async fn fetch(&self, (): &()) -> Result<Option<LedgerIndex>, <Self as StorageBackend>::Error> {
@@ -256,3 +354,63 @@ impl Fetch<bool, Vec<TreasuryOutput>> for Storage {
         ))
     }
 }
+
+#[async_trait::async_trait]
+impl Fetch<MilestoneIndex, LedgerStateHash> for Storage {
+    async fn fetch(&self, index: &MilestoneIndex) -> Result<Option<LedgerStateHash>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(
+                self.cf_handle(CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH)?,
+                index.pack_new(),
+            )?
+            // Unpacking from storage is fine.
+            .map(|v| LedgerStateHash::unpack_unchecked(&mut v.as_slice()).unwrap()))
+    }
+}
+
+#[async_trait::async_trait]
+impl Fetch<OptionalIndex, MilestoneIndex> for Storage {
+    async fn fetch(
+        &self,
+        optional_index: &OptionalIndex,
+    ) -> Result<Option<MilestoneIndex>, <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .get_cf(
+                self.cf_handle(CF_OPTIONAL_INDEX_TO_PRUNING_INDEX)?,
+                optional_index.pack_new(),
+            )?
+            // Unpacking from storage is fine.
+            .map(|v| MilestoneIndex::unpack_unchecked(&mut v.as_slice()).unwrap()))
+    }
+}
+
+/// Implements the fetch of the values of several keys in a single multi-get, returned in the order of the keys.
+macro_rules! impl_multi_fetch {
+    ($key:ty, $value:ty, $cf:expr) => {
+        #[async_trait::async_trait]
+        impl Fetch<Vec<$key>, Vec<Option<$value>>> for Storage {
+            async fn fetch(
+                &self,
+                keys: &Vec<$key>,
+            ) -> Result<Option<Vec<Option<$value>>>, <Self as StorageBackend>::Error> {
+                let cf = self.cf_handle($cf)?;
+
+                Ok(Some(
+                    self.inner
+                        .multi_get_cf(keys.iter().map(|key| (cf, key.pack_new())))?
+                        .into_iter()
+                        // Missing keys have an empty value, which no stored value has.
+                        // Unpacking from storage is fine.
+                        .map(|v| (!v.is_empty()).then(|| <$value>::unpack_unchecked(&mut v.as_slice()).unwrap()))
+                        .collect(),
+                ))
+            }
+        }
+    };
+}
+
+impl_multi_fetch!(MessageId, Message, CF_MESSAGE_ID_TO_MESSAGE);
+impl_multi_fetch!(OutputId, CreatedOutput, CF_OUTPUT_ID_TO_CREATED_OUTPUT);
+impl_multi_fetch!(OutputId, ConsumedOutput, CF_OUTPUT_ID_TO_CONSUMED_OUTPUT);
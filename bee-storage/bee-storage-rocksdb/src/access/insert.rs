@@ -9,8 +9,8 @@ use crate::{
 
 use bee_common::packable::Packable;
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_message::{
     address::{Address, Ed25519Address},
@@ -144,6 +144,47 @@ impl Insert<(Ed25519Address, OutputId), ()> for Storage {
     }
 }
 
+#[async_trait::async_trait]
+impl Insert<(Ed25519Address, AddressHistoryEntry), ()> for Storage {
+    async fn insert(
+        &self,
+        (address, entry): &(Ed25519Address, AddressHistoryEntry),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = address.as_ref().to_vec();
+        key.extend_from_slice(&entry.pack_new());
+
+        Ok(self
+            .inner
+            .put_cf(self.cf_handle(CF_ED25519_ADDRESS_TO_HISTORY)?, key, [])?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Insert<IndexationEntry, ()> for Storage {
+    async fn insert(&self, entry: &IndexationEntry, (): &()) -> Result<(), <Self as StorageBackend>::Error> {
+        Ok(self
+            .inner
+            .put_cf(self.cf_handle(CF_RAW_INDEX_TO_MESSAGE_ID)?, entry.pack_new(), [])?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Insert<(MilestoneIndex, IndexationEntry), ()> for Storage {
+    async fn insert(
+        &self,
+        (index, entry): &(MilestoneIndex, IndexationEntry),
+        (): &(),
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        let mut key = index.to_be_bytes().to_vec();
+        key.extend_from_slice(&entry.pack_new());
+
+        Ok(self
+            .inner
+            .put_cf(self.cf_handle(CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY)?, key, [])?)
+    }
+}
+
 #[async_trait::async_trait]
 impl Insert<(), LedgerIndex> for Storage {
     async fn insert(&self, (): &(), index: &LedgerIndex) -> Result<(), <Self as StorageBackend>::Error> {
@@ -262,3 +303,33 @@ impl Insert<(bool, TreasuryOutput), ()> for Storage {
             .put_cf(self.cf_handle(CF_SPENT_TO_TREASURY_OUTPUT)?, key, [])?)
     }
 }
+
+#[async_trait::async_trait]
+impl Insert<MilestoneIndex, LedgerStateHash> for Storage {
+    async fn insert(
+        &self,
+        index: &MilestoneIndex,
+        hash: &LedgerStateHash,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        Ok(self.inner.put_cf(
+            self.cf_handle(CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH)?,
+            index.pack_new(),
+            hash.pack_new(),
+        )?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Insert<OptionalIndex, MilestoneIndex> for Storage {
+    async fn insert(
+        &self,
+        optional_index: &OptionalIndex,
+        index: &MilestoneIndex,
+    ) -> Result<(), <Self as StorageBackend>::Error> {
+        Ok(self.inner.put_cf(
+            self.cf_handle(CF_OPTIONAL_INDEX_TO_PRUNING_INDEX)?,
+            optional_index.pack_new(),
+            index.pack_new(),
+        )?)
+    }
+}
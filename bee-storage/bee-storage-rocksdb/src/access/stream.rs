@@ -9,11 +9,11 @@ use crate::{
 
 use bee_common::packable::Packable;
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_message::{
-    address::{Address, Ed25519Address},
+    address::{Address, Ed25519Address, ED25519_ADDRESS_LENGTH},
     milestone::{Milestone, MilestoneIndex},
     output::OutputId,
     payload::indexation::{PaddedIndex, INDEXATION_PADDED_INDEX_LENGTH},
@@ -326,6 +326,70 @@ impl<'a> StorageStream<'a, (bool, TreasuryOutput), ()> {
     }
 }
 
+impl<'a> StorageStream<'a, MilestoneIndex, LedgerStateHash> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (MilestoneIndex, LedgerStateHash) {
+        (
+            // Unpacking from storage is fine.
+            MilestoneIndex::unpack_unchecked(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            LedgerStateHash::unpack_unchecked(&mut value).unwrap(),
+        )
+    }
+}
+
+impl<'a> StorageStream<'a, OptionalIndex, MilestoneIndex> {
+    fn unpack_key_value(mut key: &[u8], mut value: &[u8]) -> (OptionalIndex, MilestoneIndex) {
+        (
+            // Unpacking from storage is fine.
+            OptionalIndex::unpack_unchecked(&mut key).unwrap(),
+            // Unpacking from storage is fine.
+            MilestoneIndex::unpack_unchecked(&mut value).unwrap(),
+        )
+    }
+}
+
+impl<'a> StorageStream<'a, (Ed25519Address, AddressHistoryEntry), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((Ed25519Address, AddressHistoryEntry), ()) {
+        let (mut address, mut entry) = key.split_at(ED25519_ADDRESS_LENGTH);
+
+        (
+            (
+                // Unpacking from storage is fine.
+                Ed25519Address::unpack_unchecked(&mut address).unwrap(),
+                // Unpacking from storage is fine.
+                AddressHistoryEntry::unpack_unchecked(&mut entry).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageStream<'a, IndexationEntry, ()> {
+    fn unpack_key_value(mut key: &[u8], _: &[u8]) -> (IndexationEntry, ()) {
+        (
+            // Unpacking from storage is fine.
+            IndexationEntry::unpack_unchecked(&mut key).unwrap(),
+            (),
+        )
+    }
+}
+
+impl<'a> StorageStream<'a, (MilestoneIndex, IndexationEntry), ()> {
+    fn unpack_key_value(key: &[u8], _: &[u8]) -> ((MilestoneIndex, IndexationEntry), ()) {
+        let (index, mut entry) = key.split_at(std::mem::size_of::<MilestoneIndex>());
+
+        (
+            (
+                // Unpacking from storage is fine.
+                MilestoneIndex(u32::from_be_bytes(index.try_into().unwrap())),
+                // Unpacking from storage is fine.
+                IndexationEntry::unpack_unchecked(&mut entry).unwrap(),
+            ),
+            (),
+        )
+    }
+}
+
 impl_stream!(u8, System, CF_SYSTEM);
 impl_stream!(MessageId, Message, CF_MESSAGE_ID_TO_MESSAGE);
 impl_stream!(MessageId, MessageMetadata, CF_MESSAGE_ID_TO_METADATA);
@@ -335,6 +399,13 @@ impl_stream!(OutputId, CreatedOutput, CF_OUTPUT_ID_TO_CREATED_OUTPUT);
 impl_stream!(OutputId, ConsumedOutput, CF_OUTPUT_ID_TO_CONSUMED_OUTPUT);
 impl_stream!(Unspent, (), CF_OUTPUT_ID_UNSPENT);
 impl_stream!((Ed25519Address, OutputId), (), CF_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_stream!((Ed25519Address, AddressHistoryEntry), (), CF_ED25519_ADDRESS_TO_HISTORY);
+impl_stream!(IndexationEntry, (), CF_RAW_INDEX_TO_MESSAGE_ID);
+impl_stream!(
+    (MilestoneIndex, IndexationEntry),
+    (),
+    CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
 impl_stream!((), LedgerIndex, CF_LEDGER_INDEX);
 impl_stream!(MilestoneIndex, Milestone, CF_MILESTONE_INDEX_TO_MILESTONE);
 impl_stream!((), SnapshotInfo, CF_SNAPSHOT_INFO);
@@ -348,3 +419,5 @@ impl_stream!(
 );
 impl_stream!((MilestoneIndex, Receipt), (), CF_MILESTONE_INDEX_TO_RECEIPT);
 impl_stream!((bool, TreasuryOutput), (), CF_SPENT_TO_TREASURY_OUTPUT);
+impl_stream!(MilestoneIndex, LedgerStateHash, CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH);
+impl_stream!(OptionalIndex, MilestoneIndex, CF_OPTIONAL_INDEX_TO_PRUNING_INDEX);
@@ -7,8 +7,8 @@ use crate::{
 };
 
 use bee_ledger::types::{
-    snapshot::SnapshotInfo, Balance, ConsumedOutput, CreatedOutput, LedgerIndex, OutputDiff, Receipt, TreasuryOutput,
-    Unspent,
+    snapshot::SnapshotInfo, AddressHistoryEntry, Balance, ConsumedOutput, CreatedOutput, IndexationEntry, LedgerIndex,
+    LedgerStateHash, OptionalIndex, OutputDiff, Receipt, TreasuryOutput, Unspent,
 };
 use bee_message::{
     address::{Address, Ed25519Address},
@@ -73,6 +73,13 @@ impl_truncate!(OutputId, CreatedOutput, CF_OUTPUT_ID_TO_CREATED_OUTPUT);
 impl_truncate!(OutputId, ConsumedOutput, CF_OUTPUT_ID_TO_CONSUMED_OUTPUT);
 impl_truncate!(Unspent, (), CF_OUTPUT_ID_UNSPENT);
 impl_truncate!((Ed25519Address, OutputId), (), CF_ED25519_ADDRESS_TO_OUTPUT_ID);
+impl_truncate!((Ed25519Address, AddressHistoryEntry), (), CF_ED25519_ADDRESS_TO_HISTORY);
+impl_truncate!(IndexationEntry, (), CF_RAW_INDEX_TO_MESSAGE_ID);
+impl_truncate!(
+    (MilestoneIndex, IndexationEntry),
+    (),
+    CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY
+);
 impl_truncate!((), LedgerIndex, CF_LEDGER_INDEX);
 impl_truncate!(MilestoneIndex, Milestone, CF_MILESTONE_INDEX_TO_MILESTONE);
 impl_truncate!((), SnapshotInfo, CF_SNAPSHOT_INFO);
@@ -86,3 +93,5 @@ impl_truncate!(
 );
 impl_truncate!((MilestoneIndex, Receipt), (), CF_MILESTONE_INDEX_TO_RECEIPT);
 impl_truncate!((bool, TreasuryOutput), (), CF_SPENT_TO_TREASURY_OUTPUT);
+impl_truncate!(MilestoneIndex, LedgerStateHash, CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH);
+impl_truncate!(OptionalIndex, MilestoneIndex, CF_OPTIONAL_INDEX_TO_PRUNING_INDEX);
@@ -10,6 +10,9 @@ pub const CF_OUTPUT_ID_TO_CREATED_OUTPUT: &str = "output_id_to_created_output";
 pub const CF_OUTPUT_ID_TO_CONSUMED_OUTPUT: &str = "output_id_to_consumed_output";
 pub const CF_OUTPUT_ID_UNSPENT: &str = "output_id_unspent";
 pub const CF_ED25519_ADDRESS_TO_OUTPUT_ID: &str = "ed25519_address_to_output_id";
+pub const CF_ED25519_ADDRESS_TO_HISTORY: &str = "ed25519_address_to_history";
+pub const CF_RAW_INDEX_TO_MESSAGE_ID: &str = "raw_index_to_message_id";
+pub const CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY: &str = "milestone_index_to_indexation_entry";
 pub const CF_LEDGER_INDEX: &str = "ledger_index";
 pub const CF_MILESTONE_INDEX_TO_MILESTONE: &str = "milestone_index_to_milestone";
 pub const CF_SNAPSHOT_INFO: &str = "snapshot_info";
@@ -19,3 +22,5 @@ pub const CF_ADDRESS_TO_BALANCE: &str = "address_to_balance";
 pub const CF_MILESTONE_INDEX_TO_UNREFERENCED_MESSAGE: &str = "milestone_index_to_unreferenced_message";
 pub const CF_MILESTONE_INDEX_TO_RECEIPT: &str = "milestone_index_to_receipt";
 pub const CF_SPENT_TO_TREASURY_OUTPUT: &str = "spent_to_treasury_output";
+pub const CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH: &str = "milestone_index_to_ledger_state_hash";
+pub const CF_OPTIONAL_INDEX_TO_PRUNING_INDEX: &str = "optional_index_to_pruning_index";
@@ -14,6 +14,7 @@ pub use bee_storage::{
     health::StorageHealth,
 };
 
+use bee_ledger::types::INDEXATION_INDEX_KEY_LENGTH;
 use bee_message::{
     address::ED25519_ADDRESS_LENGTH, milestone::MilestoneIndex, payload::indexation::INDEXATION_PADDED_INDEX_LENGTH,
     MESSAGE_ID_LENGTH,
@@ -58,6 +59,18 @@ impl Storage {
         options.set_prefix_extractor(SliceTransform::create_fixed_prefix(ED25519_ADDRESS_LENGTH));
         let cf_ed25519_address_to_output_id = ColumnFamilyDescriptor::new(CF_ED25519_ADDRESS_TO_OUTPUT_ID, options);
 
+        let mut options = Options::default();
+        options.set_prefix_extractor(SliceTransform::create_fixed_prefix(ED25519_ADDRESS_LENGTH));
+        let cf_ed25519_address_to_history = ColumnFamilyDescriptor::new(CF_ED25519_ADDRESS_TO_HISTORY, options);
+
+        let mut options = Options::default();
+        options.set_prefix_extractor(SliceTransform::create_fixed_prefix(INDEXATION_INDEX_KEY_LENGTH));
+        let cf_raw_index_to_message_id = ColumnFamilyDescriptor::new(CF_RAW_INDEX_TO_MESSAGE_ID, options);
+
+        // The entries are also iterated across milestones, so there is no prefix extractor.
+        let cf_milestone_index_to_indexation_entry =
+            ColumnFamilyDescriptor::new(CF_MILESTONE_INDEX_TO_INDEXATION_ENTRY, Options::default());
+
         let cf_ledger_index = ColumnFamilyDescriptor::new(CF_LEDGER_INDEX, Options::default());
 
         let cf_milestone_index_to_milestone =
@@ -90,6 +103,12 @@ impl Storage {
         options.set_prefix_extractor(SliceTransform::create_fixed_prefix(std::mem::size_of::<bool>()));
         let cf_spent_to_treasury = ColumnFamilyDescriptor::new(CF_SPENT_TO_TREASURY_OUTPUT, options);
 
+        let cf_milestone_index_to_ledger_state_hash =
+            ColumnFamilyDescriptor::new(CF_MILESTONE_INDEX_TO_LEDGER_STATE_HASH, Options::default());
+
+        let cf_optional_index_to_pruning_index =
+            ColumnFamilyDescriptor::new(CF_OPTIONAL_INDEX_TO_PRUNING_INDEX, Options::default());
+
         let mut opts = Options::default();
         opts.create_if_missing(config.create_if_missing);
         opts.create_missing_column_families(config.create_missing_column_families);
@@ -134,6 +153,9 @@ impl Storage {
                 cf_output_id_to_consumed_output,
                 cf_output_id_unspent,
                 cf_ed25519_address_to_output_id,
+                cf_ed25519_address_to_history,
+                cf_raw_index_to_message_id,
+                cf_milestone_index_to_indexation_entry,
                 cf_ledger_index,
                 cf_milestone_index_to_milestone,
                 cf_snapshot_info,
@@ -143,6 +165,8 @@ impl Storage {
                 cf_milestone_index_to_unreferenced_message,
                 cf_milestone_index_to_receipt,
                 cf_spent_to_treasury,
+                cf_milestone_index_to_ledger_state_hash,
+                cf_optional_index_to_pruning_index,
             ],
         )?;
 
@@ -195,7 +219,10 @@ impl StorageBackend for Storage {
     }
 
     async fn shutdown(self) -> Result<(), Self::Error> {
-        self.set_health(StorageHealth::Healthy).await?;
+        // A storage that has been marked as corrupted must not be considered healthy at the next start.
+        if self.get_health().await? != Some(StorageHealth::Corrupted) {
+            self.set_health(StorageHealth::Healthy).await?;
+        }
 
         Ok(self.inner.flush()?)
     }